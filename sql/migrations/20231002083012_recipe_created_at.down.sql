@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS recipe_created_at_index;
+DROP INDEX IF EXISTS recipe_name_index;
+ALTER TABLE recipe DROP COLUMN created_at
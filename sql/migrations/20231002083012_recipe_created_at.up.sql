@@ -0,0 +1,5 @@
+-- Add up migration script here
+ALTER TABLE recipe ADD COLUMN created_at TEXT NOT NULL DEFAULT '1970-01-01 00:00:00.000';
+UPDATE recipe SET created_at = strftime('%Y-%m-%d %H:%M:%f', 'now');
+CREATE INDEX IF NOT EXISTS recipe_name_index ON recipe (name COLLATE NOCASE);
+CREATE INDEX IF NOT EXISTS recipe_created_at_index ON recipe (created_at);
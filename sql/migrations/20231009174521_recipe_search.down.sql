@@ -0,0 +1,8 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS recipe_search_ingredient_update;
+DROP TRIGGER IF EXISTS recipe_search_link_delete;
+DROP TRIGGER IF EXISTS recipe_search_link_insert;
+DROP TRIGGER IF EXISTS recipe_search_recipe_delete;
+DROP TRIGGER IF EXISTS recipe_search_recipe_update;
+DROP TRIGGER IF EXISTS recipe_search_recipe_insert;
+DROP TABLE IF EXISTS recipe_search
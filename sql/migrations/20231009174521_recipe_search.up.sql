@@ -0,0 +1,65 @@
+-- Add up migration script here
+CREATE VIRTUAL TABLE IF NOT EXISTS recipe_search USING fts5(
+    recipe_uuid UNINDEXED,
+    name,
+    method,
+    ingredients,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO recipe_search (recipe_uuid, name, method, ingredients)
+SELECT recipe.uuid, recipe.name, coalesce(recipe.method, ''), coalesce((
+    SELECT group_concat(ingredient.name, ' ') FROM recipe_ingredient
+    JOIN ingredient ON ingredient.uuid = recipe_ingredient.ingredient_uuid
+    WHERE recipe_ingredient.recipe_uuid = recipe.uuid
+), '')
+FROM recipe;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_insert AFTER INSERT ON recipe
+BEGIN
+    INSERT INTO recipe_search (recipe_uuid, name, method, ingredients)
+    VALUES (new.uuid, new.name, coalesce(new.method, ''), '');
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_update AFTER UPDATE OF name, method ON recipe
+BEGIN
+    UPDATE recipe_search SET name = new.name, method = coalesce(new.method, '')
+    WHERE recipe_uuid = new.uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_delete AFTER DELETE ON recipe
+BEGIN
+    DELETE FROM recipe_search WHERE recipe_uuid = old.uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_link_insert AFTER INSERT ON recipe_ingredient
+BEGIN
+    UPDATE recipe_search SET ingredients = coalesce((
+        SELECT group_concat(ingredient.name, ' ') FROM recipe_ingredient
+        JOIN ingredient ON ingredient.uuid = recipe_ingredient.ingredient_uuid
+        WHERE recipe_ingredient.recipe_uuid = new.recipe_uuid
+    ), '')
+    WHERE recipe_uuid = new.recipe_uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_link_delete AFTER DELETE ON recipe_ingredient
+BEGIN
+    UPDATE recipe_search SET ingredients = coalesce((
+        SELECT group_concat(ingredient.name, ' ') FROM recipe_ingredient
+        JOIN ingredient ON ingredient.uuid = recipe_ingredient.ingredient_uuid
+        WHERE recipe_ingredient.recipe_uuid = old.recipe_uuid
+    ), '')
+    WHERE recipe_uuid = old.recipe_uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_ingredient_update AFTER UPDATE OF name ON ingredient
+BEGIN
+    UPDATE recipe_search SET ingredients = coalesce((
+        SELECT group_concat(ingredient.name, ' ') FROM recipe_ingredient
+        JOIN ingredient ON ingredient.uuid = recipe_ingredient.ingredient_uuid
+        WHERE recipe_ingredient.recipe_uuid = recipe_search.recipe_uuid
+    ), '')
+    WHERE recipe_uuid IN (
+        SELECT recipe_uuid FROM recipe_ingredient WHERE ingredient_uuid = new.uuid
+    );
+END;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS recipe_image_variant_name_index;
+DROP TABLE IF EXISTS recipe_image_variant
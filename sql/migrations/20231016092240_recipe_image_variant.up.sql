@@ -0,0 +1,12 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS recipe_image_variant (
+    recipe_uuid VARCHAR(16) NOT NULL,
+    size INTEGER NOT NULL,
+    format VARCHAR(10) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    CONSTRAINT recipe_image_variant_unique unique (recipe_uuid, size, format),
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS recipe_image_variant_name_index ON recipe_image_variant (name);
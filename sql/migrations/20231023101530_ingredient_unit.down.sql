@@ -0,0 +1,2 @@
+-- Add down migration script here
+-- The original spellings are not kept, canonical units stay in place.
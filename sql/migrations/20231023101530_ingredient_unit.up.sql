@@ -0,0 +1,54 @@
+-- Add up migration script here
+-- Rewrite ingredient units to the canonical spellings understood by the
+-- `Unit` domain type. Units nobody recognises are kept in the ingredient
+-- name and counted as pieces so no information is lost.
+CREATE TEMPORARY TABLE unit_spelling AS
+SELECT uuid,
+    CASE
+        WHEN trim(unit) IN ('T', 'Tb', 'Tbs') THEN 'tbsp'
+        WHEN trim(unit) = 't' THEN 'tsp'
+        ELSE (
+            SELECT CASE
+            WHEN spelling IN ('mg', 'milligram', 'milligrams', 'milligramme', 'milligrammes') THEN 'mg'
+            WHEN spelling IN ('g', 'gr', 'grs', 'gram', 'grams', 'gramme', 'grammes') THEN 'g'
+            WHEN spelling IN ('kg', 'kgs', 'kilo', 'kilos', 'kilogram', 'kilograms', 'kilogramme', 'kilogrammes') THEN 'kg'
+            WHEN spelling IN ('oz', 'ozs', 'ounce', 'ounces') THEN 'oz'
+            WHEN spelling IN ('lb', 'lbs', 'pound', 'pounds') THEN 'lb'
+            WHEN spelling IN ('ml', 'mls', 'millilitre', 'millilitres', 'milliliter', 'milliliters') THEN 'ml'
+            WHEN spelling IN ('cl', 'centilitre', 'centilitres', 'centiliter', 'centiliters') THEN 'cl'
+            WHEN spelling IN ('dl', 'decilitre', 'decilitres', 'deciliter', 'deciliters') THEN 'dl'
+            WHEN spelling IN ('l', 'lt', 'ltr', 'ltrs', 'litre', 'litres', 'liter', 'liters') THEN 'l'
+            WHEN spelling IN ('tsp', 'tsps', 'teaspoon', 'teaspoons') THEN 'tsp'
+            WHEN spelling IN ('tbsp', 'tbsps', 'tbs', 'tbl', 'tablespoon', 'tablespoons') THEN 'tbsp'
+            WHEN spelling IN ('c', 'cup', 'cups') THEN 'cup'
+            WHEN spelling IN ('fl oz', 'floz', 'fl ozs', 'fluid ounce', 'fluid ounces') THEN 'fl oz'
+            WHEN spelling IN ('pt', 'pts', 'pint', 'pints') THEN 'pt'
+            WHEN spelling IN ('qt', 'qts', 'quart', 'quarts') THEN 'qt'
+            WHEN spelling IN ('gal', 'gals', 'gallon', 'gallons') THEN 'gal'
+            WHEN spelling IN ('', 'piece', 'pieces', 'pc', 'pcs', 'unit', 'units', 'whole', 'each', 'ea', 'x') THEN 'piece'
+            WHEN spelling IN ('clove', 'cloves') THEN 'clove'
+            WHEN spelling IN ('slice', 'slices') THEN 'slice'
+            WHEN spelling IN ('can', 'cans', 'tin', 'tins') THEN 'can'
+            WHEN spelling IN ('bunch', 'bunches') THEN 'bunch'
+            WHEN spelling IN ('pinch', 'pinches') THEN 'pinch'
+            WHEN spelling IN ('to taste', 'taste', 'tt') THEN 'to taste'
+            END
+            FROM (
+                SELECT trim(replace(replace(replace(lower(unit), '.', ' '), '  ', ' '), '  ', ' ')) AS spelling
+            )
+        )
+    END AS canonical
+FROM ingredient;
+
+UPDATE ingredient
+SET name = name || ' (' || trim(unit) || ')'
+WHERE uuid IN (SELECT uuid FROM unit_spelling WHERE canonical IS NULL);
+
+UPDATE ingredient
+SET unit = (
+    SELECT coalesce(canonical, 'piece')
+    FROM unit_spelling
+    WHERE unit_spelling.uuid = ingredient.uuid
+);
+
+DROP TABLE unit_spelling;
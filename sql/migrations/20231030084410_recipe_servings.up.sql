@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE recipe ADD COLUMN servings INTEGER NOT NULL DEFAULT 4;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS ingredient_nutrient;
+DROP INDEX IF EXISTS nutrient_name_index;
+DROP TABLE IF EXISTS nutrient;
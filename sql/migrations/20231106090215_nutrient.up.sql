@@ -0,0 +1,24 @@
+-- Add up migration script here
+-- Nutrient values are per 100 g. portion_g is the weight of one piece,
+-- density_g_ml the weight of a millilitre; both are optional.
+CREATE TABLE IF NOT EXISTS nutrient (
+    id INTEGER PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    description VARCHAR(255) NOT NULL,
+    energy_kcal DOUBLE NOT NULL,
+    protein_g DOUBLE NOT NULL,
+    fat_g DOUBLE NOT NULL,
+    carbohydrate_g DOUBLE NOT NULL,
+    fiber_g DOUBLE NOT NULL,
+    sugars_g DOUBLE NOT NULL,
+    portion_g DOUBLE,
+    density_g_ml DOUBLE
+);
+CREATE INDEX IF NOT EXISTS nutrient_name_index ON nutrient (name);
+
+CREATE TABLE IF NOT EXISTS ingredient_nutrient (
+    ingredient_uuid VARCHAR(16) PRIMARY KEY,
+    nutrient_id INTEGER NOT NULL,
+    CONSTRAINT fk_ingredient foreign key (ingredient_uuid) references ingredient(uuid) on delete cascade,
+    CONSTRAINT fk_nutrient foreign key (nutrient_id) references nutrient(id) on delete cascade
+);
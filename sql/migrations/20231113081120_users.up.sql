@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS users (
+    uuid VARCHAR(16) PRIMARY KEY,
+    username VARCHAR(32) NOT NULL COLLATE NOCASE,
+    password_hash VARCHAR(255) NOT NULL,
+    created_at TEXT NOT NULL,
+    CONSTRAINT users_username_unique unique (username)
+);
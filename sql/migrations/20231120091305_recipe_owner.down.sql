@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS recipe_owner_index;
+ALTER TABLE recipe DROP COLUMN visibility;
+ALTER TABLE recipe DROP COLUMN owner_uuid;
+ALTER TABLE users DROP COLUMN role;
@@ -0,0 +1,7 @@
+-- Add up migration script here
+ALTER TABLE users ADD COLUMN role VARCHAR(16) NOT NULL DEFAULT 'member';
+
+-- Recipes created before accounts existed have no owner and stay public.
+ALTER TABLE recipe ADD COLUMN owner_uuid VARCHAR(16);
+ALTER TABLE recipe ADD COLUMN visibility VARCHAR(16) NOT NULL DEFAULT 'public';
+CREATE INDEX recipe_owner_index ON recipe (owner_uuid);
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS recipe_tag;
+DROP TABLE IF EXISTS tag;
@@ -0,0 +1,16 @@
+-- Add up migration script here
+-- Tag names are stored normalised: trimmed, lower case, single spaces.
+CREATE TABLE IF NOT EXISTS tag (
+    id INTEGER PRIMARY KEY,
+    name VARCHAR(32) NOT NULL,
+    CONSTRAINT tag_name_unique unique (name)
+);
+
+CREATE TABLE IF NOT EXISTS recipe_tag (
+    recipe_uuid VARCHAR(16) NOT NULL,
+    tag_id INTEGER NOT NULL,
+    CONSTRAINT recipe_tag_unique unique (recipe_uuid, tag_id),
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade,
+    CONSTRAINT fk_tag foreign key (tag_id) references tag(id) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS recipe_tag_tag_index ON recipe_tag (tag_id);
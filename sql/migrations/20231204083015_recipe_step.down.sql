@@ -0,0 +1,28 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS recipe_search_step_delete;
+DROP TRIGGER IF EXISTS recipe_search_step_update;
+DROP TRIGGER IF EXISTS recipe_search_step_insert;
+DROP TRIGGER IF EXISTS recipe_search_recipe_update;
+DROP TRIGGER IF EXISTS recipe_search_recipe_insert;
+
+ALTER TABLE recipe ADD COLUMN method VARCHAR(255);
+UPDATE recipe SET method = (
+    SELECT group_concat(text, char(10)) FROM (
+        SELECT text FROM recipe_step WHERE recipe_uuid = recipe.uuid ORDER BY position
+    )
+);
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_insert AFTER INSERT ON recipe
+BEGIN
+    INSERT INTO recipe_search (recipe_uuid, name, method, ingredients)
+    VALUES (new.uuid, new.name, coalesce(new.method, ''), '');
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_update AFTER UPDATE OF name, method ON recipe
+BEGIN
+    UPDATE recipe_search SET name = new.name, method = coalesce(new.method, '')
+    WHERE recipe_uuid = new.uuid;
+END;
+
+DROP TABLE IF EXISTS recipe_step_ingredient;
+DROP TABLE IF EXISTS recipe_step
@@ -0,0 +1,73 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS recipe_step (
+    id INTEGER PRIMARY KEY,
+    recipe_uuid VARCHAR(16) NOT NULL,
+    position INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    duration_seconds INTEGER,
+    timer VARCHAR(64),
+    CONSTRAINT recipe_step_unique unique (recipe_uuid, position),
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade
+);
+
+CREATE TABLE IF NOT EXISTS recipe_step_ingredient (
+    step_id INTEGER NOT NULL,
+    ingredient_uuid VARCHAR(16) NOT NULL,
+    CONSTRAINT recipe_step_ingredient_unique unique (step_id, ingredient_uuid),
+    CONSTRAINT fk_step foreign key (step_id) references recipe_step(id) on delete cascade,
+    CONSTRAINT fk_ingredient foreign key (ingredient_uuid) references ingredient(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS recipe_step_ingredient_ingredient_index ON recipe_step_ingredient (ingredient_uuid);
+
+-- Every non blank line of a method becomes a step.
+WITH RECURSIVE split(recipe_uuid, line_number, line, rest) AS (
+    SELECT uuid, 0, '', replace(coalesce(method, ''), char(13), '') || char(10) FROM recipe
+    UNION ALL
+    SELECT recipe_uuid, line_number + 1,
+        substr(rest, 1, instr(rest, char(10)) - 1),
+        substr(rest, instr(rest, char(10)) + 1)
+    FROM split WHERE rest <> ''
+)
+INSERT INTO recipe_step (recipe_uuid, position, text)
+SELECT recipe_uuid, row_number() OVER (PARTITION BY recipe_uuid ORDER BY line_number) - 1, trim(line)
+FROM split WHERE trim(line) <> '';
+
+-- The search index now follows the steps, the triggers reading method go first.
+DROP TRIGGER IF EXISTS recipe_search_recipe_insert;
+DROP TRIGGER IF EXISTS recipe_search_recipe_update;
+ALTER TABLE recipe DROP COLUMN method;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_insert AFTER INSERT ON recipe
+BEGIN
+    INSERT INTO recipe_search (recipe_uuid, name, method, ingredients)
+    VALUES (new.uuid, new.name, '', '');
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_update AFTER UPDATE OF name ON recipe
+BEGIN
+    UPDATE recipe_search SET name = new.name WHERE recipe_uuid = new.uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_step_insert AFTER INSERT ON recipe_step
+BEGIN
+    UPDATE recipe_search SET method = coalesce((
+        SELECT group_concat(text, ' ') FROM recipe_step WHERE recipe_uuid = new.recipe_uuid
+    ), '')
+    WHERE recipe_uuid = new.recipe_uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_step_update AFTER UPDATE OF text ON recipe_step
+BEGIN
+    UPDATE recipe_search SET method = coalesce((
+        SELECT group_concat(text, ' ') FROM recipe_step WHERE recipe_uuid = new.recipe_uuid
+    ), '')
+    WHERE recipe_uuid = new.recipe_uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_step_delete AFTER DELETE ON recipe_step
+BEGIN
+    UPDATE recipe_search SET method = coalesce((
+        SELECT group_concat(text, ' ') FROM recipe_step WHERE recipe_uuid = old.recipe_uuid
+    ), '')
+    WHERE recipe_uuid = old.recipe_uuid;
+END;
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS recipe_total_minutes_index;
+ALTER TABLE recipe DROP COLUMN total_minutes;
+ALTER TABLE recipe DROP COLUMN difficulty;
+ALTER TABLE recipe DROP COLUMN cook_minutes;
+ALTER TABLE recipe DROP COLUMN prep_minutes;
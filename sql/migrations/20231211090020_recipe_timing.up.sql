@@ -0,0 +1,11 @@
+-- Add up migration script here
+ALTER TABLE recipe ADD COLUMN prep_minutes INTEGER;
+ALTER TABLE recipe ADD COLUMN cook_minutes INTEGER;
+ALTER TABLE recipe ADD COLUMN difficulty VARCHAR(16);
+
+-- Unknown when neither time is, so untimed recipes never pass a time filter.
+ALTER TABLE recipe ADD COLUMN total_minutes INTEGER GENERATED ALWAYS AS (
+    CASE WHEN prep_minutes IS NULL AND cook_minutes IS NULL THEN NULL
+    ELSE coalesce(prep_minutes, 0) + coalesce(cook_minutes, 0) END
+) VIRTUAL;
+CREATE INDEX recipe_total_minutes_index ON recipe (total_minutes);
@@ -0,0 +1,8 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS review_rating_delete;
+DROP TRIGGER IF EXISTS review_rating_update;
+DROP TRIGGER IF EXISTS review_rating_insert;
+ALTER TABLE recipe DROP COLUMN review_count;
+ALTER TABLE recipe DROP COLUMN rating_sum;
+DROP INDEX IF EXISTS review_recipe_created_at_index;
+DROP TABLE IF EXISTS review;
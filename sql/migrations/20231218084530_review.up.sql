@@ -0,0 +1,32 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS review (
+    uuid VARCHAR(16) PRIMARY KEY,
+    recipe_uuid VARCHAR(16) NOT NULL,
+    author_uuid VARCHAR(16) NOT NULL,
+    rating INTEGER NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    text TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    CONSTRAINT review_author_unique unique (recipe_uuid, author_uuid),
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS review_recipe_created_at_index ON review (recipe_uuid, created_at);
+
+-- Running totals so reading a recipe never has to aggregate its reviews.
+ALTER TABLE recipe ADD COLUMN rating_sum INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE recipe ADD COLUMN review_count INTEGER NOT NULL DEFAULT 0;
+
+CREATE TRIGGER IF NOT EXISTS review_rating_insert AFTER INSERT ON review BEGIN
+    UPDATE recipe SET rating_sum = rating_sum + new.rating, review_count = review_count + 1
+    WHERE uuid = new.recipe_uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS review_rating_update AFTER UPDATE OF rating ON review BEGIN
+    UPDATE recipe SET rating_sum = rating_sum - old.rating + new.rating
+    WHERE uuid = new.recipe_uuid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS review_rating_delete AFTER DELETE ON review BEGIN
+    UPDATE recipe SET rating_sum = rating_sum - old.rating, review_count = review_count - 1
+    WHERE uuid = old.recipe_uuid;
+END;
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS favourite_recipe_index;
+DROP TABLE IF EXISTS favourite;
+DROP INDEX IF EXISTS collection_recipe_recipe_index;
+DROP TABLE IF EXISTS collection_recipe;
+DROP TABLE IF EXISTS collection;
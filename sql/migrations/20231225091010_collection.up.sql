@@ -0,0 +1,28 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS collection (
+    uuid VARCHAR(16) PRIMARY KEY,
+    owner_uuid VARCHAR(16) NOT NULL,
+    name VARCHAR(64) NOT NULL COLLATE NOCASE,
+    created_at TEXT NOT NULL,
+    CONSTRAINT collection_name_unique unique (owner_uuid, name)
+);
+
+-- Recipes of a collection are listed by increasing position.
+CREATE TABLE IF NOT EXISTS collection_recipe (
+    collection_uuid VARCHAR(16) NOT NULL,
+    recipe_uuid VARCHAR(16) NOT NULL,
+    position INTEGER NOT NULL,
+    CONSTRAINT collection_recipe_unique unique (collection_uuid, recipe_uuid),
+    CONSTRAINT fk_collection foreign key (collection_uuid) references collection(uuid) on delete cascade,
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS collection_recipe_recipe_index ON collection_recipe (recipe_uuid);
+
+CREATE TABLE IF NOT EXISTS favourite (
+    user_uuid VARCHAR(16) NOT NULL,
+    recipe_uuid VARCHAR(16) NOT NULL,
+    created_at TEXT NOT NULL,
+    CONSTRAINT favourite_unique unique (user_uuid, recipe_uuid),
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS favourite_recipe_index ON favourite (recipe_uuid);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS meal_plan_entry_recipe_index;
+DROP INDEX IF EXISTS meal_plan_entry_owner_date_index;
+DROP TABLE IF EXISTS meal_plan_entry;
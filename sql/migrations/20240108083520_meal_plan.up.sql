@@ -0,0 +1,14 @@
+-- Add up migration script here
+-- Dates are stored as `YYYY-MM-DD` so they sort chronologically.
+CREATE TABLE IF NOT EXISTS meal_plan_entry (
+    uuid VARCHAR(16) PRIMARY KEY,
+    owner_uuid VARCHAR(16) NOT NULL,
+    plan_date VARCHAR(10) NOT NULL,
+    slot VARCHAR(16) NOT NULL,
+    recipe_uuid VARCHAR(16) NOT NULL,
+    servings INTEGER,
+    created_at TEXT NOT NULL,
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS meal_plan_entry_owner_date_index ON meal_plan_entry (owner_uuid, plan_date);
+CREATE INDEX IF NOT EXISTS meal_plan_entry_recipe_index ON meal_plan_entry (recipe_uuid);
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS shopping_list_item_list_index;
+DROP TABLE IF EXISTS shopping_list_item;
+DROP INDEX IF EXISTS shopping_list_owner_index;
+DROP TABLE IF EXISTS shopping_list;
@@ -0,0 +1,24 @@
+-- Add up migration script here
+-- Lists keep their own copy of the merged ingredients, editing or deleting
+-- a recipe afterwards does not change them.
+CREATE TABLE IF NOT EXISTS shopping_list (
+    uuid VARCHAR(16) PRIMARY KEY,
+    owner_uuid VARCHAR(16) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    created_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS shopping_list_owner_index ON shopping_list (owner_uuid);
+
+-- Items of a list are listed by increasing position.
+CREATE TABLE IF NOT EXISTS shopping_list_item (
+    uuid VARCHAR(16) PRIMARY KEY,
+    list_uuid VARCHAR(16) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    amount REAL NOT NULL,
+    unit VARCHAR(16) NOT NULL,
+    aisle VARCHAR(16) NOT NULL,
+    checked BOOLEAN NOT NULL DEFAULT 0,
+    position INTEGER NOT NULL,
+    CONSTRAINT fk_list foreign key (list_uuid) references shopping_list(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS shopping_list_item_list_index ON shopping_list_item (list_uuid);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS cooking_history_recipe_index;
+DROP INDEX IF EXISTS cooking_history_user_index;
+DROP TABLE IF EXISTS cooking_history;
+DROP INDEX IF EXISTS pantry_item_owner_index;
+DROP TABLE IF EXISTS pantry_item;
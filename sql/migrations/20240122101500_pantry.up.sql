@@ -0,0 +1,23 @@
+-- Add up migration script here
+-- Expiry dates are stored as `YYYY-MM-DD` so they sort chronologically.
+CREATE TABLE IF NOT EXISTS pantry_item (
+    uuid VARCHAR(16) PRIMARY KEY,
+    owner_uuid VARCHAR(16) NOT NULL,
+    name VARCHAR(128) NOT NULL,
+    amount REAL NOT NULL,
+    unit VARCHAR(16) NOT NULL,
+    expires_on VARCHAR(10),
+    created_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS pantry_item_owner_index ON pantry_item (owner_uuid);
+
+CREATE TABLE IF NOT EXISTS cooking_history (
+    uuid VARCHAR(16) PRIMARY KEY,
+    user_uuid VARCHAR(16) NOT NULL,
+    recipe_uuid VARCHAR(16) NOT NULL,
+    servings INTEGER NOT NULL,
+    cooked_at TEXT NOT NULL,
+    CONSTRAINT fk_recipe foreign key (recipe_uuid) references recipe(uuid) on delete cascade
+);
+CREATE INDEX IF NOT EXISTS cooking_history_user_index ON cooking_history (user_uuid, cooked_at);
+CREATE INDEX IF NOT EXISTS cooking_history_recipe_index ON cooking_history (recipe_uuid);
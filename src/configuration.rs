@@ -8,6 +8,20 @@ use notify::{
     Config, Event, RecommendedWatcher, RecursiveMode, Watcher,
 };
 
+const DEFAULT_IMAGE_MAX_SIZE: usize = 5 * 1024 * 1024;
+const DEFAULT_IMAGE_SIZES: [u32; 3] = [128, 512, 1024];
+const DEFAULT_IMAGE_QUALITY: u8 = 80;
+const DEFAULT_TOKEN_TTL: u64 = 7 * 24 * 60 * 60;
+
+/// How the caller of a request is identified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentitySource {
+    /// Session tokens issued by the login endpoint.
+    Token,
+    /// `X-User-Id` and `X-User-Role` headers set by a trusted gateway.
+    Header,
+}
+
 pub struct Configuration {
     configuration: ini::Ini,
     watcher: UnboundedReceiver<notify::Result<Event>>,
@@ -52,6 +66,66 @@ impl Configuration {
             .expect("Invalid migrations path")
     }
 
+    pub(crate) fn image_base_path(&self) -> &str {
+        self.configuration
+            .get_from(Some("IMAGE_SERVICE"), "base_path")
+            .expect("Invalid image base path")
+    }
+
+    pub(crate) fn image_max_size(&self) -> usize {
+        self.configuration
+            .get_from(Some("IMAGE_SERVICE"), "max_size")
+            .map(|size| size.parse::<usize>().expect("Invalid image max size"))
+            .unwrap_or(DEFAULT_IMAGE_MAX_SIZE)
+    }
+
+    pub(crate) fn image_sizes(&self) -> Vec<u32> {
+        self.configuration
+            .get_from(Some("IMAGE_SERVICE"), "sizes")
+            .map(|sizes| {
+                sizes
+                    .split(',')
+                    .map(|size| size.trim().parse::<u32>().expect("Invalid image size"))
+                    .collect()
+            })
+            .unwrap_or_else(|| DEFAULT_IMAGE_SIZES.to_vec())
+    }
+
+    pub(crate) fn image_quality(&self) -> u8 {
+        self.configuration
+            .get_from(Some("IMAGE_SERVICE"), "quality")
+            .map(|quality| quality.parse::<u8>().expect("Invalid image quality"))
+            .unwrap_or(DEFAULT_IMAGE_QUALITY)
+    }
+
+    /// CSV the nutrient database is seeded from on startup, if any.
+    pub(crate) fn nutrient_seed_path(&self) -> Option<&str> {
+        self.configuration.get_from(Some("NUTRITION"), "seed_path")
+    }
+
+    /// Key session tokens are signed with.
+    pub(crate) fn auth_secret(&self) -> Option<&str> {
+        self.configuration
+            .get_from(Some("AUTH"), "secret")
+            .filter(|secret| !secret.is_empty())
+    }
+
+    /// Lifetime of session tokens in seconds.
+    pub(crate) fn token_ttl(&self) -> u64 {
+        self.configuration
+            .get_from(Some("AUTH"), "token_ttl")
+            .map(|ttl| ttl.parse::<u64>().expect("Invalid token ttl"))
+            .unwrap_or(DEFAULT_TOKEN_TTL)
+    }
+
+    pub(crate) fn identity_source(&self) -> IdentitySource {
+        match self.configuration.get_from(Some("AUTH"), "identity") {
+            None | Some("token") => IdentitySource::Token,
+            Some("header") => IdentitySource::Header,
+            Some(_) => panic!("Invalid identity source"),
+        }
+    }
+
     pub(crate) fn address(&self) -> ([u8; 4], u16) {
         let address: Vec<u8> = self
             .configuration
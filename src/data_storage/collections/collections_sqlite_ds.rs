@@ -0,0 +1,381 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, Sqlite, SqlitePool, Transaction};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::services::collections::{
+    domain::collection::Collection,
+    ports::outgoing::{
+        collection_port::{CollectionError, CollectionPort},
+        favourite_port::{FavouriteError, FavouritePort},
+    },
+};
+
+// SQLITE_CONSTRAINT_UNIQUE
+const UNIQUE_VIOLATION_CODE: &str = "2067";
+
+fn has_code(error: &sqlx::Error, code: &str) -> bool {
+    match error {
+        sqlx::Error::Database(e) => e.code().is_some_and(|c| c.as_ref() == code),
+        _ => false,
+    }
+}
+
+impl From<sqlx::Error> for CollectionError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => CollectionError::CollectionNotFound,
+            _ if has_code(&value, UNIQUE_VIOLATION_CODE) => CollectionError::CollectionExists,
+            _ => {
+                info!("{}", value);
+                CollectionError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for FavouriteError {
+    fn from(value: sqlx::Error) -> Self {
+        info!("{}", value);
+        FavouriteError::InternalError
+    }
+}
+
+/// Stores the recipes of a collection, in order, after the ones it already
+/// holds. Fails with `RecipeNotFound` when one of them does not exist.
+async fn insert_recipes(
+    transaction: &mut Transaction<'_, Sqlite>,
+    collection: &str,
+    recipes: &[Uuid],
+) -> Result<(), CollectionError> {
+    if recipes.is_empty() {
+        return Ok(());
+    }
+    let mut builder = QueryBuilder::new("SELECT COUNT(*) FROM recipe WHERE uuid IN (");
+    let mut separated = builder.separated(", ");
+    for recipe in recipes {
+        separated.push_bind(recipe.to_string());
+    }
+    separated.push_unseparated(")");
+    let (existing,) = builder
+        .build_query_as::<(i64,)>()
+        .fetch_one(&mut *transaction)
+        .await?;
+    if existing as usize != recipes.iter().collect::<HashSet<&Uuid>>().len() {
+        return Err(CollectionError::RecipeNotFound);
+    }
+    let mut builder = QueryBuilder::new(
+        "INSERT INTO collection_recipe (collection_uuid, recipe_uuid, position) ",
+    );
+    builder.push_values(recipes.iter().enumerate(), |mut row, (position, recipe)| {
+        row.push_bind(collection.to_string())
+            .push_bind(recipe.to_string())
+            .push_bind(position as i64);
+    });
+    builder.build().execute(transaction).await?;
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct CollectionSqliteDS {
+    pool: SqlitePool,
+}
+
+impl CollectionSqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Recipes of each of `collections`, in order.
+    async fn recipes_of(
+        &self,
+        collections: &[String],
+    ) -> Result<HashMap<String, Vec<Uuid>>, sqlx::Error> {
+        let mut recipes: HashMap<String, Vec<Uuid>> = HashMap::new();
+        if collections.is_empty() {
+            return Ok(recipes);
+        }
+        let mut builder = QueryBuilder::new(
+            "SELECT collection_uuid, recipe_uuid FROM collection_recipe WHERE collection_uuid IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for uuid in collections {
+            separated.push_bind(uuid.clone());
+        }
+        separated.push_unseparated(") ORDER BY position");
+        let rows = builder
+            .build_query_as::<(String, String)>()
+            .fetch_all(&self.pool)
+            .await?;
+        for (collection, recipe) in rows {
+            if let Ok(recipe) = Uuid::parse_str(&recipe) {
+                recipes.entry(collection).or_default().push(recipe);
+            }
+        }
+        Ok(recipes)
+    }
+}
+
+#[async_trait]
+impl CollectionPort for CollectionSqliteDS {
+    async fn list_collections(
+        &self,
+        owner: uuid::Uuid,
+    ) -> Result<Vec<Collection>, CollectionError> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT uuid, name FROM collection WHERE owner_uuid = ? ORDER BY name",
+        )
+        .bind(owner.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        let mut recipes = self
+            .recipes_of(
+                &rows
+                    .iter()
+                    .map(|(uuid, _)| uuid.clone())
+                    .collect::<Vec<String>>(),
+            )
+            .await?;
+        rows.into_iter()
+            .map(|(uuid, name)| {
+                let collection_recipes = recipes.remove(&uuid).unwrap_or_default();
+                let uuid = Uuid::parse_str(&uuid).map_err(|_| CollectionError::InternalError)?;
+                Ok(Collection::new(uuid, owner, name).with_recipes(collection_recipes))
+            })
+            .collect()
+    }
+
+    async fn query_collection(&self, uuid: uuid::Uuid) -> Result<Collection, CollectionError> {
+        let uuid = uuid.to_string();
+        let (owner, name) = sqlx::query_as::<_, (String, String)>(
+            "SELECT owner_uuid, name FROM collection WHERE uuid = ?",
+        )
+        .bind(&uuid)
+        .fetch_one(&self.pool)
+        .await?;
+        let recipes = self
+            .recipes_of(std::slice::from_ref(&uuid))
+            .await?
+            .remove(&uuid)
+            .unwrap_or_default();
+        Ok(Collection::new(
+            Uuid::parse_str(&uuid).map_err(|_| CollectionError::InternalError)?,
+            Uuid::parse_str(&owner).map_err(|_| CollectionError::InternalError)?,
+            name,
+        )
+        .with_recipes(recipes))
+    }
+
+    async fn insert_collection(
+        &self,
+        collection: &Collection,
+    ) -> Result<Collection, CollectionError> {
+        let uuid = collection.uuid().to_string();
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO collection (uuid, owner_uuid, name, created_at) \
+            VALUES (?, ?, ?, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
+        )
+        .bind(&uuid)
+        .bind(collection.owner().to_string())
+        .bind(collection.name())
+        .execute(&mut transaction)
+        .await?;
+        insert_recipes(&mut transaction, &uuid, collection.recipes()).await?;
+        transaction.commit().await?;
+        self.query_collection(collection.uuid()).await
+    }
+
+    async fn update_collection(
+        &self,
+        collection: &Collection,
+    ) -> Result<Collection, CollectionError> {
+        let uuid = collection.uuid().to_string();
+        let mut transaction = self.pool.begin().await?;
+        let result = sqlx::query("UPDATE collection SET name = ? WHERE uuid = ?")
+            .bind(collection.name())
+            .bind(&uuid)
+            .execute(&mut transaction)
+            .await?;
+        if result.rows_affected() == 0 {
+            transaction.rollback().await?;
+            return Err(CollectionError::CollectionNotFound);
+        }
+        sqlx::query("DELETE FROM collection_recipe WHERE collection_uuid = ?")
+            .bind(&uuid)
+            .execute(&mut transaction)
+            .await?;
+        insert_recipes(&mut transaction, &uuid, collection.recipes()).await?;
+        transaction.commit().await?;
+        self.query_collection(collection.uuid()).await
+    }
+
+    async fn delete_collection(&self, uuid: uuid::Uuid) -> Result<(), CollectionError> {
+        // collection_recipe rows go with the collection, the foreign key
+        // cascades.
+        let result = sqlx::query("DELETE FROM collection WHERE uuid = ?")
+            .bind(uuid.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(CollectionError::CollectionNotFound);
+        }
+        Ok(())
+    }
+
+    async fn add_recipe(
+        &self,
+        collection: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, CollectionError> {
+        // Nothing is inserted for a recipe that does not exist.
+        let result = sqlx::query(
+            "INSERT INTO collection_recipe (collection_uuid, recipe_uuid, position) \
+            SELECT ?1, uuid, (SELECT coalesce(MAX(position) + 1, 0) FROM collection_recipe \
+            WHERE collection_uuid = ?1) FROM recipe WHERE uuid = ?2",
+        )
+        .bind(collection.to_string())
+        .bind(recipe.to_string())
+        .execute(&self.pool)
+        .await;
+        match result {
+            Err(e) if has_code(&e, UNIQUE_VIOLATION_CODE) => {
+                return Err(CollectionError::RecipeExists)
+            }
+            Ok(result) if result.rows_affected() == 0 => {
+                return Err(CollectionError::RecipeNotFound)
+            }
+            result => result?,
+        };
+        self.query_collection(collection).await
+    }
+
+    async fn remove_recipe(
+        &self,
+        collection: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, CollectionError> {
+        let result = sqlx::query(
+            "DELETE FROM collection_recipe WHERE collection_uuid = ? AND recipe_uuid = ?",
+        )
+        .bind(collection.to_string())
+        .bind(recipe.to_string())
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(CollectionError::RecipeNotFound);
+        }
+        self.query_collection(collection).await
+    }
+}
+
+#[async_trait]
+impl FavouritePort for CollectionSqliteDS {
+    async fn list_favourites(&self, user: uuid::Uuid) -> Result<Vec<uuid::Uuid>, FavouriteError> {
+        let rows = sqlx::query_scalar::<_, String>(
+            "SELECT recipe_uuid FROM favourite WHERE user_uuid = ? \
+            ORDER BY created_at DESC, recipe_uuid",
+        )
+        .bind(user.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter()
+            .map(|recipe| Uuid::parse_str(recipe).map_err(|_| FavouriteError::InternalError))
+            .collect()
+    }
+
+    async fn add_favourite(
+        &self,
+        user: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<(), FavouriteError> {
+        let exists: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM recipe WHERE uuid = ?")
+            .bind(recipe.to_string())
+            .fetch_one(&self.pool)
+            .await?;
+        if exists == 0 {
+            return Err(FavouriteError::RecipeNotFound);
+        }
+        sqlx::query(
+            "INSERT OR IGNORE INTO favourite (user_uuid, recipe_uuid, created_at) \
+            VALUES (?, ?, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
+        )
+        .bind(user.to_string())
+        .bind(recipe.to_string())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_favourite(
+        &self,
+        user: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<bool, FavouriteError> {
+        let result = sqlx::query("DELETE FROM favourite WHERE user_uuid = ? AND recipe_uuid = ?")
+            .bind(user.to_string())
+            .bind(recipe.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, stored_recipe},
+        services::recipes::domain::access::{RecipeAccess, Visibility},
+    };
+
+    #[tokio::test]
+    async fn collections_need_existing_recipes_but_no_user_row() {
+        let pool = memory_pool().await;
+        let recipe = stored_recipe(&pool, RecipeAccess::new(None, Visibility::Private)).await;
+        let storage = CollectionSqliteDS::new(pool);
+        // Owners identified by a trusted header are unknown to `users`.
+        let owner = Uuid::new_v4();
+        let missing = Uuid::new_v4();
+
+        let collection = Collection::new(Uuid::new_v4(), owner, "Winter".into());
+        assert!(matches!(
+            storage
+                .insert_collection(
+                    &collection
+                        .clone()
+                        .with_recipes(vec![recipe.uuid(), missing])
+                )
+                .await,
+            Err(CollectionError::RecipeNotFound)
+        ));
+        assert!(storage.list_collections(owner).await.unwrap().is_empty());
+        storage.insert_collection(&collection).await.unwrap();
+        let stored = storage
+            .add_recipe(collection.uuid(), recipe.uuid())
+            .await
+            .unwrap();
+        assert_eq!(stored.recipes(), &[recipe.uuid()]);
+        assert!(matches!(
+            storage.add_recipe(collection.uuid(), recipe.uuid()).await,
+            Err(CollectionError::RecipeExists)
+        ));
+        assert!(matches!(
+            storage.add_recipe(collection.uuid(), missing).await,
+            Err(CollectionError::RecipeNotFound)
+        ));
+
+        storage.add_favourite(owner, recipe.uuid()).await.unwrap();
+        storage.add_favourite(owner, recipe.uuid()).await.unwrap();
+        assert_eq!(
+            storage.list_favourites(owner).await.unwrap(),
+            [recipe.uuid()]
+        );
+        assert!(matches!(
+            storage.add_favourite(owner, missing).await,
+            Err(FavouriteError::RecipeNotFound)
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+use std::{io::ErrorKind, path::PathBuf};
+
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::services::images::{
+    domain::image::{Image, ImageName},
+    ports::outgoing::image_storage_port::{ImageStorageError, ImageStoragePort},
+};
+
+impl From<std::io::Error> for ImageStorageError {
+    fn from(value: std::io::Error) -> Self {
+        match value.kind() {
+            ErrorKind::NotFound => ImageStorageError::NotFound,
+            _ => {
+                info!("{}", value);
+                ImageStorageError::InternalError
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ImageFsDS {
+    base_path: PathBuf,
+}
+
+#[async_trait]
+impl ImageStoragePort for ImageFsDS {
+    async fn store_image(&self, image: &Image) -> Result<(), ImageStorageError> {
+        let path = self.path(image.name());
+        // Same name means same content, nothing to do.
+        if tokio::fs::try_exists(&path).await? {
+            return Ok(());
+        }
+        tokio::fs::create_dir_all(&self.base_path).await?;
+        let temporary = self.base_path.join(format!(
+            ".{}.{}",
+            image.name().as_str(),
+            uuid::Uuid::new_v4()
+        ));
+        tokio::fs::write(&temporary, image.bytes()).await?;
+        if let Err(e) = tokio::fs::rename(&temporary, &path).await {
+            let _ = tokio::fs::remove_file(&temporary).await;
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    async fn load_image(&self, name: &ImageName) -> Result<Image, ImageStorageError> {
+        let bytes = tokio::fs::read(self.path(name)).await?;
+        Ok(Image::new(name.clone(), bytes))
+    }
+
+    async fn remove_image(&self, name: &ImageName) -> Result<(), ImageStorageError> {
+        tokio::fs::remove_file(self.path(name))
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+impl ImageFsDS {
+    pub fn new(base_path: PathBuf) -> Self {
+        Self { base_path }
+    }
+
+    fn path(&self, name: &ImageName) -> PathBuf {
+        self.base_path.join(name.as_str())
+    }
+}
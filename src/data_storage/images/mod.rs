@@ -0,0 +1 @@
+pub mod images_fs_ds;
@@ -0,0 +1,193 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::services::meal_plans::{
+    domain::{plan_date::PlanDate, plan_entry::PlanEntry},
+    ports::outgoing::meal_plan_port::{MealPlanError, MealPlanPort},
+};
+
+const ENTRY_COLUMNS: &str = "uuid, owner_uuid, plan_date, slot, recipe_uuid, servings";
+
+impl From<sqlx::Error> for MealPlanError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => MealPlanError::EntryNotFound,
+            _ => {
+                info!("{}", value);
+                MealPlanError::InternalError
+            }
+        }
+    }
+}
+
+/// The `ENTRY_COLUMNS` of a row of `meal_plan_entry`.
+#[derive(Debug, sqlx::FromRow)]
+struct EntryRow {
+    uuid: String,
+    owner_uuid: String,
+    plan_date: String,
+    slot: String,
+    recipe_uuid: String,
+    servings: Option<i64>,
+}
+
+impl EntryRow {
+    /// `None` when a column holds a value the domain does not know.
+    fn into_entry(self) -> Option<PlanEntry> {
+        let entry = PlanEntry::new(
+            Uuid::parse_str(&self.uuid).ok()?,
+            Uuid::parse_str(&self.owner_uuid).ok()?,
+            self.plan_date.parse().ok()?,
+            self.slot.parse().ok()?,
+            Uuid::parse_str(&self.recipe_uuid).ok()?,
+        )
+        .with_servings(self.servings.map(|servings| servings as u32));
+        Some(entry)
+    }
+}
+
+#[derive(Clone)]
+pub struct MealPlanSqliteDS {
+    pool: SqlitePool,
+}
+
+impl MealPlanSqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MealPlanPort for MealPlanSqliteDS {
+    async fn list_entries(
+        &self,
+        owner: uuid::Uuid,
+        from: PlanDate,
+        to: PlanDate,
+    ) -> Result<Vec<PlanEntry>, MealPlanError> {
+        let rows: Vec<EntryRow> = sqlx::query_as(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM meal_plan_entry \
+            WHERE owner_uuid = ? AND plan_date BETWEEN ? AND ? \
+            ORDER BY plan_date, CASE slot WHEN 'breakfast' THEN 0 WHEN 'lunch' THEN 1 \
+            WHEN 'dinner' THEN 2 ELSE 3 END, created_at"
+        ))
+        .bind(owner.to_string())
+        .bind(from.to_string())
+        .bind(to.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
+            .map(EntryRow::into_entry)
+            .collect::<Option<Vec<PlanEntry>>>()
+            .ok_or(MealPlanError::InternalError)
+    }
+
+    async fn query_entry(&self, uuid: uuid::Uuid) -> Result<PlanEntry, MealPlanError> {
+        let row: EntryRow = sqlx::query_as(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM meal_plan_entry WHERE uuid = ?"
+        ))
+        .bind(uuid.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+        row.into_entry().ok_or(MealPlanError::InternalError)
+    }
+
+    async fn insert_entry(&self, entry: &PlanEntry) -> Result<PlanEntry, MealPlanError> {
+        // Nothing is inserted for a recipe that does not exist.
+        let result = sqlx::query(
+            "INSERT INTO meal_plan_entry (uuid, owner_uuid, plan_date, slot, recipe_uuid, \
+            servings, created_at) SELECT ?, ?, ?, ?, uuid, ?, \
+            strftime('%Y-%m-%d %H:%M:%f', 'now') FROM recipe WHERE uuid = ?",
+        )
+        .bind(entry.uuid().to_string())
+        .bind(entry.owner().to_string())
+        .bind(entry.date().to_string())
+        .bind(entry.slot().as_str())
+        .bind(entry.servings().map(i64::from))
+        .bind(entry.recipe().to_string())
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(MealPlanError::RecipeNotFound);
+        }
+        self.query_entry(entry.uuid()).await
+    }
+
+    async fn update_entry(&self, entry: &PlanEntry) -> Result<PlanEntry, MealPlanError> {
+        let result = sqlx::query(
+            "UPDATE meal_plan_entry SET plan_date = ?, slot = ?, servings = ? WHERE uuid = ?",
+        )
+        .bind(entry.date().to_string())
+        .bind(entry.slot().as_str())
+        .bind(entry.servings().map(i64::from))
+        .bind(entry.uuid().to_string())
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(MealPlanError::EntryNotFound);
+        }
+        self.query_entry(entry.uuid()).await
+    }
+
+    async fn delete_entry(&self, uuid: uuid::Uuid) -> Result<(), MealPlanError> {
+        let result = sqlx::query("DELETE FROM meal_plan_entry WHERE uuid = ?")
+            .bind(uuid.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(MealPlanError::EntryNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, stored_recipe},
+        services::{
+            meal_plans::domain::meal_slot::MealSlot,
+            recipes::domain::access::{RecipeAccess, Visibility},
+        },
+    };
+
+    fn date(date: &str) -> PlanDate {
+        date.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn entries_need_a_recipe_but_no_user_row() {
+        let pool = memory_pool().await;
+        let recipe = stored_recipe(&pool, RecipeAccess::new(None, Visibility::Private)).await;
+        let storage = MealPlanSqliteDS::new(pool);
+        // Owners identified by a trusted header are unknown to `users`.
+        let owner = Uuid::new_v4();
+        let entry = |day: &str, slot, recipe| {
+            PlanEntry::new(Uuid::new_v4(), owner, date(day), slot, recipe)
+        };
+
+        let dinner = entry("2024-01-02", MealSlot::Dinner, recipe.uuid());
+        let breakfast = entry("2024-01-02", MealSlot::Breakfast, recipe.uuid());
+        let outside = entry("2024-01-09", MealSlot::Lunch, recipe.uuid());
+        for entry in [&dinner, &breakfast, &outside] {
+            storage.insert_entry(entry).await.unwrap();
+        }
+        let listed = storage
+            .list_entries(owner, date("2024-01-01"), date("2024-01-07"))
+            .await
+            .unwrap();
+        assert_eq!(
+            listed.iter().map(PlanEntry::uuid).collect::<Vec<_>>(),
+            [breakfast.uuid(), dinner.uuid()]
+        );
+        assert!(matches!(
+            storage
+                .insert_entry(&entry("2024-01-03", MealSlot::Lunch, Uuid::new_v4()))
+                .await,
+            Err(MealPlanError::RecipeNotFound)
+        ));
+    }
+}
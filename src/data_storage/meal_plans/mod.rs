@@ -0,0 +1 @@
+pub mod meal_plans_sqlite_ds;
@@ -1 +1,62 @@
+pub mod collections;
+pub mod images;
+pub mod meal_plans;
+pub mod nutrition;
+pub mod pantry;
 pub mod recipes;
+pub mod reviews;
+pub mod shopping_lists;
+pub mod tags;
+pub mod users;
+
+/// A fresh in-memory database with every migration applied. It lives as long
+/// as its single connection, so the pool never closes it.
+#[cfg(test)]
+pub(crate) async fn memory_pool() -> sqlx::SqlitePool {
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to open in-memory database");
+    sqlx::migrate!("sql/migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run migrations");
+    pool
+}
+
+/// A recipe of one litre of water with the given access.
+#[cfg(test)]
+pub(crate) fn test_recipe(
+    access: crate::services::recipes::domain::access::RecipeAccess,
+) -> crate::services::recipes::domain::recipe::Recipe {
+    use crate::services::recipes::domain::{ingredient::Ingredient, recipe::Recipe};
+
+    let water = Ingredient::new(uuid::Uuid::new_v4(), "water".into(), 1.0, "l".into());
+    Recipe::new(
+        uuid::Uuid::new_v4(),
+        "Soup".into(),
+        String::new(),
+        vec![],
+        vec![water],
+    )
+    .with_access(access)
+}
+
+/// A [`test_recipe`] stored in `pool`.
+#[cfg(test)]
+pub(crate) async fn stored_recipe(
+    pool: &sqlx::SqlitePool,
+    access: crate::services::recipes::domain::access::RecipeAccess,
+) -> crate::services::recipes::domain::recipe::Recipe {
+    use crate::services::recipes::ports::outgoing::insert_recipe_port::InsertRecipePort;
+
+    let recipe = test_recipe(access);
+    recipes::recipes_sqlite_ds::RecipeSqliteDS::new(pool.clone())
+        .insert_recipe(recipe.clone())
+        .await
+        .expect("Failed to store the recipe");
+    recipe
+}
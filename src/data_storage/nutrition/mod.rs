@@ -0,0 +1 @@
+pub mod nutrients_sqlite_ds;
@@ -0,0 +1,247 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use tracing::info;
+
+use crate::services::nutrition::{
+    domain::nutrient::{NutrientProfile, Nutrients},
+    ports::outgoing::nutrient_port::{NutrientError, NutrientPort},
+};
+
+const NUTRIENT_COLUMNS: &str = "id, name, description, energy_kcal, protein_g, fat_g, \
+    carbohydrate_g, fiber_g, sugars_g, portion_g, density_g_ml";
+
+impl From<sqlx::Error> for NutrientError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => NutrientError::NutrientNotFound,
+            _ => {
+                info!("{}", value);
+                NutrientError::InternalError
+            }
+        }
+    }
+}
+
+/// A row of the `nutrient` table, which is also the layout of the seed CSV.
+#[derive(Debug, Deserialize, sqlx::FromRow)]
+struct NutrientRow {
+    id: i64,
+    name: String,
+    description: String,
+    energy_kcal: f64,
+    protein_g: f64,
+    fat_g: f64,
+    carbohydrate_g: f64,
+    fiber_g: f64,
+    sugars_g: f64,
+    portion_g: Option<f64>,
+    density_g_ml: Option<f64>,
+}
+
+impl From<NutrientRow> for NutrientProfile {
+    fn from(value: NutrientRow) -> Self {
+        NutrientProfile::new(
+            value.id,
+            value.name,
+            value.description,
+            Nutrients::new(
+                value.energy_kcal,
+                value.protein_g,
+                value.fat_g,
+                value.carbohydrate_g,
+                value.fiber_g,
+                value.sugars_g,
+            ),
+            value.portion_g,
+            value.density_g_ml,
+        )
+    }
+}
+
+/// Upserts every row of the CSV at `path` into the nutrient table, so the
+/// bundled database can be updated without losing manual links.
+pub async fn seed_nutrients(pool: &SqlitePool, path: &Path) -> anyhow::Result<usize> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let rows = reader
+        .deserialize::<NutrientRow>()
+        .collect::<Result<Vec<NutrientRow>, csv::Error>>()?;
+    let mut transaction = pool.begin().await?;
+    for row in rows.iter() {
+        sqlx::query(
+            "INSERT INTO nutrient (id, name, description, energy_kcal, protein_g, fat_g, \
+            carbohydrate_g, fiber_g, sugars_g, portion_g, density_g_ml) \
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+            ON CONFLICT (id) DO UPDATE SET name = excluded.name, \
+            description = excluded.description, energy_kcal = excluded.energy_kcal, \
+            protein_g = excluded.protein_g, fat_g = excluded.fat_g, \
+            carbohydrate_g = excluded.carbohydrate_g, fiber_g = excluded.fiber_g, \
+            sugars_g = excluded.sugars_g, portion_g = excluded.portion_g, \
+            density_g_ml = excluded.density_g_ml",
+        )
+        .bind(row.id)
+        .bind(row.name.trim().to_lowercase())
+        .bind(&row.description)
+        .bind(row.energy_kcal)
+        .bind(row.protein_g)
+        .bind(row.fat_g)
+        .bind(row.carbohydrate_g)
+        .bind(row.fiber_g)
+        .bind(row.sugars_g)
+        .bind(row.portion_g)
+        .bind(row.density_g_ml)
+        .execute(&mut transaction)
+        .await?;
+    }
+    transaction.commit().await?;
+    Ok(rows.len())
+}
+
+#[derive(Clone)]
+pub struct NutrientSqliteDS {
+    pool: SqlitePool,
+}
+
+impl NutrientSqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn owns_ingredient(
+        &self,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+    ) -> Result<bool, NutrientError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM recipe_ingredient WHERE recipe_uuid = ? AND ingredient_uuid = ?",
+        )
+        .bind(recipe.to_string())
+        .bind(ingredient.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count > 0)
+    }
+}
+
+#[async_trait]
+impl NutrientPort for NutrientSqliteDS {
+    async fn nutrient_by_name(&self, key: &str) -> Result<Option<NutrientProfile>, NutrientError> {
+        // Nutrient names are matched literally, never as `%` or `_` wildcards.
+        let row: Option<NutrientRow> = sqlx::query_as(&format!(
+            "SELECT {NUTRIENT_COLUMNS} FROM nutrient \
+            WHERE (' ' || ? || ' ') LIKE ('% ' || \
+            replace(replace(replace(name, '\\', '\\\\'), '%', '\\%'), '_', '\\_') || ' %') \
+            ESCAPE '\\' \
+            ORDER BY length(name) DESC, id LIMIT 1"
+        ))
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(NutrientProfile::from))
+    }
+
+    async fn linked_nutrient(
+        &self,
+        ingredient: uuid::Uuid,
+    ) -> Result<Option<NutrientProfile>, NutrientError> {
+        let row: Option<NutrientRow> = sqlx::query_as(&format!(
+            "SELECT {NUTRIENT_COLUMNS} FROM nutrient \
+            WHERE id = (SELECT nutrient_id FROM ingredient_nutrient WHERE ingredient_uuid = ?)"
+        ))
+        .bind(ingredient.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(NutrientProfile::from))
+    }
+
+    async fn link_nutrient(
+        &self,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+        nutrient: i64,
+    ) -> Result<NutrientProfile, NutrientError> {
+        if !self.owns_ingredient(recipe, ingredient).await? {
+            return Err(NutrientError::IngredientNotFound);
+        }
+        let row: NutrientRow = sqlx::query_as(&format!(
+            "SELECT {NUTRIENT_COLUMNS} FROM nutrient WHERE id = ?"
+        ))
+        .bind(nutrient)
+        .fetch_one(&self.pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO ingredient_nutrient (ingredient_uuid, nutrient_id) VALUES (?, ?) \
+            ON CONFLICT (ingredient_uuid) DO UPDATE SET nutrient_id = excluded.nutrient_id",
+        )
+        .bind(ingredient.to_string())
+        .bind(nutrient)
+        .execute(&self.pool)
+        .await?;
+        Ok(row.into())
+    }
+
+    async fn unlink_nutrient(
+        &self,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+    ) -> Result<(), NutrientError> {
+        if !self.owns_ingredient(recipe, ingredient).await? {
+            return Err(NutrientError::IngredientNotFound);
+        }
+        sqlx::query("DELETE FROM ingredient_nutrient WHERE ingredient_uuid = ?")
+            .bind(ingredient.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_storage::memory_pool;
+
+    #[tokio::test]
+    async fn names_match_whole_words_and_never_as_wildcards() {
+        let pool = memory_pool().await;
+        let count = seed_nutrients(&pool, Path::new("resources/nutrients.csv"))
+            .await
+            .unwrap();
+        assert!(count > 0);
+        sqlx::query(
+            "INSERT INTO nutrient (id, name, description, energy_kcal, protein_g, fat_g, \
+            carbohydrate_g, fiber_g, sugars_g) VALUES (1000, 'b_tt%', 'Wildcards', 0, 0, 0, 0, 0, 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let nutrients = NutrientSqliteDS::new(pool);
+
+        let bread_flour = nutrients
+            .nutrient_by_name("strong bread flour")
+            .await
+            .unwrap();
+        assert_eq!(bread_flour.unwrap().name(), "bread flour");
+        assert!(nutrients
+            .nutrient_by_name("batter")
+            .await
+            .unwrap()
+            .is_none());
+        let wildcards = nutrients.nutrient_by_name("b_tt%").await.unwrap();
+        assert_eq!(wildcards.unwrap().name(), "b_tt%");
+        assert!(nutrients
+            .nutrient_by_name("flourless")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_seed_files_are_an_error() {
+        let pool = memory_pool().await;
+        let result = seed_nutrients(&pool, Path::new("resources/missing.csv")).await;
+        assert!(result.is_err());
+    }
+}
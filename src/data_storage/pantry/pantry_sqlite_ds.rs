@@ -0,0 +1,311 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::services::pantry::{
+    domain::{cooking_entry::CookingEntry, pantry_item::PantryItem, stock::Deduction},
+    ports::outgoing::{
+        cooking_port::{CookingError, CookingPort},
+        pantry_port::{PantryError, PantryPort},
+    },
+};
+
+const ITEM_COLUMNS: &str = "uuid, owner_uuid, name, amount, unit, expires_on";
+
+const ENTRY_COLUMNS: &str = "uuid, user_uuid, recipe_uuid, servings, cooked_at";
+
+impl From<sqlx::Error> for PantryError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => PantryError::ItemNotFound,
+            _ => {
+                info!("{}", value);
+                PantryError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for CookingError {
+    fn from(value: sqlx::Error) -> Self {
+        info!("{}", value);
+        CookingError::InternalError
+    }
+}
+
+/// The `ITEM_COLUMNS` of a row of `pantry_item`.
+#[derive(Debug, sqlx::FromRow)]
+struct ItemRow {
+    uuid: String,
+    owner_uuid: String,
+    name: String,
+    amount: f64,
+    unit: String,
+    expires_on: Option<String>,
+}
+
+impl ItemRow {
+    /// `None` when a column holds a value the domain does not know.
+    fn into_item(self) -> Option<PantryItem> {
+        let expires_on = match self.expires_on {
+            Some(date) => Some(date.parse().ok()?),
+            None => None,
+        };
+        let item = PantryItem::new(
+            Uuid::parse_str(&self.uuid).ok()?,
+            Uuid::parse_str(&self.owner_uuid).ok()?,
+            self.name,
+            self.amount,
+            self.unit,
+        )
+        .with_expiry(expires_on);
+        Some(item)
+    }
+}
+
+/// The `ENTRY_COLUMNS` of a row of `cooking_history`.
+#[derive(Debug, sqlx::FromRow)]
+struct EntryRow {
+    uuid: String,
+    user_uuid: String,
+    recipe_uuid: String,
+    servings: i64,
+    cooked_at: String,
+}
+
+impl EntryRow {
+    /// `None` when a column holds a value the domain does not know.
+    fn into_entry(self) -> Option<CookingEntry> {
+        let entry = CookingEntry::new(
+            Uuid::parse_str(&self.uuid).ok()?,
+            Uuid::parse_str(&self.user_uuid).ok()?,
+            Uuid::parse_str(&self.recipe_uuid).ok()?,
+            u32::try_from(self.servings).ok()?,
+        )
+        .with_cooked_at(self.cooked_at);
+        Some(entry)
+    }
+}
+
+#[derive(Clone)]
+pub struct PantrySqliteDS {
+    pool: SqlitePool,
+}
+
+impl PantrySqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PantryPort for PantrySqliteDS {
+    async fn list_items(&self, owner: uuid::Uuid) -> Result<Vec<PantryItem>, PantryError> {
+        // NULL sorts first in SQLite, items without expiry go last.
+        let rows: Vec<ItemRow> = sqlx::query_as(&format!(
+            "SELECT {ITEM_COLUMNS} FROM pantry_item WHERE owner_uuid = ? \
+            ORDER BY name COLLATE NOCASE, expires_on IS NULL, expires_on, created_at"
+        ))
+        .bind(owner.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
+            .map(ItemRow::into_item)
+            .collect::<Option<Vec<PantryItem>>>()
+            .ok_or(PantryError::InternalError)
+    }
+
+    async fn query_item(&self, uuid: uuid::Uuid) -> Result<PantryItem, PantryError> {
+        let row: ItemRow = sqlx::query_as(&format!(
+            "SELECT {ITEM_COLUMNS} FROM pantry_item WHERE uuid = ?"
+        ))
+        .bind(uuid.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+        row.into_item().ok_or(PantryError::InternalError)
+    }
+
+    async fn insert_item(&self, item: &PantryItem) -> Result<PantryItem, PantryError> {
+        sqlx::query(
+            "INSERT INTO pantry_item (uuid, owner_uuid, name, amount, unit, expires_on, \
+            created_at) VALUES (?, ?, ?, ?, ?, ?, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
+        )
+        .bind(item.uuid().to_string())
+        .bind(item.owner().to_string())
+        .bind(item.name())
+        .bind(item.amount())
+        .bind(item.unit())
+        .bind(item.expires_on().map(|date| date.to_string()))
+        .execute(&self.pool)
+        .await?;
+        self.query_item(item.uuid()).await
+    }
+
+    async fn update_item(&self, item: &PantryItem) -> Result<PantryItem, PantryError> {
+        let result = sqlx::query(
+            "UPDATE pantry_item SET name = ?, amount = ?, unit = ?, expires_on = ? WHERE uuid = ?",
+        )
+        .bind(item.name())
+        .bind(item.amount())
+        .bind(item.unit())
+        .bind(item.expires_on().map(|date| date.to_string()))
+        .bind(item.uuid().to_string())
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(PantryError::ItemNotFound);
+        }
+        self.query_item(item.uuid()).await
+    }
+
+    async fn delete_item(&self, uuid: uuid::Uuid) -> Result<(), PantryError> {
+        let result = sqlx::query("DELETE FROM pantry_item WHERE uuid = ?")
+            .bind(uuid.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(PantryError::ItemNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CookingPort for PantrySqliteDS {
+    async fn record_cooking(
+        &self,
+        entry: &CookingEntry,
+        deduction: &Deduction,
+    ) -> Result<CookingEntry, CookingError> {
+        let mut transaction = self.pool.begin().await?;
+        let result = sqlx::query(
+            "INSERT INTO cooking_history (uuid, user_uuid, recipe_uuid, servings, cooked_at) \
+            SELECT ?, ?, uuid, ?, strftime('%Y-%m-%d %H:%M:%f', 'now') FROM recipe \
+            WHERE uuid = ?",
+        )
+        .bind(entry.uuid().to_string())
+        .bind(entry.user().to_string())
+        .bind(i64::from(entry.servings()))
+        .bind(entry.recipe().to_string())
+        .execute(&mut transaction)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(CookingError::RecipeNotFound);
+        }
+        // The pantry may have changed since the deduction was planned, what
+        // was taken comes off whatever the items hold now.
+        for (uuid, taken) in deduction.taken() {
+            sqlx::query("UPDATE pantry_item SET amount = amount - ? WHERE uuid = ?")
+                .bind(taken)
+                .bind(uuid.to_string())
+                .execute(&mut transaction)
+                .await?;
+            sqlx::query("DELETE FROM pantry_item WHERE uuid = ? AND amount <= 0")
+                .bind(uuid.to_string())
+                .execute(&mut transaction)
+                .await?;
+        }
+        let row: EntryRow = sqlx::query_as(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM cooking_history WHERE uuid = ?"
+        ))
+        .bind(entry.uuid().to_string())
+        .fetch_one(&mut transaction)
+        .await?;
+        transaction.commit().await?;
+        row.into_entry().ok_or(CookingError::InternalError)
+    }
+
+    async fn list_history(
+        &self,
+        user: uuid::Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<CookingEntry>, CookingError> {
+        let rows: Vec<EntryRow> = sqlx::query_as(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM cooking_history WHERE user_uuid = ? \
+            ORDER BY cooked_at DESC, uuid LIMIT ? OFFSET ?"
+        ))
+        .bind(user.to_string())
+        .bind(i64::from(limit))
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
+            .map(EntryRow::into_entry)
+            .collect::<Option<Vec<CookingEntry>>>()
+            .ok_or(CookingError::InternalError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::{
+            pantry::domain::stock::deduct,
+            recipes::{
+                domain::{ingredient::Ingredient, recipe::Recipe},
+                ports::outgoing::insert_recipe_port::InsertRecipePort,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn cooking_takes_from_what_the_pantry_holds_by_then() {
+        let pool = memory_pool().await;
+        let storage = PantrySqliteDS::new(pool.clone());
+        let milk = Ingredient::new(Uuid::new_v4(), "milk".into(), 500.0, "ml".into());
+        let recipe = Recipe::new(
+            Uuid::new_v4(),
+            "Pudding".into(),
+            String::new(),
+            vec![],
+            vec![milk],
+        );
+        RecipeSqliteDS::new(pool)
+            .insert_recipe(recipe.clone())
+            .await
+            .unwrap();
+        // Owners identified by a trusted header are unknown to `users`.
+        let owner = Uuid::new_v4();
+        let carton = PantryItem::new(Uuid::new_v4(), owner, "Milk".into(), 1.0, "l".into());
+        let glass = PantryItem::new(Uuid::new_v4(), owner, "milk".into(), 200.0, "ml".into())
+            .with_expiry(Some("2024-01-01".parse().unwrap()));
+        storage.insert_item(&carton).await.unwrap();
+        storage.insert_item(&glass).await.unwrap();
+
+        let deduction = deduct(
+            recipe.ingredients(),
+            &storage.list_items(owner).await.unwrap(),
+        );
+        // Somebody else drinks from the carton before the recipe is cooked.
+        storage
+            .update_item(&carton.clone().with_amount(0.5))
+            .await
+            .unwrap();
+        let entry = CookingEntry::new(Uuid::new_v4(), owner, recipe.uuid(), recipe.servings());
+        storage.record_cooking(&entry, &deduction).await.unwrap();
+
+        let left = storage.list_items(owner).await.unwrap();
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].uuid(), carton.uuid());
+        assert!(
+            (left[0].amount() - 0.2).abs() < 1e-9,
+            "{}",
+            left[0].amount()
+        );
+        assert_eq!(storage.list_history(owner, 0, 10).await.unwrap().len(), 1);
+
+        let unknown = CookingEntry::new(Uuid::new_v4(), owner, Uuid::new_v4(), 1);
+        assert!(matches!(
+            storage.record_cooking(&unknown, &deduction).await,
+            Err(CookingError::RecipeNotFound)
+        ));
+        assert_eq!(
+            storage.list_items(owner).await.unwrap()[0].amount(),
+            left[0].amount()
+        );
+    }
+}
@@ -1 +1,2 @@
+pub mod recipe_page_http_ds;
 pub mod recipes_sqlite_ds;
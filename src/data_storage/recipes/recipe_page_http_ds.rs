@@ -0,0 +1,233 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use reqwest::{header::LOCATION, redirect::Policy, Client, Url};
+use tokio::net::lookup_host;
+use tracing::info;
+
+use crate::services::recipes::ports::outgoing::recipe_page_port::{
+    RecipePageError, RecipePagePort,
+};
+
+/// Recipe pages are small, anything bigger is not worth parsing.
+const MAX_PAGE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Redirects followed before giving up, each target is checked like the
+/// page itself.
+const MAX_REDIRECTS: usize = 5;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Whether `ip` may be fetched from. Addresses of the server itself and of
+/// the networks it sits on are not, whoever asks for them.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, _] = ip.octets();
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                // "This network" 0.0.0.0/8, carrier-grade NAT 100.64.0.0/10,
+                // IETF protocol assignments 192.0.0.0/24 and benchmarking
+                // 198.18.0.0/15.
+                || a == 0
+                || (a == 100 && b & 0xc0 == 64)
+                || (a == 192 && b == 0 && c == 0)
+                || (a == 198 && b & 0xfe == 18))
+        }
+        IpAddr::V6(ip) => match embedded_ipv4(ip) {
+            Some(ip) => is_public(IpAddr::V4(ip)),
+            // Unique local addresses are in fc00::/7, link-local ones in
+            // fe80::/10.
+            None => {
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.segments()[0] & 0xfe00 == 0xfc00
+                    || ip.segments()[0] & 0xffc0 == 0xfe80)
+            }
+        },
+    }
+}
+
+/// The IPv4 address an IPv6 one reaches: mapped ones, NAT64 ones in
+/// 64:ff9b::/96 and 6to4 ones in 2002::/16.
+fn embedded_ipv4(ip: Ipv6Addr) -> Option<Ipv4Addr> {
+    let segments = ip.segments();
+    let from = |high: u16, low: u16| {
+        let [a, b] = high.to_be_bytes();
+        let [c, d] = low.to_be_bytes();
+        Ipv4Addr::new(a, b, c, d)
+    };
+    match segments {
+        [0x64, 0xff9b, 0, 0, 0, 0, high, low] => Some(from(high, low)),
+        [0x2002, high, low, ..] => Some(from(high, low)),
+        _ => ip.to_ipv4_mapped(),
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct RecipePageHttpDS;
+
+impl RecipePageHttpDS {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// A client for `url` that connects to the addresses checked here only,
+    /// so the host cannot resolve elsewhere in between.
+    async fn client(url: &Url) -> Result<Client, RecipePageError> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(RecipePageError::InvalidUrl);
+        }
+        let host = url.host_str().ok_or(RecipePageError::InvalidUrl)?;
+        let port = url
+            .port_or_known_default()
+            .ok_or(RecipePageError::InvalidUrl)?;
+        // A proxy would make the connection instead of the checked addresses.
+        let builder = Client::builder()
+            .no_proxy()
+            .timeout(TIMEOUT)
+            .user_agent(concat!("yaiss/", env!("CARGO_PKG_VERSION")))
+            .redirect(Policy::none());
+        let literal = host.trim_start_matches('[').trim_end_matches(']');
+        let builder = match literal.parse::<IpAddr>() {
+            Ok(ip) if is_public(ip) => builder,
+            Ok(_) => return Err(RecipePageError::PrivateAddress),
+            Err(_) => {
+                let addrs = lookup_host((host, port))
+                    .await
+                    .map_err(|e| {
+                        info!("{}", e);
+                        RecipePageError::Unreachable
+                    })?
+                    .collect::<Vec<SocketAddr>>();
+                if addrs.is_empty() {
+                    return Err(RecipePageError::Unreachable);
+                }
+                if !addrs.iter().all(|addr| is_public(addr.ip())) {
+                    return Err(RecipePageError::PrivateAddress);
+                }
+                builder.resolve_to_addrs(host, &addrs)
+            }
+        };
+        builder.build().map_err(|e| {
+            info!("{}", e);
+            RecipePageError::Unreachable
+        })
+    }
+}
+
+#[async_trait]
+impl RecipePagePort for RecipePageHttpDS {
+    async fn fetch_page(&self, url: &str) -> Result<String, RecipePageError> {
+        let mut url = Url::parse(url.trim()).map_err(|_| RecipePageError::InvalidUrl)?;
+        let mut redirects = 0;
+        let mut response = loop {
+            let response = Self::client(&url)
+                .await?
+                .get(url.clone())
+                .send()
+                .await
+                .map_err(|e| {
+                    info!("{}", e);
+                    RecipePageError::Unreachable
+                })?;
+            if !response.status().is_redirection() {
+                break response.error_for_status().map_err(|e| {
+                    info!("{}", e);
+                    RecipePageError::Unreachable
+                })?;
+            }
+            redirects += 1;
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .filter(|_| redirects <= MAX_REDIRECTS)
+                .ok_or(RecipePageError::Unreachable)?;
+            url = url
+                .join(location)
+                .map_err(|_| RecipePageError::Unreachable)?;
+        };
+        if response
+            .content_length()
+            .is_some_and(|length| length > MAX_PAGE_BYTES as u64)
+        {
+            return Err(RecipePageError::TooLarge);
+        }
+        let mut body: Vec<u8> = vec![];
+        while let Some(chunk) = response.chunk().await.map_err(|e| {
+            info!("{}", e);
+            RecipePageError::Unreachable
+        })? {
+            if body.len() + chunk.len() > MAX_PAGE_BYTES {
+                return Err(RecipePageError::TooLarge);
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pages_on_private_networks_are_refused() {
+        let pages = RecipePageHttpDS::new();
+        for url in [
+            "http://127.0.0.1:8080/recipe",
+            "http://localhost/",
+            "http://2130706433/",
+            "http://10.0.0.1/",
+            "https://192.168.1.1/",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://0.0.0.0/",
+            "http://[::1]/",
+            "http://[::ffff:127.0.0.1]/",
+            "http://[fd00::1]/",
+            "http://[fe80::1]/",
+            "http://0.1.2.3/",
+            "http://100.64.0.1/",
+            "http://100.127.255.254/",
+            "http://192.0.0.8/",
+            "http://198.18.0.1/",
+            "http://198.19.255.255/",
+            "http://[64:ff9b::7f00:1]/",
+            "http://[64:ff9b::a9fe:a9fe]/",
+            "http://[2002:7f00:1::]/",
+            "http://[2002:c0a8:101::1]/",
+        ] {
+            assert_eq!(
+                pages.fetch_page(url).await,
+                Err(RecipePageError::PrivateAddress),
+                "{url}"
+            );
+        }
+        assert_eq!(
+            pages.fetch_page("file:///etc/passwd").await,
+            Err(RecipePageError::InvalidUrl)
+        );
+    }
+
+    #[test]
+    fn internet_addresses_are_public() {
+        for ip in [
+            "93.184.216.34",
+            "100.128.0.1",
+            "198.20.0.1",
+            "2606:2800:220:1:248:1893:25c8:1946",
+            "64:ff9b::5db8:d822",
+            "2002:5db8:d822::1",
+        ] {
+            assert!(is_public(ip.parse().unwrap()), "{ip}");
+        }
+    }
+}
@@ -1,18 +1,47 @@
+use std::{collections::HashMap, time::Duration};
+
 use async_trait::async_trait;
-use futures::TryFutureExt;
-use sqlx::{Execute, QueryBuilder, SqlitePool};
+use sqlx::{Execute, QueryBuilder, Sqlite, SqlitePool, Transaction};
 use tracing::info;
 use uuid::Uuid;
 
+use crate::services::images::{
+    domain::{image::ImageName, variant::ImageVariant},
+    ports::outgoing::{
+        image_variant_port::{ImageVariantError, ImageVariantPort},
+        recipe_image_port::{RecipeImageError, RecipeImagePort},
+    },
+};
+use crate::services::library::{
+    domain::library_import::{ConflictMode, ImportSummary},
+    ports::outgoing::library_port::{LibraryError, LibraryPort},
+};
 use crate::services::recipes::{
-    domain::{ingredient::Ingredient, recipe::Recipe},
+    domain::{
+        access::RecipeAccess,
+        filter::RecipeFilter,
+        ingredient::Ingredient,
+        pagination::{PageRequest, SortBy, SortDirection},
+        rating::RatingSummary,
+        recipe::Recipe,
+        recipe_changes::RecipeChanges,
+        recipe_match::RecipeMatch,
+        search::RecipeSearchHit,
+        step::Step,
+        tag::{TagFilter, TagMatch},
+    },
     ports::outgoing::{
         delete_recipe_port::{DeleteRecipeError, DeleteRecipePort},
         insert_recipe_port::{InsertRecipeError, InsertRecipePort},
+        list_recipes_port::{ListRecipesError, ListRecipesPort},
+        match_recipe_port::{MatchRecipeError, MatchRecipePort},
         query_recipe_port::{QueryRecipeError, QueryRecipePort},
+        recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+        search_recipe_port::{SearchRecipeError, SearchRecipePort},
         update_recipe_port::{UpdateRecipeError, UpdateRecipePort},
     },
 };
+use crate::services::users::domain::caller::Caller;
 
 impl From<sqlx::Error> for QueryRecipeError {
     fn from(value: sqlx::Error) -> Self {
@@ -41,6 +70,63 @@ impl From<sqlx::Error> for DeleteRecipeError {
     }
 }
 
+impl From<sqlx::Error> for ListRecipesError {
+    fn from(value: sqlx::Error) -> Self {
+        info!("{}", value);
+        ListRecipesError::InternalError
+    }
+}
+
+impl From<sqlx::Error> for SearchRecipeError {
+    fn from(value: sqlx::Error) -> Self {
+        info!("{}", value);
+        SearchRecipeError::InternalError
+    }
+}
+
+impl From<sqlx::Error> for MatchRecipeError {
+    fn from(value: sqlx::Error) -> Self {
+        info!("{}", value);
+        MatchRecipeError::InternalError
+    }
+}
+
+impl From<sqlx::Error> for RecipeImageError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => RecipeImageError::RecordNotFound,
+            _ => {
+                info!("{}", value);
+                RecipeImageError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for ImageVariantError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => ImageVariantError::RecordNotFound,
+            _ => {
+                info!("{}", value);
+                ImageVariantError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for RecipeAccessError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => RecipeAccessError::RecordNotFound,
+            _ => {
+                info!("{}", value);
+                RecipeAccessError::InternalError
+            }
+        }
+    }
+}
+
 impl From<sqlx::Error> for InsertRecipeError {
     fn from(value: sqlx::Error) -> Self {
         info!("{}", value);
@@ -48,6 +134,403 @@ impl From<sqlx::Error> for InsertRecipeError {
     }
 }
 
+impl From<sqlx::Error> for LibraryError {
+    fn from(value: sqlx::Error) -> Self {
+        info!("{}", value);
+        LibraryError::InternalError
+    }
+}
+
+// SQLITE_CONSTRAINT_UNIQUE and SQLITE_CONSTRAINT_PRIMARYKEY
+const UNIQUE_VIOLATION_CODES: [&str; 2] = ["2067", "1555"];
+
+/// Imports only run into unique violations on ingredients, the uuids of
+/// recipes are checked beforehand.
+fn import_error(recipe: usize, error: sqlx::Error) -> LibraryError {
+    match error {
+        sqlx::Error::Database(e)
+            if e.code()
+                .is_some_and(|code| UNIQUE_VIOLATION_CODES.contains(&code.as_ref())) =>
+        {
+            LibraryError::IngredientConflict { recipe }
+        }
+        _ => error.into(),
+    }
+}
+
+fn parse_access(owner: Option<&str>, visibility: &str) -> Option<RecipeAccess> {
+    let owner = match owner {
+        Some(owner) => Some(Uuid::parse_str(owner).ok()?),
+        None => None,
+    };
+    Some(RecipeAccess::new(owner, visibility.parse().ok()?))
+}
+
+const RECIPE_COLUMNS: &str = "recipe.uuid, recipe.name, recipe.image, recipe.servings, \
+    recipe.prep_minutes, recipe.cook_minutes, recipe.difficulty, recipe.owner_uuid, \
+    recipe.visibility, recipe.rating_sum, recipe.review_count";
+
+/// The `RECIPE_COLUMNS` of a row of `recipe`.
+#[derive(Debug, sqlx::FromRow)]
+struct RecipeRow {
+    uuid: String,
+    name: String,
+    image: Option<String>,
+    servings: i64,
+    prep_minutes: Option<i64>,
+    cook_minutes: Option<i64>,
+    difficulty: Option<String>,
+    owner_uuid: Option<String>,
+    visibility: String,
+    rating_sum: i64,
+    review_count: i64,
+}
+
+impl RecipeRow {
+    /// `None` when a column holds a value the domain does not know.
+    fn into_recipe(self, steps: Vec<Step>, ingredients: Vec<Ingredient>) -> Option<Recipe> {
+        let difficulty = match self.difficulty {
+            Some(difficulty) => Some(difficulty.parse().ok()?),
+            None => None,
+        };
+        let recipe = Recipe::new(
+            Uuid::parse_str(&self.uuid).ok()?,
+            self.name,
+            self.image.unwrap_or_default(),
+            steps,
+            ingredients,
+        )
+        .with_servings(self.servings as u32)
+        .with_times(
+            self.prep_minutes.map(|minutes| minutes as u32),
+            self.cook_minutes.map(|minutes| minutes as u32),
+        )
+        .with_difficulty(difficulty)
+        .with_access(parse_access(self.owner_uuid.as_deref(), &self.visibility)?)
+        .with_rating(RatingSummary::new(
+            self.rating_sum as u32,
+            self.review_count as u32,
+        ));
+        Some(recipe)
+    }
+}
+
+/// Restricts a query on `recipe` to the rows `caller` may view, the same
+/// rules as `RecipeAccess::can_view`.
+pub(crate) fn push_viewable_by(builder: &mut QueryBuilder<'_, Sqlite>, caller: Option<Caller>) {
+    match caller {
+        Some(caller) if caller.is_admin() => {
+            builder.push("1");
+        }
+        Some(caller) => {
+            builder
+                .push("(recipe.visibility IN ('public', 'shared') OR recipe.owner_uuid = ")
+                .push_bind(caller.user().to_string())
+                .push(")");
+        }
+        None => {
+            builder.push("recipe.visibility = 'public'");
+        }
+    }
+}
+
+/// Appends ` AND` a condition restricting a query on `recipe` to the rows
+/// `filter` matches, nothing for empty filters.
+fn push_tag_filter(builder: &mut QueryBuilder<'_, Sqlite>, filter: &TagFilter) {
+    if filter.is_empty() {
+        return;
+    }
+    builder.push(
+        " AND recipe.uuid IN (SELECT recipe_uuid FROM recipe_tag \
+        JOIN tag ON tag.id = recipe_tag.tag_id WHERE tag.name IN (",
+    );
+    let mut separated = builder.separated(", ");
+    for tag in filter.tags() {
+        separated.push_bind(tag.clone());
+    }
+    separated.push_unseparated(")");
+    if filter.mode() == TagMatch::All {
+        builder
+            .push(" GROUP BY recipe_uuid HAVING COUNT(*) = ")
+            .push_bind(filter.tags().len() as i64);
+    }
+    builder.push(")");
+}
+
+/// Appends ` AND` the conditions of `filter`, like `push_tag_filter`.
+fn push_recipe_filter(builder: &mut QueryBuilder<'_, Sqlite>, filter: &RecipeFilter) {
+    push_tag_filter(builder, filter.tags());
+    if let Some(minutes) = filter.max_total_minutes() {
+        builder
+            .push(" AND recipe.total_minutes <= ")
+            .push_bind(i64::from(minutes));
+    }
+    if let Some(difficulty) = filter.difficulty() {
+        builder
+            .push(" AND recipe.difficulty = ")
+            .push_bind(difficulty.as_str());
+    }
+}
+
+/// Replaces the tags of `recipe`, creating the tags not used before.
+async fn replace_tags(
+    transaction: &mut Transaction<'_, Sqlite>,
+    recipe: &str,
+    tags: &[String],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM recipe_tag WHERE recipe_uuid = ?")
+        .bind(recipe)
+        .execute(&mut *transaction)
+        .await?;
+    for tag in tags {
+        sqlx::query("INSERT INTO tag (name) VALUES (?) ON CONFLICT (name) DO NOTHING")
+            .bind(tag)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query(
+            "INSERT INTO recipe_tag (recipe_uuid, tag_id) SELECT ?, id FROM tag WHERE name = ?",
+        )
+        .bind(recipe)
+        .bind(tag)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Replaces the steps of `recipe`. Returns the position of the first step
+/// using an ingredient `recipe` does not have, the caller then rolls back.
+async fn replace_steps(
+    transaction: &mut Transaction<'_, Sqlite>,
+    recipe: &str,
+    steps: &[Step],
+) -> Result<Option<usize>, sqlx::Error> {
+    sqlx::query("DELETE FROM recipe_step WHERE recipe_uuid = ?")
+        .bind(recipe)
+        .execute(&mut *transaction)
+        .await?;
+    for (position, step) in steps.iter().enumerate() {
+        let step_id: i64 = sqlx::query_scalar(
+            "INSERT INTO recipe_step (recipe_uuid, position, text, duration_seconds, timer) \
+            VALUES (?, ?, ?, ?, ?) RETURNING id",
+        )
+        .bind(recipe)
+        .bind(position as i64)
+        .bind(step.text())
+        .bind(step.duration().map(|duration| duration.as_secs() as i64))
+        .bind(step.timer())
+        .fetch_one(&mut *transaction)
+        .await?;
+        for ingredient in step.ingredients() {
+            let result = sqlx::query(
+                "INSERT INTO recipe_step_ingredient (step_id, ingredient_uuid) \
+                SELECT ?, ingredient_uuid FROM recipe_ingredient \
+                WHERE recipe_uuid = ? AND ingredient_uuid = ?",
+            )
+            .bind(step_id)
+            .bind(recipe)
+            .bind(ingredient.to_string())
+            .execute(&mut *transaction)
+            .await?;
+            if result.rows_affected() == 0 {
+                return Ok(Some(position));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Position in `ingredients` of the first one whose uuid is taken by an
+/// ingredient of another recipe than `recipe`.
+async fn foreign_ingredient(
+    transaction: &mut Transaction<'_, Sqlite>,
+    recipe: &str,
+    ingredients: &[Ingredient],
+) -> Result<Option<usize>, sqlx::Error> {
+    if ingredients.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = QueryBuilder::new("SELECT uuid FROM ingredient WHERE uuid IN (");
+    let mut separated = builder.separated(", ");
+    for ingredient in ingredients {
+        separated.push_bind(ingredient.uuid().to_string());
+    }
+    separated.push_unseparated(
+        ") AND uuid NOT IN (SELECT ingredient_uuid FROM recipe_ingredient WHERE recipe_uuid = ",
+    );
+    let taken = builder
+        .push_bind(recipe)
+        .push(")")
+        .build_query_as::<(String,)>()
+        .fetch_all(&mut *transaction)
+        .await?;
+    Ok(ingredients
+        .iter()
+        .position(|ingredient| taken.contains(&(ingredient.uuid().to_string(),))))
+}
+
+/// Updates `ingredient` when `recipe` already has it, adds it to `recipe`
+/// otherwise. The uuid must not belong to another recipe, see
+/// `foreign_ingredient`.
+async fn upsert_ingredient(
+    transaction: &mut Transaction<'_, Sqlite>,
+    recipe: &str,
+    ingredient: &Ingredient,
+) -> Result<(), sqlx::Error> {
+    let mut builder = QueryBuilder::new("UPDATE ingredient SET name = ");
+    let result = builder
+        .push_bind(ingredient.name())
+        .push(", amount = ")
+        .push_bind(ingredient.amount())
+        .push(", unit = ")
+        .push_bind(ingredient.unit())
+        .push(" WHERE uuid = ")
+        .push_bind(ingredient.uuid().to_string())
+        .push(" AND uuid IN (SELECT ingredient_uuid FROM recipe_ingredient WHERE recipe_uuid = ")
+        .push_bind(recipe)
+        .push(")")
+        .build()
+        .execute(&mut *transaction)
+        .await?;
+    if result.rows_affected() > 0 {
+        return Ok(());
+    }
+
+    let mut builder =
+        QueryBuilder::new("INSERT INTO ingredient (uuid, name, amount, unit) VALUES (");
+    builder
+        .push_bind(ingredient.uuid().to_string())
+        .push(", ")
+        .push_bind(ingredient.name())
+        .push(", ")
+        .push_bind(ingredient.amount())
+        .push(", ")
+        .push_bind(ingredient.unit())
+        .push(")")
+        .build()
+        .execute(&mut *transaction)
+        .await?;
+    let mut builder =
+        QueryBuilder::new("INSERT INTO recipe_ingredient (recipe_uuid, ingredient_uuid) VALUES (");
+    builder
+        .push_bind(recipe)
+        .push(", ")
+        .push_bind(ingredient.uuid().to_string())
+        .push(")")
+        .build()
+        .execute(&mut *transaction)
+        .await?;
+    Ok(())
+}
+
+/// Inserts `record` with its ingredients, tags and steps. Returns the
+/// position of a step using an unknown ingredient like `replace_steps`.
+async fn insert_recipe_rows(
+    transaction: &mut Transaction<'_, Sqlite>,
+    record: &Recipe,
+) -> Result<Option<usize>, sqlx::Error> {
+    let recipe_uuid = record.uuid().to_string();
+    let mut builder = QueryBuilder::new(
+        "INSERT INTO recipe (uuid, name, image, servings, prep_minutes, cook_minutes, difficulty, \
+        owner_uuid, visibility, created_at) VALUES (",
+    );
+    builder
+        .push_bind(recipe_uuid.clone())
+        .push(", ")
+        .push_bind(record.name())
+        .push(", ")
+        .push_bind(record.image())
+        .push(", ")
+        .push_bind(i64::from(record.servings()))
+        .push(", ")
+        .push_bind(record.prep_minutes().map(i64::from))
+        .push(", ")
+        .push_bind(record.cook_minutes().map(i64::from))
+        .push(", ")
+        .push_bind(record.difficulty().map(|difficulty| difficulty.as_str()))
+        .push(", ")
+        .push_bind(record.access().owner().map(|owner| owner.to_string()))
+        .push(", ")
+        .push_bind(record.access().visibility().as_str())
+        .push(", strftime('%Y-%m-%d %H:%M:%f', 'now')) ")
+        .build()
+        .execute(&mut *transaction)
+        .await?;
+    let mut builder = QueryBuilder::new("INSERT INTO ingredient (uuid, name, amount, unit) ");
+    builder
+        .push_values(record.ingredients().iter(), |mut q, item| {
+            q.push_bind(item.uuid().to_string())
+                .push_bind(item.name())
+                .push_bind(item.amount())
+                .push_bind(item.unit());
+        })
+        .build()
+        .execute(&mut *transaction)
+        .await?;
+    let mut builder =
+        QueryBuilder::new("INSERT INTO recipe_ingredient (recipe_uuid, ingredient_uuid ) ");
+    builder
+        .push_values(record.ingredients().iter(), |mut q, item| {
+            q.push_bind(recipe_uuid.clone());
+            q.push_bind(item.uuid().to_string());
+        })
+        .build()
+        .execute(&mut *transaction)
+        .await?;
+    replace_tags(transaction, &recipe_uuid, record.tags()).await?;
+    replace_steps(transaction, &recipe_uuid, record.steps()).await
+}
+
+/// Makes the stored recipe `record`, keeping its reviews and creation date.
+/// Returns like `insert_recipe_rows`.
+async fn overwrite_recipe_rows(
+    transaction: &mut Transaction<'_, Sqlite>,
+    record: &Recipe,
+) -> Result<Option<usize>, sqlx::Error> {
+    let recipe_uuid = record.uuid().to_string();
+    let mut builder = QueryBuilder::new("UPDATE recipe SET name = ");
+    builder
+        .push_bind(record.name())
+        .push(", image = ")
+        .push_bind(record.image())
+        .push(", servings = ")
+        .push_bind(i64::from(record.servings()))
+        .push(", prep_minutes = ")
+        .push_bind(record.prep_minutes().map(i64::from))
+        .push(", cook_minutes = ")
+        .push_bind(record.cook_minutes().map(i64::from))
+        .push(", difficulty = ")
+        .push_bind(record.difficulty().map(|difficulty| difficulty.as_str()))
+        .push(", owner_uuid = ")
+        .push_bind(record.access().owner().map(|owner| owner.to_string()))
+        .push(", visibility = ")
+        .push_bind(record.access().visibility().as_str())
+        .push(" WHERE uuid = ")
+        .push_bind(recipe_uuid.clone())
+        .build()
+        .execute(&mut *transaction)
+        .await?;
+
+    // Ingredients kept by uuid keep their nutrient links.
+    let mut builder = QueryBuilder::new(
+        "DELETE FROM ingredient WHERE uuid IN (SELECT ingredient_uuid FROM recipe_ingredient WHERE recipe_uuid = ",
+    );
+    builder
+        .push_bind(recipe_uuid.clone())
+        .push(") AND uuid NOT IN (");
+    let mut separated = builder.separated(", ");
+    for ingredient in record.ingredients() {
+        separated.push_bind(ingredient.uuid().to_string());
+    }
+    separated.push_unseparated(")");
+    builder.build().execute(&mut *transaction).await?;
+    for ingredient in record.ingredients() {
+        upsert_ingredient(transaction, &recipe_uuid, ingredient).await?;
+    }
+    replace_tags(transaction, &recipe_uuid, record.tags()).await?;
+    replace_steps(transaction, &recipe_uuid, record.steps()).await
+}
+
 #[derive(Clone)]
 pub struct RecipeSqliteDS {
     pool: SqlitePool,
@@ -58,27 +541,100 @@ impl UpdateRecipePort for RecipeSqliteDS {
     async fn update_recipe(
         &self,
         record: Recipe,
+        changes: RecipeChanges,
         deleted_ingredients: Vec<uuid::Uuid>,
-    ) -> Result<(), UpdateRecipeError> {
-        let name = record.name().to_string();
-        let method = record.method();
-        let image = record.image();
-        let uuid = record.uuid().to_string();
-        sqlx::query!(
-            r#"
-            UPDATE recipe 
-            SET name = ?, method = ?, image = ?
-            WHERE uuid = ?
-        "#,
-            name,
-            method,
-            image,
-            uuid
-        )
-        .execute(&self.pool)
-        .await
-        .map(|_e| ())
-        .map_err(|e| e.into())
+        tags: Option<Vec<String>>,
+        steps: Option<Vec<Step>>,
+    ) -> Result<Recipe, UpdateRecipeError> {
+        let recipe_uuid = record.uuid().to_string();
+        let mut transaction = self.pool.begin().await?;
+
+        // The image is only ever changed by uploading one.
+        let mut builder = QueryBuilder::new("UPDATE recipe SET name = ");
+        builder.push_bind(record.name());
+        if let Some(servings) = changes.servings() {
+            builder.push(", servings = ").push_bind(i64::from(servings));
+        }
+        if let Some(visibility) = changes.visibility() {
+            builder
+                .push(", visibility = ")
+                .push_bind(visibility.as_str());
+        }
+        if let Some(prep_minutes) = changes.prep_minutes() {
+            builder
+                .push(", prep_minutes = ")
+                .push_bind(i64::from(prep_minutes));
+        }
+        if let Some(cook_minutes) = changes.cook_minutes() {
+            builder
+                .push(", cook_minutes = ")
+                .push_bind(i64::from(cook_minutes));
+        }
+        if let Some(difficulty) = changes.difficulty() {
+            builder
+                .push(", difficulty = ")
+                .push_bind(difficulty.as_str());
+        }
+        let result = builder
+            .push(" WHERE uuid = ")
+            .push_bind(recipe_uuid.clone())
+            .build()
+            .execute(&mut transaction)
+            .await?;
+        if result.rows_affected() == 0 {
+            transaction.rollback().await?;
+            return Err(UpdateRecipeError::RecordNotFound);
+        }
+
+        if !deleted_ingredients.is_empty() {
+            let mut builder = QueryBuilder::new(
+                "DELETE FROM ingredient WHERE uuid IN (SELECT ingredient_uuid FROM recipe_ingredient WHERE recipe_uuid = ",
+            );
+            builder
+                .push_bind(recipe_uuid.clone())
+                .push(") AND uuid IN (");
+            let mut separated = builder.separated(", ");
+            for uuid in deleted_ingredients.iter() {
+                separated.push_bind(uuid.to_string());
+            }
+            separated.push_unseparated(")");
+            builder.build().execute(&mut transaction).await?;
+        }
+
+        if let Some(index) =
+            foreign_ingredient(&mut transaction, &recipe_uuid, record.ingredients()).await?
+        {
+            transaction.rollback().await?;
+            return Err(UpdateRecipeError::ForeignIngredient { index });
+        }
+        for ingredient in record.ingredients() {
+            upsert_ingredient(&mut transaction, &recipe_uuid, ingredient).await?;
+        }
+
+        if let Some(tags) = tags {
+            replace_tags(&mut transaction, &recipe_uuid, &tags).await?;
+        }
+        if let Some(steps) = steps {
+            if let Some(step) = replace_steps(&mut transaction, &recipe_uuid, &steps).await? {
+                transaction.rollback().await?;
+                return Err(UpdateRecipeError::UnknownStepIngredient { step });
+            }
+        }
+
+        let remaining: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM recipe_ingredient WHERE recipe_uuid = ?")
+                .bind(recipe_uuid)
+                .fetch_one(&mut transaction)
+                .await?;
+        if remaining == 0 {
+            transaction.rollback().await?;
+            return Err(UpdateRecipeError::NoIngredients);
+        }
+        transaction.commit().await?;
+
+        self.query_recipe(record.uuid())
+            .await
+            .map_err(|_e| UpdateRecipeError::InternalError)
     }
 }
 
@@ -86,21 +642,15 @@ impl UpdateRecipePort for RecipeSqliteDS {
 impl QueryRecipePort for RecipeSqliteDS {
     async fn query_recipe(&self, uuid: uuid::Uuid) -> Result<Recipe, QueryRecipeError> {
         let uuid = uuid.to_string();
-        let result = sqlx::query!(
-            r#"SELECT recipe.uuid as ruuid, recipe.name as rname, recipe.method, recipe.image, ingredient.uuid, ingredient.name, ingredient.unit, ingredient.amount FROM recipe 
+        let records = sqlx::query!(
+            r#"SELECT recipe.uuid as ruuid, recipe.name as rname, recipe.image, recipe.servings, recipe.prep_minutes, recipe.cook_minutes, recipe.difficulty, recipe.owner_uuid, recipe.visibility, recipe.rating_sum, recipe.review_count, ingredient.uuid, ingredient.name, ingredient.unit, ingredient.amount FROM recipe 
             JOIN recipe_ingredient ON recipe.uuid = recipe_uuid
             JOIN ingredient ON ingredient.uuid = ingredient_uuid 
             WHERE recipe.uuid = ?"#,
             uuid
         )
         .fetch_all(&self.pool)
-        .await
-        .map_err(|e| e.into());
-        if result.is_err() {
-            return Err(result.unwrap_err());
-        }
-
-        let records = result.unwrap();
+        .await?;
         if records.is_empty() {
             return Err(QueryRecipeError::RecordNotFound);
         }
@@ -116,24 +666,338 @@ impl QueryRecipePort for RecipeSqliteDS {
                 )
             })
             .collect::<Vec<Ingredient>>();
-        let row = records.get(0).unwrap();
-        let recipe = Recipe::new(
-            Uuid::parse_str(
-                row.uuid
-                    .as_ref()
-                    .ok_or(QueryRecipeError::InternalError)?
-                    .as_str(),
-            )
-            .map_err(|_e| QueryRecipeError::InternalError)?,
-            row.rname.clone(),
-            row.image.clone().unwrap_or(String::new()),
-            row.method.clone().unwrap_or(String::new()),
-            ingredient,
-        );
+        let row = records.first().unwrap();
+        let row = RecipeRow {
+            uuid: row.ruuid.clone().ok_or(QueryRecipeError::InternalError)?,
+            name: row.rname.clone(),
+            image: row.image.clone(),
+            servings: row.servings,
+            prep_minutes: row.prep_minutes,
+            cook_minutes: row.cook_minutes,
+            difficulty: row.difficulty.clone(),
+            owner_uuid: row.owner_uuid.clone(),
+            visibility: row.visibility.clone(),
+            rating_sum: row.rating_sum,
+            review_count: row.review_count,
+        };
+        let steps = self
+            .steps_of(std::slice::from_ref(&uuid))
+            .await?
+            .remove(&uuid)
+            .unwrap_or_default();
+        let recipe = row
+            .into_recipe(steps, ingredient)
+            .ok_or(QueryRecipeError::InternalError)?
+            .with_tags(
+                self.tags_of(std::slice::from_ref(&uuid))
+                    .await?
+                    .remove(&uuid)
+                    .unwrap_or_default(),
+            );
         Ok(recipe)
     }
 }
 
+#[async_trait]
+impl ListRecipesPort for RecipeSqliteDS {
+    async fn list_recipes(
+        &self,
+        caller: Option<Caller>,
+        request: PageRequest,
+        filter: &RecipeFilter,
+        limit: u32,
+    ) -> Result<Vec<Recipe>, ListRecipesError> {
+        let direction = match request.direction() {
+            SortDirection::Ascending => "ASC",
+            SortDirection::Descending => "DESC",
+        };
+        let column = match request.sort_by() {
+            SortBy::Name => "name COLLATE NOCASE",
+            SortBy::CreatedAt => "created_at",
+        };
+        let mut builder = QueryBuilder::new(format!("SELECT {RECIPE_COLUMNS} FROM recipe WHERE "));
+        push_viewable_by(&mut builder, caller);
+        push_recipe_filter(&mut builder, filter);
+        let query = builder
+            .push(format!(" ORDER BY {column} {direction}, uuid {direction}"))
+            .push(" LIMIT ")
+            .push_bind(i64::from(limit))
+            .push(" OFFSET ")
+            .push_bind(request.offset() as i64)
+            .build_query_as::<RecipeRow>();
+        info!("{}", query.sql());
+        let rows = query.fetch_all(&self.pool).await?;
+        Ok(self.summaries_of(rows).await?)
+    }
+}
+
+// Control characters marking the matches in snippets, a recipe's text is
+// escaped before they become HTML tags.
+const MATCH_START: char = '\u{2}';
+const MATCH_END: char = '\u{3}';
+
+/// `snippet` as HTML: its text escaped, the matches in `<mark>`.
+fn snippet_html(snippet: &str) -> String {
+    let mut html = String::with_capacity(snippet.len());
+    for c in snippet.chars() {
+        match c {
+            MATCH_START => html.push_str("<mark>"),
+            MATCH_END => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#x27;"),
+            c => html.push(c),
+        }
+    }
+    html
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct SearchRow {
+    #[sqlx(flatten)]
+    recipe: RecipeRow,
+    snippet: String,
+    score: f64,
+}
+
+#[async_trait]
+impl SearchRecipePort for RecipeSqliteDS {
+    async fn search_recipes(
+        &self,
+        caller: Option<Caller>,
+        terms: Vec<String>,
+        tags: &TagFilter,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<RecipeSearchHit>, SearchRecipeError> {
+        // Every term is a quoted prefix query, so user input never reaches the
+        // FTS5 query parser as syntax.
+        let fts_query = terms
+            .iter()
+            .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+            .collect::<Vec<String>>()
+            .join(" ");
+        let mut builder = QueryBuilder::new(format!(
+            "SELECT {RECIPE_COLUMNS}, \
+            snippet(recipe_search, -1, char(2), char(3), '…', 16) AS snippet, \
+            bm25(recipe_search, 0.0, 10.0, 1.0, 5.0) AS score \
+            FROM recipe_search JOIN recipe ON recipe.uuid = recipe_search.recipe_uuid \
+            WHERE recipe_search MATCH "
+        ));
+        builder.push_bind(fts_query).push(" AND ");
+        push_viewable_by(&mut builder, caller);
+        push_tag_filter(&mut builder, tags);
+        let query = builder
+            .push(" ORDER BY score ASC LIMIT ")
+            .push_bind(i64::from(limit))
+            .push(" OFFSET ")
+            .push_bind(offset as i64)
+            .build_query_as::<SearchRow>();
+        info!("{}", query.sql());
+        let rows = query.fetch_all(&self.pool).await?;
+        // Hits are summaries like the listing's, the snippet shows the match.
+        let (rows, matches): (Vec<RecipeRow>, Vec<(String, f64)>) = rows
+            .into_iter()
+            .map(|row| (row.recipe, (row.snippet, row.score)))
+            .unzip();
+        let recipes = self.summaries_of(rows).await?;
+
+        Ok(recipes
+            .into_iter()
+            .zip(matches)
+            .map(|(recipe, (snippet, score))| {
+                RecipeSearchHit::new(recipe, snippet_html(&snippet), score)
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct MatchRow {
+    #[sqlx(flatten)]
+    recipe: RecipeRow,
+    ingredient_uuid: String,
+    ingredient_name: String,
+    amount: f64,
+    unit: String,
+    available: bool,
+}
+
+#[async_trait]
+impl MatchRecipePort for RecipeSqliteDS {
+    async fn match_recipes(
+        &self,
+        caller: Option<Caller>,
+        names: Vec<String>,
+    ) -> Result<Vec<RecipeMatch>, MatchRecipeError> {
+        let mut builder = QueryBuilder::new(format!(
+            "SELECT {RECIPE_COLUMNS}, ingredient.uuid AS ingredient_uuid, \
+            ingredient.name AS ingredient_name, ingredient.amount, ingredient.unit, \
+            lower(trim(ingredient.name)) IN ("
+        ));
+        let mut separated = builder.separated(", ");
+        for name in names.iter() {
+            separated.push_bind(name.clone());
+        }
+        builder.push(
+            ") AS available FROM recipe \
+            JOIN recipe_ingredient ON recipe.uuid = recipe_ingredient.recipe_uuid \
+            JOIN ingredient ON ingredient.uuid = recipe_ingredient.ingredient_uuid \
+            WHERE recipe.uuid IN (SELECT recipe_uuid FROM recipe_ingredient \
+            JOIN ingredient ON ingredient.uuid = ingredient_uuid \
+            WHERE lower(trim(ingredient.name)) IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for name in names.iter() {
+            separated.push_bind(name.clone());
+        }
+        builder.push(")) AND ");
+        push_viewable_by(&mut builder, caller);
+        builder.push(" ORDER BY recipe.uuid");
+        let query = builder.build_query_as::<MatchRow>();
+        let rows = query.fetch_all(&self.pool).await?;
+
+        // Rows are ordered by recipe, so each recipe is a contiguous run.
+        let mut grouped: Vec<(RecipeRow, Vec<Ingredient>, Vec<Ingredient>)> = vec![];
+        for row in rows {
+            let ingredient = Ingredient::new(
+                Uuid::parse_str(&row.ingredient_uuid)
+                    .map_err(|_e| MatchRecipeError::InternalError)?,
+                row.ingredient_name,
+                row.amount,
+                row.unit,
+            );
+            if grouped.last().map(|(recipe, _, _)| &recipe.uuid) != Some(&row.recipe.uuid) {
+                grouped.push((row.recipe, vec![], vec![]));
+            }
+            let (_, ingredients, missing) = grouped.last_mut().unwrap();
+            if !row.available {
+                missing.push(ingredient.clone());
+            }
+            ingredients.push(ingredient);
+        }
+
+        let uuids = grouped
+            .iter()
+            .map(|(recipe, ..)| recipe.uuid.clone())
+            .collect::<Vec<String>>();
+        let mut recipe_steps = self.steps_of(&uuids).await?;
+
+        grouped
+            .into_iter()
+            .map(|(recipe, ingredients, missing)| {
+                let steps = recipe_steps.remove(&recipe.uuid).unwrap_or_default();
+                let recipe = recipe
+                    .into_recipe(steps, ingredients)
+                    .ok_or(MatchRecipeError::InternalError)?;
+                Ok(RecipeMatch::new(recipe, missing))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl RecipeImagePort for RecipeSqliteDS {
+    async fn recipe_image(&self, recipe: uuid::Uuid) -> Result<Option<String>, RecipeImageError> {
+        let image: Option<String> = sqlx::query_scalar("SELECT image FROM recipe WHERE uuid = ?")
+            .bind(recipe.to_string())
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(image.filter(|image| !image.is_empty()))
+    }
+
+    async fn set_recipe_image(
+        &self,
+        recipe: uuid::Uuid,
+        name: &ImageName,
+    ) -> Result<Option<String>, RecipeImageError> {
+        let mut transaction = self.pool.begin().await?;
+        let previous: Option<String> =
+            sqlx::query_scalar("SELECT image FROM recipe WHERE uuid = ?")
+                .bind(recipe.to_string())
+                .fetch_one(&mut transaction)
+                .await?;
+        sqlx::query("UPDATE recipe SET image = ? WHERE uuid = ?")
+            .bind(name.as_str())
+            .bind(recipe.to_string())
+            .execute(&mut transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(previous.filter(|previous| !previous.is_empty()))
+    }
+
+    async fn image_references(&self, name: &ImageName) -> Result<u64, RecipeImageError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT (SELECT COUNT(*) FROM recipe WHERE image = ?1) \
+            + (SELECT COUNT(*) FROM recipe_image_variant WHERE name = ?1)",
+        )
+        .bind(name.as_str())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count as u64)
+    }
+}
+
+#[async_trait]
+impl ImageVariantPort for RecipeSqliteDS {
+    async fn image_variants(
+        &self,
+        recipe: uuid::Uuid,
+    ) -> Result<Vec<ImageVariant>, ImageVariantError> {
+        let rows: Vec<(i64, String, i64, i64)> = sqlx::query_as(
+            "SELECT size, name, width, height FROM recipe_image_variant WHERE recipe_uuid = ?",
+        )
+        .bind(recipe.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
+            .map(|(size, name, width, height)| {
+                let name = ImageName::parse(&name).ok_or(ImageVariantError::InternalError)?;
+                Ok(ImageVariant::new(
+                    size as u32,
+                    name,
+                    width as u32,
+                    height as u32,
+                ))
+            })
+            .collect()
+    }
+
+    async fn replace_image_variants(
+        &self,
+        recipe: uuid::Uuid,
+        variants: &[ImageVariant],
+    ) -> Result<Vec<ImageVariant>, ImageVariantError> {
+        let previous = self.image_variants(recipe).await?;
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query("DELETE FROM recipe_image_variant WHERE recipe_uuid = ?")
+            .bind(recipe.to_string())
+            .execute(&mut transaction)
+            .await?;
+        if !variants.is_empty() {
+            let mut builder = QueryBuilder::new(
+                "INSERT INTO recipe_image_variant (recipe_uuid, size, format, name, width, height) ",
+            );
+            builder
+                .push_values(variants.iter(), |mut q, item| {
+                    q.push_bind(recipe.to_string())
+                        .push_bind(i64::from(item.size()))
+                        .push_bind(item.format().extension())
+                        .push_bind(item.name().as_str())
+                        .push_bind(i64::from(item.width()))
+                        .push_bind(i64::from(item.height()));
+                })
+                .build()
+                .execute(&mut transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(previous)
+    }
+}
+
 #[async_trait]
 impl DeleteRecipePort for RecipeSqliteDS {
     async fn delete_recipe(&self, uuid: Uuid) -> Result<(), DeleteRecipeError> {
@@ -150,51 +1014,91 @@ impl DeleteRecipePort for RecipeSqliteDS {
 #[async_trait]
 impl InsertRecipePort for RecipeSqliteDS {
     async fn insert_recipe(&self, record: Recipe) -> Result<(), InsertRecipeError> {
-        let mut builder =
-            QueryBuilder::new("INSERT INTO recipe (uuid, name, image, method ) VALUES (");
-        let recipe_insert_query = builder
-            .push_bind(record.uuid().to_string())
-            .push(", ")
-            .push_bind(record.name())
-            .push(", ")
-            .push_bind(record.image())
-            .push(", ")
-            .push_bind(record.method())
-            .push(") ")
-            .build();
-        let mut builder = QueryBuilder::new("INSERT INTO ingredient (uuid, name, amount, unit) ");
-        let ingredient_insert_query = builder
-            .push_values(record.ingredients().iter(), |mut q, item| {
-                q.push_bind(item.uuid().to_string())
-                    .push_bind(item.name())
-                    .push_bind(item.amount())
-                    .push_bind(item.unit());
-            })
-            .build();
-
-        let mut builder =
-            QueryBuilder::new("INSERT INTO recipe_ingredient (recipe_uuid, ingredient_uuid ) ");
-        let nm_insert_query = builder
-            .push_values(record.ingredients().iter(), |mut q, item| {
-                q.push_bind(record.uuid().to_string());
-                q.push_bind(item.uuid().to_string());
-            })
-            .build();
-
-        let transaction = self.pool.begin().await?;
+        // Dropping the transaction on an error rolls it back.
+        let mut transaction = self.pool.begin().await?;
+        if let Some(step) = insert_recipe_rows(&mut transaction, &record).await? {
+            transaction.rollback().await?;
+            return Err(InsertRecipeError::UnknownStepIngredient { step });
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+}
 
-        let result: Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> = recipe_insert_query
-            .execute(&self.pool)
-            .and_then(|_f| ingredient_insert_query.execute(&self.pool))
-            .and_then(|_f| nm_insert_query.execute(&self.pool))
-            .await;
+#[async_trait]
+impl LibraryPort for RecipeSqliteDS {
+    async fn export_recipes(
+        &self,
+        after: Option<uuid::Uuid>,
+        limit: u32,
+    ) -> Result<Vec<Recipe>, LibraryError> {
+        let mut builder = QueryBuilder::new(format!("SELECT {RECIPE_COLUMNS} FROM recipe"));
+        if let Some(after) = after {
+            builder.push(" WHERE uuid > ").push_bind(after.to_string());
+        }
+        let rows = builder
+            .push(" ORDER BY uuid LIMIT ")
+            .push_bind(i64::from(limit))
+            .build_query_as::<RecipeRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(self.recipes_of(rows).await?)
+    }
 
-        if result.is_ok() {
-            transaction.commit().await?;
-        } else {
-            transaction.rollback().await?;
+    async fn import_recipes(
+        &self,
+        recipes: &[Recipe],
+        mode: ConflictMode,
+    ) -> Result<ImportSummary, LibraryError> {
+        let mut summary = ImportSummary::default();
+        // Dropping the transaction on an error rolls it back.
+        let mut transaction = self.pool.begin().await?;
+        for (index, recipe) in recipes.iter().enumerate() {
+            let stored: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM recipe WHERE uuid = ?")
+                .bind(recipe.uuid().to_string())
+                .fetch_one(&mut transaction)
+                .await?;
+            let result = match (stored > 0, mode) {
+                (false, _) => {
+                    summary.record_created(recipe.uuid());
+                    insert_recipe_rows(&mut transaction, recipe).await
+                }
+                (true, ConflictMode::Skip) => {
+                    summary.record_skipped(recipe.uuid());
+                    continue;
+                }
+                (true, ConflictMode::Overwrite) => {
+                    summary.record_overwritten(recipe.uuid());
+                    overwrite_recipe_rows(&mut transaction, recipe).await
+                }
+                (true, ConflictMode::Duplicate) => {
+                    let copy = recipe.duplicate();
+                    summary.record_duplicated(recipe.uuid(), copy.uuid());
+                    insert_recipe_rows(&mut transaction, &copy).await
+                }
+            };
+            if let Some(step) = result.map_err(|e| import_error(index, e))? {
+                transaction.rollback().await?;
+                return Err(LibraryError::UnknownStepIngredient {
+                    recipe: index,
+                    step,
+                });
+            }
         }
-        result.and_then(|_v| Ok(())).map_err(|e| e.into())
+        transaction.commit().await?;
+        Ok(summary)
+    }
+}
+
+#[async_trait]
+impl RecipeAccessPort for RecipeSqliteDS {
+    async fn recipe_access(&self, uuid: uuid::Uuid) -> Result<RecipeAccess, RecipeAccessError> {
+        let (owner, visibility): (Option<String>, String) =
+            sqlx::query_as("SELECT owner_uuid, visibility FROM recipe WHERE uuid = ?")
+                .bind(uuid.to_string())
+                .fetch_one(&self.pool)
+                .await?;
+        parse_access(owner.as_deref(), &visibility).ok_or(RecipeAccessError::InternalError)
     }
 }
 
@@ -202,4 +1106,378 @@ impl RecipeSqliteDS {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    /// The recipes of `rows`, with their ingredients, tags and steps.
+    async fn recipes_of(&self, rows: Vec<RecipeRow>) -> Result<Vec<Recipe>, sqlx::Error> {
+        let uuids = rows
+            .iter()
+            .map(|row| row.uuid.clone())
+            .collect::<Vec<String>>();
+        let mut recipe_ingredients = self.ingredients_of(&uuids).await?;
+        let mut recipe_tags = self.tags_of(&uuids).await?;
+        let mut recipe_steps = self.steps_of(&uuids).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let ingredients = recipe_ingredients.remove(&row.uuid).unwrap_or_default();
+                let steps = recipe_steps.remove(&row.uuid).unwrap_or_default();
+                let tags = recipe_tags.remove(&row.uuid).unwrap_or_default();
+                Ok(row
+                    .into_recipe(steps, ingredients)
+                    .ok_or_else(|| sqlx::Error::Decode("Invalid recipe row".into()))?
+                    .with_tags(tags))
+            })
+            .collect()
+    }
+
+    /// The recipes of `rows` with their tags only. Ingredients and steps
+    /// are left to the single recipe query.
+    async fn summaries_of(&self, rows: Vec<RecipeRow>) -> Result<Vec<Recipe>, sqlx::Error> {
+        let uuids = rows
+            .iter()
+            .map(|row| row.uuid.clone())
+            .collect::<Vec<String>>();
+        let mut recipe_tags = self.tags_of(&uuids).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let tags = recipe_tags.remove(&row.uuid).unwrap_or_default();
+                Ok(row
+                    .into_recipe(vec![], vec![])
+                    .ok_or_else(|| sqlx::Error::Decode("Invalid recipe row".into()))?
+                    .with_tags(tags))
+            })
+            .collect()
+    }
+
+    /// Ingredients of each of `recipes`.
+    async fn ingredients_of(
+        &self,
+        recipes: &[String],
+    ) -> Result<HashMap<String, Vec<Ingredient>>, sqlx::Error> {
+        let mut ingredients: HashMap<String, Vec<Ingredient>> = HashMap::new();
+        if recipes.is_empty() {
+            return Ok(ingredients);
+        }
+        let mut builder = QueryBuilder::new(
+            "SELECT recipe_uuid, ingredient.uuid, ingredient.name, ingredient.amount, ingredient.unit \
+            FROM recipe_ingredient JOIN ingredient ON ingredient.uuid = ingredient_uuid \
+            WHERE recipe_uuid IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for uuid in recipes {
+            separated.push_bind(uuid.clone());
+        }
+        separated.push_unseparated(")");
+        let rows = builder
+            .build_query_as::<(String, String, String, f64, String)>()
+            .fetch_all(&self.pool)
+            .await?;
+        for (recipe, uuid, name, amount, unit) in rows {
+            let uuid = Uuid::parse_str(&uuid).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+            ingredients
+                .entry(recipe)
+                .or_default()
+                .push(Ingredient::new(uuid, name, amount, unit));
+        }
+        Ok(ingredients)
+    }
+
+    /// Tags of each of `recipes`, alphabetically.
+    async fn tags_of(
+        &self,
+        recipes: &[String],
+    ) -> Result<HashMap<String, Vec<String>>, sqlx::Error> {
+        let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+        if recipes.is_empty() {
+            return Ok(tags);
+        }
+        let mut builder = QueryBuilder::new(
+            "SELECT recipe_uuid, tag.name FROM recipe_tag JOIN tag ON tag.id = recipe_tag.tag_id \
+            WHERE recipe_uuid IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for uuid in recipes {
+            separated.push_bind(uuid.clone());
+        }
+        separated.push_unseparated(") ORDER BY tag.name");
+        let rows = builder
+            .build_query_as::<(String, String)>()
+            .fetch_all(&self.pool)
+            .await?;
+        for (recipe, tag) in rows {
+            tags.entry(recipe).or_default().push(tag);
+        }
+        Ok(tags)
+    }
+
+    /// Steps of each of `recipes`, in order.
+    async fn steps_of(
+        &self,
+        recipes: &[String],
+    ) -> Result<HashMap<String, Vec<Step>>, sqlx::Error> {
+        let mut steps: HashMap<String, Vec<Step>> = HashMap::new();
+        if recipes.is_empty() {
+            return Ok(steps);
+        }
+        let mut builder = QueryBuilder::new(
+            "SELECT recipe_step_ingredient.step_id, recipe_step_ingredient.ingredient_uuid \
+            FROM recipe_step_ingredient JOIN recipe_step ON recipe_step.id = step_id \
+            WHERE recipe_step.recipe_uuid IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for uuid in recipes {
+            separated.push_bind(uuid.clone());
+        }
+        separated.push_unseparated(") ORDER BY recipe_step_ingredient.rowid");
+        let links = builder
+            .build_query_as::<(i64, String)>()
+            .fetch_all(&self.pool)
+            .await?;
+        let mut ingredients: HashMap<i64, Vec<Uuid>> = HashMap::new();
+        for (step, ingredient) in links {
+            if let Ok(ingredient) = Uuid::parse_str(&ingredient) {
+                ingredients.entry(step).or_default().push(ingredient);
+            }
+        }
+
+        let mut builder = QueryBuilder::new(
+            "SELECT id, recipe_uuid, text, duration_seconds, timer FROM recipe_step \
+            WHERE recipe_uuid IN (",
+        );
+        let mut separated = builder.separated(", ");
+        for uuid in recipes {
+            separated.push_bind(uuid.clone());
+        }
+        separated.push_unseparated(") ORDER BY recipe_uuid, position");
+        let rows = builder
+            .build_query_as::<(i64, String, String, Option<i64>, Option<String>)>()
+            .fetch_all(&self.pool)
+            .await?;
+        for (id, recipe, text, duration, timer) in rows {
+            let step = Step::new(text)
+                .with_duration(duration.map(|seconds| Duration::from_secs(seconds as u64)))
+                .with_timer(timer)
+                .with_ingredients(ingredients.remove(&id).unwrap_or_default());
+            steps.entry(recipe).or_default().push(step);
+        }
+        Ok(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data_storage::memory_pool, services::recipes::domain::access::Visibility};
+
+    fn ingredient(name: &str) -> Ingredient {
+        Ingredient::new(Uuid::new_v4(), name.into(), 100.0, "g".into())
+    }
+
+    async fn stored(storage: &RecipeSqliteDS, ingredients: Vec<Ingredient>) -> Recipe {
+        let recipe = Recipe::new(
+            Uuid::new_v4(),
+            "Soup".into(),
+            "".into(),
+            vec![],
+            ingredients,
+        );
+        storage.insert_recipe(recipe.clone()).await.unwrap();
+        recipe
+    }
+
+    async fn stored_public(storage: &RecipeSqliteDS, name: &str) -> Recipe {
+        let recipe = Recipe::new(
+            Uuid::new_v4(),
+            name.into(),
+            "".into(),
+            vec![],
+            vec![ingredient("water")],
+        )
+        .with_access(RecipeAccess::new(None, Visibility::Public));
+        storage.insert_recipe(recipe.clone()).await.unwrap();
+        recipe
+    }
+
+    #[tokio::test]
+    async fn updates_are_committed_as_a_whole() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        let leek = ingredient("leek");
+        let potato = ingredient("potato");
+        let recipe = stored(&storage, vec![leek.clone(), potato.clone()]).await;
+
+        let renamed = Ingredient::new(leek.uuid(), "leeks".into(), 200.0, "g".into());
+        let update = Recipe::new(
+            recipe.uuid(),
+            "Leek soup".into(),
+            "".into(),
+            vec![],
+            vec![renamed, ingredient("cream")],
+        );
+        let updated = storage
+            .update_recipe(
+                update,
+                RecipeChanges::default(),
+                vec![potato.uuid()],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated.name(), "Leek soup");
+        let mut names = updated
+            .ingredients()
+            .iter()
+            .map(|ingredient| (ingredient.name(), ingredient.amount()))
+            .collect::<Vec<_>>();
+        names.sort_by(|a, b| a.0.cmp(b.0));
+        assert_eq!(names, [("cream", 100.0), ("leeks", 200.0)]);
+    }
+
+    #[tokio::test]
+    async fn steps_may_name_an_ingredient_twice() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        let leek = ingredient("leek");
+        let recipe = stored(&storage, vec![leek.clone()]).await;
+
+        let step = Step::new("Stir the leek, then the leek again".into())
+            .with_ingredients(vec![leek.uuid(), leek.uuid()]);
+        let updated = storage
+            .update_recipe(
+                recipe,
+                RecipeChanges::default(),
+                vec![],
+                None,
+                Some(vec![step]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated.steps()[0].ingredients(), [leek.uuid()]);
+    }
+
+    #[tokio::test]
+    async fn updates_leaving_no_ingredient_are_rolled_back() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        let leek = ingredient("leek");
+        let recipe = stored(&storage, vec![leek.clone()]).await;
+
+        let update = Recipe::new(recipe.uuid(), "Nothing".into(), "".into(), vec![], vec![]);
+        let error = storage
+            .update_recipe(
+                update,
+                RecipeChanges::default(),
+                vec![leek.uuid()],
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, UpdateRecipeError::NoIngredients));
+        let unchanged = storage.query_recipe(recipe.uuid()).await.unwrap();
+        assert_eq!(unchanged.name(), "Soup");
+        assert_eq!(unchanged.ingredients().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ingredients_of_other_recipes_are_refused() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        let leek = ingredient("leek");
+        let other = stored(&storage, vec![leek.clone()]).await;
+        let recipe = stored(&storage, vec![ingredient("potato")]).await;
+
+        let stolen = Ingredient::new(leek.uuid(), "stolen".into(), 1.0, "g".into());
+        let update = Recipe::new(
+            recipe.uuid(),
+            "Soup".into(),
+            "".into(),
+            vec![],
+            vec![ingredient("salt"), stolen],
+        );
+        let error = storage
+            .update_recipe(update, RecipeChanges::default(), vec![], None, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            UpdateRecipeError::ForeignIngredient { index: 1 }
+        ));
+        let other = storage.query_recipe(other.uuid()).await.unwrap();
+        assert_eq!(other.ingredients()[0].name(), "leek");
+        assert_eq!(
+            storage
+                .query_recipe(recipe.uuid())
+                .await
+                .unwrap()
+                .ingredients()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn search_snippets_escape_the_recipe_text() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        stored_public(&storage, "<img src=x onerror=alert(1)> soup").await;
+
+        let hits = storage
+            .search_recipes(None, vec!["soup".into()], &TagFilter::default(), 0, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(
+            hits[0].snippet(),
+            "&lt;img src=x onerror=alert(1)&gt; <mark>soup</mark>"
+        );
+    }
+
+    #[tokio::test]
+    async fn search_hits_are_summaries() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        let soup = Recipe::new(
+            Uuid::new_v4(),
+            "Soup".into(),
+            "".into(),
+            vec![Step::new("Simmer".into())],
+            vec![ingredient("water")],
+        )
+        .with_access(RecipeAccess::new(None, Visibility::Public));
+        storage.insert_recipe(soup.clone()).await.unwrap();
+
+        let hits = storage
+            .search_recipes(None, vec!["soup".into()], &TagFilter::default(), 0, 10)
+            .await
+            .unwrap();
+
+        // Like listed recipes, hits leave ingredients and steps out.
+        assert_eq!(hits[0].recipe().uuid(), soup.uuid());
+        assert!(hits[0].recipe().ingredients().is_empty());
+        assert!(hits[0].recipe().steps().is_empty());
+    }
+
+    #[tokio::test]
+    async fn search_terms_are_never_query_syntax() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        stored_public(&storage, "Tomato soup").await;
+        let tags = TagFilter::default();
+        let search = |terms: &[&str]| {
+            storage.search_recipes(
+                None,
+                terms.iter().map(|term| term.to_string()).collect(),
+                &tags,
+                0,
+                10,
+            )
+        };
+
+        assert_eq!(search(&["tom"]).await.unwrap().len(), 1);
+        assert_eq!(search(&["tomato", "OR", "x"]).await.unwrap().len(), 0);
+        for terms in [&["\"soup"][..], &["NOT"], &["soup*", "("], &["name:soup"]] {
+            assert!(search(terms).await.is_ok(), "{:?}", terms);
+        }
+    }
 }
@@ -0,0 +1 @@
+pub mod reviews_sqlite_ds;
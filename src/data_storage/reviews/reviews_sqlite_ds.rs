@@ -0,0 +1,253 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::services::reviews::{
+    domain::review::Review,
+    ports::outgoing::{
+        delete_review_port::{DeleteReviewError, DeleteReviewPort},
+        insert_review_port::{InsertReviewError, InsertReviewPort},
+        list_reviews_port::{ListReviewsError, ListReviewsPort},
+        query_review_port::{QueryReviewError, QueryReviewPort},
+        update_review_port::{UpdateReviewError, UpdateReviewPort},
+    },
+};
+
+// SQLITE_CONSTRAINT_UNIQUE
+const UNIQUE_VIOLATION_CODE: &str = "2067";
+
+const REVIEW_COLUMNS: &str = "uuid, recipe_uuid, author_uuid, rating, text, created_at, updated_at";
+
+impl From<sqlx::Error> for InsertReviewError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::Database(e)
+                if e.code()
+                    .is_some_and(|code| code.as_ref() == UNIQUE_VIOLATION_CODE) =>
+            {
+                InsertReviewError::ReviewExists
+            }
+            _ => {
+                info!("{}", value);
+                InsertReviewError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for QueryReviewError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => QueryReviewError::RecordNotFound,
+            _ => {
+                info!("{}", value);
+                QueryReviewError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for UpdateReviewError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => UpdateReviewError::RecordNotFound,
+            _ => {
+                info!("{}", value);
+                UpdateReviewError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for DeleteReviewError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => DeleteReviewError::RecordNotFound,
+            _ => {
+                info!("{}", value);
+                DeleteReviewError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for ListReviewsError {
+    fn from(value: sqlx::Error) -> Self {
+        info!("{}", value);
+        ListReviewsError::InternalError
+    }
+}
+
+/// The `REVIEW_COLUMNS` of a row of `review`.
+#[derive(Debug, sqlx::FromRow)]
+struct ReviewRow {
+    uuid: String,
+    recipe_uuid: String,
+    author_uuid: String,
+    rating: i64,
+    text: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl ReviewRow {
+    fn into_review(self) -> Option<Review> {
+        let review = Review::new(
+            Uuid::parse_str(&self.uuid).ok()?,
+            Uuid::parse_str(&self.recipe_uuid).ok()?,
+            Uuid::parse_str(&self.author_uuid).ok()?,
+            u8::try_from(self.rating).ok()?,
+            self.text,
+        )
+        .with_timestamps(self.created_at, self.updated_at);
+        Some(review)
+    }
+}
+
+#[derive(Clone)]
+pub struct ReviewSqliteDS {
+    pool: SqlitePool,
+}
+
+impl ReviewSqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QueryReviewPort for ReviewSqliteDS {
+    async fn query_review(&self, uuid: uuid::Uuid) -> Result<Review, QueryReviewError> {
+        let row: ReviewRow = sqlx::query_as(&format!(
+            "SELECT {REVIEW_COLUMNS} FROM review WHERE uuid = ?"
+        ))
+        .bind(uuid.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+        row.into_review().ok_or(QueryReviewError::InternalError)
+    }
+}
+
+#[async_trait]
+impl InsertReviewPort for ReviewSqliteDS {
+    async fn insert_review(&self, review: Review) -> Result<Review, InsertReviewError> {
+        // The rating totals on `recipe` follow through a trigger. Nothing is
+        // inserted for a recipe that does not exist.
+        let result = sqlx::query(
+            "INSERT INTO review (uuid, recipe_uuid, author_uuid, rating, text, created_at, \
+            updated_at) SELECT ?, uuid, ?, ?, ?, strftime('%Y-%m-%d %H:%M:%f', 'now'), \
+            strftime('%Y-%m-%d %H:%M:%f', 'now') FROM recipe WHERE uuid = ?",
+        )
+        .bind(review.uuid().to_string())
+        .bind(review.author().to_string())
+        .bind(i64::from(review.rating()))
+        .bind(review.text())
+        .bind(review.recipe().to_string())
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(InsertReviewError::RecipeNotFound);
+        }
+        self.query_review(review.uuid())
+            .await
+            .map_err(|_| InsertReviewError::InternalError)
+    }
+}
+
+#[async_trait]
+impl UpdateReviewPort for ReviewSqliteDS {
+    async fn update_review(&self, review: Review) -> Result<Review, UpdateReviewError> {
+        let result = sqlx::query(
+            "UPDATE review SET rating = ?, text = ?, \
+            updated_at = strftime('%Y-%m-%d %H:%M:%f', 'now') WHERE uuid = ?",
+        )
+        .bind(i64::from(review.rating()))
+        .bind(review.text())
+        .bind(review.uuid().to_string())
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(UpdateReviewError::RecordNotFound);
+        }
+        self.query_review(review.uuid())
+            .await
+            .map_err(|_| UpdateReviewError::InternalError)
+    }
+}
+
+#[async_trait]
+impl DeleteReviewPort for ReviewSqliteDS {
+    async fn delete_review(&self, uuid: uuid::Uuid) -> Result<(), DeleteReviewError> {
+        let result = sqlx::query("DELETE FROM review WHERE uuid = ?")
+            .bind(uuid.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DeleteReviewError::RecordNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ListReviewsPort for ReviewSqliteDS {
+    async fn list_reviews(
+        &self,
+        recipe: uuid::Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<Review>, ListReviewsError> {
+        let rows: Vec<ReviewRow> = sqlx::query_as(&format!(
+            "SELECT {REVIEW_COLUMNS} FROM review WHERE recipe_uuid = ? \
+            ORDER BY created_at DESC, uuid LIMIT ? OFFSET ?"
+        ))
+        .bind(recipe.to_string())
+        .bind(i64::from(limit))
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
+            .map(ReviewRow::into_review)
+            .collect::<Option<Vec<Review>>>()
+            .ok_or(ListReviewsError::InternalError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS, stored_recipe},
+        services::recipes::{
+            domain::access::{RecipeAccess, Visibility},
+            ports::outgoing::query_recipe_port::QueryRecipePort,
+        },
+    };
+
+    #[tokio::test]
+    async fn reviews_need_a_recipe_but_no_user_row() {
+        let pool = memory_pool().await;
+        let recipes = RecipeSqliteDS::new(pool.clone());
+        let recipe = stored_recipe(&pool, RecipeAccess::new(None, Visibility::Private)).await;
+        let reviews = ReviewSqliteDS::new(pool);
+        // Authors identified by a trusted header are unknown to `users`.
+        let author = Uuid::new_v4();
+
+        let review = Review::new(Uuid::new_v4(), recipe.uuid(), author, 4, None);
+        reviews.insert_review(review).await.unwrap();
+        let rating = recipes.query_recipe(recipe.uuid()).await.unwrap().rating();
+        assert_eq!((rating.count(), rating.average()), (1, Some(4.0)));
+
+        let twice = Review::new(Uuid::new_v4(), recipe.uuid(), author, 2, None);
+        assert!(matches!(
+            reviews.insert_review(twice).await,
+            Err(InsertReviewError::ReviewExists)
+        ));
+        let elsewhere = Review::new(Uuid::new_v4(), Uuid::new_v4(), author, 2, None);
+        assert!(matches!(
+            reviews.insert_review(elsewhere).await,
+            Err(InsertReviewError::RecipeNotFound)
+        ));
+    }
+}
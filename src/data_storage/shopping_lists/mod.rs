@@ -0,0 +1 @@
+pub mod shopping_lists_sqlite_ds;
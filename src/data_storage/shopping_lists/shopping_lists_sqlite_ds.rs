@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, SqlitePool};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::services::shopping_lists::{
+    domain::{shopping_item::ShoppingItem, shopping_list::ShoppingList},
+    ports::outgoing::shopping_list_port::{ShoppingListError, ShoppingListPort},
+};
+
+const ITEM_COLUMNS: &str = "uuid, list_uuid, name, amount, unit, aisle, checked";
+
+impl From<sqlx::Error> for ShoppingListError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => ShoppingListError::ListNotFound,
+            _ => {
+                info!("{}", value);
+                ShoppingListError::InternalError
+            }
+        }
+    }
+}
+
+/// The `ITEM_COLUMNS` of a row of `shopping_list_item`.
+#[derive(Debug, sqlx::FromRow)]
+struct ItemRow {
+    uuid: String,
+    list_uuid: String,
+    name: String,
+    amount: f64,
+    unit: String,
+    aisle: String,
+    checked: bool,
+}
+
+impl ItemRow {
+    /// `None` when a column holds a value the domain does not know.
+    fn into_item(self) -> Option<ShoppingItem> {
+        let item = ShoppingItem::new(
+            Uuid::parse_str(&self.uuid).ok()?,
+            self.name,
+            self.amount,
+            self.unit,
+            self.aisle.parse().ok()?,
+        )
+        .with_checked(self.checked);
+        Some(item)
+    }
+}
+
+#[derive(Clone)]
+pub struct ShoppingListSqliteDS {
+    pool: SqlitePool,
+}
+
+impl ShoppingListSqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Items of each of `lists`, in order.
+    async fn items_of(
+        &self,
+        lists: &[String],
+    ) -> Result<HashMap<String, Vec<ShoppingItem>>, ShoppingListError> {
+        let mut items: HashMap<String, Vec<ShoppingItem>> = HashMap::new();
+        if lists.is_empty() {
+            return Ok(items);
+        }
+        let mut builder = QueryBuilder::new(format!(
+            "SELECT {ITEM_COLUMNS} FROM shopping_list_item WHERE list_uuid IN ("
+        ));
+        let mut separated = builder.separated(", ");
+        for uuid in lists {
+            separated.push_bind(uuid.clone());
+        }
+        separated.push_unseparated(") ORDER BY position");
+        let rows = builder
+            .build_query_as::<ItemRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        for row in rows {
+            let list = row.list_uuid.clone();
+            let item = row.into_item().ok_or(ShoppingListError::InternalError)?;
+            items.entry(list).or_default().push(item);
+        }
+        Ok(items)
+    }
+}
+
+#[async_trait]
+impl ShoppingListPort for ShoppingListSqliteDS {
+    async fn list_lists(&self, owner: uuid::Uuid) -> Result<Vec<ShoppingList>, ShoppingListError> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT uuid, name FROM shopping_list WHERE owner_uuid = ? \
+            ORDER BY created_at DESC, uuid",
+        )
+        .bind(owner.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        let mut items = self
+            .items_of(
+                &rows
+                    .iter()
+                    .map(|(uuid, _)| uuid.clone())
+                    .collect::<Vec<String>>(),
+            )
+            .await?;
+        rows.into_iter()
+            .map(|(uuid, name)| {
+                let list_items = items.remove(&uuid).unwrap_or_default();
+                let uuid = Uuid::parse_str(&uuid).map_err(|_| ShoppingListError::InternalError)?;
+                Ok(ShoppingList::new(uuid, owner, name).with_items(list_items))
+            })
+            .collect()
+    }
+
+    async fn query_list(&self, uuid: uuid::Uuid) -> Result<ShoppingList, ShoppingListError> {
+        let uuid = uuid.to_string();
+        let (owner, name) = sqlx::query_as::<_, (String, String)>(
+            "SELECT owner_uuid, name FROM shopping_list WHERE uuid = ?",
+        )
+        .bind(&uuid)
+        .fetch_one(&self.pool)
+        .await?;
+        let items = self
+            .items_of(std::slice::from_ref(&uuid))
+            .await?
+            .remove(&uuid)
+            .unwrap_or_default();
+        Ok(ShoppingList::new(
+            Uuid::parse_str(&uuid).map_err(|_| ShoppingListError::InternalError)?,
+            Uuid::parse_str(&owner).map_err(|_| ShoppingListError::InternalError)?,
+            name,
+        )
+        .with_items(items))
+    }
+
+    async fn insert_list(&self, list: &ShoppingList) -> Result<ShoppingList, ShoppingListError> {
+        let uuid = list.uuid().to_string();
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO shopping_list (uuid, owner_uuid, name, created_at) \
+            VALUES (?, ?, ?, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
+        )
+        .bind(&uuid)
+        .bind(list.owner().to_string())
+        .bind(list.name())
+        .execute(&mut transaction)
+        .await?;
+        if !list.items().is_empty() {
+            let mut builder = QueryBuilder::new(
+                "INSERT INTO shopping_list_item (uuid, list_uuid, name, amount, unit, aisle, \
+                checked, position) ",
+            );
+            builder.push_values(
+                list.items().iter().enumerate(),
+                |mut row, (position, item)| {
+                    row.push_bind(item.uuid().to_string())
+                        .push_bind(uuid.clone())
+                        .push_bind(item.name())
+                        .push_bind(item.amount())
+                        .push_bind(item.unit())
+                        .push_bind(item.aisle().as_str())
+                        .push_bind(item.checked())
+                        .push_bind(position as i64);
+                },
+            );
+            builder.build().execute(&mut transaction).await?;
+        }
+        transaction.commit().await?;
+        self.query_list(list.uuid()).await
+    }
+
+    async fn set_checked(
+        &self,
+        list: uuid::Uuid,
+        item: uuid::Uuid,
+        checked: bool,
+    ) -> Result<ShoppingItem, ShoppingListError> {
+        let result = sqlx::query(
+            "UPDATE shopping_list_item SET checked = ? WHERE list_uuid = ? AND uuid = ?",
+        )
+        .bind(checked)
+        .bind(list.to_string())
+        .bind(item.to_string())
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(ShoppingListError::ItemNotFound);
+        }
+        let row: ItemRow = sqlx::query_as(&format!(
+            "SELECT {ITEM_COLUMNS} FROM shopping_list_item WHERE uuid = ?"
+        ))
+        .bind(item.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+        row.into_item().ok_or(ShoppingListError::InternalError)
+    }
+
+    async fn delete_list(&self, uuid: uuid::Uuid) -> Result<(), ShoppingListError> {
+        // Items go with the list, the foreign key cascades.
+        let result = sqlx::query("DELETE FROM shopping_list WHERE uuid = ?")
+            .bind(uuid.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(ShoppingListError::ListNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data_storage::memory_pool, services::shopping_lists::domain::aisle::Aisle};
+
+    #[tokio::test]
+    async fn lists_keep_their_items_in_order_without_a_user_row() {
+        let storage = ShoppingListSqliteDS::new(memory_pool().await);
+        // Owners identified by a trusted header are unknown to `users`.
+        let owner = Uuid::new_v4();
+        let items = ["milk", "flour", "eggs"]
+            .into_iter()
+            .map(|name| {
+                ShoppingItem::new(Uuid::new_v4(), name.into(), 1.0, "".into(), Aisle::Other)
+            })
+            .collect::<Vec<ShoppingItem>>();
+        let list = ShoppingList::new(Uuid::new_v4(), owner, "Weekend".into()).with_items(items);
+        storage.insert_list(&list).await.unwrap();
+
+        let flour = list.items()[1].uuid();
+        let checked = storage.set_checked(list.uuid(), flour, true).await.unwrap();
+        assert!(checked.checked());
+        let stored = storage.list_lists(owner).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(
+            stored[0]
+                .items()
+                .iter()
+                .map(|item| (item.name(), item.checked()))
+                .collect::<Vec<_>>(),
+            [("milk", false), ("flour", true), ("eggs", false)]
+        );
+        assert!(matches!(
+            storage.set_checked(Uuid::new_v4(), flour, false).await,
+            Err(ShoppingListError::ItemNotFound)
+        ));
+    }
+}
@@ -0,0 +1 @@
+pub mod tags_sqlite_ds;
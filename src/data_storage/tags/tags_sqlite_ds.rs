@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, SqlitePool};
+use tracing::info;
+
+use crate::{
+    data_storage::recipes::recipes_sqlite_ds::push_viewable_by,
+    services::{
+        tags::{
+            domain::tag::Tag,
+            ports::outgoing::tag_port::{TagError, TagPort},
+        },
+        users::domain::caller::Caller,
+    },
+};
+
+// SQLITE_CONSTRAINT_UNIQUE
+const UNIQUE_VIOLATION_CODE: &str = "2067";
+
+impl From<sqlx::Error> for TagError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => TagError::TagNotFound,
+            sqlx::Error::Database(e)
+                if e.code()
+                    .is_some_and(|code| code.as_ref() == UNIQUE_VIOLATION_CODE) =>
+            {
+                TagError::TagExists
+            }
+            _ => {
+                info!("{}", value);
+                TagError::InternalError
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TagSqliteDS {
+    pool: SqlitePool,
+}
+
+impl TagSqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn query_tag(&self, name: &str) -> Result<Tag, TagError> {
+        let (name, recipes) = sqlx::query_as::<_, (String, i64)>(
+            "SELECT tag.name, COUNT(recipe_tag.recipe_uuid) FROM tag \
+            LEFT JOIN recipe_tag ON tag.id = recipe_tag.tag_id \
+            WHERE tag.name = ? GROUP BY tag.id",
+        )
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(Tag::new(name, recipes as u32))
+    }
+}
+
+#[async_trait]
+impl TagPort for TagSqliteDS {
+    async fn list_tags(&self, caller: Option<Caller>) -> Result<Vec<Tag>, TagError> {
+        let mut builder = QueryBuilder::new(
+            "SELECT tag.name, COUNT(recipe.uuid) FROM tag \
+            LEFT JOIN recipe_tag ON tag.id = recipe_tag.tag_id \
+            LEFT JOIN recipe ON recipe.uuid = recipe_tag.recipe_uuid AND ",
+        );
+        push_viewable_by(&mut builder, caller);
+        let rows = builder
+            .push(" GROUP BY tag.id ORDER BY tag.name")
+            .build_query_as::<(String, i64)>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(name, recipes)| Tag::new(name, recipes as u32))
+            .collect())
+    }
+
+    async fn insert_tag(&self, name: &str) -> Result<Tag, TagError> {
+        sqlx::query("INSERT INTO tag (name) VALUES (?)")
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        Ok(Tag::new(name.to_string(), 0))
+    }
+
+    async fn rename_tag(&self, name: &str, new_name: &str) -> Result<Tag, TagError> {
+        let result = sqlx::query("UPDATE tag SET name = ? WHERE name = ?")
+            .bind(new_name)
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(TagError::TagNotFound);
+        }
+        self.query_tag(new_name).await
+    }
+
+    async fn delete_tag(&self, name: &str) -> Result<(), TagError> {
+        // recipe_tag rows go with the tag, the foreign key cascades.
+        let result = sqlx::query("DELETE FROM tag WHERE name = ?")
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(TagError::TagNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS, test_recipe},
+        services::{
+            recipes::{
+                domain::access::{RecipeAccess, Visibility},
+                ports::outgoing::insert_recipe_port::InsertRecipePort,
+            },
+            users::domain::caller::Role,
+        },
+    };
+
+    #[tokio::test]
+    async fn recipes_are_counted_only_for_their_viewers() {
+        let pool = memory_pool().await;
+        let recipes = RecipeSqliteDS::new(pool.clone());
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        for visibility in [Visibility::Public, Visibility::Private] {
+            let recipe = test_recipe(RecipeAccess::new(Some(owner.user()), visibility))
+                .with_tags(vec!["soup".into()]);
+            recipes.insert_recipe(recipe).await.unwrap();
+        }
+        let tags = TagSqliteDS::new(pool);
+        tags.insert_tag("unused").await.unwrap();
+
+        let stranger = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        for (caller, count) in [
+            (None, 1),
+            (Some(stranger), 1),
+            (Some(owner), 2),
+            (Some(admin), 2),
+        ] {
+            assert_eq!(
+                tags.list_tags(caller).await.unwrap(),
+                vec![Tag::new("soup".into(), count), Tag::new("unused".into(), 0)]
+            );
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::services::users::{
+    domain::user::User,
+    ports::outgoing::{
+        insert_user_port::{InsertUserError, InsertUserPort},
+        query_user_port::{QueryUserError, QueryUserPort},
+    },
+};
+
+// SQLITE_CONSTRAINT_UNIQUE and SQLITE_CONSTRAINT_PRIMARYKEY
+const UNIQUE_VIOLATION_CODES: [&str; 2] = ["2067", "1555"];
+
+impl From<sqlx::Error> for InsertUserError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::Database(e)
+                if e.code()
+                    .is_some_and(|code| UNIQUE_VIOLATION_CODES.contains(&code.as_ref())) =>
+            {
+                InsertUserError::UsernameTaken
+            }
+            _ => {
+                info!("{}", value);
+                InsertUserError::InternalError
+            }
+        }
+    }
+}
+
+impl From<sqlx::Error> for QueryUserError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => QueryUserError::RecordNotFound,
+            _ => {
+                info!("{}", value);
+                QueryUserError::InternalError
+            }
+        }
+    }
+}
+
+fn user_from_row(
+    (uuid, username, password_hash, role): (String, String, String, String),
+) -> Result<User, QueryUserError> {
+    Ok(User::new(
+        Uuid::parse_str(&uuid).map_err(|_e| QueryUserError::InternalError)?,
+        username,
+        password_hash,
+    )
+    .with_role(role.parse().map_err(|_e| QueryUserError::InternalError)?))
+}
+
+#[derive(Clone)]
+pub struct UserSqliteDS {
+    pool: SqlitePool,
+}
+
+impl UserSqliteDS {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InsertUserPort for UserSqliteDS {
+    async fn insert_user(&self, user: &User) -> Result<(), InsertUserError> {
+        sqlx::query(
+            "INSERT INTO users (uuid, username, password_hash, role, created_at) \
+            VALUES (?, ?, ?, ?, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
+        )
+        .bind(user.uuid().to_string())
+        .bind(user.username())
+        .bind(user.password_hash())
+        .bind(user.role().as_str())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl QueryUserPort for UserSqliteDS {
+    async fn query_user(&self, uuid: uuid::Uuid) -> Result<User, QueryUserError> {
+        let row: (String, String, String, String) =
+            sqlx::query_as("SELECT uuid, username, password_hash, role FROM users WHERE uuid = ?")
+                .bind(uuid.to_string())
+                .fetch_one(&self.pool)
+                .await?;
+        user_from_row(row)
+    }
+
+    async fn query_user_by_username(&self, username: &str) -> Result<User, QueryUserError> {
+        let row: (String, String, String, String) = sqlx::query_as(
+            "SELECT uuid, username, password_hash, role FROM users WHERE username = ?",
+        )
+        .bind(username)
+        .fetch_one(&self.pool)
+        .await?;
+        user_from_row(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data_storage::memory_pool, services::users::domain::caller::Role};
+
+    #[tokio::test]
+    async fn usernames_are_unique_regardless_of_case() {
+        let storage = UserSqliteDS::new(memory_pool().await);
+        let admin =
+            User::new(uuid::Uuid::new_v4(), "Alice".into(), "hash".into()).with_role(Role::Admin);
+        storage.insert_user(&admin).await.unwrap();
+
+        let stored = storage.query_user_by_username("alice").await.unwrap();
+        assert_eq!(stored.uuid(), admin.uuid());
+        assert_eq!(stored.role(), Role::Admin);
+        let twin = User::new(uuid::Uuid::new_v4(), "ALICE".into(), "hash".into());
+        assert!(matches!(
+            storage.insert_user(&twin).await,
+            Err(InsertUserError::UsernameTaken)
+        ));
+        assert!(matches!(
+            storage.query_user(twin.uuid()).await,
+            Err(QueryUserError::RecordNotFound)
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use axum::{
@@ -7,15 +8,18 @@ use axum::{
         Method,
     },
     routing::get,
-    Router,
+    Extension, Router,
 };
 use axum_server::Handle;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{event, Level};
 
-use crate::configuration::Configuration;
+use crate::configuration::{Configuration, IdentitySource};
 use crate::state::State;
 use crate::web;
+use crate::web::users::identity::{
+    BearerTokenIdentity, DynIdentityExtractor, TrustedHeaderIdentity,
+};
 
 pub struct Server {
     handle: Option<Handle>,
@@ -84,9 +88,26 @@ impl Server {
             .allow_origin(Any)
             .allow_methods([Method::GET])
             .allow_headers([AUTHORIZATION, ORIGIN, ACCEPT, ACCESS_CONTROL_ALLOW_ORIGIN]);
+        let identity = match state.identity_source() {
+            IdentitySource::Token => {
+                Arc::new(BearerTokenIdentity::new(state.token_signer())) as DynIdentityExtractor
+            }
+            IdentitySource::Header => Arc::new(TrustedHeaderIdentity) as DynIdentityExtractor,
+        };
         Router::new()
             .route("/", get(hello_world))
-            .merge(web::recipes::router(state))
+            .merge(web::recipes::router(state.clone()))
+            .merge(web::collections::router(state.clone()))
+            .merge(web::reviews::router(state.clone()))
+            .merge(web::shopping_lists::router(state.clone()))
+            .merge(web::images::router(state.clone()))
+            .merge(web::library::router(state.clone()))
+            .merge(web::meal_plans::router(state.clone()))
+            .merge(web::nutrition::router(state.clone()))
+            .merge(web::pantry::router(state.clone()))
+            .merge(web::tags::router(state.clone()))
+            .merge(web::users::router(state))
+            .layer(Extension(identity))
             .layer(cors)
             .fallback(web::handler_404)
     }
@@ -0,0 +1,88 @@
+use std::{error::Error, fmt::Display};
+
+pub const MAX_COLLECTION_NAME_LENGTH: usize = 64;
+
+/// A named, ordered list of recipes kept by one user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collection {
+    uuid: uuid::Uuid,
+    owner: uuid::Uuid,
+    name: String,
+    recipes: Vec<uuid::Uuid>,
+}
+
+impl Collection {
+    pub fn new(uuid: uuid::Uuid, owner: uuid::Uuid, name: String) -> Self {
+        Self {
+            uuid,
+            owner,
+            name,
+            recipes: vec![],
+        }
+    }
+
+    /// Recipes in the order they are shown.
+    pub fn with_recipes(self, recipes: Vec<uuid::Uuid>) -> Self {
+        Self { recipes, ..self }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn owner(&self) -> uuid::Uuid {
+        self.owner
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn recipes(&self) -> &[uuid::Uuid] {
+        self.recipes.as_ref()
+    }
+
+    /// Trims the name and drops repeated recipes, keeping the first place
+    /// each recipe appears at.
+    pub fn normalized(self) -> Result<Self, CollectionNameError> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err(CollectionNameError::Empty);
+        }
+        if name.chars().count() > MAX_COLLECTION_NAME_LENGTH {
+            return Err(CollectionNameError::TooLong);
+        }
+        let mut recipes: Vec<uuid::Uuid> = vec![];
+        for recipe in self.recipes {
+            if !recipes.contains(&recipe) {
+                recipes.push(recipe);
+            }
+        }
+        Ok(Self {
+            name,
+            recipes,
+            ..self
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CollectionNameError {
+    Empty,
+    TooLong,
+}
+
+impl Display for CollectionNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CollectionNameError::Empty => f.write_str("Collection name is empty"),
+            CollectionNameError::TooLong => write!(
+                f,
+                "Collection names are at most {} characters long",
+                MAX_COLLECTION_NAME_LENGTH
+            ),
+        }
+    }
+}
+
+impl Error for CollectionNameError {}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+
+use super::ports::{
+    incoming::favourite_recipes_service::{FavouriteRecipesService, FavouriteRecipesServiceError},
+    outgoing::favourite_port::{FavouriteError, FavouritePort},
+};
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+impl From<FavouriteError> for FavouriteRecipesServiceError {
+    fn from(value: FavouriteError) -> Self {
+        match value {
+            FavouriteError::RecipeNotFound => FavouriteRecipesServiceError::RecipeNotFound,
+            FavouriteError::InternalError => FavouriteRecipesServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for FavouriteRecipesServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => FavouriteRecipesServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => FavouriteRecipesServiceError::InternalError,
+        }
+    }
+}
+
+pub struct FavouriteRecipes<Storage, Recipes>
+where
+    Storage: FavouritePort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+}
+
+#[async_trait]
+impl<Storage, Recipes> FavouriteRecipesService for FavouriteRecipes<Storage, Recipes>
+where
+    Storage: FavouritePort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    async fn list_favourites(
+        &self,
+        caller: Caller,
+    ) -> Result<Vec<uuid::Uuid>, FavouriteRecipesServiceError> {
+        Ok(self.storage.list_favourites(caller.user()).await?)
+    }
+
+    async fn toggle_favourite(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+    ) -> Result<bool, FavouriteRecipesServiceError> {
+        // Removing needs no access check, a recipe that was made private
+        // can still be dropped from the favourites.
+        if self.storage.remove_favourite(caller.user(), recipe).await? {
+            return Ok(false);
+        }
+        let access = self.recipes.recipe_access(recipe).await?;
+        if !access.can_view(Some(&caller)) {
+            return Err(FavouriteRecipesServiceError::RecipeNotFound);
+        }
+        self.storage.add_favourite(caller.user(), recipe).await?;
+        Ok(true)
+    }
+}
+
+impl<Storage, Recipes> FavouriteRecipes<Storage, Recipes>
+where
+    Storage: FavouritePort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes) -> Self {
+        Self { storage, recipes }
+    }
+}
@@ -0,0 +1,285 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::collection::Collection,
+    ports::{
+        incoming::manage_collections_service::{
+            ManageCollectionsService, ManageCollectionsServiceError,
+        },
+        outgoing::collection_port::{CollectionError, CollectionPort},
+    },
+};
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+impl From<CollectionError> for ManageCollectionsServiceError {
+    fn from(value: CollectionError) -> Self {
+        match value {
+            CollectionError::CollectionNotFound => {
+                ManageCollectionsServiceError::CollectionNotFound
+            }
+            CollectionError::CollectionExists => ManageCollectionsServiceError::CollectionExists,
+            CollectionError::RecipeExists => ManageCollectionsServiceError::RecipeExists,
+            // Recipes are checked before they are stored, a missing one
+            // means it was deleted meanwhile.
+            CollectionError::RecipeNotFound | CollectionError::InternalError => {
+                ManageCollectionsServiceError::InternalError
+            }
+        }
+    }
+}
+
+pub struct ManageCollections<Storage, Recipes>
+where
+    Storage: CollectionPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+}
+
+impl<Storage, Recipes> ManageCollections<Storage, Recipes>
+where
+    Storage: CollectionPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes) -> Self {
+        Self { storage, recipes }
+    }
+
+    /// The collection, unless it belongs to somebody else.
+    async fn owned_collection(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<Collection, ManageCollectionsServiceError> {
+        let collection = self.storage.query_collection(uuid).await?;
+        if collection.owner() != caller.user() {
+            return Err(ManageCollectionsServiceError::CollectionNotFound);
+        }
+        Ok(collection)
+    }
+
+    async fn check_viewable(
+        &self,
+        caller: Caller,
+        recipes: &[uuid::Uuid],
+    ) -> Result<(), ManageCollectionsServiceError> {
+        for &recipe in recipes {
+            match self.recipes.recipe_access(recipe).await {
+                Ok(access) if access.can_view(Some(&caller)) => (),
+                Ok(_) | Err(RecipeAccessError::RecordNotFound) => {
+                    return Err(ManageCollectionsServiceError::RecipeNotFound(recipe))
+                }
+                Err(RecipeAccessError::InternalError) => {
+                    return Err(ManageCollectionsServiceError::InternalError)
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Storage, Recipes> ManageCollectionsService for ManageCollections<Storage, Recipes>
+where
+    Storage: CollectionPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    async fn list_collections(
+        &self,
+        caller: Caller,
+    ) -> Result<Vec<Collection>, ManageCollectionsServiceError> {
+        Ok(self.storage.list_collections(caller.user()).await?)
+    }
+
+    async fn query_collection(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<Collection, ManageCollectionsServiceError> {
+        self.owned_collection(caller, uuid).await
+    }
+
+    async fn create_collection(
+        &self,
+        caller: Caller,
+        name: String,
+        recipes: Vec<uuid::Uuid>,
+    ) -> Result<Collection, ManageCollectionsServiceError> {
+        let collection = Collection::new(uuid::Uuid::new_v4(), caller.user(), name)
+            .with_recipes(recipes)
+            .normalized()
+            .map_err(ManageCollectionsServiceError::InvalidName)?;
+        self.check_viewable(caller, collection.recipes()).await?;
+        Ok(self.storage.insert_collection(&collection).await?)
+    }
+
+    async fn update_collection(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        name: Option<String>,
+        recipes: Option<Vec<uuid::Uuid>>,
+    ) -> Result<Collection, ManageCollectionsServiceError> {
+        let current = self.owned_collection(caller, uuid).await?;
+        let collection = Collection::new(
+            uuid,
+            current.owner(),
+            name.unwrap_or_else(|| current.name().to_string()),
+        )
+        .with_recipes(recipes.unwrap_or_else(|| current.recipes().to_vec()))
+        .normalized()
+        .map_err(ManageCollectionsServiceError::InvalidName)?;
+        // Recipes kept from before may stay even if they are no longer
+        // visible, reordering should not fail because of them.
+        let added = collection
+            .recipes()
+            .iter()
+            .filter(|recipe| !current.recipes().contains(recipe))
+            .copied()
+            .collect::<Vec<uuid::Uuid>>();
+        self.check_viewable(caller, &added).await?;
+        Ok(self.storage.update_collection(&collection).await?)
+    }
+
+    async fn delete_collection(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManageCollectionsServiceError> {
+        self.owned_collection(caller, uuid).await?;
+        Ok(self.storage.delete_collection(uuid).await?)
+    }
+
+    async fn add_recipe(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, ManageCollectionsServiceError> {
+        self.owned_collection(caller, uuid).await?;
+        self.check_viewable(caller, &[recipe]).await?;
+        self.storage
+            .add_recipe(uuid, recipe)
+            .await
+            .map_err(|err| match err {
+                CollectionError::RecipeNotFound => {
+                    ManageCollectionsServiceError::RecipeNotFound(recipe)
+                }
+                err => err.into(),
+            })
+    }
+
+    async fn remove_recipe(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, ManageCollectionsServiceError> {
+        self.owned_collection(caller, uuid).await?;
+        self.storage
+            .remove_recipe(uuid, recipe)
+            .await
+            .map_err(|err| match err {
+                CollectionError::RecipeNotFound => {
+                    ManageCollectionsServiceError::RecipeNotFound(recipe)
+                }
+                err => err.into(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use super::*;
+    use crate::{
+        data_storage::{
+            collections::collections_sqlite_ds::CollectionSqliteDS, memory_pool,
+            recipes::recipes_sqlite_ds::RecipeSqliteDS, stored_recipe,
+        },
+        services::{
+            collections::domain::collection::CollectionNameError,
+            recipes::domain::access::{RecipeAccess, Visibility},
+            users::domain::caller::Role,
+        },
+    };
+
+    async fn service() -> (
+        ManageCollections<CollectionSqliteDS, RecipeSqliteDS>,
+        SqlitePool,
+    ) {
+        let pool = memory_pool().await;
+        let recipes = RecipeSqliteDS::new(pool.clone());
+        (
+            ManageCollections::new(CollectionSqliteDS::new(pool.clone()), recipes),
+            pool,
+        )
+    }
+
+    #[tokio::test]
+    async fn collections_of_others_are_not_found() {
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        let (service, _pool) = service().await;
+        let uuid = service
+            .create_collection(owner, "Christmas".into(), vec![])
+            .await
+            .unwrap()
+            .uuid();
+
+        assert!(service.query_collection(owner, uuid).await.is_ok());
+        assert_eq!(
+            service.query_collection(admin, uuid).await.unwrap_err(),
+            ManageCollectionsServiceError::CollectionNotFound
+        );
+        assert_eq!(
+            service.delete_collection(admin, uuid).await.unwrap_err(),
+            ManageCollectionsServiceError::CollectionNotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn only_viewable_recipes_are_added() {
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let (service, pool) = service().await;
+        let private = |owner| RecipeAccess::new(Some(owner), Visibility::Private);
+        let kept = stored_recipe(&pool, private(owner.user())).await.uuid();
+        let added = stored_recipe(&pool, private(uuid::Uuid::new_v4()))
+            .await
+            .uuid();
+        let uuid = service
+            .create_collection(owner, "Christmas".into(), vec![kept])
+            .await
+            .unwrap()
+            .uuid();
+
+        assert_eq!(
+            service.add_recipe(owner, uuid, added).await.unwrap_err(),
+            ManageCollectionsServiceError::RecipeNotFound(added)
+        );
+        let reordered = service
+            .update_collection(owner, uuid, None, Some(vec![kept, kept]))
+            .await
+            .unwrap();
+        assert_eq!(reordered.recipes(), &[kept]);
+        assert_eq!(
+            service
+                .update_collection(owner, uuid, None, Some(vec![added, kept]))
+                .await
+                .unwrap_err(),
+            ManageCollectionsServiceError::RecipeNotFound(added)
+        );
+        assert_eq!(
+            service
+                .update_collection(owner, uuid, Some("  ".into()), None)
+                .await
+                .unwrap_err(),
+            ManageCollectionsServiceError::InvalidName(CollectionNameError::Empty)
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod favourite_recipes_service;
+pub mod manage_collections_service;
+pub mod ports;
@@ -0,0 +1,37 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::users::domain::caller::Caller;
+
+#[async_trait]
+pub trait FavouriteRecipesService {
+    /// Favourite recipes of `caller`, most recent first.
+    async fn list_favourites(
+        &self,
+        caller: Caller,
+    ) -> Result<Vec<uuid::Uuid>, FavouriteRecipesServiceError>;
+    /// Marks the recipe as a favourite of `caller`, or unmarks it when it
+    /// already was one. Returns whether it is a favourite afterwards.
+    async fn toggle_favourite(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+    ) -> Result<bool, FavouriteRecipesServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FavouriteRecipesServiceError {
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for FavouriteRecipesServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FavouriteRecipesServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            FavouriteRecipesServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for FavouriteRecipesServiceError {}
@@ -0,0 +1,90 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    collections::domain::collection::{Collection, CollectionNameError},
+    users::domain::caller::Caller,
+};
+
+/// Collections are private, other users' collections are reported as not
+/// found.
+#[async_trait]
+pub trait ManageCollectionsService {
+    async fn list_collections(
+        &self,
+        caller: Caller,
+    ) -> Result<Vec<Collection>, ManageCollectionsServiceError>;
+    async fn query_collection(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<Collection, ManageCollectionsServiceError>;
+    /// Creates a collection holding `recipes`, in order. `caller` must be
+    /// able to view each of them.
+    async fn create_collection(
+        &self,
+        caller: Caller,
+        name: String,
+        recipes: Vec<uuid::Uuid>,
+    ) -> Result<Collection, ManageCollectionsServiceError>;
+    /// Renames the collection and replaces its recipes, unless they are
+    /// `None`.
+    async fn update_collection(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        name: Option<String>,
+        recipes: Option<Vec<uuid::Uuid>>,
+    ) -> Result<Collection, ManageCollectionsServiceError>;
+    async fn delete_collection(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManageCollectionsServiceError>;
+    /// Appends a recipe `caller` may view to the collection.
+    async fn add_recipe(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, ManageCollectionsServiceError>;
+    async fn remove_recipe(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, ManageCollectionsServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ManageCollectionsServiceError {
+    InvalidName(CollectionNameError),
+    CollectionNotFound,
+    CollectionExists,
+    RecipeNotFound(uuid::Uuid),
+    RecipeExists,
+    InternalError,
+}
+
+impl Display for ManageCollectionsServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageCollectionsServiceError::InvalidName(error) => write!(f, "{}", error),
+            ManageCollectionsServiceError::CollectionNotFound => {
+                f.write_str("Collection not found")
+            }
+            ManageCollectionsServiceError::CollectionExists => {
+                f.write_str("You already have a collection with that name")
+            }
+            ManageCollectionsServiceError::RecipeNotFound(recipe) => {
+                write!(f, "Recipe `{}` not found", recipe)
+            }
+            ManageCollectionsServiceError::RecipeExists => {
+                f.write_str("Recipe already in collection")
+            }
+            ManageCollectionsServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for ManageCollectionsServiceError {}
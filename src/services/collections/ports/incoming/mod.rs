@@ -0,0 +1,2 @@
+pub mod favourite_recipes_service;
+pub mod manage_collections_service;
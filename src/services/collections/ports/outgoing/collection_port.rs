@@ -0,0 +1,58 @@
+use crate::services::collections::domain::collection::Collection;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+/// Collections given to the port are already normalized.
+#[async_trait]
+pub trait CollectionPort {
+    /// Collections of `owner`, by name.
+    async fn list_collections(&self, owner: uuid::Uuid)
+        -> Result<Vec<Collection>, CollectionError>;
+    async fn query_collection(&self, uuid: uuid::Uuid) -> Result<Collection, CollectionError>;
+    async fn insert_collection(
+        &self,
+        collection: &Collection,
+    ) -> Result<Collection, CollectionError>;
+    /// Renames the collection and replaces its recipes, in order.
+    async fn update_collection(
+        &self,
+        collection: &Collection,
+    ) -> Result<Collection, CollectionError>;
+    async fn delete_collection(&self, uuid: uuid::Uuid) -> Result<(), CollectionError>;
+    /// Appends `recipe` after the recipes already in the collection.
+    async fn add_recipe(
+        &self,
+        collection: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, CollectionError>;
+    async fn remove_recipe(
+        &self,
+        collection: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<Collection, CollectionError>;
+}
+
+#[derive(Debug)]
+pub enum CollectionError {
+    CollectionNotFound,
+    /// The owner already has a collection with that name.
+    CollectionExists,
+    RecipeNotFound,
+    /// The recipe is already in the collection.
+    RecipeExists,
+    InternalError,
+}
+
+impl Display for CollectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CollectionNotFound => write!(f, "Collection not found"),
+            Self::CollectionExists => write!(f, "Collection already exists"),
+            Self::RecipeNotFound => write!(f, "Recipe not found"),
+            Self::RecipeExists => write!(f, "Recipe already in collection"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for CollectionError {}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait FavouritePort {
+    /// Favourite recipes of `user`, most recent first.
+    async fn list_favourites(&self, user: uuid::Uuid) -> Result<Vec<uuid::Uuid>, FavouriteError>;
+    async fn add_favourite(
+        &self,
+        user: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<(), FavouriteError>;
+    /// `false` when the recipe was not a favourite of `user`.
+    async fn remove_favourite(
+        &self,
+        user: uuid::Uuid,
+        recipe: uuid::Uuid,
+    ) -> Result<bool, FavouriteError>;
+}
+
+#[derive(Debug)]
+pub enum FavouriteError {
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for FavouriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecipeNotFound => write!(f, "Recipe not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for FavouriteError {}
@@ -0,0 +1,2 @@
+pub mod collection_port;
+pub mod favourite_port;
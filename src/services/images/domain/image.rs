@@ -0,0 +1,164 @@
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Webp,
+    Gif,
+}
+
+impl ImageFormat {
+    pub fn from_mime(mime: &str) -> Option<Self> {
+        match mime.trim().to_ascii_lowercase().as_str() {
+            "image/jpeg" | "image/jpg" => Some(Self::Jpeg),
+            "image/png" => Some(Self::Png),
+            "image/webp" => Some(Self::Webp),
+            "image/gif" => Some(Self::Gif),
+            _ => None,
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "jpg" => Some(Self::Jpeg),
+            "png" => Some(Self::Png),
+            "webp" => Some(Self::Webp),
+            "gif" => Some(Self::Gif),
+            _ => None,
+        }
+    }
+
+    /// Detects the format from the file signature.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+            Some(Self::Png)
+        } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else {
+            None
+        }
+    }
+
+    pub fn mime(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Webp => "image/webp",
+            Self::Gif => "image/gif",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::Webp => "webp",
+            Self::Gif => "gif",
+        }
+    }
+}
+
+/// Name of an image managed by the image service: the sha256 of its content
+/// followed by the format extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageName(String);
+
+impl ImageName {
+    pub fn for_content(bytes: &[u8], format: ImageFormat) -> Self {
+        let digest = Sha256::digest(bytes)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        Self(format!("{}.{}", digest, format.extension()))
+    }
+
+    /// Accepts only names produced by [`ImageName::for_content`], so a stored
+    /// name can never point outside the image directory.
+    pub fn parse(name: &str) -> Option<Self> {
+        let (digest, extension) = name.split_once('.')?;
+        let valid_digest = digest.len() == 64
+            && digest
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+        if valid_digest && ImageFormat::from_extension(extension).is_some() {
+            Some(Self(name.to_string()))
+        } else {
+            None
+        }
+    }
+
+    pub fn digest(&self) -> &str {
+        self.0.split_once('.').map(|(digest, _)| digest).unwrap()
+    }
+
+    pub fn format(&self) -> ImageFormat {
+        self.0
+            .split_once('.')
+            .and_then(|(_, extension)| ImageFormat::from_extension(extension))
+            .unwrap()
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    name: ImageName,
+    bytes: Vec<u8>,
+}
+
+impl Image {
+    pub fn new(name: ImageName, bytes: Vec<u8>) -> Self {
+        Self { name, bytes }
+    }
+
+    pub fn name(&self) -> &ImageName {
+        &self.name
+    }
+
+    pub fn format(&self) -> ImageFormat {
+        self.name.format()
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_addressed_names_round_trip() {
+        let name = ImageName::for_content(b"\x89PNG\r\n\x1a\nrest", ImageFormat::Png);
+
+        assert_eq!(ImageName::parse(name.as_str()), Some(name.clone()));
+        assert_eq!(name.format(), ImageFormat::Png);
+        assert_eq!(name.digest().len(), 64);
+    }
+
+    #[test]
+    fn foreign_names_are_rejected() {
+        for name in [
+            "../../etc/passwd",
+            "cat.png",
+            "https://example.com/cat.png",
+            &format!("{}.exe", "a".repeat(64)),
+            &format!("{}.png", "A".repeat(64)),
+        ] {
+            assert_eq!(ImageName::parse(name), None, "{name}");
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::io::Cursor;
+
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, GenericImageView};
+
+use super::image::{Image, ImageFormat, ImageName};
+
+/// Formats every configured size is rendered in.
+pub const VARIANT_FORMATS: [ImageFormat; 2] = [ImageFormat::Webp, ImageFormat::Jpeg];
+
+#[derive(Debug, Clone)]
+pub struct VariantSettings {
+    sizes: Vec<u32>,
+    quality: u8,
+}
+
+impl VariantSettings {
+    /// `quality` applies to JPEG variants, WebP variants are lossless.
+    pub fn new(sizes: Vec<u32>, quality: u8) -> Self {
+        let mut sizes = sizes
+            .into_iter()
+            .filter(|size| *size > 0)
+            .collect::<Vec<u32>>();
+        sizes.sort_unstable();
+        sizes.dedup();
+        Self {
+            sizes,
+            quality: quality.clamp(1, 100),
+        }
+    }
+
+    pub fn sizes(&self) -> &[u32] {
+        self.sizes.as_ref()
+    }
+
+    pub fn quality(&self) -> u8 {
+        self.quality
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageVariant {
+    size: u32,
+    name: ImageName,
+    width: u32,
+    height: u32,
+}
+
+impl ImageVariant {
+    pub fn new(size: u32, name: ImageName, width: u32, height: u32) -> Self {
+        Self {
+            size,
+            name,
+            width,
+            height,
+        }
+    }
+
+    /// The configured bound on the longest edge this variant was rendered for.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn format(&self) -> ImageFormat {
+        self.name.format()
+    }
+
+    pub fn name(&self) -> &ImageName {
+        &self.name
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum VariantError {
+    UndecodableImage,
+    EncodingFailed,
+}
+
+/// Decodes `bytes` and renders one variant per configured size and format.
+/// Images are never upscaled: sizes above the original keep its dimensions.
+pub fn render_variants(
+    bytes: &[u8],
+    settings: &VariantSettings,
+) -> Result<Vec<(ImageVariant, Image)>, VariantError> {
+    let source = image::load_from_memory(bytes).map_err(|_e| VariantError::UndecodableImage)?;
+    let (width, height) = source.dimensions();
+
+    let mut variants = vec![];
+    for size in settings.sizes() {
+        let resized = if width.max(height) > *size {
+            source.resize(*size, *size, FilterType::Lanczos3)
+        } else {
+            source.clone()
+        };
+        for format in VARIANT_FORMATS {
+            let encoded = encode(&resized, format, settings.quality())?;
+            let name = ImageName::for_content(&encoded, format);
+            variants.push((
+                ImageVariant::new(*size, name.clone(), resized.width(), resized.height()),
+                Image::new(name, encoded),
+            ));
+        }
+    }
+    Ok(variants)
+}
+
+fn encode(image: &DynamicImage, format: ImageFormat, quality: u8) -> Result<Vec<u8>, VariantError> {
+    match format {
+        ImageFormat::Jpeg => {
+            let mut buffer = Cursor::new(vec![]);
+            JpegEncoder::new_with_quality(&mut buffer, quality)
+                .encode_image(&DynamicImage::ImageRgb8(image.to_rgb8()))
+                .map_err(|_e| VariantError::EncodingFailed)?;
+            Ok(buffer.into_inner())
+        }
+        // The image crate only writes lossless WebP, which ignores `quality`
+        // and comes out larger than the JPEG it is meant to replace.
+        ImageFormat::Webp => {
+            let rgba = image.to_rgba8();
+            webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height())
+                .encode_simple(false, f32::from(quality))
+                .map(|encoded| encoded.to_vec())
+                .map_err(|_e| VariantError::EncodingFailed)
+        }
+        _ => Err(VariantError::EncodingFailed),
+    }
+}
+
+/// Picks the variant to serve for a requested size: the smallest one at least
+/// as large as `size` in the first available preferred format, or the largest
+/// one when none is big enough.
+pub fn select_variant<'a>(
+    variants: &'a [ImageVariant],
+    size: u32,
+    formats: &[ImageFormat],
+) -> Option<&'a ImageVariant> {
+    formats.iter().find_map(|format| {
+        let mut candidates = variants
+            .iter()
+            .filter(|variant| variant.format() == *format)
+            .collect::<Vec<&ImageVariant>>();
+        candidates.sort_by_key(|variant| variant.size());
+        candidates
+            .iter()
+            .find(|variant| variant.size() >= size)
+            .or(candidates.last())
+            .copied()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut buffer = Cursor::new(vec![]);
+        DynamicImage::new_rgb8(width, height)
+            .write_to(&mut buffer, image::ImageFormat::Png)
+            .unwrap();
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn variants_are_bounded_by_size_and_never_upscaled() {
+        let settings = VariantSettings::new(vec![64, 16, 512], 80);
+
+        let variants = render_variants(&png(200, 100), &settings).unwrap();
+
+        let dimensions = variants
+            .iter()
+            .map(|(variant, _)| {
+                (
+                    variant.size(),
+                    variant.format(),
+                    variant.width(),
+                    variant.height(),
+                )
+            })
+            .collect::<Vec<(u32, ImageFormat, u32, u32)>>();
+        assert_eq!(
+            dimensions,
+            vec![
+                (16, ImageFormat::Webp, 16, 8),
+                (16, ImageFormat::Jpeg, 16, 8),
+                (64, ImageFormat::Webp, 64, 32),
+                (64, ImageFormat::Jpeg, 64, 32),
+                (512, ImageFormat::Webp, 200, 100),
+                (512, ImageFormat::Jpeg, 200, 100),
+            ]
+        );
+        for (variant, image) in variants {
+            assert_eq!(ImageFormat::sniff(image.bytes()), Some(variant.format()));
+        }
+    }
+
+    #[test]
+    fn webp_variants_follow_the_quality() {
+        let noise = image::RgbImage::from_fn(256, 256, |x, y| {
+            image::Rgb([(x * 7 + y * 13) as u8, (x * y) as u8, (x ^ y) as u8])
+        });
+        let mut buffer = Cursor::new(vec![]);
+        DynamicImage::ImageRgb8(noise)
+            .write_to(&mut buffer, image::ImageFormat::Png)
+            .unwrap();
+        let webp_length = |quality| {
+            render_variants(buffer.get_ref(), &VariantSettings::new(vec![256], quality))
+                .unwrap()
+                .into_iter()
+                .find(|(variant, _)| variant.format() == ImageFormat::Webp)
+                .map(|(_, image)| image.bytes().len())
+                .unwrap()
+        };
+
+        assert!(webp_length(20) < webp_length(95));
+    }
+
+    #[test]
+    fn undecodable_images_are_rejected() {
+        let settings = VariantSettings::new(vec![64], 80);
+
+        let result = render_variants(b"\x89PNG\r\n\x1a\ngarbage", &settings);
+        assert_eq!(result.unwrap_err(), VariantError::UndecodableImage);
+    }
+
+    #[test]
+    fn selection_prefers_smallest_sufficient_size() {
+        let variant = |size, format| {
+            ImageVariant::new(
+                size,
+                ImageName::for_content(&[size as u8], format),
+                size,
+                size,
+            )
+        };
+        let variants = vec![
+            variant(128, ImageFormat::Jpeg),
+            variant(512, ImageFormat::Jpeg),
+            variant(128, ImageFormat::Webp),
+        ];
+
+        let pick = |size, formats: &[ImageFormat]| {
+            select_variant(&variants, size, formats).map(|v| (v.size(), v.format()))
+        };
+        assert_eq!(
+            pick(200, &[ImageFormat::Jpeg]),
+            Some((512, ImageFormat::Jpeg))
+        );
+        assert_eq!(
+            pick(2000, &[ImageFormat::Jpeg]),
+            Some((512, ImageFormat::Jpeg))
+        );
+        assert_eq!(
+            pick(200, &[ImageFormat::Webp, ImageFormat::Jpeg]),
+            Some((128, ImageFormat::Webp))
+        );
+        assert_eq!(pick(100, &[ImageFormat::Png]), None);
+    }
+}
@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+use super::{
+    domain::{
+        image::{Image, ImageName},
+        variant::{self, ImageVariant, VariantError, VariantSettings},
+    },
+    ports::{
+        incoming::generate_variants_service::{
+            GenerateVariantsService, GenerateVariantsServiceError,
+        },
+        outgoing::{
+            image_storage_port::{ImageStorageError, ImageStoragePort},
+            image_variant_port::{ImageVariantError, ImageVariantPort},
+            recipe_image_port::{RecipeImageError, RecipeImagePort},
+        },
+    },
+    upload_image_service::remove_orphaned_image,
+};
+
+impl From<VariantError> for GenerateVariantsServiceError {
+    fn from(value: VariantError) -> Self {
+        match value {
+            VariantError::UndecodableImage => GenerateVariantsServiceError::UndecodableImage,
+            VariantError::EncodingFailed => GenerateVariantsServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeImageError> for GenerateVariantsServiceError {
+    fn from(value: RecipeImageError) -> Self {
+        match value {
+            RecipeImageError::RecordNotFound => GenerateVariantsServiceError::RecipeNotFound,
+            RecipeImageError::InternalError => GenerateVariantsServiceError::InternalError,
+        }
+    }
+}
+
+impl From<ImageVariantError> for GenerateVariantsServiceError {
+    fn from(value: ImageVariantError) -> Self {
+        match value {
+            ImageVariantError::RecordNotFound => GenerateVariantsServiceError::RecipeNotFound,
+            ImageVariantError::InternalError => GenerateVariantsServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for GenerateVariantsServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => GenerateVariantsServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => GenerateVariantsServiceError::InternalError,
+        }
+    }
+}
+
+impl From<ImageStorageError> for GenerateVariantsServiceError {
+    fn from(value: ImageStorageError) -> Self {
+        match value {
+            ImageStorageError::NotFound => GenerateVariantsServiceError::ImageNotFound,
+            ImageStorageError::InternalError => GenerateVariantsServiceError::InternalError,
+        }
+    }
+}
+
+/// Renders the variants off the async runtime, resizing is CPU bound.
+pub(crate) async fn render_variants(
+    bytes: Vec<u8>,
+    settings: VariantSettings,
+) -> Result<Vec<(ImageVariant, Image)>, GenerateVariantsServiceError> {
+    tokio::task::spawn_blocking(move || variant::render_variants(&bytes, &settings))
+        .await
+        .map_err(|e| {
+            info!("{}", e);
+            GenerateVariantsServiceError::InternalError
+        })?
+        .map_err(|e| e.into())
+}
+
+/// Stores the rendered files, records them for `recipe` and drops the files
+/// of the variants they replace.
+pub(crate) async fn persist_variants<Storage, Images>(
+    storage: &Storage,
+    images: &Images,
+    recipe: uuid::Uuid,
+    rendered: Vec<(ImageVariant, Image)>,
+) -> Result<Vec<ImageVariant>, GenerateVariantsServiceError>
+where
+    Storage: RecipeImagePort + ImageVariantPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    let mut variants = vec![];
+    for (variant, image) in rendered {
+        images.store_image(&image).await?;
+        variants.push(variant);
+    }
+    let replaced = storage.replace_image_variants(recipe, &variants).await?;
+    for old in replaced {
+        remove_orphaned_image(storage, images, old.name().as_str()).await;
+    }
+    Ok(variants)
+}
+
+pub struct GenerateVariants<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    storage: Storage,
+    images: Images,
+    settings: VariantSettings,
+}
+
+#[async_trait]
+impl<Storage, Images> GenerateVariantsService for GenerateVariants<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    async fn generate_variants(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+    ) -> Result<Vec<ImageVariant>, GenerateVariantsServiceError> {
+        let access = self.storage.recipe_access(recipe).await?;
+        if !access.can_view(Some(&caller)) {
+            return Err(GenerateVariantsServiceError::RecipeNotFound);
+        }
+        if !access.can_modify(&caller) {
+            return Err(GenerateVariantsServiceError::Forbidden);
+        }
+        let name = self
+            .storage
+            .recipe_image(recipe)
+            .await?
+            .and_then(|name| ImageName::parse(&name))
+            .ok_or(GenerateVariantsServiceError::ImageNotFound)?;
+        let original = self.images.load_image(&name).await?;
+        let rendered = render_variants(original.into_bytes(), self.settings.clone()).await?;
+        persist_variants(&self.storage, &self.images, recipe, rendered).await
+    }
+}
+
+impl<Storage, Images> GenerateVariants<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    pub fn new(storage: Storage, images: Images, settings: VariantSettings) -> Self {
+        Self {
+            storage,
+            images,
+            settings,
+        }
+    }
+}
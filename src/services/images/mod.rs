@@ -0,0 +1,5 @@
+pub mod domain;
+pub mod generate_variants_service;
+pub mod ports;
+pub mod query_image_service;
+pub mod upload_image_service;
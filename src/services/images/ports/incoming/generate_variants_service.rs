@@ -0,0 +1,41 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{images::domain::variant::ImageVariant, users::domain::caller::Caller};
+
+#[async_trait]
+pub trait GenerateVariantsService {
+    /// Only the owner of the recipe or an admin may change its image.
+    async fn generate_variants(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+    ) -> Result<Vec<ImageVariant>, GenerateVariantsServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum GenerateVariantsServiceError {
+    RecipeNotFound,
+    Forbidden,
+    ImageNotFound,
+    UndecodableImage,
+    InternalError,
+}
+
+impl Display for GenerateVariantsServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerateVariantsServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            GenerateVariantsServiceError::Forbidden => {
+                f.write_str("You are not allowed to change this recipe")
+            }
+            GenerateVariantsServiceError::ImageNotFound => f.write_str("Image not found"),
+            GenerateVariantsServiceError::UndecodableImage => {
+                f.write_str("Image could not be decoded")
+            }
+            GenerateVariantsServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for GenerateVariantsServiceError {}
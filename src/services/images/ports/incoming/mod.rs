@@ -0,0 +1,3 @@
+pub mod generate_variants_service;
+pub mod query_image_service;
+pub mod upload_image_service;
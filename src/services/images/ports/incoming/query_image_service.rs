@@ -0,0 +1,40 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    images::domain::image::{Image, ImageFormat},
+    users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait QueryImageService {
+    /// Without `size` the original upload is returned, otherwise the closest
+    /// variant in the first of `formats` that has one. Only callers who may
+    /// view the recipe get its image.
+    async fn query_image(
+        &self,
+        caller: Option<Caller>,
+        recipe: uuid::Uuid,
+        size: Option<u32>,
+        formats: Vec<ImageFormat>,
+    ) -> Result<Image, QueryImageServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QueryImageServiceError {
+    RecipeNotFound,
+    ImageNotFound,
+    InternalError,
+}
+
+impl Display for QueryImageServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryImageServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            QueryImageServiceError::ImageNotFound => f.write_str("Image not found"),
+            QueryImageServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for QueryImageServiceError {}
@@ -0,0 +1,47 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{images::domain::image::ImageName, users::domain::caller::Caller};
+
+#[async_trait]
+pub trait UploadImageService {
+    /// Only the owner of the recipe or an admin may change its image.
+    async fn upload_image(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Result<ImageName, UploadImageServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UploadImageServiceError {
+    RecipeNotFound,
+    Forbidden,
+    EmptyImage,
+    ImageTooLarge,
+    UnsupportedContentType,
+    UndecodableImage,
+    InternalError,
+}
+
+impl Display for UploadImageServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UploadImageServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            UploadImageServiceError::Forbidden => {
+                f.write_str("You are not allowed to change this recipe")
+            }
+            UploadImageServiceError::EmptyImage => f.write_str("Image is empty"),
+            UploadImageServiceError::ImageTooLarge => f.write_str("Image is too large"),
+            UploadImageServiceError::UnsupportedContentType => {
+                f.write_str("Image must be a JPEG, PNG, WebP or GIF file")
+            }
+            UploadImageServiceError::UndecodableImage => f.write_str("Image could not be decoded"),
+            UploadImageServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for UploadImageServiceError {}
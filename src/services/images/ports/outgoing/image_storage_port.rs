@@ -0,0 +1,26 @@
+use crate::services::images::domain::image::{Image, ImageName};
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait ImageStoragePort {
+    async fn store_image(&self, image: &Image) -> Result<(), ImageStorageError>;
+    async fn load_image(&self, name: &ImageName) -> Result<Image, ImageStorageError>;
+    async fn remove_image(&self, name: &ImageName) -> Result<(), ImageStorageError>;
+}
+
+#[derive(Debug)]
+pub enum ImageStorageError {
+    NotFound,
+    InternalError,
+}
+
+impl Display for ImageStorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "Image not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for ImageStorageError {}
@@ -0,0 +1,33 @@
+use crate::services::images::domain::variant::ImageVariant;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait ImageVariantPort {
+    async fn image_variants(
+        &self,
+        recipe: uuid::Uuid,
+    ) -> Result<Vec<ImageVariant>, ImageVariantError>;
+    /// Replaces every variant of the recipe and returns the replaced ones.
+    async fn replace_image_variants(
+        &self,
+        recipe: uuid::Uuid,
+        variants: &[ImageVariant],
+    ) -> Result<Vec<ImageVariant>, ImageVariantError>;
+}
+
+#[derive(Debug)]
+pub enum ImageVariantError {
+    RecordNotFound,
+    InternalError,
+}
+
+impl Display for ImageVariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecordNotFound => write!(f, "Record not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for ImageVariantError {}
@@ -0,0 +1,3 @@
+pub mod image_storage_port;
+pub mod image_variant_port;
+pub mod recipe_image_port;
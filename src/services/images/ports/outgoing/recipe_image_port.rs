@@ -0,0 +1,33 @@
+use crate::services::images::domain::image::ImageName;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait RecipeImagePort {
+    /// Returns the raw `recipe.image` value, `None` when it is empty.
+    async fn recipe_image(&self, recipe: uuid::Uuid) -> Result<Option<String>, RecipeImageError>;
+    /// Points the recipe at `name` and returns the value it replaced.
+    async fn set_recipe_image(
+        &self,
+        recipe: uuid::Uuid,
+        name: &ImageName,
+    ) -> Result<Option<String>, RecipeImageError>;
+    /// Number of recipes currently pointing at `name`.
+    async fn image_references(&self, name: &ImageName) -> Result<u64, RecipeImageError>;
+}
+
+#[derive(Debug)]
+pub enum RecipeImageError {
+    RecordNotFound,
+    InternalError,
+}
+
+impl Display for RecipeImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecordNotFound => write!(f, "Record not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for RecipeImageError {}
@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+use super::{
+    domain::{
+        image::{Image, ImageFormat, ImageName},
+        variant::select_variant,
+    },
+    ports::{
+        incoming::query_image_service::{QueryImageService, QueryImageServiceError},
+        outgoing::{
+            image_storage_port::{ImageStorageError, ImageStoragePort},
+            image_variant_port::{ImageVariantError, ImageVariantPort},
+            recipe_image_port::{RecipeImageError, RecipeImagePort},
+        },
+    },
+};
+
+impl From<RecipeImageError> for QueryImageServiceError {
+    fn from(value: RecipeImageError) -> Self {
+        match value {
+            RecipeImageError::RecordNotFound => QueryImageServiceError::RecipeNotFound,
+            RecipeImageError::InternalError => QueryImageServiceError::InternalError,
+        }
+    }
+}
+
+impl From<ImageVariantError> for QueryImageServiceError {
+    fn from(value: ImageVariantError) -> Self {
+        match value {
+            ImageVariantError::RecordNotFound => QueryImageServiceError::RecipeNotFound,
+            ImageVariantError::InternalError => QueryImageServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for QueryImageServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => QueryImageServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => QueryImageServiceError::InternalError,
+        }
+    }
+}
+
+impl From<ImageStorageError> for QueryImageServiceError {
+    fn from(value: ImageStorageError) -> Self {
+        match value {
+            ImageStorageError::NotFound => QueryImageServiceError::ImageNotFound,
+            ImageStorageError::InternalError => QueryImageServiceError::InternalError,
+        }
+    }
+}
+
+pub struct QueryImage<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    storage: Storage,
+    images: Images,
+}
+
+#[async_trait]
+impl<Storage, Images> QueryImageService for QueryImage<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    async fn query_image(
+        &self,
+        caller: Option<Caller>,
+        recipe: uuid::Uuid,
+        size: Option<u32>,
+        formats: Vec<ImageFormat>,
+    ) -> Result<Image, QueryImageServiceError> {
+        if !self
+            .storage
+            .recipe_access(recipe)
+            .await?
+            .can_view(caller.as_ref())
+        {
+            return Err(QueryImageServiceError::RecipeNotFound);
+        }
+        let original = self
+            .storage
+            .recipe_image(recipe)
+            .await?
+            .and_then(|name| ImageName::parse(&name))
+            .ok_or(QueryImageServiceError::ImageNotFound)?;
+        let Some(size) = size else {
+            return self
+                .images
+                .load_image(&original)
+                .await
+                .map_err(|e| e.into());
+        };
+
+        // Recipes without variants yet still get their original image.
+        let variants = self.storage.image_variants(recipe).await?;
+        let name = select_variant(&variants, size, &formats)
+            .map(|variant| variant.name().clone())
+            .unwrap_or(original);
+        self.images.load_image(&name).await.map_err(|e| e.into())
+    }
+}
+
+impl<Storage, Images> QueryImage<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    pub fn new(storage: Storage, images: Images) -> Self {
+        Self { storage, images }
+    }
+}
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+use super::{
+    domain::{
+        image::{Image, ImageFormat, ImageName},
+        variant::VariantSettings,
+    },
+    generate_variants_service::{persist_variants, render_variants},
+    ports::{
+        incoming::{
+            generate_variants_service::GenerateVariantsServiceError,
+            upload_image_service::{UploadImageService, UploadImageServiceError},
+        },
+        outgoing::{
+            image_storage_port::{ImageStorageError, ImageStoragePort},
+            image_variant_port::ImageVariantPort,
+            recipe_image_port::{RecipeImageError, RecipeImagePort},
+        },
+    },
+};
+
+impl From<RecipeImageError> for UploadImageServiceError {
+    fn from(value: RecipeImageError) -> Self {
+        match value {
+            RecipeImageError::RecordNotFound => UploadImageServiceError::RecipeNotFound,
+            RecipeImageError::InternalError => UploadImageServiceError::InternalError,
+        }
+    }
+}
+
+impl From<GenerateVariantsServiceError> for UploadImageServiceError {
+    fn from(value: GenerateVariantsServiceError) -> Self {
+        match value {
+            GenerateVariantsServiceError::RecipeNotFound => UploadImageServiceError::RecipeNotFound,
+            GenerateVariantsServiceError::Forbidden => UploadImageServiceError::Forbidden,
+            GenerateVariantsServiceError::UndecodableImage => {
+                UploadImageServiceError::UndecodableImage
+            }
+            GenerateVariantsServiceError::ImageNotFound
+            | GenerateVariantsServiceError::InternalError => UploadImageServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for UploadImageServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => UploadImageServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => UploadImageServiceError::InternalError,
+        }
+    }
+}
+
+impl From<ImageStorageError> for UploadImageServiceError {
+    fn from(_value: ImageStorageError) -> Self {
+        UploadImageServiceError::InternalError
+    }
+}
+
+/// Removes the file behind `name` when no recipe points at it anymore.
+/// Failures are only logged: a leftover file is harmless.
+pub(crate) async fn remove_orphaned_image<Storage, Images>(
+    storage: &Storage,
+    images: &Images,
+    name: &str,
+) where
+    Storage: RecipeImagePort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    let Some(name) = ImageName::parse(name) else {
+        return;
+    };
+    match storage.image_references(&name).await {
+        Ok(0) => {
+            if let Err(e) = images.remove_image(&name).await {
+                info!("failed to remove image {}: {}", name.as_str(), e);
+            }
+        }
+        Ok(_) => (),
+        Err(e) => info!("failed to count references of {}: {}", name.as_str(), e),
+    }
+}
+
+pub struct UploadImage<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    storage: Storage,
+    images: Images,
+    max_size: usize,
+    settings: VariantSettings,
+}
+
+#[async_trait]
+impl<Storage, Images> UploadImageService for UploadImage<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    async fn upload_image(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Result<ImageName, UploadImageServiceError> {
+        if bytes.is_empty() {
+            return Err(UploadImageServiceError::EmptyImage);
+        }
+        if bytes.len() > self.max_size {
+            return Err(UploadImageServiceError::ImageTooLarge);
+        }
+        // The declared type has to agree with the actual file signature.
+        let format = match (
+            ImageFormat::from_mime(&content_type),
+            ImageFormat::sniff(&bytes),
+        ) {
+            (Some(declared), Some(sniffed)) if declared == sniffed => declared,
+            _ => return Err(UploadImageServiceError::UnsupportedContentType),
+        };
+
+        let access = self.storage.recipe_access(recipe).await?;
+        if !access.can_view(Some(&caller)) {
+            return Err(UploadImageServiceError::RecipeNotFound);
+        }
+        if !access.can_modify(&caller) {
+            return Err(UploadImageServiceError::Forbidden);
+        }
+        let name = ImageName::for_content(&bytes, format);
+        // Rendering first also rejects files that only look like images.
+        let rendered = render_variants(bytes.clone(), self.settings.clone()).await?;
+        self.images
+            .store_image(&Image::new(name.clone(), bytes))
+            .await?;
+        let previous = self.storage.set_recipe_image(recipe, &name).await?;
+        persist_variants(&self.storage, &self.images, recipe, rendered).await?;
+        if let Some(previous) = previous.filter(|previous| previous != name.as_str()) {
+            remove_orphaned_image(&self.storage, &self.images, &previous).await;
+        }
+        Ok(name)
+    }
+}
+
+impl<Storage, Images> UploadImage<Storage, Images>
+where
+    Storage: RecipeImagePort + ImageVariantPort + RecipeAccessPort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    pub fn new(
+        storage: Storage,
+        images: Images,
+        max_size: usize,
+        settings: VariantSettings,
+    ) -> Self {
+        Self {
+            storage,
+            images,
+            max_size,
+            settings,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Version of the library documents written by export. Import reads this
+/// version and the ones before it.
+pub const LIBRARY_VERSION: u32 = 1;
+
+/// What to do with an imported recipe whose uuid is already taken.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictMode {
+    /// Keep the stored recipe.
+    #[default]
+    Skip,
+    /// Replace the stored recipe, keeping its reviews and the collections,
+    /// plans and lists it is part of.
+    Overwrite,
+    /// Store the imported recipe next to it, under a new uuid.
+    Duplicate,
+}
+
+impl ConflictMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictMode::Skip => "skip",
+            ConflictMode::Overwrite => "overwrite",
+            ConflictMode::Duplicate => "duplicate",
+        }
+    }
+}
+
+impl Display for ConflictMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ConflictMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(ConflictMode::Skip),
+            "overwrite" => Ok(ConflictMode::Overwrite),
+            "duplicate" => Ok(ConflictMode::Duplicate),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What an import did with each recipe of the document.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportSummary {
+    created: Vec<uuid::Uuid>,
+    overwritten: Vec<uuid::Uuid>,
+    skipped: Vec<uuid::Uuid>,
+    duplicated: Vec<(uuid::Uuid, uuid::Uuid)>,
+}
+
+impl ImportSummary {
+    pub fn record_created(&mut self, uuid: uuid::Uuid) {
+        self.created.push(uuid);
+    }
+
+    pub fn record_overwritten(&mut self, uuid: uuid::Uuid) {
+        self.overwritten.push(uuid);
+    }
+
+    pub fn record_skipped(&mut self, uuid: uuid::Uuid) {
+        self.skipped.push(uuid);
+    }
+
+    pub fn record_duplicated(&mut self, original: uuid::Uuid, copy: uuid::Uuid) {
+        self.duplicated.push((original, copy));
+    }
+
+    pub fn created(&self) -> &[uuid::Uuid] {
+        self.created.as_ref()
+    }
+
+    pub fn overwritten(&self) -> &[uuid::Uuid] {
+        self.overwritten.as_ref()
+    }
+
+    pub fn skipped(&self) -> &[uuid::Uuid] {
+        self.skipped.as_ref()
+    }
+
+    /// The uuid of each conflicting recipe with the one its copy got.
+    pub fn duplicated(&self) -> &[(uuid::Uuid, uuid::Uuid)] {
+        self.duplicated.as_ref()
+    }
+}
@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::library_import::ConflictMode,
+    manage_library_service::MAX_IMPORT_RECIPES,
+    ports::{
+        incoming::exchange_recipes_service::{ExchangeRecipesService, ExchangeRecipesServiceError},
+        outgoing::library_port::{LibraryError, LibraryPort},
+    },
+};
+use crate::services::{
+    recipes::{
+        domain::{
+            access::{RecipeAccess, Visibility},
+            recipe::Recipe,
+            recipe_format::FormatKind,
+        },
+        ports::outgoing::query_recipe_port::{QueryRecipeError, QueryRecipePort},
+    },
+    users::domain::caller::Caller,
+};
+
+impl From<LibraryError> for ExchangeRecipesServiceError {
+    fn from(value: LibraryError) -> Self {
+        match value {
+            error @ LibraryError::UnknownStepIngredient { recipe, .. } => {
+                ExchangeRecipesServiceError::InvalidRecipe {
+                    index: recipe,
+                    reason: format!("{}", error),
+                }
+            }
+            error @ LibraryError::IngredientConflict { recipe } => {
+                ExchangeRecipesServiceError::InvalidRecipe {
+                    index: recipe,
+                    reason: format!("{}", error),
+                }
+            }
+            LibraryError::InternalError => ExchangeRecipesServiceError::InternalError,
+        }
+    }
+}
+
+pub struct ExchangeRecipes<Storage>
+where
+    Storage: LibraryPort + QueryRecipePort + Send + Sync,
+{
+    storage: Storage,
+}
+
+impl<Storage> ExchangeRecipes<Storage>
+where
+    Storage: LibraryPort + QueryRecipePort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<Storage> ExchangeRecipesService for ExchangeRecipes<Storage>
+where
+    Storage: LibraryPort + QueryRecipePort + Send + Sync,
+{
+    async fn import_recipes(
+        &self,
+        caller: Caller,
+        format: FormatKind,
+        data: Vec<u8>,
+        visibility: Visibility,
+    ) -> Result<Vec<uuid::Uuid>, ExchangeRecipesServiceError> {
+        // Reading decompresses and parses the whole file.
+        let recipes = tokio::task::spawn_blocking(move || format.format().read(&data))
+            .await
+            .map_err(|_e| ExchangeRecipesServiceError::InternalError)?
+            .map_err(ExchangeRecipesServiceError::InvalidFile)?;
+        if recipes.is_empty() {
+            return Err(ExchangeRecipesServiceError::NoRecipes);
+        }
+        if recipes.len() > MAX_IMPORT_RECIPES {
+            return Err(ExchangeRecipesServiceError::TooManyRecipes);
+        }
+        let recipes = recipes
+            .into_iter()
+            .enumerate()
+            .map(|(index, recipe)| {
+                recipe
+                    .with_access(RecipeAccess::new(Some(caller.user()), visibility))
+                    .validated()
+                    .map_err(|e| ExchangeRecipesServiceError::InvalidRecipe {
+                        index,
+                        reason: format!("{}", e),
+                    })
+            })
+            .collect::<Result<Vec<Recipe>, ExchangeRecipesServiceError>>()?;
+        // Files exported from this instance keep their uuids, so importing
+        // one again stores copies rather than touching the originals.
+        let summary = self
+            .storage
+            .import_recipes(&recipes, ConflictMode::Duplicate)
+            .await?;
+        Ok(recipes
+            .iter()
+            .map(|recipe| {
+                summary
+                    .duplicated()
+                    .iter()
+                    .find(|(original, _)| *original == recipe.uuid())
+                    .map_or(recipe.uuid(), |(_, copy)| *copy)
+            })
+            .collect())
+    }
+
+    async fn export_recipe(
+        &self,
+        caller: Option<Caller>,
+        format: FormatKind,
+        uuid: uuid::Uuid,
+    ) -> Result<Vec<u8>, ExchangeRecipesServiceError> {
+        let recipe = match self.storage.query_recipe(uuid).await {
+            Ok(recipe) => recipe,
+            Err(QueryRecipeError::RecordNotFound) => {
+                return Err(ExchangeRecipesServiceError::RecipeNotFound)
+            }
+            Err(QueryRecipeError::InternalError) => {
+                return Err(ExchangeRecipesServiceError::InternalError)
+            }
+        };
+        // Recipes the caller may not see are not told apart from missing ones.
+        if !recipe.access().can_view(caller.as_ref()) {
+            return Err(ExchangeRecipesServiceError::RecipeNotFound);
+        }
+        format
+            .format()
+            .write(std::slice::from_ref(&recipe))
+            .map_err(ExchangeRecipesServiceError::Unwritable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::{recipes::domain::ingredient::Ingredient, users::domain::caller::Role},
+    };
+
+    #[tokio::test]
+    async fn imported_recipes_belong_to_the_caller() {
+        let service = ExchangeRecipes::new(RecipeSqliteDS::new(memory_pool().await));
+        let alice = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let bob = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let bread = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Bread".into(),
+            "".into(),
+            vec![],
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "flour".into(),
+                500.0,
+                "grams".into(),
+            )],
+        )
+        .with_access(RecipeAccess::new(Some(bob.user()), Visibility::Public));
+        let file = FormatKind::Mealie
+            .format()
+            .write(std::slice::from_ref(&bread))
+            .unwrap();
+
+        let first = service
+            .import_recipes(alice, FormatKind::Mealie, file.clone(), Visibility::Private)
+            .await
+            .unwrap();
+        let again = service
+            .import_recipes(alice, FormatKind::Mealie, file, Visibility::Private)
+            .await
+            .unwrap();
+
+        assert_eq!(first, [bread.uuid()]);
+        assert_ne!(again, first);
+        let stored = service.storage.query_recipe(again[0]).await.unwrap();
+        assert_eq!(stored.access().owner(), Some(alice.user()));
+        assert_eq!(stored.ingredients()[0].unit(), "g");
+        assert_eq!(
+            service
+                .export_recipe(Some(bob), FormatKind::Cooklang, again[0])
+                .await
+                .unwrap_err(),
+            ExchangeRecipesServiceError::RecipeNotFound
+        );
+        assert!(service
+            .export_recipe(Some(alice), FormatKind::Cooklang, again[0])
+            .await
+            .is_ok());
+        assert!(matches!(
+            service
+                .import_recipes(
+                    alice,
+                    FormatKind::Mealie,
+                    b"[]".to_vec(),
+                    Visibility::Private
+                )
+                .await
+                .unwrap_err(),
+            ExchangeRecipesServiceError::NoRecipes
+        ));
+    }
+}
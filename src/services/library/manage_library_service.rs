@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+
+use super::{
+    domain::library_import::{ConflictMode, ImportSummary, LIBRARY_VERSION},
+    ports::{
+        incoming::manage_library_service::{ManageLibraryService, ManageLibraryServiceError},
+        outgoing::library_port::{LibraryError, LibraryPort},
+    },
+};
+use crate::services::{
+    recipes::{domain::recipe::Recipe, list_recipes_service::MAX_PAGE_SIZE},
+    users::domain::caller::Caller,
+};
+
+/// Every recipe of an import is written in one transaction, this keeps it
+/// reasonably short.
+pub const MAX_IMPORT_RECIPES: usize = 10_000;
+
+impl From<LibraryError> for ManageLibraryServiceError {
+    fn from(value: LibraryError) -> Self {
+        match value {
+            error @ LibraryError::UnknownStepIngredient { recipe, .. } => {
+                ManageLibraryServiceError::InvalidRecipe {
+                    index: recipe,
+                    reason: format!("{}", error),
+                }
+            }
+            error @ LibraryError::IngredientConflict { recipe } => {
+                ManageLibraryServiceError::InvalidRecipe {
+                    index: recipe,
+                    reason: format!("{}", error),
+                }
+            }
+            LibraryError::InternalError => ManageLibraryServiceError::InternalError,
+        }
+    }
+}
+
+pub struct ManageLibrary<Storage>
+where
+    Storage: LibraryPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+impl<Storage> ManageLibrary<Storage>
+where
+    Storage: LibraryPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<Storage> ManageLibraryService for ManageLibrary<Storage>
+where
+    Storage: LibraryPort + Send + Sync,
+{
+    async fn export_recipes(
+        &self,
+        caller: Caller,
+        after: Option<uuid::Uuid>,
+        limit: u32,
+    ) -> Result<Vec<Recipe>, ManageLibraryServiceError> {
+        if !caller.is_admin() {
+            return Err(ManageLibraryServiceError::Forbidden);
+        }
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(ManageLibraryServiceError::InvalidPageSize);
+        }
+        Ok(self.storage.export_recipes(after, limit).await?)
+    }
+
+    async fn import_recipes(
+        &self,
+        caller: Caller,
+        version: u32,
+        recipes: Vec<Recipe>,
+        mode: ConflictMode,
+    ) -> Result<ImportSummary, ManageLibraryServiceError> {
+        if !caller.is_admin() {
+            return Err(ManageLibraryServiceError::Forbidden);
+        }
+        if version == 0 || version > LIBRARY_VERSION {
+            return Err(ManageLibraryServiceError::UnsupportedVersion(version));
+        }
+        if recipes.len() > MAX_IMPORT_RECIPES {
+            return Err(ManageLibraryServiceError::TooManyRecipes);
+        }
+        let mut uuids = HashSet::new();
+        let recipes = recipes
+            .into_iter()
+            .enumerate()
+            .map(|(index, recipe)| {
+                if !uuids.insert(recipe.uuid()) {
+                    return Err(ManageLibraryServiceError::InvalidRecipe {
+                        index,
+                        reason: "The recipe appears twice in the document".to_string(),
+                    });
+                }
+                recipe
+                    .validated()
+                    .map_err(|e| ManageLibraryServiceError::InvalidRecipe {
+                        index,
+                        reason: format!("{}", e),
+                    })
+            })
+            .collect::<Result<Vec<Recipe>, ManageLibraryServiceError>>()?;
+        Ok(self.storage.import_recipes(&recipes, mode).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::{
+            recipes::domain::{
+                access::{RecipeAccess, Visibility},
+                ingredient::Ingredient,
+            },
+            users::domain::caller::Role,
+        },
+    };
+
+    fn recipe(unit: &str) -> Recipe {
+        Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Bread".into(),
+            "".into(),
+            vec![],
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "flour".into(),
+                1.0,
+                unit.into(),
+            )],
+        )
+    }
+
+    #[tokio::test]
+    async fn only_admins_use_the_library() {
+        let service = ManageLibrary::new(RecipeSqliteDS::new(memory_pool().await));
+        let member = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        assert_eq!(
+            service.export_recipes(member, None, 10).await.unwrap_err(),
+            ManageLibraryServiceError::Forbidden
+        );
+        assert_eq!(
+            service
+                .import_recipes(member, LIBRARY_VERSION, vec![], ConflictMode::Skip)
+                .await
+                .unwrap_err(),
+            ManageLibraryServiceError::Forbidden
+        );
+    }
+
+    #[tokio::test]
+    async fn imports_are_refused_as_a_whole() {
+        let service = ManageLibrary::new(RecipeSqliteDS::new(memory_pool().await));
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+
+        assert_eq!(
+            service
+                .import_recipes(admin, LIBRARY_VERSION + 1, vec![], ConflictMode::Skip)
+                .await
+                .unwrap_err(),
+            ManageLibraryServiceError::UnsupportedVersion(LIBRARY_VERSION + 1)
+        );
+        let error = service
+            .import_recipes(
+                admin,
+                LIBRARY_VERSION,
+                vec![recipe("kilos"), recipe("handful")],
+                ConflictMode::Skip,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            ManageLibraryServiceError::InvalidRecipe { index: 1, .. }
+        ));
+        assert!(service
+            .storage
+            .export_recipes(None, 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let bread = recipe("kilos");
+        let error = service
+            .import_recipes(
+                admin,
+                LIBRARY_VERSION,
+                vec![bread.clone(), bread],
+                ConflictMode::Skip,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            ManageLibraryServiceError::InvalidRecipe { index: 1, .. }
+        ));
+
+        let summary = service
+            .import_recipes(
+                admin,
+                LIBRARY_VERSION,
+                vec![recipe("kilos")],
+                ConflictMode::Skip,
+            )
+            .await
+            .unwrap();
+        assert_eq!(summary.created().len(), 1);
+        // Units are stored canonical, as for created recipes.
+        assert_eq!(
+            service.storage.export_recipes(None, 10).await.unwrap()[0].ingredients()[0].unit(),
+            "kg"
+        );
+    }
+
+    #[tokio::test]
+    async fn imports_keep_the_exported_owners() {
+        let service = ManageLibrary::new(RecipeSqliteDS::new(memory_pool().await));
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        let owner = uuid::Uuid::new_v4();
+        let bread = recipe("kilos").with_access(RecipeAccess::new(Some(owner), Visibility::Shared));
+
+        service
+            .import_recipes(admin, LIBRARY_VERSION, vec![bread], ConflictMode::Skip)
+            .await
+            .unwrap();
+        assert_eq!(
+            service.storage.export_recipes(None, 10).await.unwrap()[0].access(),
+            RecipeAccess::new(Some(owner), Visibility::Shared)
+        );
+    }
+}
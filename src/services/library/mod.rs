@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod exchange_recipes_service;
+pub mod manage_library_service;
+pub mod ports;
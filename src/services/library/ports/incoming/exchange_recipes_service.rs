@@ -0,0 +1,68 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::domain::{
+        access::Visibility,
+        recipe_format::{FormatError, FormatKind},
+    },
+    users::domain::caller::Caller,
+};
+
+/// Recipes read from and written to the files of other recipe apps.
+#[async_trait]
+pub trait ExchangeRecipesService {
+    /// Creates a recipe owned by `caller` for every recipe of `data`, all of
+    /// them or none, and returns their uuids in the order of the file.
+    async fn import_recipes(
+        &self,
+        caller: Caller,
+        format: FormatKind,
+        data: Vec<u8>,
+        visibility: Visibility,
+    ) -> Result<Vec<uuid::Uuid>, ExchangeRecipesServiceError>;
+    /// The recipe `uuid` as a file of `format`.
+    async fn export_recipe(
+        &self,
+        caller: Option<Caller>,
+        format: FormatKind,
+        uuid: uuid::Uuid,
+    ) -> Result<Vec<u8>, ExchangeRecipesServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExchangeRecipesServiceError {
+    InvalidFile(FormatError),
+    NoRecipes,
+    TooManyRecipes,
+    /// The recipe at `index` of the file was refused.
+    InvalidRecipe {
+        index: usize,
+        reason: String,
+    },
+    RecipeNotFound,
+    /// The recipe cannot be written in the format asked for.
+    Unwritable(FormatError),
+    InternalError,
+}
+
+impl Display for ExchangeRecipesServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExchangeRecipesServiceError::InvalidFile(error) => write!(f, "{}", error),
+            ExchangeRecipesServiceError::NoRecipes => f.write_str("The file has no recipes"),
+            ExchangeRecipesServiceError::TooManyRecipes => {
+                f.write_str("Too many recipes in a single import")
+            }
+            ExchangeRecipesServiceError::InvalidRecipe { index, reason } => {
+                write!(f, "Invalid recipe {}: {}", index, reason)
+            }
+            ExchangeRecipesServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            ExchangeRecipesServiceError::Unwritable(error) => write!(f, "{}", error),
+            ExchangeRecipesServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+
+impl Error for ExchangeRecipesServiceError {}
@@ -0,0 +1,66 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    library::domain::library_import::{ConflictMode, ImportSummary},
+    recipes::domain::recipe::Recipe,
+    users::domain::caller::Caller,
+};
+
+/// Backups of the whole recipe library, so admins alone may use it.
+#[async_trait]
+pub trait ManageLibraryService {
+    /// Up to `limit` recipes of every user, by uuid, following `after`.
+    async fn export_recipes(
+        &self,
+        caller: Caller,
+        after: Option<uuid::Uuid>,
+        limit: u32,
+    ) -> Result<Vec<Recipe>, ManageLibraryServiceError>;
+    /// Stores every recipe or, when any of them is refused, none.
+    async fn import_recipes(
+        &self,
+        caller: Caller,
+        version: u32,
+        recipes: Vec<Recipe>,
+        mode: ConflictMode,
+    ) -> Result<ImportSummary, ManageLibraryServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ManageLibraryServiceError {
+    Forbidden,
+    InvalidPageSize,
+    UnsupportedVersion(u32),
+    TooManyRecipes,
+    /// The recipe at `index` of the document was refused.
+    InvalidRecipe {
+        index: usize,
+        reason: String,
+    },
+    InternalError,
+}
+
+impl Display for ManageLibraryServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageLibraryServiceError::Forbidden => {
+                f.write_str("Only admins can export and import the library")
+            }
+            ManageLibraryServiceError::InvalidPageSize => f.write_str("Invalid page size"),
+            ManageLibraryServiceError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported library version {}", version)
+            }
+            ManageLibraryServiceError::TooManyRecipes => {
+                f.write_str("Too many recipes in a single import")
+            }
+            ManageLibraryServiceError::InvalidRecipe { index, reason } => {
+                write!(f, "Invalid recipe {}: {}", index, reason)
+            }
+            ManageLibraryServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+
+impl Error for ManageLibraryServiceError {}
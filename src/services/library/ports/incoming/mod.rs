@@ -0,0 +1,2 @@
+pub mod exchange_recipes_service;
+pub mod manage_library_service;
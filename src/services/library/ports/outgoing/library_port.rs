@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+
+use std::{error::Error, fmt::Display};
+
+use crate::services::{
+    library::domain::library_import::{ConflictMode, ImportSummary},
+    recipes::domain::recipe::Recipe,
+};
+
+#[async_trait]
+pub trait LibraryPort {
+    /// Up to `limit` recipes of every user, by uuid, following `after`.
+    async fn export_recipes(
+        &self,
+        after: Option<uuid::Uuid>,
+        limit: u32,
+    ) -> Result<Vec<Recipe>, LibraryError>;
+    /// Stores every recipe or none of them, with the owner it names.
+    async fn import_recipes(
+        &self,
+        recipes: &[Recipe],
+        mode: ConflictMode,
+    ) -> Result<ImportSummary, LibraryError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LibraryError {
+    /// A step of the recipe at `recipe` uses an ingredient the recipe does
+    /// not have.
+    UnknownStepIngredient {
+        recipe: usize,
+        step: usize,
+    },
+    /// The recipe at `recipe` reuses the uuid of an ingredient of another
+    /// recipe.
+    IngredientConflict {
+        recipe: usize,
+    },
+    InternalError,
+}
+
+impl Display for LibraryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownStepIngredient { recipe, step } => write!(
+                f,
+                "Step {} of recipe {} uses an unknown ingredient",
+                step, recipe
+            ),
+            Self::IngredientConflict { recipe } => {
+                write!(f, "Recipe {} uses ingredients of another recipe", recipe)
+            }
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for LibraryError {}
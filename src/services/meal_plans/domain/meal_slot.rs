@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+/// The meal of the day an entry is planned for, in the order they are
+/// eaten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MealSlot {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+impl MealSlot {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MealSlot::Breakfast => "breakfast",
+            MealSlot::Lunch => "lunch",
+            MealSlot::Dinner => "dinner",
+            MealSlot::Snack => "snack",
+        }
+    }
+}
+
+impl FromStr for MealSlot {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "breakfast" => Ok(MealSlot::Breakfast),
+            "lunch" => Ok(MealSlot::Lunch),
+            "dinner" => Ok(MealSlot::Dinner),
+            "snack" => Ok(MealSlot::Snack),
+            _ => Err(()),
+        }
+    }
+}
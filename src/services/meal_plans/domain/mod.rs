@@ -0,0 +1,3 @@
+pub mod meal_slot;
+pub mod plan_date;
+pub mod plan_entry;
@@ -0,0 +1,118 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A calendar day, written `YYYY-MM-DD`. Dates order chronologically and
+/// so do their texts, which is what storage relies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlanDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl PlanDate {
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Days since 0000-03-01, only meaningful as a difference.
+    fn day_number(&self) -> i64 {
+        // Counting years from March puts the leap day last.
+        let (year, month) = match self.month {
+            1 | 2 => (i64::from(self.year) - 1, i64::from(self.month) + 9),
+            _ => (i64::from(self.year), i64::from(self.month) - 3),
+        };
+        365 * year + year / 4 - year / 100
+            + year / 400
+            + (153 * month + 2) / 5
+            + i64::from(self.day)
+            - 1
+    }
+
+    /// Number of days from `self` to `other`, negative when `other` is
+    /// earlier.
+    pub fn days_until(&self, other: &PlanDate) -> i64 {
+        other.day_number() - self.day_number()
+    }
+}
+
+impl FromStr for PlanDate {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(());
+        }
+        let digits = |part: &str| {
+            part.chars()
+                .all(|c| c.is_ascii_digit())
+                .then(|| part.parse::<u16>().ok())
+                .flatten()
+        };
+        let (year, month, day) = (
+            digits(year).ok_or(())?,
+            digits(month).ok_or(())?,
+            digits(day).ok_or(())?,
+        );
+        PlanDate::new(year, month as u8, day as u8).ok_or(())
+    }
+}
+
+impl Display for PlanDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_parse_only_when_they_exist() {
+        let date: PlanDate = "2024-02-29".parse().unwrap();
+        assert_eq!(date.to_string(), "2024-02-29");
+        for invalid in [
+            "2023-02-29",
+            "2024-13-01",
+            "2024-04-31",
+            "2024-1-01",
+            "+024-01-01",
+            "2024-01-01-01",
+            "",
+        ] {
+            assert!(invalid.parse::<PlanDate>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn days_are_counted_across_months_and_years() {
+        let date = |s: &str| s.parse::<PlanDate>().unwrap();
+        assert_eq!(date("2024-01-01").days_until(&date("2024-01-07")), 6);
+        assert_eq!(date("2024-02-28").days_until(&date("2024-03-01")), 2);
+        assert_eq!(date("2023-02-28").days_until(&date("2023-03-01")), 1);
+        assert_eq!(date("2023-12-31").days_until(&date("2024-01-01")), 1);
+        assert_eq!(date("2024-01-01").days_until(&date("2023-01-01")), -365);
+    }
+}
@@ -0,0 +1,65 @@
+use super::{meal_slot::MealSlot, plan_date::PlanDate};
+
+/// A recipe planned for one meal of a user's day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanEntry {
+    uuid: uuid::Uuid,
+    owner: uuid::Uuid,
+    date: PlanDate,
+    slot: MealSlot,
+    recipe: uuid::Uuid,
+    servings: Option<u32>,
+}
+
+impl PlanEntry {
+    pub fn new(
+        uuid: uuid::Uuid,
+        owner: uuid::Uuid,
+        date: PlanDate,
+        slot: MealSlot,
+        recipe: uuid::Uuid,
+    ) -> Self {
+        Self {
+            uuid,
+            owner,
+            date,
+            slot,
+            recipe,
+            servings: None,
+        }
+    }
+
+    /// Portions to cook instead of the recipe's own servings.
+    pub fn with_servings(self, servings: Option<u32>) -> Self {
+        Self { servings, ..self }
+    }
+
+    /// The same entry planned for another meal.
+    pub fn moved_to(self, date: PlanDate, slot: MealSlot) -> Self {
+        Self { date, slot, ..self }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn owner(&self) -> uuid::Uuid {
+        self.owner
+    }
+
+    pub fn date(&self) -> PlanDate {
+        self.date
+    }
+
+    pub fn slot(&self) -> MealSlot {
+        self.slot
+    }
+
+    pub fn recipe(&self) -> uuid::Uuid {
+        self.recipe
+    }
+
+    pub fn servings(&self) -> Option<u32> {
+        self.servings
+    }
+}
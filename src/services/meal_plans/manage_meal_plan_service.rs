@@ -0,0 +1,256 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::{meal_slot::MealSlot, plan_date::PlanDate, plan_entry::PlanEntry},
+    ports::{
+        incoming::manage_meal_plan_service::{ManageMealPlanService, ManageMealPlanServiceError},
+        outgoing::meal_plan_port::{MealPlanError, MealPlanPort},
+    },
+};
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+/// Longest range of days listed at once.
+pub const MAX_RANGE_DAYS: i64 = 62;
+
+impl From<MealPlanError> for ManageMealPlanServiceError {
+    fn from(value: MealPlanError) -> Self {
+        match value {
+            MealPlanError::EntryNotFound => ManageMealPlanServiceError::EntryNotFound,
+            MealPlanError::RecipeNotFound => ManageMealPlanServiceError::RecipeNotFound,
+            MealPlanError::InternalError => ManageMealPlanServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for ManageMealPlanServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => ManageMealPlanServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => ManageMealPlanServiceError::InternalError,
+        }
+    }
+}
+
+pub struct ManageMealPlan<Storage, Recipes>
+where
+    Storage: MealPlanPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+}
+
+impl<Storage, Recipes> ManageMealPlan<Storage, Recipes>
+where
+    Storage: MealPlanPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes) -> Self {
+        Self { storage, recipes }
+    }
+
+    /// The entry, unless it belongs to somebody else.
+    async fn owned_entry(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<PlanEntry, ManageMealPlanServiceError> {
+        let entry = self.storage.query_entry(uuid).await?;
+        if entry.owner() != caller.user() {
+            return Err(ManageMealPlanServiceError::EntryNotFound);
+        }
+        Ok(entry)
+    }
+}
+
+#[async_trait]
+impl<Storage, Recipes> ManageMealPlanService for ManageMealPlan<Storage, Recipes>
+where
+    Storage: MealPlanPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    async fn list_entries(
+        &self,
+        caller: Caller,
+        from: PlanDate,
+        to: PlanDate,
+    ) -> Result<Vec<PlanEntry>, ManageMealPlanServiceError> {
+        if !(0..MAX_RANGE_DAYS).contains(&from.days_until(&to)) {
+            return Err(ManageMealPlanServiceError::InvalidRange);
+        }
+        Ok(self.storage.list_entries(caller.user(), from, to).await?)
+    }
+
+    async fn create_entry(
+        &self,
+        caller: Caller,
+        date: PlanDate,
+        slot: MealSlot,
+        recipe: uuid::Uuid,
+        servings: Option<u32>,
+    ) -> Result<PlanEntry, ManageMealPlanServiceError> {
+        if servings == Some(0) {
+            return Err(ManageMealPlanServiceError::InvalidServings);
+        }
+        let access = self.recipes.recipe_access(recipe).await?;
+        if !access.can_view(Some(&caller)) {
+            return Err(ManageMealPlanServiceError::RecipeNotFound);
+        }
+        let entry = PlanEntry::new(uuid::Uuid::new_v4(), caller.user(), date, slot, recipe)
+            .with_servings(servings);
+        Ok(self.storage.insert_entry(&entry).await?)
+    }
+
+    async fn move_entry(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        date: PlanDate,
+        slot: MealSlot,
+        servings: Option<Option<u32>>,
+    ) -> Result<PlanEntry, ManageMealPlanServiceError> {
+        if servings == Some(Some(0)) {
+            return Err(ManageMealPlanServiceError::InvalidServings);
+        }
+        let entry = self.owned_entry(caller, uuid).await?.moved_to(date, slot);
+        let entry = match servings {
+            Some(servings) => entry.with_servings(servings),
+            None => entry,
+        };
+        Ok(self.storage.update_entry(&entry).await?)
+    }
+
+    async fn delete_entry(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManageMealPlanServiceError> {
+        self.owned_entry(caller, uuid).await?;
+        Ok(self.storage.delete_entry(uuid).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{
+            meal_plans::meal_plans_sqlite_ds::MealPlanSqliteDS, memory_pool,
+            recipes::recipes_sqlite_ds::RecipeSqliteDS, stored_recipe,
+        },
+        services::{
+            recipes::domain::access::{RecipeAccess, Visibility},
+            users::domain::caller::Role,
+        },
+    };
+
+    fn date(date: &str) -> PlanDate {
+        date.parse().unwrap()
+    }
+
+    /// A service whose owner planned a public recipe for dinner on the first
+    /// day of 2024.
+    async fn service(
+        owner: Caller,
+    ) -> (ManageMealPlan<MealPlanSqliteDS, RecipeSqliteDS>, uuid::Uuid) {
+        let pool = memory_pool().await;
+        let recipe = stored_recipe(&pool, RecipeAccess::new(None, Visibility::Public)).await;
+        let service = ManageMealPlan::new(
+            MealPlanSqliteDS::new(pool.clone()),
+            RecipeSqliteDS::new(pool),
+        );
+        let entry = service
+            .create_entry(
+                owner,
+                date("2024-01-01"),
+                MealSlot::Dinner,
+                recipe.uuid(),
+                None,
+            )
+            .await
+            .unwrap();
+        (service, entry.uuid())
+    }
+
+    #[tokio::test]
+    async fn ranges_are_bounded() {
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let (service, uuid) = service(owner).await;
+
+        let week = service
+            .list_entries(owner, date("2024-01-01"), date("2024-01-07"))
+            .await
+            .unwrap();
+        assert_eq!(week.iter().map(PlanEntry::uuid).collect::<Vec<_>>(), [uuid]);
+        for (from, to) in [("2024-01-07", "2024-01-01"), ("2024-01-01", "2024-03-31")] {
+            assert_eq!(
+                service
+                    .list_entries(owner, date(from), date(to))
+                    .await
+                    .unwrap_err(),
+                ManageMealPlanServiceError::InvalidRange
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn entries_are_moved_by_their_owner_only() {
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let other = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        let (service, uuid) = service(owner).await;
+
+        let moved = service
+            .move_entry(
+                owner,
+                uuid,
+                date("2024-01-02"),
+                MealSlot::Lunch,
+                Some(Some(2)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            (moved.date(), moved.slot(), moved.servings()),
+            (date("2024-01-02"), MealSlot::Lunch, Some(2))
+        );
+        let kept = service
+            .move_entry(owner, uuid, date("2024-01-03"), MealSlot::Dinner, None)
+            .await
+            .unwrap();
+        assert_eq!(kept.servings(), Some(2));
+        let cleared = service
+            .move_entry(
+                owner,
+                uuid,
+                date("2024-01-03"),
+                MealSlot::Dinner,
+                Some(None),
+            )
+            .await
+            .unwrap();
+        assert_eq!(cleared.servings(), None);
+        assert_eq!(
+            service
+                .move_entry(other, uuid, date("2024-01-02"), MealSlot::Lunch, None)
+                .await
+                .unwrap_err(),
+            ManageMealPlanServiceError::EntryNotFound
+        );
+        assert_eq!(
+            service
+                .move_entry(
+                    owner,
+                    uuid,
+                    date("2024-01-02"),
+                    MealSlot::Lunch,
+                    Some(Some(0))
+                )
+                .await
+                .unwrap_err(),
+            ManageMealPlanServiceError::InvalidServings
+        );
+    }
+}
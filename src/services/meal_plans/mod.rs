@@ -0,0 +1,3 @@
+pub mod domain;
+pub mod manage_meal_plan_service;
+pub mod ports;
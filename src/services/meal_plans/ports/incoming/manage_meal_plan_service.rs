@@ -0,0 +1,77 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    meal_plans::{
+        domain::{meal_slot::MealSlot, plan_date::PlanDate, plan_entry::PlanEntry},
+        manage_meal_plan_service::MAX_RANGE_DAYS,
+    },
+    users::domain::caller::Caller,
+};
+
+/// Meal plans are private, entries of other users are reported as not
+/// found.
+#[async_trait]
+pub trait ManageMealPlanService {
+    /// Entries of `caller` from `from` to `to` included.
+    async fn list_entries(
+        &self,
+        caller: Caller,
+        from: PlanDate,
+        to: PlanDate,
+    ) -> Result<Vec<PlanEntry>, ManageMealPlanServiceError>;
+    /// Plans a recipe `caller` may view, for `servings` portions instead of
+    /// the recipe's own when given.
+    async fn create_entry(
+        &self,
+        caller: Caller,
+        date: PlanDate,
+        slot: MealSlot,
+        recipe: uuid::Uuid,
+        servings: Option<u32>,
+    ) -> Result<PlanEntry, ManageMealPlanServiceError>;
+    /// Moves an entry to another meal. Its servings are kept when `servings`
+    /// is `None`, and go back to the recipe's own when it is `Some(None)`.
+    async fn move_entry(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        date: PlanDate,
+        slot: MealSlot,
+        servings: Option<Option<u32>>,
+    ) -> Result<PlanEntry, ManageMealPlanServiceError>;
+    async fn delete_entry(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManageMealPlanServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ManageMealPlanServiceError {
+    InvalidRange,
+    InvalidServings,
+    EntryNotFound,
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for ManageMealPlanServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageMealPlanServiceError::InvalidRange => write!(
+                f,
+                "Ranges end on or after they start and span at most {} days",
+                MAX_RANGE_DAYS
+            ),
+            ManageMealPlanServiceError::InvalidServings => {
+                f.write_str("Servings must be at least one")
+            }
+            ManageMealPlanServiceError::EntryNotFound => f.write_str("Entry not found"),
+            ManageMealPlanServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            ManageMealPlanServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for ManageMealPlanServiceError {}
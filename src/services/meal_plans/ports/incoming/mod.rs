@@ -0,0 +1 @@
+pub mod manage_meal_plan_service;
@@ -0,0 +1,38 @@
+use crate::services::meal_plans::domain::{plan_date::PlanDate, plan_entry::PlanEntry};
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait MealPlanPort {
+    /// Entries of `owner` from `from` to `to` included, by date and slot.
+    async fn list_entries(
+        &self,
+        owner: uuid::Uuid,
+        from: PlanDate,
+        to: PlanDate,
+    ) -> Result<Vec<PlanEntry>, MealPlanError>;
+    async fn query_entry(&self, uuid: uuid::Uuid) -> Result<PlanEntry, MealPlanError>;
+    async fn insert_entry(&self, entry: &PlanEntry) -> Result<PlanEntry, MealPlanError>;
+    /// Stores the date, slot and servings of an entry.
+    async fn update_entry(&self, entry: &PlanEntry) -> Result<PlanEntry, MealPlanError>;
+    async fn delete_entry(&self, uuid: uuid::Uuid) -> Result<(), MealPlanError>;
+}
+
+#[derive(Debug)]
+pub enum MealPlanError {
+    EntryNotFound,
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for MealPlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EntryNotFound => write!(f, "Entry not found"),
+            Self::RecipeNotFound => write!(f, "Recipe not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for MealPlanError {}
@@ -0,0 +1 @@
+pub mod meal_plan_port;
@@ -1 +1,11 @@
+pub mod collections;
+pub mod images;
+pub mod library;
+pub mod meal_plans;
+pub mod nutrition;
+pub mod pantry;
 pub mod recipes;
+pub mod reviews;
+pub mod shopping_lists;
+pub mod tags;
+pub mod users;
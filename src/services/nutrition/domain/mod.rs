@@ -0,0 +1,2 @@
+pub mod nutrient;
+pub mod nutrition;
@@ -0,0 +1,144 @@
+use std::ops::Add;
+
+/// Energy and macronutrients, either per 100 g or for an actual amount.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Nutrients {
+    energy_kcal: f64,
+    protein_g: f64,
+    fat_g: f64,
+    carbohydrate_g: f64,
+    fiber_g: f64,
+    sugars_g: f64,
+}
+
+impl Nutrients {
+    pub fn new(
+        energy_kcal: f64,
+        protein_g: f64,
+        fat_g: f64,
+        carbohydrate_g: f64,
+        fiber_g: f64,
+        sugars_g: f64,
+    ) -> Self {
+        Self {
+            energy_kcal,
+            protein_g,
+            fat_g,
+            carbohydrate_g,
+            fiber_g,
+            sugars_g,
+        }
+    }
+
+    pub fn energy_kcal(&self) -> f64 {
+        self.energy_kcal
+    }
+
+    pub fn protein_g(&self) -> f64 {
+        self.protein_g
+    }
+
+    pub fn fat_g(&self) -> f64 {
+        self.fat_g
+    }
+
+    pub fn carbohydrate_g(&self) -> f64 {
+        self.carbohydrate_g
+    }
+
+    pub fn fiber_g(&self) -> f64 {
+        self.fiber_g
+    }
+
+    pub fn sugars_g(&self) -> f64 {
+        self.sugars_g
+    }
+
+    pub fn scale(self, factor: f64) -> Self {
+        Self {
+            energy_kcal: self.energy_kcal * factor,
+            protein_g: self.protein_g * factor,
+            fat_g: self.fat_g * factor,
+            carbohydrate_g: self.carbohydrate_g * factor,
+            fiber_g: self.fiber_g * factor,
+            sugars_g: self.sugars_g * factor,
+        }
+    }
+}
+
+impl Add for Nutrients {
+    type Output = Nutrients;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            energy_kcal: self.energy_kcal + rhs.energy_kcal,
+            protein_g: self.protein_g + rhs.protein_g,
+            fat_g: self.fat_g + rhs.fat_g,
+            carbohydrate_g: self.carbohydrate_g + rhs.carbohydrate_g,
+            fiber_g: self.fiber_g + rhs.fiber_g,
+            sugars_g: self.sugars_g + rhs.sugars_g,
+        }
+    }
+}
+
+/// An entry of the nutrient database. `name` is the short name ingredients
+/// are matched against, `description` the full food description.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NutrientProfile {
+    id: i64,
+    name: String,
+    description: String,
+    per_100g: Nutrients,
+    portion_grams: Option<f64>,
+    density: Option<f64>,
+}
+
+impl NutrientProfile {
+    pub fn new(
+        id: i64,
+        name: String,
+        description: String,
+        per_100g: Nutrients,
+        portion_grams: Option<f64>,
+        density: Option<f64>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            description,
+            per_100g,
+            portion_grams,
+            density,
+        }
+    }
+
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn description(&self) -> &str {
+        self.description.as_ref()
+    }
+
+    pub fn per_100g(&self) -> Nutrients {
+        self.per_100g
+    }
+
+    /// Weight of one piece, clove or slice.
+    pub fn portion_grams(&self) -> Option<f64> {
+        self.portion_grams
+    }
+
+    /// Grams per millilitre.
+    pub fn density(&self) -> Option<f64> {
+        self.density
+    }
+
+    pub fn nutrients_for(&self, grams: f64) -> Nutrients {
+        self.per_100g.scale(grams / 100.0)
+    }
+}
@@ -0,0 +1,283 @@
+use crate::services::recipes::domain::{
+    density::density_of,
+    ingredient::Ingredient,
+    unit::{Unit, UnitKind},
+};
+
+use super::nutrient::{NutrientProfile, Nutrients};
+
+/// A pinch is taken as a sixteenth of a teaspoon.
+const PINCH_MILLILITRES: f64 = 0.308;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnmatchedReason {
+    /// No nutrient entry matches the ingredient name.
+    NoNutrientMatch,
+    /// The stored unit is not understood.
+    UnknownUnit,
+    /// The amount can't be turned into grams, e.g. a volume without a known
+    /// density or a piece without a known weight.
+    NoWeight,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnmatchedIngredient {
+    uuid: uuid::Uuid,
+    name: String,
+    reason: UnmatchedReason,
+}
+
+impl UnmatchedIngredient {
+    pub fn new(ingredient: &Ingredient, reason: UnmatchedReason) -> Self {
+        Self {
+            uuid: ingredient.uuid(),
+            name: ingredient.name().to_string(),
+            reason,
+        }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn reason(&self) -> UnmatchedReason {
+        self.reason
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientNutrition {
+    uuid: uuid::Uuid,
+    name: String,
+    nutrient_id: i64,
+    grams: f64,
+    nutrients: Nutrients,
+}
+
+impl IngredientNutrition {
+    pub fn new(ingredient: &Ingredient, profile: &NutrientProfile, grams: f64) -> Self {
+        Self {
+            uuid: ingredient.uuid(),
+            name: ingredient.name().to_string(),
+            nutrient_id: profile.id(),
+            grams,
+            nutrients: profile.nutrients_for(grams),
+        }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn nutrient_id(&self) -> i64 {
+        self.nutrient_id
+    }
+
+    pub fn grams(&self) -> f64 {
+        self.grams
+    }
+
+    pub fn nutrients(&self) -> Nutrients {
+        self.nutrients
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeNutrition {
+    recipe: uuid::Uuid,
+    servings: u32,
+    ingredients: Vec<IngredientNutrition>,
+    unmatched: Vec<UnmatchedIngredient>,
+}
+
+impl RecipeNutrition {
+    pub fn new(
+        recipe: uuid::Uuid,
+        servings: u32,
+        ingredients: Vec<IngredientNutrition>,
+        unmatched: Vec<UnmatchedIngredient>,
+    ) -> Self {
+        Self {
+            recipe,
+            servings,
+            ingredients,
+            unmatched,
+        }
+    }
+
+    pub fn recipe(&self) -> uuid::Uuid {
+        self.recipe
+    }
+
+    pub fn servings(&self) -> u32 {
+        self.servings
+    }
+
+    pub fn ingredients(&self) -> &[IngredientNutrition] {
+        self.ingredients.as_ref()
+    }
+
+    /// Ingredients left out of the totals, which are then a lower bound.
+    pub fn unmatched(&self) -> &[UnmatchedIngredient] {
+        self.unmatched.as_ref()
+    }
+
+    pub fn total(&self) -> Nutrients {
+        self.ingredients
+            .iter()
+            .fold(Nutrients::default(), |total, ingredient| {
+                total + ingredient.nutrients()
+            })
+    }
+
+    pub fn per_serving(&self) -> Nutrients {
+        self.total().scale(1.0 / f64::from(self.servings.max(1)))
+    }
+}
+
+/// Names to look the ingredient up with, most literal first: the lowercase
+/// words of the name, then the same words in singular.
+pub fn match_keys(name: &str) -> Vec<String> {
+    let words = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    if words.is_empty() {
+        return vec![];
+    }
+    let key = words.join(" ");
+    let singular = words
+        .iter()
+        .map(|word| singular(word))
+        .collect::<Vec<String>>()
+        .join(" ");
+    if singular == key {
+        vec![key]
+    } else {
+        vec![key, singular]
+    }
+}
+
+fn singular(word: &str) -> String {
+    if word.len() <= 3 || word.ends_with("ss") {
+        word.to_string()
+    } else if let Some(stem) = word.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if let Some(stem) = word.strip_suffix("oes") {
+        format!("{stem}o")
+    } else if let Some(stem) = word.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+/// Weight of the ingredient in grams, using the profile's portion weight and
+/// density first and the common density table second.
+pub fn grams_of(
+    ingredient: &Ingredient,
+    profile: &NutrientProfile,
+) -> Result<f64, UnmatchedReason> {
+    let unit = ingredient
+        .parsed_unit()
+        .map_err(|_e| UnmatchedReason::UnknownUnit)?;
+    let density = || {
+        profile
+            .density()
+            .or_else(|| density_of(ingredient.name()).map(|density| density.grams_per_millilitre()))
+    };
+    let amount = ingredient.amount();
+    match (unit.kind(), unit) {
+        (UnitKind::Mass, _) => Ok(amount * unit.base_factor()),
+        (UnitKind::Volume, _) => density()
+            .map(|density| amount * unit.base_factor() * density)
+            .ok_or(UnmatchedReason::NoWeight),
+        (UnitKind::Count, Unit::Pinch) => density()
+            .map(|density| amount * PINCH_MILLILITRES * density)
+            .ok_or(UnmatchedReason::NoWeight),
+        (UnitKind::Count, Unit::Piece | Unit::Clove | Unit::Slice) => profile
+            .portion_grams()
+            .map(|grams| amount * grams)
+            .ok_or(UnmatchedReason::NoWeight),
+        (UnitKind::Count, _) => Err(UnmatchedReason::NoWeight),
+        (UnitKind::ToTaste, _) => Ok(0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(portion_grams: Option<f64>, density: Option<f64>) -> NutrientProfile {
+        NutrientProfile::new(
+            1,
+            "flour".to_string(),
+            "Wheat flour".to_string(),
+            Nutrients::new(364.0, 10.3, 1.0, 76.3, 2.7, 0.3),
+            portion_grams,
+            density,
+        )
+    }
+
+    fn ingredient(name: &str, amount: f64, unit: &str) -> Ingredient {
+        Ingredient::new(
+            uuid::Uuid::new_v4(),
+            name.to_string(),
+            amount,
+            unit.to_string(),
+        )
+    }
+
+    #[test]
+    fn keys_include_the_singular_form() {
+        assert_eq!(match_keys("Eggs"), vec!["eggs", "egg"]);
+        assert_eq!(match_keys("all-purpose flour"), vec!["all purpose flour"]);
+        assert_eq!(
+            match_keys("cherry tomatoes"),
+            vec!["cherry tomatoes", "cherry tomato"]
+        );
+    }
+
+    #[test]
+    fn amounts_are_converted_to_grams() {
+        let flour = profile(None, None);
+        assert_eq!(grams_of(&ingredient("flour", 0.5, "kg"), &flour), Ok(500.0));
+        let cup = grams_of(&ingredient("flour", 1.0, "cup"), &flour).unwrap();
+        assert!((cup - 125.4).abs() < 0.1, "{cup}");
+        assert_eq!(
+            grams_of(&ingredient("flour", 2.0, "piece"), &flour),
+            Err(UnmatchedReason::NoWeight)
+        );
+        assert_eq!(
+            grams_of(&ingredient("egg", 2.0, "piece"), &profile(Some(50.0), None)),
+            Ok(100.0)
+        );
+    }
+
+    #[test]
+    fn totals_are_split_per_serving() {
+        let flour = profile(None, None);
+        let nutrition = RecipeNutrition::new(
+            uuid::Uuid::new_v4(),
+            4,
+            vec![IngredientNutrition::new(
+                &ingredient("flour", 200.0, "g"),
+                &flour,
+                200.0,
+            )],
+            vec![],
+        );
+        assert_eq!(nutrition.total().energy_kcal(), 728.0);
+        assert_eq!(nutrition.per_serving().energy_kcal(), 182.0);
+    }
+}
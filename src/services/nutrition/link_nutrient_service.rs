@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+use super::{
+    domain::nutrient::NutrientProfile,
+    ports::{
+        incoming::link_nutrient_service::{LinkNutrientService, LinkNutrientServiceError},
+        outgoing::nutrient_port::{NutrientError, NutrientPort},
+    },
+};
+
+impl From<NutrientError> for LinkNutrientServiceError {
+    fn from(value: NutrientError) -> Self {
+        match value {
+            NutrientError::IngredientNotFound => LinkNutrientServiceError::IngredientNotFound,
+            NutrientError::NutrientNotFound => LinkNutrientServiceError::NutrientNotFound,
+            NutrientError::InternalError => LinkNutrientServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for LinkNutrientServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => LinkNutrientServiceError::IngredientNotFound,
+            RecipeAccessError::InternalError => LinkNutrientServiceError::InternalError,
+        }
+    }
+}
+
+pub struct LinkNutrient<Storage, Recipes>
+where
+    Storage: NutrientPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+}
+
+impl<Storage, Recipes> LinkNutrient<Storage, Recipes>
+where
+    Storage: NutrientPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    async fn authorize(
+        &self,
+        caller: &Caller,
+        recipe: uuid::Uuid,
+    ) -> Result<(), LinkNutrientServiceError> {
+        let access = self.recipes.recipe_access(recipe).await?;
+        if access.can_modify(caller) {
+            Ok(())
+        } else if access.can_view(Some(caller)) {
+            Err(LinkNutrientServiceError::Forbidden)
+        } else {
+            Err(LinkNutrientServiceError::IngredientNotFound)
+        }
+    }
+}
+
+#[async_trait]
+impl<Storage, Recipes> LinkNutrientService for LinkNutrient<Storage, Recipes>
+where
+    Storage: NutrientPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    async fn link_nutrient(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+        nutrient: i64,
+    ) -> Result<NutrientProfile, LinkNutrientServiceError> {
+        self.authorize(&caller, recipe).await?;
+        self.storage
+            .link_nutrient(recipe, ingredient, nutrient)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    async fn unlink_nutrient(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+    ) -> Result<(), LinkNutrientServiceError> {
+        self.authorize(&caller, recipe).await?;
+        self.storage
+            .unlink_nutrient(recipe, ingredient)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+impl<Storage, Recipes> LinkNutrient<Storage, Recipes>
+where
+    Storage: NutrientPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes) -> Self {
+        Self { storage, recipes }
+    }
+}
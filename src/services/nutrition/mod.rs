@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod link_nutrient_service;
+pub mod ports;
+pub mod query_nutrition_service;
@@ -0,0 +1,48 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    nutrition::domain::nutrient::NutrientProfile, users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait LinkNutrientService {
+    /// Makes `nutrient` the source of nutrition facts for the ingredient.
+    async fn link_nutrient(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+        nutrient: i64,
+    ) -> Result<NutrientProfile, LinkNutrientServiceError>;
+    /// Goes back to matching the ingredient by name.
+    async fn unlink_nutrient(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+    ) -> Result<(), LinkNutrientServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LinkNutrientServiceError {
+    IngredientNotFound,
+    NutrientNotFound,
+    Forbidden,
+    InternalError,
+}
+
+impl Display for LinkNutrientServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkNutrientServiceError::IngredientNotFound => f.write_str("Ingredient not found"),
+            LinkNutrientServiceError::NutrientNotFound => f.write_str("Nutrient not found"),
+            LinkNutrientServiceError::Forbidden => {
+                f.write_str("You are not allowed to change this recipe")
+            }
+            LinkNutrientServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for LinkNutrientServiceError {}
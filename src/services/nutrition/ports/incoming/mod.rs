@@ -0,0 +1,2 @@
+pub mod link_nutrient_service;
+pub mod query_nutrition_service;
@@ -0,0 +1,32 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    nutrition::domain::nutrition::RecipeNutrition, users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait QueryNutritionService {
+    async fn query_nutrition(
+        &self,
+        caller: Option<Caller>,
+        recipe: uuid::Uuid,
+    ) -> Result<RecipeNutrition, QueryNutritionServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QueryNutritionServiceError {
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for QueryNutritionServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryNutritionServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            QueryNutritionServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for QueryNutritionServiceError {}
@@ -0,0 +1 @@
+pub mod nutrient_port;
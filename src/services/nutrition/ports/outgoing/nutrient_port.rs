@@ -0,0 +1,43 @@
+use crate::services::nutrition::domain::nutrient::NutrientProfile;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait NutrientPort {
+    /// Longest nutrient name found as whole words in `key`.
+    async fn nutrient_by_name(&self, key: &str) -> Result<Option<NutrientProfile>, NutrientError>;
+    /// Nutrient manually linked to the ingredient, overriding name matching.
+    async fn linked_nutrient(
+        &self,
+        ingredient: uuid::Uuid,
+    ) -> Result<Option<NutrientProfile>, NutrientError>;
+    async fn link_nutrient(
+        &self,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+        nutrient: i64,
+    ) -> Result<NutrientProfile, NutrientError>;
+    async fn unlink_nutrient(
+        &self,
+        recipe: uuid::Uuid,
+        ingredient: uuid::Uuid,
+    ) -> Result<(), NutrientError>;
+}
+
+#[derive(Debug)]
+pub enum NutrientError {
+    IngredientNotFound,
+    NutrientNotFound,
+    InternalError,
+}
+
+impl Display for NutrientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IngredientNotFound => write!(f, "Ingredient not found"),
+            Self::NutrientNotFound => write!(f, "Nutrient not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for NutrientError {}
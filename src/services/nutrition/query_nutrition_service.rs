@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::ports::outgoing::query_recipe_port::{QueryRecipeError, QueryRecipePort},
+    users::domain::caller::Caller,
+};
+
+use super::{
+    domain::{
+        nutrient::NutrientProfile,
+        nutrition::{
+            grams_of, match_keys, IngredientNutrition, RecipeNutrition, UnmatchedIngredient,
+            UnmatchedReason,
+        },
+    },
+    ports::{
+        incoming::query_nutrition_service::{QueryNutritionService, QueryNutritionServiceError},
+        outgoing::nutrient_port::{NutrientError, NutrientPort},
+    },
+};
+
+impl From<QueryRecipeError> for QueryNutritionServiceError {
+    fn from(value: QueryRecipeError) -> Self {
+        match value {
+            QueryRecipeError::RecordNotFound => QueryNutritionServiceError::RecipeNotFound,
+            QueryRecipeError::InternalError => QueryNutritionServiceError::InternalError,
+        }
+    }
+}
+
+impl From<NutrientError> for QueryNutritionServiceError {
+    fn from(_value: NutrientError) -> Self {
+        QueryNutritionServiceError::InternalError
+    }
+}
+
+pub struct QueryNutrition<Recipes, Nutrients>
+where
+    Recipes: QueryRecipePort + Send + Sync,
+    Nutrients: NutrientPort + Send + Sync,
+{
+    recipes: Recipes,
+    nutrients: Nutrients,
+}
+
+impl<Recipes, Nutrients> QueryNutrition<Recipes, Nutrients>
+where
+    Recipes: QueryRecipePort + Send + Sync,
+    Nutrients: NutrientPort + Send + Sync,
+{
+    pub fn new(recipes: Recipes, nutrients: Nutrients) -> Self {
+        Self { recipes, nutrients }
+    }
+
+    /// The manual link wins, otherwise the first key with a match.
+    async fn profile_for(
+        &self,
+        ingredient: uuid::Uuid,
+        name: &str,
+    ) -> Result<Option<NutrientProfile>, NutrientError> {
+        if let Some(profile) = self.nutrients.linked_nutrient(ingredient).await? {
+            return Ok(Some(profile));
+        }
+        for key in match_keys(name) {
+            if let Some(profile) = self.nutrients.nutrient_by_name(&key).await? {
+                return Ok(Some(profile));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl<Recipes, Nutrients> QueryNutritionService for QueryNutrition<Recipes, Nutrients>
+where
+    Recipes: QueryRecipePort + Send + Sync,
+    Nutrients: NutrientPort + Send + Sync,
+{
+    async fn query_nutrition(
+        &self,
+        caller: Option<Caller>,
+        recipe: uuid::Uuid,
+    ) -> Result<RecipeNutrition, QueryNutritionServiceError> {
+        let recipe = self.recipes.query_recipe(recipe).await?;
+        if !recipe.access().can_view(caller.as_ref()) {
+            return Err(QueryNutritionServiceError::RecipeNotFound);
+        }
+        let mut matched = vec![];
+        let mut unmatched = vec![];
+        for ingredient in recipe.ingredients() {
+            let Some(profile) = self
+                .profile_for(ingredient.uuid(), ingredient.name())
+                .await?
+            else {
+                unmatched.push(UnmatchedIngredient::new(
+                    ingredient,
+                    UnmatchedReason::NoNutrientMatch,
+                ));
+                continue;
+            };
+            match grams_of(ingredient, &profile) {
+                Ok(grams) => matched.push(IngredientNutrition::new(ingredient, &profile, grams)),
+                Err(reason) => unmatched.push(UnmatchedIngredient::new(ingredient, reason)),
+            }
+        }
+        Ok(RecipeNutrition::new(
+            recipe.uuid(),
+            recipe.servings(),
+            matched,
+            unmatched,
+        ))
+    }
+}
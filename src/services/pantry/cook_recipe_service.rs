@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::{
+        cooking_entry::CookingEntry,
+        stock::{deduct, Deduction, Shortage},
+    },
+    ports::{
+        incoming::cook_recipe_service::{CookRecipeService, CookRecipeServiceError},
+        outgoing::{
+            cooking_port::{CookingError, CookingPort},
+            pantry_port::{PantryError, PantryPort},
+        },
+    },
+};
+use crate::services::{
+    recipes::{
+        domain::{pagination::Page, recipe::Recipe},
+        list_recipes_service::MAX_PAGE_SIZE,
+        ports::outgoing::query_recipe_port::{QueryRecipeError, QueryRecipePort},
+    },
+    users::domain::caller::Caller,
+};
+
+impl From<PantryError> for CookRecipeServiceError {
+    fn from(_: PantryError) -> Self {
+        CookRecipeServiceError::InternalError
+    }
+}
+
+impl From<CookingError> for CookRecipeServiceError {
+    fn from(value: CookingError) -> Self {
+        match value {
+            CookingError::RecipeNotFound => CookRecipeServiceError::RecipeNotFound,
+            CookingError::InternalError => CookRecipeServiceError::InternalError,
+        }
+    }
+}
+
+impl From<QueryRecipeError> for CookRecipeServiceError {
+    fn from(value: QueryRecipeError) -> Self {
+        match value {
+            QueryRecipeError::RecordNotFound => CookRecipeServiceError::RecipeNotFound,
+            QueryRecipeError::InternalError => CookRecipeServiceError::InternalError,
+        }
+    }
+}
+
+pub struct CookRecipe<Storage, Recipes>
+where
+    Storage: PantryPort + CookingPort + Send + Sync,
+    Recipes: QueryRecipePort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+}
+
+impl<Storage, Recipes> CookRecipe<Storage, Recipes>
+where
+    Storage: PantryPort + CookingPort + Send + Sync,
+    Recipes: QueryRecipePort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes) -> Self {
+        Self { storage, recipes }
+    }
+
+    /// The recipe scaled to `servings`, and what taking it out of the
+    /// pantry of `caller` would do.
+    async fn plan(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        servings: Option<u32>,
+    ) -> Result<(Recipe, Deduction), CookRecipeServiceError> {
+        if servings == Some(0) {
+            return Err(CookRecipeServiceError::InvalidServings);
+        }
+        let recipe = self.recipes.query_recipe(uuid).await?;
+        if !recipe.access().can_view(Some(&caller)) {
+            return Err(CookRecipeServiceError::RecipeNotFound);
+        }
+        let recipe = match servings {
+            Some(servings) => recipe.scale(servings),
+            None => recipe,
+        };
+        let pantry = self.storage.list_items(caller.user()).await?;
+        let deduction = deduct(recipe.ingredients(), &pantry);
+        Ok((recipe, deduction))
+    }
+}
+
+#[async_trait]
+impl<Storage, Recipes> CookRecipeService for CookRecipe<Storage, Recipes>
+where
+    Storage: PantryPort + CookingPort + Send + Sync,
+    Recipes: QueryRecipePort + Send + Sync,
+{
+    async fn missing_ingredients(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        servings: Option<u32>,
+    ) -> Result<Vec<Shortage>, CookRecipeServiceError> {
+        let (_, deduction) = self.plan(caller, recipe, servings).await?;
+        Ok(deduction.shortages().to_vec())
+    }
+
+    async fn cook_recipe(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        servings: Option<u32>,
+    ) -> Result<(CookingEntry, Deduction), CookRecipeServiceError> {
+        let (recipe, deduction) = self.plan(caller, recipe, servings).await?;
+        let entry = CookingEntry::new(
+            uuid::Uuid::new_v4(),
+            caller.user(),
+            recipe.uuid(),
+            recipe.servings(),
+        );
+        let entry = self.storage.record_cooking(&entry, &deduction).await?;
+        Ok((entry, deduction))
+    }
+
+    async fn list_history(
+        &self,
+        caller: Caller,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Page<CookingEntry>, CookRecipeServiceError> {
+        if page_size == 0 || page_size > MAX_PAGE_SIZE {
+            return Err(CookRecipeServiceError::InvalidPageSize);
+        }
+        // One extra row tells us whether a next page exists.
+        let offset = u64::from(page) * u64::from(page_size);
+        let mut entries = self
+            .storage
+            .list_history(caller.user(), offset, page_size + 1)
+            .await?;
+        let next_page = if entries.len() > page_size as usize {
+            entries.truncate(page_size as usize);
+            Some(page + 1)
+        } else {
+            None
+        };
+        Ok(Page::new(entries, page, page_size, next_page))
+    }
+}
@@ -0,0 +1,46 @@
+/// A recipe one user cooked, kept in their cooking history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CookingEntry {
+    uuid: uuid::Uuid,
+    user: uuid::Uuid,
+    recipe: uuid::Uuid,
+    servings: u32,
+    cooked_at: String,
+}
+
+impl CookingEntry {
+    pub fn new(uuid: uuid::Uuid, user: uuid::Uuid, recipe: uuid::Uuid, servings: u32) -> Self {
+        Self {
+            uuid,
+            user,
+            recipe,
+            servings,
+            cooked_at: String::new(),
+        }
+    }
+
+    /// Set by the storage when the entry is recorded.
+    pub fn with_cooked_at(self, cooked_at: String) -> Self {
+        Self { cooked_at, ..self }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn user(&self) -> uuid::Uuid {
+        self.user
+    }
+
+    pub fn recipe(&self) -> uuid::Uuid {
+        self.recipe
+    }
+
+    pub fn servings(&self) -> u32 {
+        self.servings
+    }
+
+    pub fn cooked_at(&self) -> &str {
+        self.cooked_at.as_ref()
+    }
+}
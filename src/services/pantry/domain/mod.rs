@@ -0,0 +1,3 @@
+pub mod cooking_entry;
+pub mod pantry_item;
+pub mod stock;
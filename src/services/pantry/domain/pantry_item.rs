@@ -0,0 +1,128 @@
+use std::{error::Error, fmt::Display};
+
+use crate::services::{
+    meal_plans::domain::plan_date::PlanDate,
+    recipes::domain::{
+        quantity::Quantity,
+        unit::{Unit, UnitError},
+    },
+};
+
+pub const MAX_PANTRY_NAME_LENGTH: usize = 128;
+
+/// An amount of an ingredient one user has at home.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PantryItem {
+    uuid: uuid::Uuid,
+    owner: uuid::Uuid,
+    name: String,
+    amount: f64,
+    unit: String,
+    expires_on: Option<PlanDate>,
+}
+
+impl PantryItem {
+    pub fn new(
+        uuid: uuid::Uuid,
+        owner: uuid::Uuid,
+        name: String,
+        amount: f64,
+        unit: String,
+    ) -> Self {
+        Self {
+            uuid,
+            owner,
+            name,
+            amount,
+            unit,
+            expires_on: None,
+        }
+    }
+
+    pub fn with_expiry(self, expires_on: Option<PlanDate>) -> Self {
+        Self { expires_on, ..self }
+    }
+
+    pub fn with_amount(self, amount: f64) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn owner(&self) -> uuid::Uuid {
+        self.owner
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn unit(&self) -> &str {
+        self.unit.as_ref()
+    }
+
+    /// The unit as a typed value; stored units are always canonical.
+    pub fn parsed_unit(&self) -> Result<Unit, UnitError> {
+        self.unit.parse()
+    }
+
+    pub fn quantity(&self) -> Quantity {
+        Quantity::new(self.amount, self.parsed_unit().ok())
+    }
+
+    /// Day after which the item should not be used any more.
+    pub fn expires_on(&self) -> Option<PlanDate> {
+        self.expires_on
+    }
+
+    /// Trims the name and rewrites the unit to its canonical spelling.
+    pub fn normalized(self) -> Result<Self, PantryItemError> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err(PantryItemError::EmptyName);
+        }
+        if name.chars().count() > MAX_PANTRY_NAME_LENGTH {
+            return Err(PantryItemError::NameTooLong);
+        }
+        if !self.amount.is_finite() || self.amount < 0.0 {
+            return Err(PantryItemError::InvalidAmount);
+        }
+        let unit = self
+            .parsed_unit()
+            .map_err(PantryItemError::UnknownUnit)?
+            .symbol()
+            .to_string();
+        Ok(Self { name, unit, ..self })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PantryItemError {
+    EmptyName,
+    NameTooLong,
+    InvalidAmount,
+    UnknownUnit(UnitError),
+}
+
+impl Display for PantryItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PantryItemError::EmptyName => f.write_str("Pantry item name is empty"),
+            PantryItemError::NameTooLong => write!(
+                f,
+                "Pantry item names are at most {} characters long",
+                MAX_PANTRY_NAME_LENGTH
+            ),
+            PantryItemError::InvalidAmount => f.write_str("Amounts can not be negative"),
+            PantryItemError::UnknownUnit(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for PantryItemError {}
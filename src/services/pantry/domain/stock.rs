@@ -0,0 +1,269 @@
+use super::pantry_item::PantryItem;
+use crate::services::recipes::domain::{
+    density::density_of,
+    ingredient::{name_key, Ingredient},
+    quantity::Quantity,
+    unit::{Unit, UnitKind},
+};
+
+/// Amounts closer than this are considered equal, conversions are not
+/// exact.
+const TOLERANCE: f64 = 1e-6;
+
+/// An ingredient the pantry does not hold enough of, amounts in the unit of
+/// the recipe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shortage {
+    name: String,
+    unit: String,
+    needed: f64,
+    available: f64,
+}
+
+impl Shortage {
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn unit(&self) -> &str {
+        self.unit.as_ref()
+    }
+
+    pub fn needed(&self) -> f64 {
+        self.needed
+    }
+
+    pub fn available(&self) -> f64 {
+        self.available
+    }
+
+    pub fn missing(&self) -> Quantity {
+        Quantity::new(self.needed - self.available, self.unit.parse().ok())
+    }
+}
+
+/// The pantry once a recipe was taken out of it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Deduction {
+    updated: Vec<PantryItem>,
+    removed: Vec<uuid::Uuid>,
+    taken: Vec<(uuid::Uuid, f64)>,
+    shortages: Vec<Shortage>,
+}
+
+impl Deduction {
+    /// Items some of which was used, with what is left of them.
+    pub fn updated(&self) -> &[PantryItem] {
+        self.updated.as_ref()
+    }
+
+    /// Items that were used up.
+    pub fn removed(&self) -> &[uuid::Uuid] {
+        self.removed.as_ref()
+    }
+
+    /// How much was taken out of each item that was used, in the unit of
+    /// the item, so storage can deduct it from whatever the item holds by
+    /// then.
+    pub fn taken(&self) -> &[(uuid::Uuid, f64)] {
+        self.taken.as_ref()
+    }
+
+    /// Ingredients the pantry did not hold enough of.
+    pub fn shortages(&self) -> &[Shortage] {
+        self.shortages.as_ref()
+    }
+}
+
+/// Converts `amount` of `from` into `to`, moving between mass and volume
+/// with the density of the ingredient called `name` when it is known.
+fn convert(amount: f64, from: Unit, to: Unit, name: &str) -> Option<f64> {
+    if let Some(amount) = from.convert(amount, to) {
+        return Some(amount);
+    }
+    let density = density_of(name)?.grams_per_millilitre();
+    let base = amount * from.base_factor();
+    let base = match (from.kind(), to.kind()) {
+        (UnitKind::Mass, UnitKind::Volume) => base / density,
+        (UnitKind::Volume, UnitKind::Mass) => base * density,
+        _ => return None,
+    };
+    Some(base / to.base_factor())
+}
+
+/// Takes the ingredients out of `pantry`, using up the items that expire
+/// first. Items are matched by name, in any unit that converts to the one
+/// of the ingredient. Seasonings added to taste are never taken out, having
+/// some is enough, and items stocked "to taste" cover any amount.
+pub fn deduct(ingredients: &[Ingredient], pantry: &[PantryItem]) -> Deduction {
+    let mut stock = pantry.to_vec();
+    let mut order = (0..stock.len()).collect::<Vec<usize>>();
+    order.sort_by_key(|&index| {
+        let expires_on = stock[index].expires_on();
+        (expires_on.is_none(), expires_on)
+    });
+    let mut used = vec![0.0; stock.len()];
+    let mut changed = vec![false; stock.len()];
+    let mut shortages = vec![];
+    for ingredient in ingredients {
+        let key = name_key(ingredient.name());
+        let matching = order
+            .iter()
+            .copied()
+            .filter(|&index| stock[index].amount() > 0.0 && name_key(stock[index].name()) == key)
+            .collect::<Vec<usize>>();
+        let unit = ingredient.parsed_unit().ok();
+        let needed = ingredient.amount();
+        let covered = matches!(unit, Some(Unit::ToTaste)) && !matching.is_empty()
+            || matching
+                .iter()
+                .any(|&index| stock[index].parsed_unit() == Ok(Unit::ToTaste));
+        if covered {
+            continue;
+        }
+        let Some(unit) = unit.filter(|unit| *unit != Unit::ToTaste) else {
+            // Nothing to measure out, either the pantry has some or not.
+            shortages.push(Shortage {
+                name: ingredient.name().to_string(),
+                unit: ingredient.unit().to_string(),
+                needed,
+                available: 0.0,
+            });
+            continue;
+        };
+        let mut remaining = needed;
+        for index in matching {
+            if remaining <= TOLERANCE {
+                break;
+            }
+            let item = &stock[index];
+            let Some(available) = item
+                .parsed_unit()
+                .ok()
+                .and_then(|from| convert(item.amount(), from, unit, ingredient.name()))
+            else {
+                continue;
+            };
+            let taken = available.min(remaining);
+            remaining -= taken;
+            let left = match available - taken <= TOLERANCE {
+                true => 0.0,
+                false => item.amount() * (1.0 - taken / available),
+            };
+            used[index] += item.amount() - left;
+            stock[index] = item.clone().with_amount(left);
+            changed[index] = true;
+        }
+        if remaining > TOLERANCE {
+            shortages.push(Shortage {
+                name: ingredient.name().to_string(),
+                unit: ingredient.unit().to_string(),
+                needed,
+                available: (needed - remaining).max(0.0),
+            });
+        }
+    }
+    let (stock, taken): (Vec<PantryItem>, Vec<(uuid::Uuid, f64)>) = stock
+        .into_iter()
+        .zip(used)
+        .zip(changed)
+        .filter(|(_, changed)| *changed)
+        .map(|((item, taken), _)| {
+            let uuid = item.uuid();
+            (item, (uuid, taken))
+        })
+        .unzip();
+    let (used_up, updated): (Vec<PantryItem>, Vec<PantryItem>) =
+        stock.into_iter().partition(|item| item.amount() == 0.0);
+    Deduction {
+        updated,
+        removed: used_up.iter().map(PantryItem::uuid).collect(),
+        taken,
+        shortages,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(name: &str, amount: f64, unit: &str) -> Ingredient {
+        Ingredient::new(
+            uuid::Uuid::new_v4(),
+            name.to_string(),
+            amount,
+            unit.to_string(),
+        )
+    }
+
+    fn item(name: &str, amount: f64, unit: &str, expires_on: Option<&str>) -> PantryItem {
+        PantryItem::new(
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+            name.to_string(),
+            amount,
+            unit.to_string(),
+        )
+        .with_expiry(expires_on.map(|date| date.parse().unwrap()))
+    }
+
+    #[test]
+    fn amounts_are_converted_before_they_are_taken() {
+        let flour = item("Flour", 0.5, "kg", None);
+        let deduction = deduct(
+            &[
+                ingredient("flour", 200.0, "g"),
+                ingredient("flour", 1.0, "cup"),
+            ],
+            std::slice::from_ref(&flour),
+        );
+        assert_eq!(deduction.updated().len(), 1);
+        // 200 g and a cup of flour, 125 g, out of 500 g.
+        let left = deduction.updated()[0].amount();
+        assert!((left - 0.175).abs() < 0.001, "{left}");
+        assert!(deduction.removed().is_empty());
+        assert!(deduction.shortages().is_empty());
+    }
+
+    #[test]
+    fn items_expiring_first_are_used_up_first() {
+        let late = item("milk", 1.0, "l", Some("2024-02-01"));
+        let early = item("milk", 250.0, "ml", Some("2024-01-10"));
+        let deduction = deduct(
+            &[ingredient("Milk", 2.0, "cup")],
+            &[late.clone(), early.clone()],
+        );
+        assert_eq!(deduction.removed(), &[early.uuid()]);
+        assert_eq!(deduction.updated()[0].uuid(), late.uuid());
+        // Used up items give all they held.
+        assert!(deduction.taken().contains(&(early.uuid(), 250.0)));
+        assert!((deduction.updated()[0].amount() - 0.777).abs() < 0.001);
+    }
+
+    #[test]
+    fn shortages_report_what_is_missing() {
+        let deduction = deduct(
+            &[
+                ingredient("eggs", 3.0, "piece"),
+                ingredient("salt", 1.0, "to taste"),
+                ingredient("pepper", 1.0, "to taste"),
+                ingredient("sugar", 2.0, "tbsp"),
+            ],
+            &[
+                item("Eggs", 1.0, "piece", None),
+                item("pepper", 1.0, "to taste", None),
+                item("sugar", 1.0, "to taste", None),
+            ],
+        );
+        let shortages = deduction
+            .shortages()
+            .iter()
+            .map(|shortage| (shortage.name(), shortage.missing().display()))
+            .collect::<Vec<(&str, String)>>();
+        assert_eq!(
+            shortages,
+            vec![("eggs", "2".to_string()), ("salt", "to taste".to_string())]
+        );
+        assert_eq!(deduction.removed().len(), 1);
+    }
+}
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::pantry_item::PantryItem,
+    ports::{
+        incoming::manage_pantry_service::{ManagePantryService, ManagePantryServiceError},
+        outgoing::pantry_port::{PantryError, PantryPort},
+    },
+};
+use crate::services::{meal_plans::domain::plan_date::PlanDate, users::domain::caller::Caller};
+
+impl From<PantryError> for ManagePantryServiceError {
+    fn from(value: PantryError) -> Self {
+        match value {
+            PantryError::ItemNotFound => ManagePantryServiceError::ItemNotFound,
+            PantryError::InternalError => ManagePantryServiceError::InternalError,
+        }
+    }
+}
+
+pub struct ManagePantry<Storage>
+where
+    Storage: PantryPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+impl<Storage> ManagePantry<Storage>
+where
+    Storage: PantryPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+
+    /// The item, unless it belongs to somebody else.
+    async fn owned_item(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<PantryItem, ManagePantryServiceError> {
+        let item = self.storage.query_item(uuid).await?;
+        if item.owner() != caller.user() {
+            return Err(ManagePantryServiceError::ItemNotFound);
+        }
+        Ok(item)
+    }
+}
+
+#[async_trait]
+impl<Storage> ManagePantryService for ManagePantry<Storage>
+where
+    Storage: PantryPort + Send + Sync,
+{
+    async fn list_items(
+        &self,
+        caller: Caller,
+    ) -> Result<Vec<PantryItem>, ManagePantryServiceError> {
+        Ok(self.storage.list_items(caller.user()).await?)
+    }
+
+    async fn add_item(
+        &self,
+        caller: Caller,
+        name: String,
+        amount: f64,
+        unit: String,
+        expires_on: Option<PlanDate>,
+    ) -> Result<PantryItem, ManagePantryServiceError> {
+        let item = PantryItem::new(uuid::Uuid::new_v4(), caller.user(), name, amount, unit)
+            .with_expiry(expires_on)
+            .normalized()
+            .map_err(ManagePantryServiceError::InvalidItem)?;
+        Ok(self.storage.insert_item(&item).await?)
+    }
+
+    async fn update_item(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        name: String,
+        amount: f64,
+        unit: String,
+        expires_on: Option<PlanDate>,
+    ) -> Result<PantryItem, ManagePantryServiceError> {
+        self.owned_item(caller, uuid).await?;
+        let item = PantryItem::new(uuid, caller.user(), name, amount, unit)
+            .with_expiry(expires_on)
+            .normalized()
+            .map_err(ManagePantryServiceError::InvalidItem)?;
+        Ok(self.storage.update_item(&item).await?)
+    }
+
+    async fn delete_item(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManagePantryServiceError> {
+        self.owned_item(caller, uuid).await?;
+        Ok(self.storage.delete_item(uuid).await?)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod cook_recipe_service;
+pub mod domain;
+pub mod manage_pantry_service;
+pub mod ports;
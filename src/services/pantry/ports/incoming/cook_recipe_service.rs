@@ -0,0 +1,60 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    pantry::domain::{
+        cooking_entry::CookingEntry,
+        stock::{Deduction, Shortage},
+    },
+    recipes::domain::pagination::Page,
+    users::domain::caller::Caller,
+};
+
+/// Cooks recipes `caller` may view out of their own pantry.
+#[async_trait]
+pub trait CookRecipeService {
+    /// Ingredients of the recipe, for `servings` portions instead of its
+    /// own when given, that the pantry of `caller` does not hold enough of.
+    async fn missing_ingredients(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        servings: Option<u32>,
+    ) -> Result<Vec<Shortage>, CookRecipeServiceError>;
+    /// Takes the ingredients of the recipe out of the pantry, as far as it
+    /// holds them, and records the recipe in the cooking history.
+    async fn cook_recipe(
+        &self,
+        caller: Caller,
+        recipe: uuid::Uuid,
+        servings: Option<u32>,
+    ) -> Result<(CookingEntry, Deduction), CookRecipeServiceError>;
+    /// Recipes `caller` cooked, most recent first.
+    async fn list_history(
+        &self,
+        caller: Caller,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Page<CookingEntry>, CookRecipeServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CookRecipeServiceError {
+    InvalidServings,
+    InvalidPageSize,
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for CookRecipeServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CookRecipeServiceError::InvalidServings => f.write_str("Servings must be at least one"),
+            CookRecipeServiceError::InvalidPageSize => f.write_str("Invalid page size"),
+            CookRecipeServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            CookRecipeServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for CookRecipeServiceError {}
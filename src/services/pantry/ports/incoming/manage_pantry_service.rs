@@ -0,0 +1,57 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    meal_plans::domain::plan_date::PlanDate,
+    pantry::domain::pantry_item::{PantryItem, PantryItemError},
+    users::domain::caller::Caller,
+};
+
+/// Pantries are private, items of other users are reported as not found.
+#[async_trait]
+pub trait ManagePantryService {
+    async fn list_items(&self, caller: Caller)
+        -> Result<Vec<PantryItem>, ManagePantryServiceError>;
+    async fn add_item(
+        &self,
+        caller: Caller,
+        name: String,
+        amount: f64,
+        unit: String,
+        expires_on: Option<PlanDate>,
+    ) -> Result<PantryItem, ManagePantryServiceError>;
+    /// Replaces the name, amount, unit and expiry of an item.
+    async fn update_item(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        name: String,
+        amount: f64,
+        unit: String,
+        expires_on: Option<PlanDate>,
+    ) -> Result<PantryItem, ManagePantryServiceError>;
+    async fn delete_item(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManagePantryServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ManagePantryServiceError {
+    InvalidItem(PantryItemError),
+    ItemNotFound,
+    InternalError,
+}
+
+impl Display for ManagePantryServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManagePantryServiceError::InvalidItem(error) => write!(f, "{}", error),
+            ManagePantryServiceError::ItemNotFound => f.write_str("Item not found"),
+            ManagePantryServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for ManagePantryServiceError {}
@@ -0,0 +1,2 @@
+pub mod cook_recipe_service;
+pub mod manage_pantry_service;
@@ -0,0 +1,38 @@
+use crate::services::pantry::domain::{cooking_entry::CookingEntry, stock::Deduction};
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait CookingPort {
+    /// Records the entry and takes what the deduction took off the pantry
+    /// items, all or nothing.
+    async fn record_cooking(
+        &self,
+        entry: &CookingEntry,
+        deduction: &Deduction,
+    ) -> Result<CookingEntry, CookingError>;
+    /// Entries of `user`, most recent first.
+    async fn list_history(
+        &self,
+        user: uuid::Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<CookingEntry>, CookingError>;
+}
+
+#[derive(Debug)]
+pub enum CookingError {
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for CookingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecipeNotFound => write!(f, "Recipe not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for CookingError {}
@@ -0,0 +1,2 @@
+pub mod cooking_port;
+pub mod pantry_port;
@@ -0,0 +1,31 @@
+use crate::services::pantry::domain::pantry_item::PantryItem;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait PantryPort {
+    /// Items of `owner`, by name then expiry.
+    async fn list_items(&self, owner: uuid::Uuid) -> Result<Vec<PantryItem>, PantryError>;
+    async fn query_item(&self, uuid: uuid::Uuid) -> Result<PantryItem, PantryError>;
+    async fn insert_item(&self, item: &PantryItem) -> Result<PantryItem, PantryError>;
+    /// Stores the name, amount, unit and expiry of an item.
+    async fn update_item(&self, item: &PantryItem) -> Result<PantryItem, PantryError>;
+    async fn delete_item(&self, uuid: uuid::Uuid) -> Result<(), PantryError>;
+}
+
+#[derive(Debug)]
+pub enum PantryError {
+    ItemNotFound,
+    InternalError,
+}
+
+impl Display for PantryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ItemNotFound => write!(f, "Item not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for PantryError {}
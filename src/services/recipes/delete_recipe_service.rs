@@ -1,36 +1,84 @@
 use async_trait::async_trait;
 
+use crate::services::{
+    images::{
+        ports::outgoing::{
+            image_storage_port::ImageStoragePort, image_variant_port::ImageVariantPort,
+            recipe_image_port::RecipeImagePort,
+        },
+        upload_image_service::remove_orphaned_image,
+    },
+    users::domain::caller::Caller,
+};
+
 use super::ports::{
     incoming::delete_recipe_service::{DeleteRecipeService, DeleteRecipeServiceError},
-    outgoing::delete_recipe_port::{DeleteRecipeError, DeleteRecipePort},
+    outgoing::{
+        delete_recipe_port::{DeleteRecipeError, DeleteRecipePort},
+        recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    },
 };
 
-pub struct DeleteRecipe<Storage>
+pub struct DeleteRecipe<Storage, Images>
 where
-    Storage: DeleteRecipePort + Send + Sync,
+    Storage: DeleteRecipePort + RecipeAccessPort + RecipeImagePort + ImageVariantPort + Send + Sync,
+    Images: ImageStoragePort + Send + Sync,
 {
     storage: Storage,
+    images: Images,
 }
 
 #[async_trait]
-impl<Storage> DeleteRecipeService for DeleteRecipe<Storage>
+impl<Storage, Images> DeleteRecipeService for DeleteRecipe<Storage, Images>
 where
-    Storage: DeleteRecipePort + Send + Sync,
+    Storage: DeleteRecipePort + RecipeAccessPort + RecipeImagePort + ImageVariantPort + Send + Sync,
+    Images: ImageStoragePort + Send + Sync,
 {
-    async fn delete_recipe(&self, uuid: uuid::Uuid) -> Result<(), DeleteRecipeServiceError> {
+    async fn delete_recipe(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), DeleteRecipeServiceError> {
+        match self.storage.recipe_access(uuid).await {
+            Ok(access) if access.can_modify(&caller) => (),
+            Ok(access) if access.can_view(Some(&caller)) => {
+                return Err(DeleteRecipeServiceError::Forbidden)
+            }
+            Ok(_) | Err(RecipeAccessError::RecordNotFound) => {
+                return Err(DeleteRecipeServiceError::RecipeNotFound)
+            }
+            Err(RecipeAccessError::InternalError) => {
+                return Err(DeleteRecipeServiceError::InternalError)
+            }
+        }
+        let mut names = self
+            .storage
+            .image_variants(uuid)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|variant| variant.name().as_str().to_string())
+            .collect::<Vec<String>>();
+        names.extend(self.storage.recipe_image(uuid).await.ok().flatten());
         match self.storage.delete_recipe(uuid).await {
             Err(DeleteRecipeError::RecordNotFound) => Err(DeleteRecipeServiceError::RecipeNotFound),
             Err(DeleteRecipeError::InternalError) => Err(DeleteRecipeServiceError::InternalError),
-            _ => Ok(()),
+            _ => {
+                for name in names {
+                    remove_orphaned_image(&self.storage, &self.images, &name).await;
+                }
+                Ok(())
+            }
         }
     }
 }
 
-impl<Storage> DeleteRecipe<Storage>
+impl<Storage, Images> DeleteRecipe<Storage, Images>
 where
-    Storage: DeleteRecipePort + Send + Sync,
+    Storage: DeleteRecipePort + RecipeAccessPort + RecipeImagePort + ImageVariantPort + Send + Sync,
+    Images: ImageStoragePort + Send + Sync,
 {
-    pub fn new(storage: Storage) -> Self {
-        Self { storage }
+    pub fn new(storage: Storage, images: Images) -> Self {
+        Self { storage, images }
     }
 }
@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use crate::services::users::domain::caller::Caller;
+
+/// Who besides the owner may read a recipe. Only the owner and admins may
+/// ever change it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    /// The owner alone.
+    #[default]
+    Private,
+    /// Any signed in user.
+    Shared,
+    /// Everybody, anonymous callers included.
+    Public,
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Private => "private",
+            Visibility::Shared => "shared",
+            Visibility::Public => "public",
+        }
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "private" => Ok(Visibility::Private),
+            "shared" => Ok(Visibility::Shared),
+            "public" => Ok(Visibility::Public),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Ownership of a recipe and the rules derived from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecipeAccess {
+    owner: Option<uuid::Uuid>,
+    visibility: Visibility,
+}
+
+impl RecipeAccess {
+    /// `owner` is `None` for recipes created before accounts existed.
+    pub fn new(owner: Option<uuid::Uuid>, visibility: Visibility) -> Self {
+        Self { owner, visibility }
+    }
+
+    pub fn owner(&self) -> Option<uuid::Uuid> {
+        self.owner
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn is_owned_by(&self, caller: &Caller) -> bool {
+        self.owner == Some(caller.user())
+    }
+
+    /// Recipes a caller may not view are reported as missing to them, so
+    /// their existence is not given away. Only callers who may view a
+    /// recipe learn that they may not change it.
+    pub fn can_view(&self, caller: Option<&Caller>) -> bool {
+        match (self.visibility, caller) {
+            (Visibility::Public, _) => true,
+            (Visibility::Shared, Some(_)) => true,
+            (Visibility::Private, Some(caller)) => self.is_owned_by(caller) || caller.is_admin(),
+            (_, None) => false,
+        }
+    }
+
+    /// Ownerless recipes can only be changed by admins.
+    pub fn can_modify(&self, caller: &Caller) -> bool {
+        self.is_owned_by(caller) || caller.is_admin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::users::domain::caller::Role;
+
+    #[test]
+    fn visibility_decides_who_can_view() {
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let other = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        let access = |visibility| RecipeAccess::new(Some(owner.user()), visibility);
+
+        assert!(access(Visibility::Public).can_view(None));
+        assert!(!access(Visibility::Shared).can_view(None));
+        assert!(access(Visibility::Shared).can_view(Some(&other)));
+        assert!(!access(Visibility::Private).can_view(Some(&other)));
+        assert!(access(Visibility::Private).can_view(Some(&owner)));
+        assert!(access(Visibility::Private).can_view(Some(&admin)));
+    }
+
+    #[test]
+    fn only_owners_and_admins_can_modify() {
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let other = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        let access = RecipeAccess::new(Some(owner.user()), Visibility::Public);
+
+        assert!(access.can_modify(&owner));
+        assert!(!access.can_modify(&other));
+        assert!(access.can_modify(&admin));
+        assert!(!RecipeAccess::new(None, Visibility::Public).can_modify(&owner));
+        assert!(RecipeAccess::new(None, Visibility::Public).can_modify(&admin));
+    }
+}
@@ -0,0 +1,605 @@
+use std::{collections::HashMap, time::Duration};
+
+use serde::Serialize;
+
+use super::{
+    difficulty::Difficulty,
+    ingredient::Ingredient,
+    recipe::{Recipe, DEFAULT_SERVINGS},
+    recipe_format::{first_number, format_amount, parse_minutes, FormatError, RecipeFormat},
+    step::Step,
+    tag::normalize_tag,
+    unit::Unit,
+};
+
+/// Characters with a meaning in Cooklang, left out of written names.
+const MARKUP: [char; 5] = ['@', '#', '~', '{', '}'];
+
+/// Metadata keys, lower cased with dashes and underscores as spaces.
+fn metadata_key(key: &str) -> String {
+    key.trim().to_lowercase().replace(['-', '_'], " ")
+}
+
+fn yaml_text(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(text) => Some(text.clone()),
+        serde_yaml::Value::Number(number) => Some(number.to_string()),
+        serde_yaml::Value::Sequence(values) => Some(
+            values
+                .iter()
+                .filter_map(yaml_text)
+                .collect::<Vec<String>>()
+                .join(", "),
+        ),
+        _ => None,
+    }
+}
+
+/// Metadata from the YAML front matter and the older `>> key: value` lines,
+/// and the rest of the text.
+fn split_metadata(text: &str) -> Result<(HashMap<String, String>, String), FormatError> {
+    let mut metadata = HashMap::new();
+    let mut body = text;
+    if let Some(rest) = text.strip_prefix("---") {
+        let (front, rest) = rest
+            .split_once("\n---")
+            .ok_or_else(|| FormatError::Malformed("Unterminated front matter".into()))?;
+        let front = serde_yaml::from_str::<serde_yaml::Mapping>(front)
+            .map_err(|e| FormatError::Malformed(e.to_string()))?;
+        for (key, value) in front.iter() {
+            if let (Some(key), Some(value)) = (key.as_str(), yaml_text(value)) {
+                metadata.insert(metadata_key(key), value);
+            }
+        }
+        body = rest;
+    }
+    let mut lines = vec![];
+    for line in body.lines() {
+        match line.trim().strip_prefix(">>") {
+            Some(entry) => {
+                if let Some((key, value)) = entry.split_once(':') {
+                    metadata.insert(metadata_key(key), value.trim().to_string());
+                }
+            }
+            None => lines.push(line),
+        }
+    }
+    Ok((metadata, lines.join("\n")))
+}
+
+/// `text` without `-- line` and `[- block -]` comments.
+fn strip_comments(text: &str) -> String {
+    let mut without_blocks = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("[-") {
+        without_blocks.push_str(&rest[..start]);
+        rest = match rest[start..].find("-]") {
+            Some(end) => &rest[start + end + 2..],
+            None => "",
+        };
+    }
+    without_blocks.push_str(rest);
+    without_blocks
+        .lines()
+        .map(|line| match line.find("--") {
+            Some(start) => &line[..start],
+            None => line,
+        })
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// A whole number, a decimal, a fraction or a mixed number such as "1 1/2".
+fn parse_number(text: &str) -> Option<f64> {
+    let mut total = 0.0;
+    for part in text.split_whitespace() {
+        total += match part.split_once('/') {
+            Some((numerator, denominator)) => {
+                let denominator = denominator.trim().parse::<f64>().ok()?;
+                (denominator != 0.0).then_some(())?;
+                numerator.trim().parse::<f64>().ok()? / denominator
+            }
+            None => part.parse::<f64>().ok()?,
+        };
+    }
+    (total > 0.0 && total.is_finite()).then_some(total)
+}
+
+/// A component following `@`, `#` or `~`: its name, what its braces hold
+/// and the length it takes in `rest`. Names of more than a word end with
+/// braces, the others at the end of the word.
+fn take_component(rest: &str) -> (&str, Option<&str>, usize) {
+    if let Some(open) = rest.find('{') {
+        let name = &rest[..open];
+        let close = rest[open..].find('}');
+        if let (false, Some(close)) = (name.contains(MARKUP), close) {
+            let braces = &rest[open + 1..open + close];
+            let mut used = open + close + 1;
+            // A preparation note such as "(diced)" follows some ingredients.
+            if rest[used..].starts_with('(') {
+                if let Some(end) = rest[used..].find(')') {
+                    used += end + 1;
+                }
+            }
+            return (name.trim(), Some(braces), used);
+        }
+    }
+    let used = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(rest.len());
+    let name = rest[..used].trim_end_matches('-');
+    (name, None, name.len())
+}
+
+/// The ingredient `name` with the amount `braces` hold. Units this instance
+/// does not know stay part of the name.
+fn new_ingredient(name: &str, braces: &str) -> Ingredient {
+    let (amount, unit) = match braces.split_once('%') {
+        Some((amount, unit)) => (amount, unit.trim()),
+        None => (braces, ""),
+    };
+    let amount = parse_number(amount.trim().trim_end_matches('*'));
+    let (amount, unit, name) = match (amount, unit.parse::<Unit>()) {
+        (None, _) => (1.0, Unit::ToTaste, name.to_string()),
+        (Some(amount), Ok(unit)) => (amount, unit, name.to_string()),
+        (Some(amount), Err(_)) => (amount, Unit::Piece, format!("{} {}", unit, name)),
+    };
+    Ingredient::new(
+        uuid::Uuid::new_v4(),
+        name,
+        amount,
+        unit.symbol().to_string(),
+    )
+}
+
+fn timer_duration(braces: &str) -> Option<Duration> {
+    let (amount, unit) = braces.split_once('%')?;
+    let amount = parse_number(amount)?;
+    let seconds = match unit.trim().to_lowercase().as_str() {
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3600.0,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60.0,
+        "s" | "sec" | "secs" | "second" | "seconds" => 1.0,
+        _ => return None,
+    };
+    Some(Duration::from_secs((amount * seconds).round() as u64))
+}
+
+/// The step of a paragraph, adding the ingredients it names first to
+/// `ingredients`.
+fn read_step(paragraph: &str, ingredients: &mut Vec<Ingredient>) -> Step {
+    let mut text = String::new();
+    let mut references = vec![];
+    let mut timer = None;
+    let mut rest = paragraph;
+    while let Some(start) = rest.find(['@', '#', '~']) {
+        text.push_str(&rest[..start]);
+        let sigil = rest[start..].chars().next().unwrap_or('@');
+        let (name, braces, used) = take_component(&rest[start + 1..]);
+        rest = &rest[start + 1 + used..];
+        match sigil {
+            '@' if !name.is_empty() => {
+                text.push_str(name);
+                let known = ingredients
+                    .iter()
+                    .find(|ingredient| ingredient.name().eq_ignore_ascii_case(name));
+                let uuid = match (known, braces.map(str::trim).filter(|b| !b.is_empty())) {
+                    // Naming an ingredient again without an amount means the same one.
+                    (Some(known), None) => known.uuid(),
+                    (_, braces) => {
+                        let ingredient = new_ingredient(name, braces.unwrap_or(""));
+                        let uuid = ingredient.uuid();
+                        ingredients.push(ingredient);
+                        uuid
+                    }
+                };
+                if !references.contains(&uuid) {
+                    references.push(uuid);
+                }
+            }
+            '#' if !name.is_empty() => text.push_str(name),
+            '~' if braces.is_some() => {
+                let braces = braces.unwrap_or("");
+                text.push_str(&braces.replace('%', " "));
+                if timer.is_none() {
+                    timer = timer_duration(braces).map(|duration| (name, duration));
+                }
+            }
+            // A lone sigil is text.
+            _ => {
+                text.push(sigil);
+                text.push_str(name);
+            }
+        }
+    }
+    text.push_str(rest);
+    let text = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let step = Step::new(text).with_ingredients(references);
+    match timer {
+        Some((name, duration)) => step
+            .with_duration(Some(duration))
+            .with_timer(Some(name.to_string()).filter(|name| !name.is_empty())),
+        None => step,
+    }
+}
+
+/// Byte position of `needle` in `text` as a whole word, ignoring ASCII case.
+fn find_word(text: &str, needle: &str, from: usize) -> Option<usize> {
+    if needle.is_empty() {
+        return None;
+    }
+    (from..text.len()).find(|&start| {
+        let end = start + needle.len();
+        text.is_char_boundary(start)
+            && text.is_char_boundary(end.min(text.len()))
+            && end <= text.len()
+            && text[start..end].eq_ignore_ascii_case(needle)
+            && !text[..start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric)
+            && !text[end..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric)
+    })
+}
+
+fn ingredient_markup(ingredient: &Ingredient) -> String {
+    let name = ingredient.name().replace(MARKUP, "");
+    match ingredient.parsed_unit() {
+        Ok(Unit::ToTaste) => format!("@{}{{}}", name),
+        Ok(Unit::Piece) => format!("@{}{{{}}}", name, format_amount(ingredient.amount())),
+        _ => format!(
+            "@{}{{{}%{}}}",
+            name,
+            format_amount(ingredient.amount()),
+            ingredient.unit()
+        ),
+    }
+}
+
+/// Marks up in `step` the first mention of each ingredient it uses and of
+/// its duration. Returns the text with the ingredients it could not find.
+fn write_step<'a>(step: &Step, ingredients: &'a [Ingredient]) -> (String, Vec<&'a Ingredient>) {
+    let text = step.text().replace(MARKUP, "");
+    // Non overlapping (start, end, markup), in any order.
+    let mut marks: Vec<(usize, usize, String)> = vec![];
+    let free = |marks: &[(usize, usize, String)], start: usize, end: usize| {
+        marks.iter().all(|(s, e, _)| end <= *s || start >= *e)
+    };
+    let mut missing = vec![];
+    for uuid in step.ingredients() {
+        let Some(ingredient) = ingredients.iter().find(|i| i.uuid() == *uuid) else {
+            continue;
+        };
+        let name = ingredient.name().replace(MARKUP, "");
+        let mut from = 0;
+        let found = loop {
+            match find_word(&text, &name, from) {
+                Some(start) if free(&marks, start, start + name.len()) => break Some(start),
+                Some(start) => from = start + 1,
+                None => break None,
+            }
+        };
+        match found {
+            Some(start) => marks.push((start, start + name.len(), ingredient_markup(ingredient))),
+            None => missing.push(ingredient),
+        }
+    }
+    let mut appended = String::new();
+    if let Some(duration) = step.duration() {
+        let timer = step.timer().unwrap_or("").replace(MARKUP, "");
+        let seconds = duration.as_secs();
+        let mut phrases = vec![];
+        if seconds.is_multiple_of(3600) {
+            phrases.extend(["hours", "hour", "hrs", "hr"].map(|u| (seconds / 3600, u)));
+        }
+        if seconds.is_multiple_of(60) {
+            phrases.extend(["minutes", "minute", "mins", "min"].map(|u| (seconds / 60, u)));
+        }
+        // A step saying how long it takes is marked up where it says so.
+        let found = phrases.iter().find_map(|(amount, unit)| {
+            let phrase = format!("{} {}", amount, unit);
+            find_word(&text, &phrase, 0)
+                .filter(|start| free(&marks, *start, start + phrase.len()))
+                .map(|start| {
+                    (
+                        start,
+                        start + phrase.len(),
+                        format!("~{}{{{}%{}}}", timer, amount, unit),
+                    )
+                })
+        });
+        match found {
+            Some(mark) => marks.push(mark),
+            None => {
+                let (amount, unit) = phrases.first().copied().unwrap_or((seconds, "seconds"));
+                appended = format!(" ~{}{{{}%{}}}", timer, amount, unit);
+            }
+        }
+    }
+    marks.sort_by_key(|(start, _, _)| *start);
+    let mut written = String::new();
+    let mut position = 0;
+    for (start, end, markup) in marks {
+        written.push_str(&text[position..start]);
+        written.push_str(&markup);
+        position = end;
+    }
+    written.push_str(&text[position..]);
+    written.push_str(&appended);
+    (written, missing)
+}
+
+#[derive(Debug, Serialize)]
+struct FrontMatter<'a> {
+    title: &'a str,
+    servings: u32,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    tags: &'a [String],
+    #[serde(rename = "prep time", skip_serializing_if = "Option::is_none")]
+    prep_time: Option<String>,
+    #[serde(rename = "cook time", skip_serializing_if = "Option::is_none")]
+    cook_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    difficulty: Option<&'static str>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    image: &'a str,
+}
+
+/// Cooklang `.cook` files, one recipe each: YAML front matter, then one
+/// step per paragraph with `@ingredients{amount%unit}`, `#cookware{}` and
+/// `~timers{amount%unit}` marked up in the text.
+pub struct Cooklang;
+
+impl RecipeFormat for Cooklang {
+    fn read(&self, data: &[u8]) -> Result<Vec<Recipe>, FormatError> {
+        let text = std::str::from_utf8(data).map_err(|e| FormatError::Malformed(e.to_string()))?;
+        let text = text.replace("\r\n", "\n");
+        let (metadata, body) = split_metadata(text.trim_start())?;
+        let title = metadata
+            .get("title")
+            .map(|title| title.trim().to_string())
+            .filter(|title| !title.is_empty())
+            .ok_or_else(|| FormatError::Malformed("The recipe has no title".into()))?;
+        let mut ingredients = vec![];
+        let mut steps = vec![];
+        let body = strip_comments(&body);
+        let mut paragraph: Vec<&str> = vec![];
+        // A trailing empty line ends the last paragraph.
+        for line in body.lines().chain([""]) {
+            let line = line.trim();
+            if line.is_empty() {
+                if !paragraph.is_empty() {
+                    steps.push(read_step(&paragraph.join(" "), &mut ingredients));
+                    paragraph.clear();
+                }
+            } else if !line.starts_with('>') && !line.starts_with('=') {
+                // Notes and section titles are not steps.
+                paragraph.push(line);
+            }
+        }
+        let tags = metadata
+            .get("tags")
+            .map(|tags| {
+                let mut unique: Vec<String> = vec![];
+                for tag in tags
+                    .trim_matches(['[', ']'])
+                    .split(',')
+                    .filter_map(|tag| normalize_tag(tag.trim().trim_matches(['"', '\''])).ok())
+                {
+                    if !unique.contains(&tag) {
+                        unique.push(tag);
+                    }
+                }
+                unique
+            })
+            .unwrap_or_default();
+        let minutes = |key: &str| metadata.get(key).and_then(|text| parse_minutes(text));
+        let prep_minutes = minutes("prep time");
+        let cook_minutes = match (prep_minutes, minutes("cook time")) {
+            (None, None) => minutes("time")
+                .or(minutes("total time"))
+                .or(minutes("duration")),
+            (_, cook_minutes) => cook_minutes,
+        };
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            title,
+            metadata
+                .get("image")
+                .filter(|image| image.starts_with("http"))
+                .cloned()
+                .unwrap_or_default(),
+            steps,
+            ingredients,
+        )
+        .with_servings(
+            metadata
+                .get("servings")
+                .and_then(|servings| first_number(servings))
+                .filter(|servings| *servings > 0)
+                .unwrap_or(DEFAULT_SERVINGS),
+        )
+        .with_times(prep_minutes, cook_minutes)
+        .with_difficulty(
+            metadata
+                .get("difficulty")
+                .and_then(|difficulty| difficulty.trim().to_lowercase().parse::<Difficulty>().ok()),
+        )
+        .with_tags(tags);
+        Ok(vec![recipe])
+    }
+
+    fn write(&self, recipes: &[Recipe]) -> Result<Vec<u8>, FormatError> {
+        let [recipe] = recipes else {
+            return Err(FormatError::Unwritable(
+                "A Cooklang file holds a single recipe".into(),
+            ));
+        };
+        let front = FrontMatter {
+            title: recipe.name(),
+            servings: recipe.servings(),
+            tags: recipe.tags(),
+            prep_time: recipe.prep_minutes().map(|m| format!("{} minutes", m)),
+            cook_time: recipe.cook_minutes().map(|m| format!("{} minutes", m)),
+            difficulty: recipe.difficulty().map(|difficulty| difficulty.as_str()),
+            image: recipe.image(),
+        };
+        let front =
+            serde_yaml::to_string(&front).map_err(|e| FormatError::Unwritable(e.to_string()))?;
+        let mut paragraphs = vec![];
+        let mut placed = vec![];
+        let mut missing: Vec<&Ingredient> = vec![];
+        for step in recipe.steps() {
+            let (text, step_missing) = write_step(step, recipe.ingredients());
+            placed.extend(step.ingredients().iter().copied());
+            missing.extend(step_missing);
+            paragraphs.push(text);
+        }
+        // Ingredients no step mentions are listed first, in a step of their own.
+        missing.extend(
+            recipe
+                .ingredients()
+                .iter()
+                .filter(|ingredient| !placed.contains(&ingredient.uuid())),
+        );
+        let mut listed: Vec<uuid::Uuid> = vec![];
+        missing.retain(|ingredient| {
+            let new = !listed.contains(&ingredient.uuid());
+            listed.push(ingredient.uuid());
+            new
+        });
+        if !missing.is_empty() {
+            let list = missing
+                .iter()
+                .map(|ingredient| ingredient_markup(ingredient))
+                .collect::<Vec<String>>()
+                .join(", ");
+            paragraphs.insert(0, format!("Ingredients: {}", list));
+        }
+        Ok(format!("---\n{}---\n\n{}\n", front, paragraphs.join("\n\n")).into_bytes())
+    }
+
+    fn content_type(&self) -> &'static str {
+        "text/plain; charset=utf-8"
+    }
+
+    fn extension(&self) -> &'static str {
+        "cook"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredients(recipe: &Recipe) -> Vec<(String, f64, String)> {
+        recipe
+            .ingredients()
+            .iter()
+            .map(|i| (i.name().to_string(), i.amount(), i.unit().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn recipes_are_read_from_their_markup() {
+        let text = "---\ntitle: Pancakes\nservings: 4 people\ntags: [Breakfast, quick]\n---\n\
+            >> cook time: 20 min\n\
+            -- a comment\n\
+            Crack @eggs{3} into a #large bowl{}, add @plain flour{125%g}(sifted)\n\
+            and @milk{250%ml}. [- whisked by hand -]Whisk.\n\
+            \n\
+            = Cooking\n\
+            Fry in @butter for ~flip{1.5%minutes} per side, season with @salt and @butter.\n";
+
+        let read = Cooklang.read(text.as_bytes()).unwrap();
+
+        assert_eq!(read.len(), 1);
+        let pancakes = &read[0];
+        assert_eq!(pancakes.name(), "Pancakes");
+        assert_eq!(pancakes.servings(), 4);
+        assert_eq!(pancakes.cook_minutes(), Some(20));
+        assert_eq!(pancakes.tags(), ["breakfast", "quick"]);
+        assert_eq!(
+            ingredients(pancakes),
+            [
+                ("eggs".to_string(), 3.0, "piece".to_string()),
+                ("plain flour".to_string(), 125.0, "g".to_string()),
+                ("milk".to_string(), 250.0, "ml".to_string()),
+                ("butter".to_string(), 1.0, "to taste".to_string()),
+                ("salt".to_string(), 1.0, "to taste".to_string()),
+            ]
+        );
+        let steps = pancakes.steps();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(
+            steps[0].text(),
+            "Crack eggs into a large bowl, add plain flour and milk. Whisk."
+        );
+        assert_eq!(steps[0].ingredients().len(), 3);
+        assert_eq!(
+            steps[1].text(),
+            "Fry in butter for 1.5 minutes per side, season with salt and butter."
+        );
+        assert_eq!(steps[1].ingredients().len(), 2);
+        assert_eq!(steps[1].duration(), Some(Duration::from_secs(90)));
+        assert_eq!(steps[1].timer(), Some("flip"));
+        assert!(Cooklang.read(b"No title @here").is_err());
+    }
+
+    #[test]
+    fn recipes_round_trip() {
+        let flour = Ingredient::new(uuid::Uuid::new_v4(), "flour".into(), 500.0, "g".into());
+        let water = Ingredient::new(uuid::Uuid::new_v4(), "water".into(), 0.3, "l".into());
+        let salt = Ingredient::new(uuid::Uuid::new_v4(), "salt".into(), 1.0, "to taste".into());
+        let yeast = Ingredient::new(uuid::Uuid::new_v4(), "dry yeast".into(), 7.0, "g".into());
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Bread".into(),
+            "".into(),
+            vec![
+                Step::new("Mix the flour, water and salt.".into()).with_ingredients(vec![
+                    flour.uuid(),
+                    water.uuid(),
+                    salt.uuid(),
+                ]),
+                Step::new("Bake for 40 minutes".into())
+                    .with_duration(Some(Duration::from_secs(40 * 60)))
+                    .with_timer(Some("bread".into())),
+            ],
+            vec![flour, water, salt, yeast],
+        )
+        .with_servings(8)
+        .with_times(Some(15), Some(40))
+        .with_difficulty(Some(Difficulty::Easy))
+        .with_tags(vec!["baking".into()]);
+
+        let written = Cooklang.write(std::slice::from_ref(&recipe)).unwrap();
+        let read = Cooklang.read(&written).unwrap().remove(0);
+
+        assert_eq!(read.name(), "Bread");
+        assert_eq!(read.servings(), 8);
+        assert_eq!(read.prep_minutes(), Some(15));
+        assert_eq!(read.cook_minutes(), Some(40));
+        assert_eq!(read.difficulty(), Some(Difficulty::Easy));
+        assert_eq!(read.tags(), ["baking"]);
+        // The yeast no step mentions comes first, in a step listing it.
+        let mut expected = ingredients(&recipe);
+        expected.rotate_right(1);
+        assert_eq!(ingredients(&read), expected);
+        let texts = read.steps().iter().map(Step::text).collect::<Vec<&str>>();
+        assert_eq!(
+            texts,
+            [
+                "Ingredients: dry yeast",
+                "Mix the flour, water and salt.",
+                "Bake for 40 minutes"
+            ]
+        );
+        assert_eq!(read.steps()[1].ingredients().len(), 3);
+        assert_eq!(read.steps()[2].duration(), Some(Duration::from_secs(2400)));
+        assert_eq!(read.steps()[2].timer(), Some("bread"));
+        assert!(Cooklang.write(&[recipe.clone(), recipe]).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+/// Grams per millilitre of common ingredients and whether they are poured
+/// rather than weighed, most specific names first so "brown sugar" wins over
+/// "sugar". Values follow the usual cup weights (a cup of all-purpose flour
+/// is 125 g, of sugar 200 g, of butter 227 g).
+const DENSITIES: [(&str, f64, bool); 29] = [
+    ("brown sugar", 0.93, false),
+    ("powdered sugar", 0.51, false),
+    ("icing sugar", 0.51, false),
+    ("caster sugar", 0.85, false),
+    ("bread flour", 0.54, false),
+    ("whole wheat flour", 0.51, false),
+    ("almond flour", 0.41, false),
+    ("cocoa powder", 0.42, false),
+    ("olive oil", 0.91, true),
+    ("heavy cream", 1.01, true),
+    ("rolled oats", 0.38, false),
+    ("flour", 0.53, false),
+    ("sugar", 0.85, false),
+    ("butter", 0.96, false),
+    ("water", 1.0, true),
+    ("milk", 1.03, true),
+    ("buttermilk", 1.03, true),
+    ("cream", 1.01, true),
+    ("yogurt", 1.03, true),
+    ("oil", 0.92, true),
+    ("honey", 1.42, true),
+    ("syrup", 1.33, true),
+    ("salt", 1.2, false),
+    ("rice", 0.85, false),
+    ("oats", 0.38, false),
+    ("cocoa", 0.42, false),
+    ("cornstarch", 0.54, false),
+    ("breadcrumbs", 0.45, false),
+    ("cheese", 0.42, false),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Density {
+    grams_per_millilitre: f64,
+    liquid: bool,
+}
+
+impl Density {
+    pub fn grams_per_millilitre(&self) -> f64 {
+        self.grams_per_millilitre
+    }
+
+    /// Liquids are measured by volume even in metric kitchens.
+    pub fn is_liquid(&self) -> bool {
+        self.liquid
+    }
+}
+
+/// Density of the ingredient called `name`, when it is a common one.
+/// Matches whole words, so "buttermilk" is not butter.
+pub fn density_of(name: &str) -> Option<Density> {
+    let name = name.to_lowercase();
+    let words = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>();
+    DENSITIES
+        .iter()
+        .find_map(|(key, grams_per_millilitre, liquid)| {
+            let key = key.split(' ').collect::<Vec<&str>>();
+            words
+                .windows(key.len())
+                .any(|window| window == key.as_slice())
+                .then_some(Density {
+                    grams_per_millilitre: *grams_per_millilitre,
+                    liquid: *liquid,
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_name_wins() {
+        let grams = |name| density_of(name).map(|d| d.grams_per_millilitre());
+        assert_eq!(grams("Light brown sugar"), Some(0.93));
+        assert_eq!(grams("sugar"), Some(0.85));
+        assert_eq!(grams("all-purpose flour"), Some(0.53));
+    }
+
+    #[test]
+    fn only_whole_words_match() {
+        assert!(density_of("buttermilk").unwrap().is_liquid());
+        assert_eq!(density_of("butternut squash"), None);
+    }
+}
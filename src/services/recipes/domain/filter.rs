@@ -0,0 +1,51 @@
+use super::{
+    difficulty::Difficulty,
+    tag::{TagError, TagFilter},
+};
+
+/// Restricts recipe listings. Empty filters match everything.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RecipeFilter {
+    tags: TagFilter,
+    max_total_minutes: Option<u32>,
+    difficulty: Option<Difficulty>,
+}
+
+impl RecipeFilter {
+    pub fn new(tags: TagFilter) -> Self {
+        Self {
+            tags,
+            ..Default::default()
+        }
+    }
+
+    /// Keeps the recipes ready in at most `minutes`. Recipes without any
+    /// time never match.
+    pub fn with_max_total_minutes(self, max_total_minutes: Option<u32>) -> Self {
+        Self {
+            max_total_minutes,
+            ..self
+        }
+    }
+
+    pub fn with_difficulty(self, difficulty: Option<Difficulty>) -> Self {
+        Self { difficulty, ..self }
+    }
+
+    pub fn tags(&self) -> &TagFilter {
+        &self.tags
+    }
+
+    pub fn max_total_minutes(&self) -> Option<u32> {
+        self.max_total_minutes
+    }
+
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty
+    }
+
+    pub fn normalized(self) -> Result<Self, TagError> {
+        let tags = self.tags.normalized()?;
+        Ok(Self { tags, ..self })
+    }
+}
@@ -1,3 +1,18 @@
+use super::{
+    density::density_of,
+    quantity::Quantity,
+    unit::{Unit, UnitError, UnitSystem},
+};
+
+/// Lowercase with single spaces, the form ingredient names are compared in,
+/// so "Red  onion" and "red onion" are the same ingredient.
+pub fn name_key(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 #[derive(Debug, Clone)]
 pub struct Ingredient {
     uuid: uuid::Uuid,
@@ -31,4 +46,45 @@ impl Ingredient {
     pub fn unit(&self) -> &str {
         self.unit.as_ref()
     }
+
+    /// The unit as a typed value; stored units are always canonical.
+    pub fn parsed_unit(&self) -> Result<Unit, UnitError> {
+        self.unit.parse()
+    }
+
+    /// Rewrites the unit to its canonical spelling.
+    pub fn normalized(self) -> Result<Self, UnitError> {
+        let unit = self.parsed_unit()?;
+        Ok(Self {
+            unit: unit.symbol().to_string(),
+            ..self
+        })
+    }
+
+    pub fn quantity(&self) -> Quantity {
+        Quantity::new(self.amount, self.parsed_unit().ok())
+    }
+
+    /// The quantity expressed in `system`, using the density of the
+    /// ingredient to move between volume and mass. Liquids are never
+    /// weighed.
+    pub fn quantity_in(&self, system: UnitSystem) -> Quantity {
+        let density = density_of(&self.name)
+            .filter(|density| system == UnitSystem::Imperial || !density.is_liquid())
+            .map(|density| density.grams_per_millilitre());
+        self.quantity().convert(system, density)
+    }
+
+    /// Replaces amount and unit, keeping the stored spelling when the
+    /// quantity carries no known unit.
+    pub fn with_quantity(self, quantity: Quantity) -> Self {
+        Self {
+            amount: quantity.amount(),
+            unit: quantity
+                .unit()
+                .map(|unit| unit.symbol().to_string())
+                .unwrap_or(self.unit),
+            ..self
+        }
+    }
 }
@@ -0,0 +1,536 @@
+use std::fmt::Display;
+
+use super::{ingredient::Ingredient, unit::Unit};
+
+/// Unicode vulgar fractions and their value as a fraction.
+const FRACTIONS: [(char, &str); 15] = [
+    ('½', "1/2"),
+    ('⅓', "1/3"),
+    ('⅔', "2/3"),
+    ('¼', "1/4"),
+    ('¾', "3/4"),
+    ('⅕', "1/5"),
+    ('⅖', "2/5"),
+    ('⅗', "3/5"),
+    ('⅘', "4/5"),
+    ('⅙', "1/6"),
+    ('⅚', "5/6"),
+    ('⅛', "1/8"),
+    ('⅜', "3/8"),
+    ('⅝', "5/8"),
+    ('⅞', "7/8"),
+];
+
+/// Words describing how an ingredient is prepared rather than what it is.
+const PREPARATIONS: [&str; 30] = [
+    "beaten",
+    "chopped",
+    "cored",
+    "crumbled",
+    "crushed",
+    "cubed",
+    "deseeded",
+    "deveined",
+    "diced",
+    "drained",
+    "grated",
+    "halved",
+    "julienned",
+    "mashed",
+    "melted",
+    "minced",
+    "peeled",
+    "pitted",
+    "quartered",
+    "rinsed",
+    "seeded",
+    "shredded",
+    "sifted",
+    "sliced",
+    "softened",
+    "squeezed",
+    "toasted",
+    "torn",
+    "trimmed",
+    "zested",
+];
+
+/// Words that only say how an ingredient is prepared, as in "finely chopped".
+const MANNERS: [&str; 8] = [
+    "coarsely", "finely", "freshly", "lightly", "roughly", "thickly", "thinly", "well",
+];
+
+/// Something in a line that was read one way but could have meant another.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseWarning {
+    /// A range such as "2-3 cloves", the larger amount is used so there is
+    /// enough.
+    Range { low: f64, high: f64 },
+    /// No amount was given, the ingredient is taken to be added to taste.
+    NoAmount,
+}
+
+impl ParseWarning {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseWarning::Range { .. } => "range",
+            ParseWarning::NoAmount => "no_amount",
+        }
+    }
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::Range { low, high } => {
+                write!(
+                    f,
+                    "Amount is a range from {} to {}, {} is used",
+                    low, high, high
+                )
+            }
+            ParseWarning::NoAmount => f.write_str("No amount given, taken to be to taste"),
+        }
+    }
+}
+
+/// An ingredient written as a single line of text, the way people and
+/// recipe sites write them: "2 1/2 cups flour, sifted", "1½ tbsp olive oil",
+/// "a pinch of salt" or "2-3 cloves garlic (finely chopped)".
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientLine {
+    amount: f64,
+    unit: Unit,
+    name: String,
+    note: Option<String>,
+    warnings: Vec<ParseWarning>,
+}
+
+impl IngredientLine {
+    /// Reads the amount, unit and name of `line`. Text in parentheses or
+    /// after the first comma, and preparation words such as "chopped", make
+    /// up the note. Lines without an amount are seasoning added to taste.
+    /// `None` when the line names no ingredient.
+    pub fn parse(line: &str) -> Option<Self> {
+        let (line, mut notes) = take_parentheses(&normalize(line));
+        let head = match line.split_once(',') {
+            Some((head, note)) => {
+                notes.push(note.trim().to_string());
+                head
+            }
+            None => line.as_str(),
+        };
+        let tokens = head.split_whitespace().collect::<Vec<&str>>();
+        let mut warnings = vec![];
+        let (amount, rest) = match parse_amount(&tokens) {
+            Some((amount, used)) => {
+                if let Some(range) = amount.range() {
+                    warnings.push(range);
+                }
+                (Some(amount.high), &tokens[used..])
+            }
+            None => (None, &tokens[..]),
+        };
+        let (unit, rest) = match amount {
+            Some(_) => match parse_unit(rest) {
+                Some((unit, used)) => (unit, &rest[used..]),
+                None => (Unit::Piece, rest),
+            },
+            None => (Unit::ToTaste, rest),
+        };
+        let rest = match rest.first() {
+            Some(word) if word.eq_ignore_ascii_case("of") => &rest[1..],
+            _ => rest,
+        };
+        let (mut name, preparation) = take_preparation(rest);
+        notes.extend(preparation);
+        if amount.is_none() {
+            let mut seasoning = false;
+            for suffix in [" to taste", " as needed"] {
+                if let Some(stripped) = strip_suffix_ignore_case(&name, suffix) {
+                    name = stripped.to_string();
+                    seasoning = true;
+                }
+            }
+            let before = notes.len();
+            notes.retain(|note| {
+                !note.eq_ignore_ascii_case("to taste") && !note.eq_ignore_ascii_case("as needed")
+            });
+            if !seasoning && notes.len() == before {
+                warnings.push(ParseWarning::NoAmount);
+            }
+        }
+        notes.retain(|note| !note.is_empty());
+        if name.is_empty() {
+            return None;
+        }
+        Some(Self {
+            amount: amount.unwrap_or(1.0),
+            unit,
+            name,
+            note: (!notes.is_empty()).then(|| notes.join(", ")),
+            warnings,
+        })
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Preparation such as "sifted", not kept on the ingredient.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// How the line could also have been read, empty when it was clear.
+    pub fn warnings(&self) -> &[ParseWarning] {
+        self.warnings.as_ref()
+    }
+
+    pub fn into_ingredient(self) -> Ingredient {
+        Ingredient::new(
+            uuid::Uuid::new_v4(),
+            self.name,
+            self.amount,
+            self.unit.symbol().to_string(),
+        )
+    }
+}
+
+/// Spells unicode fractions, dashes and decimal commas the ASCII way, and
+/// puts spaces around range dashes: "1½–2" becomes "1 1/2 - 2".
+fn normalize(line: &str) -> String {
+    let characters = line.chars().collect::<Vec<char>>();
+    let mut normalized = String::with_capacity(line.len());
+    for (index, &character) in characters.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|index| characters[index]);
+        let next = characters.get(index + 1).copied();
+        let is_number = |c: Option<char>| {
+            c.is_some_and(|c| c.is_ascii_digit() || FRACTIONS.iter().any(|(f, _)| *f == c))
+        };
+        match character {
+            '⁄' => normalized.push('/'),
+            ',' if is_number(previous) && next.is_some_and(|c| c.is_ascii_digit()) => {
+                normalized.push('.')
+            }
+            '-' | '–' | '—' if is_number(previous) && is_number(next) => {
+                normalized.push_str(" - ")
+            }
+            _ => match FRACTIONS
+                .iter()
+                .find(|(fraction, _)| *fraction == character)
+            {
+                Some((_, ascii)) => {
+                    normalized.push(' ');
+                    normalized.push_str(ascii);
+                    normalized.push(' ');
+                }
+                None => normalized.push(character),
+            },
+        }
+    }
+    normalized
+}
+
+/// The line without its parenthesised parts, and those parts.
+fn take_parentheses(line: &str) -> (String, Vec<String>) {
+    let mut rest = String::with_capacity(line.len());
+    let mut notes = vec![];
+    let mut depth = 0;
+    let mut note = String::new();
+    for character in line.chars() {
+        match character {
+            '(' => {
+                if depth > 0 {
+                    note.push(character);
+                }
+                depth += 1;
+            }
+            ')' if depth > 0 => {
+                depth -= 1;
+                match depth {
+                    0 => notes.push(std::mem::take(&mut note).trim().to_string()),
+                    _ => note.push(character),
+                }
+            }
+            _ if depth > 0 => note.push(character),
+            _ => rest.push(character),
+        }
+    }
+    (rest, notes)
+}
+
+/// Name words of `tokens`, and the preparation they mention.
+fn take_preparation(tokens: &[&str]) -> (String, Option<String>) {
+    let word = |token: &str| {
+        token
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    };
+    let is_preparation = |token: &str| PREPARATIONS.contains(&word(token).as_str());
+    let mut name = vec![];
+    let mut preparation = vec![];
+    for (index, token) in tokens.iter().enumerate() {
+        let next = tokens.get(index + 1).copied();
+        let previous = index.checked_sub(1).map(|index| tokens[index]);
+        let joins = |token: &str| {
+            word(token) == "and"
+                && previous.is_some_and(is_preparation)
+                && next.is_some_and(is_preparation)
+        };
+        let describes = MANNERS.contains(&word(token).as_str()) && next.is_some_and(is_preparation);
+        if is_preparation(token) || describes || joins(token) {
+            preparation.push(word(token));
+        } else {
+            name.push(*token);
+        }
+    }
+    if name.is_empty() {
+        // A name made of preparation words only is still a name.
+        return (tokens.join(" "), None);
+    }
+    let preparation = (!preparation.is_empty()).then(|| preparation.join(" "));
+    (name.join(" "), preparation)
+}
+
+fn strip_suffix_ignore_case<'a>(value: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = value.len().checked_sub(suffix.len())?;
+    match value.get(split..) {
+        Some(end) if end.eq_ignore_ascii_case(suffix) => Some(value[..split].trim_end()),
+        _ => None,
+    }
+}
+
+/// A whole number, a decimal or a fraction such as "1/2".
+fn parse_number(token: &str) -> Option<f64> {
+    let value = match token.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator = numerator.parse::<u32>().ok()?;
+            let denominator = denominator.parse::<u32>().ok().filter(|d| *d != 0)?;
+            f64::from(numerator) / f64::from(denominator)
+        }
+        None => token.parse::<f64>().ok()?,
+    };
+    (value.is_finite() && value >= 0.0).then_some(value)
+}
+
+/// A number at the start of `tokens` and the number of tokens it takes,
+/// reading mixed numbers such as "2 1/2" and "a" or "an" as one.
+fn parse_quantity(tokens: &[&str]) -> Option<(f64, usize)> {
+    let first = tokens.first()?;
+    if first.eq_ignore_ascii_case("a") || first.eq_ignore_ascii_case("an") {
+        return Some((1.0, 1));
+    }
+    let whole = parse_number(first)?;
+    let fraction = tokens
+        .get(1)
+        .filter(|token| token.contains('/') && !first.contains(['/', '.']))
+        .and_then(|token| parse_number(token))
+        .filter(|fraction| *fraction < 1.0);
+    match fraction {
+        Some(fraction) => Some((whole + fraction, 2)),
+        None => Some((whole, 1)),
+    }
+}
+
+/// An amount, a single one when `low` and `high` are equal.
+struct Amount {
+    low: f64,
+    high: f64,
+}
+
+impl Amount {
+    fn range(&self) -> Option<ParseWarning> {
+        (self.low != self.high).then_some(ParseWarning::Range {
+            low: self.low,
+            high: self.high,
+        })
+    }
+}
+
+/// The amount at the start of `tokens` and the number of tokens it takes,
+/// reading ranges such as "2 - 3" and "2 to 3".
+fn parse_amount(tokens: &[&str]) -> Option<(Amount, usize)> {
+    let (low, used) = parse_quantity(tokens)?;
+    let single = Some((Amount { low, high: low }, used));
+    let Some(separator) = tokens.get(used) else {
+        return single;
+    };
+    if !matches!(separator.to_lowercase().as_str(), "-" | "to" | "or") {
+        return single;
+    }
+    match parse_quantity(&tokens[used + 1..]) {
+        Some((high, more)) if high > low => Some((Amount { low, high }, used + 1 + more)),
+        _ => single,
+    }
+}
+
+/// The unit at the start of `tokens` and the number of tokens it takes,
+/// trying two word units such as "fl oz" first.
+fn parse_unit(tokens: &[&str]) -> Option<(Unit, usize)> {
+    for length in [2, 1] {
+        if tokens.len() <= length {
+            // A unit alone is more likely the name, as in "2 cans".
+            continue;
+        }
+        let spelling = tokens[..length].join(" ");
+        if let Ok(unit) = spelling.parse::<Unit>() {
+            return Some((unit, length));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(line: &str) -> (f64, Unit, String, Option<String>) {
+        let line = IngredientLine::parse(line).unwrap();
+        (
+            line.amount(),
+            line.unit(),
+            line.name().to_string(),
+            line.note().map(str::to_string),
+        )
+    }
+
+    #[test]
+    fn lines_are_split_into_amount_unit_and_name() {
+        assert_eq!(
+            parsed("2 1/2 cups flour, sifted"),
+            (2.5, Unit::Cup, "flour".into(), Some("sifted".into()))
+        );
+        assert_eq!(
+            parsed("1.5 fl oz dark rum"),
+            (1.5, Unit::FluidOunce, "dark rum".into(), None)
+        );
+        assert_eq!(
+            parsed("3 large eggs"),
+            (3.0, Unit::Piece, "large eggs".into(), None)
+        );
+        assert_eq!(
+            parsed("1 cup of milk"),
+            (1.0, Unit::Cup, "milk".into(), None)
+        );
+        assert_eq!(
+            parsed("1/2 tsp salt"),
+            (0.5, Unit::Teaspoon, "salt".into(), None)
+        );
+        assert_eq!(
+            parsed("0,5 l stock"),
+            (0.5, Unit::Litre, "stock".into(), None)
+        );
+    }
+
+    #[test]
+    fn unicode_fractions_and_articles_are_amounts() {
+        assert_eq!(
+            parsed("1½ tbsp olive oil"),
+            (1.5, Unit::Tablespoon, "olive oil".into(), None)
+        );
+        assert_eq!(
+            parsed("¾ cup sugar"),
+            (0.75, Unit::Cup, "sugar".into(), None)
+        );
+        assert_eq!(
+            parsed("1 ⅓ cups oats"),
+            (1.0 + 1.0 / 3.0, Unit::Cup, "oats".into(), None)
+        );
+        assert_eq!(
+            parsed("a pinch of salt"),
+            (1.0, Unit::Pinch, "salt".into(), None)
+        );
+        assert_eq!(parsed("An onion"), (1.0, Unit::Piece, "onion".into(), None));
+    }
+
+    #[test]
+    fn ranges_use_the_larger_amount() {
+        let line = IngredientLine::parse("2-3 cloves garlic").unwrap();
+        assert_eq!((line.amount(), line.unit()), (3.0, Unit::Clove));
+        assert_eq!(
+            line.warnings(),
+            &[ParseWarning::Range {
+                low: 2.0,
+                high: 3.0
+            }]
+        );
+        assert_eq!(
+            parsed("1½–2 cups stock"),
+            (2.0, Unit::Cup, "stock".into(), None)
+        );
+        assert_eq!(
+            parsed("1 to 2 tbsp honey"),
+            (2.0, Unit::Tablespoon, "honey".into(), None)
+        );
+        assert!(IngredientLine::parse("2 eggs")
+            .unwrap()
+            .warnings()
+            .is_empty());
+    }
+
+    #[test]
+    fn notes_collect_parentheses_and_preparation() {
+        assert_eq!(
+            parsed("1 can (400 g) chopped tomatoes"),
+            (
+                1.0,
+                Unit::Can,
+                "tomatoes".into(),
+                Some("400 g, chopped".into())
+            )
+        );
+        assert_eq!(
+            parsed("2 cloves garlic, peeled (finely chopped)"),
+            (
+                2.0,
+                Unit::Clove,
+                "garlic".into(),
+                Some("finely chopped, peeled".into())
+            )
+        );
+        assert_eq!(
+            parsed("1 onion peeled and diced"),
+            (
+                1.0,
+                Unit::Piece,
+                "onion".into(),
+                Some("peeled and diced".into())
+            )
+        );
+        assert_eq!(
+            parsed("100 g ground almonds"),
+            (100.0, Unit::Gram, "ground almonds".into(), None)
+        );
+    }
+
+    #[test]
+    fn unmeasured_lines_are_to_taste() {
+        assert_eq!(
+            parsed("Salt, to taste"),
+            (1.0, Unit::ToTaste, "Salt".into(), None)
+        );
+        assert_eq!(
+            parsed("black pepper to taste"),
+            (1.0, Unit::ToTaste, "black pepper".into(), None)
+        );
+        assert!(IngredientLine::parse("salt to taste")
+            .unwrap()
+            .warnings()
+            .is_empty());
+        assert_eq!(
+            IngredientLine::parse("fresh basil").unwrap().warnings(),
+            &[ParseWarning::NoAmount]
+        );
+        assert!(IngredientLine::parse("  ").is_none());
+        assert!(IngredientLine::parse("2 cups").is_some_and(|line| line.name() == "cups"));
+    }
+}
@@ -0,0 +1,409 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+    ingredient::Ingredient,
+    ingredient_line::IngredientLine,
+    recipe::{Recipe, DEFAULT_SERVINGS},
+    recipe_format::{
+        first_number, ingredient_line, known_times, minutes_text, parse_minutes, FormatError,
+        RecipeFormat,
+    },
+    step::Step,
+    tag::normalize_tag,
+    unit::Unit,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct MealieName {
+    name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct MealieTag {
+    name: String,
+    slug: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MealieIngredient {
+    quantity: Option<f64>,
+    unit: Option<MealieName>,
+    food: Option<MealieName>,
+    note: String,
+    display: String,
+    original_text: Option<String>,
+    disable_amount: bool,
+    reference_id: Option<uuid::Uuid>,
+}
+
+impl MealieIngredient {
+    /// Parsed ingredients have a food, the others only text.
+    fn into_ingredient(self) -> Option<Ingredient> {
+        let uuid = self.reference_id.unwrap_or_else(uuid::Uuid::new_v4);
+        let food = self
+            .food
+            .map(|food| food.name.trim().to_string())
+            .filter(|food| !food.is_empty());
+        let Some(food) = food else {
+            let text = self
+                .original_text
+                .filter(|text| !text.trim().is_empty())
+                .or_else(|| Some(self.note).filter(|note| !note.trim().is_empty()))
+                .unwrap_or(self.display);
+            let line = IngredientLine::parse(&text)?;
+            return Some(Ingredient::new(
+                uuid,
+                line.name().to_string(),
+                line.amount(),
+                line.unit().symbol().to_string(),
+            ));
+        };
+        let unit = self
+            .unit
+            .map(|unit| unit.name.trim().to_string())
+            .unwrap_or_default();
+        let (amount, unit, name) = match (self.quantity.filter(|q| *q > 0.0), unit.parse::<Unit>())
+        {
+            (None, Ok(Unit::Piece)) => (1.0, Unit::ToTaste, food),
+            (quantity, Ok(unit)) => (quantity.unwrap_or(1.0), unit, food),
+            // Units this instance does not know stay part of the name.
+            (quantity, Err(_)) => (
+                quantity.unwrap_or(1.0),
+                Unit::Piece,
+                format!("{} {}", unit, food),
+            ),
+        };
+        Some(Ingredient::new(
+            uuid,
+            name,
+            amount,
+            unit.symbol().to_string(),
+        ))
+    }
+}
+
+impl From<&Ingredient> for MealieIngredient {
+    fn from(value: &Ingredient) -> Self {
+        let unit = value.parsed_unit().ok();
+        Self {
+            quantity: match unit {
+                Some(Unit::ToTaste) => None,
+                _ => Some(value.amount()),
+            },
+            unit: match unit {
+                Some(Unit::ToTaste) | Some(Unit::Piece) => None,
+                _ => Some(MealieName {
+                    name: value.unit().to_string(),
+                }),
+            },
+            food: Some(MealieName {
+                name: value.name().to_string(),
+            }),
+            display: ingredient_line(value),
+            reference_id: Some(value.uuid()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct MealieReference {
+    #[serde(rename = "referenceId")]
+    reference_id: Option<uuid::Uuid>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MealieInstruction {
+    title: String,
+    text: String,
+    ingredient_references: Vec<MealieReference>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MealieRecipe {
+    id: Option<uuid::Uuid>,
+    name: String,
+    slug: String,
+    description: String,
+    image: Option<String>,
+    recipe_yield: Option<String>,
+    recipe_servings: Option<f64>,
+    prep_time: Option<String>,
+    cook_time: Option<String>,
+    perform_time: Option<String>,
+    total_time: Option<String>,
+    recipe_category: Vec<MealieTag>,
+    tags: Vec<MealieTag>,
+    recipe_ingredient: Vec<MealieIngredient>,
+    recipe_instructions: Vec<MealieInstruction>,
+    #[serde(rename = "orgURL")]
+    org_url: Option<String>,
+}
+
+fn minutes(text: &Option<String>) -> Option<u32> {
+    text.as_deref().and_then(parse_minutes)
+}
+
+impl MealieRecipe {
+    fn into_recipe(self) -> Recipe {
+        let ingredients = self
+            .recipe_ingredient
+            .into_iter()
+            .filter_map(MealieIngredient::into_ingredient)
+            .collect::<Vec<Ingredient>>();
+        let steps = self
+            .recipe_instructions
+            .into_iter()
+            .filter(|instruction| !instruction.text.trim().is_empty())
+            .map(|instruction| {
+                // References to ingredients left out would fail the import.
+                let references = instruction
+                    .ingredient_references
+                    .iter()
+                    .filter_map(|reference| reference.reference_id)
+                    .filter(|uuid| ingredients.iter().any(|i| i.uuid() == *uuid))
+                    .collect();
+                Step::new(instruction.text.trim().to_string()).with_ingredients(references)
+            })
+            .collect();
+        let mut tags: Vec<String> = vec![];
+        for tag in self.tags.iter().chain(self.recipe_category.iter()) {
+            if let Ok(tag) = normalize_tag(&tag.name) {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+        }
+        let servings = self
+            .recipe_servings
+            .map(|servings| servings.round() as u32)
+            .or_else(|| self.recipe_yield.as_deref().and_then(first_number))
+            .filter(|servings| *servings > 0)
+            .unwrap_or(DEFAULT_SERVINGS);
+        let (prep_minutes, cook_minutes) = known_times(
+            minutes(&self.prep_time),
+            minutes(&self.cook_time).or(minutes(&self.perform_time)),
+            minutes(&self.total_time),
+        );
+        let image = self
+            .image
+            .filter(|image| image.starts_with("http"))
+            .unwrap_or_default();
+        Recipe::new(
+            self.id.unwrap_or_else(uuid::Uuid::new_v4),
+            self.name.trim().to_string(),
+            image,
+            steps,
+            ingredients,
+        )
+        .with_servings(servings)
+        .with_times(prep_minutes, cook_minutes)
+        .with_tags(tags)
+    }
+}
+
+impl From<&Recipe> for MealieRecipe {
+    fn from(value: &Recipe) -> Self {
+        Self {
+            id: Some(value.uuid()),
+            name: value.name().to_string(),
+            slug: slug(value.name()),
+            image: Some(value.image().to_string()).filter(|image| !image.is_empty()),
+            recipe_yield: Some(format!("{} servings", value.servings())),
+            recipe_servings: Some(f64::from(value.servings())),
+            prep_time: value.prep_minutes().map(minutes_text),
+            cook_time: value.cook_minutes().map(minutes_text),
+            perform_time: value.cook_minutes().map(minutes_text),
+            total_time: value.total_minutes().map(minutes_text),
+            tags: value
+                .tags()
+                .iter()
+                .map(|tag| MealieTag {
+                    name: tag.clone(),
+                    slug: slug(tag),
+                })
+                .collect(),
+            recipe_ingredient: value
+                .ingredients()
+                .iter()
+                .map(MealieIngredient::from)
+                .collect(),
+            recipe_instructions: value
+                .steps()
+                .iter()
+                .map(|step| MealieInstruction {
+                    text: step.text().to_string(),
+                    ingredient_references: step
+                        .ingredients()
+                        .iter()
+                        .map(|uuid| MealieReference {
+                            reference_id: Some(*uuid),
+                        })
+                        .collect(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Lower case words joined by dashes, as Mealie names recipes in URLs.
+fn slug(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+/// Recipes as Mealie's API and exports give them: a single recipe, a list
+/// of them, or a page of them under `items`. Ingredients Mealie has not
+/// parsed are read from their text.
+pub struct Mealie;
+
+impl RecipeFormat for Mealie {
+    fn read(&self, data: &[u8]) -> Result<Vec<Recipe>, FormatError> {
+        let malformed = |e: serde_json::Error| FormatError::Malformed(e.to_string());
+        let value = serde_json::from_slice::<Value>(data).map_err(malformed)?;
+        let recipes = match value {
+            Value::Array(recipes) => recipes,
+            Value::Object(mut page) if page.contains_key("items") => match page.remove("items") {
+                Some(Value::Array(recipes)) => recipes,
+                _ => return Err(FormatError::Malformed("`items` is not a list".into())),
+            },
+            recipe @ Value::Object(_) => vec![recipe],
+            _ => return Err(FormatError::Malformed("Not a Mealie recipe".into())),
+        };
+        recipes
+            .into_iter()
+            .map(|recipe| {
+                serde_json::from_value::<MealieRecipe>(recipe)
+                    .map(MealieRecipe::into_recipe)
+                    .map_err(malformed)
+            })
+            .collect()
+    }
+
+    /// A single recipe is written as an object, more as a list.
+    fn write(&self, recipes: &[Recipe]) -> Result<Vec<u8>, FormatError> {
+        let mut recipes = recipes.iter().map(MealieRecipe::from).collect::<Vec<_>>();
+        let json = match recipes.len() {
+            1 => serde_json::to_vec_pretty(&recipes.remove(0)),
+            _ => serde_json::to_vec_pretty(&recipes),
+        };
+        json.map_err(|e| FormatError::Unwritable(e.to_string()))
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredients(recipe: &Recipe) -> Vec<(uuid::Uuid, String, f64, String)> {
+        recipe
+            .ingredients()
+            .iter()
+            .map(|i| {
+                (
+                    i.uuid(),
+                    i.name().to_string(),
+                    i.amount(),
+                    i.unit().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn recipes_round_trip() {
+        let flour = Ingredient::new(uuid::Uuid::new_v4(), "flour".into(), 250.0, "g".into());
+        let eggs = Ingredient::new(uuid::Uuid::new_v4(), "eggs".into(), 3.0, "piece".into());
+        let salt = Ingredient::new(uuid::Uuid::new_v4(), "salt".into(), 1.0, "to taste".into());
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Crêpes".into(),
+            "".into(),
+            vec![
+                Step::new("Whisk the flour and eggs".into())
+                    .with_ingredients(vec![flour.uuid(), eggs.uuid()]),
+                Step::new("Season".into()).with_ingredients(vec![salt.uuid()]),
+            ],
+            vec![flour, eggs, salt],
+        )
+        .with_servings(4)
+        .with_times(Some(10), Some(75))
+        .with_tags(vec!["breakfast".into(), "french".into()]);
+
+        for recipes in [
+            vec![recipe.clone()],
+            vec![recipe.clone(), recipe.duplicate()],
+        ] {
+            let read = Mealie.read(&Mealie.write(&recipes).unwrap()).unwrap();
+            assert_eq!(read.len(), recipes.len());
+            let read = &read[0];
+            assert_eq!(read.uuid(), recipe.uuid());
+            assert_eq!(read.name(), "Crêpes");
+            assert_eq!(ingredients(read), ingredients(&recipe));
+            assert_eq!(read.steps(), recipe.steps());
+            assert_eq!(read.servings(), 4);
+            assert_eq!(read.prep_minutes(), Some(10));
+            assert_eq!(read.cook_minutes(), Some(75));
+            assert_eq!(read.tags(), recipe.tags());
+        }
+    }
+
+    #[test]
+    fn unparsed_ingredients_are_read_from_their_text() {
+        let json = r#"{"items": [{
+            "name": "Soup", "recipeYield": "6 bowls", "totalTime": "1 hour",
+            "recipeCategory": [{"name": "Dinner", "slug": "dinner"}],
+            "recipeIngredient": [
+                {"quantity": 0, "unit": null, "food": null, "note": "2 leeks, sliced"},
+                {"quantity": 2, "unit": {"name": "handful"}, "food": {"name": "croutons"}},
+                {"quantity": 1, "unit": {"name": "Liters"}, "food": {"name": "stock"}}
+            ],
+            "recipeInstructions": [{"text": "Simmer", "ingredientReferences": [
+                {"referenceId": "6b3f6d3e-3b5e-4a57-9f55-1c1b3c0c1b2a"}
+            ]}]
+        }]}"#;
+
+        let read = Mealie.read(json.as_bytes()).unwrap();
+
+        assert_eq!(read.len(), 1);
+        let soup = &read[0];
+        assert_eq!(soup.servings(), 6);
+        assert_eq!(soup.cook_minutes(), Some(60));
+        assert_eq!(soup.tags(), ["dinner"]);
+        let ingredients = ingredients(soup)
+            .into_iter()
+            .map(|(_, name, amount, unit)| (name, amount, unit))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ingredients,
+            [
+                ("leeks".to_string(), 2.0, "piece".to_string()),
+                ("handful croutons".to_string(), 2.0, "piece".to_string()),
+                ("stock".to_string(), 1.0, "l".to_string()),
+            ]
+        );
+        // The reference names no ingredient of the recipe.
+        assert!(soup.steps()[0].ingredients().is_empty());
+        assert!(Mealie.read(b"[1, 2]").is_err());
+    }
+}
@@ -1,2 +1,24 @@
+pub mod access;
+pub mod cooklang;
+pub mod density;
+pub mod difficulty;
+pub mod filter;
 pub mod ingredient;
+pub mod ingredient_line;
+pub mod mealie;
+pub mod pagination;
+pub mod paprika;
+pub mod quantity;
+pub mod rating;
 pub mod recipe;
+pub mod recipe_card;
+pub mod recipe_card_html;
+pub mod recipe_card_pdf;
+pub mod recipe_changes;
+pub mod recipe_format;
+pub mod recipe_match;
+pub mod schema_org;
+pub mod search;
+pub mod step;
+pub mod tag;
+pub mod unit;
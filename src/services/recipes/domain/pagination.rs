@@ -0,0 +1,89 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    Name,
+    CreatedAt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PageRequest {
+    page: u32,
+    page_size: u32,
+    sort_by: SortBy,
+    direction: SortDirection,
+}
+
+impl PageRequest {
+    pub fn new(page: u32, page_size: u32, sort_by: SortBy, direction: SortDirection) -> Self {
+        Self {
+            page,
+            page_size,
+            sort_by,
+            direction,
+        }
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    pub fn sort_by(&self) -> SortBy {
+        self.sort_by
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        self.direction
+    }
+
+    pub fn offset(&self) -> u64 {
+        u64::from(self.page) * u64::from(self.page_size)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    items: Vec<T>,
+    page: u32,
+    page_size: u32,
+    next_page: Option<u32>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, page: u32, page_size: u32, next_page: Option<u32>) -> Self {
+        Self {
+            items,
+            page,
+            page_size,
+            next_page,
+        }
+    }
+
+    pub fn items(&self) -> &[T] {
+        self.items.as_ref()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    pub fn next_page(&self) -> Option<u32> {
+        self.next_page
+    }
+}
@@ -0,0 +1,340 @@
+use std::io::{Cursor, Read, Write};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zip::{write::FileOptions, ZipArchive, ZipWriter};
+
+use super::{
+    difficulty::Difficulty,
+    ingredient::Ingredient,
+    ingredient_line::IngredientLine,
+    recipe::{Recipe, DEFAULT_SERVINGS},
+    recipe_format::{
+        first_number, ingredient_line, known_times, minutes_text, parse_minutes, FormatError,
+        RecipeFormat,
+    },
+    step::{join_steps, split_method},
+    tag::normalize_tag,
+};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Most a recipe may take once decompressed, its photo included.
+const MAX_RECIPE_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Most all recipes of an archive may take once decompressed.
+const MAX_ARCHIVE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// A recipe as Paprika stores it, every field but the name optional.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct PaprikaRecipe {
+    uid: String,
+    name: String,
+    /// One ingredient per line.
+    ingredients: String,
+    /// One step per line.
+    directions: String,
+    description: String,
+    notes: String,
+    servings: String,
+    prep_time: String,
+    cook_time: String,
+    total_time: String,
+    difficulty: String,
+    categories: Vec<String>,
+    source: String,
+    source_url: String,
+    image_url: String,
+    photo_data: Option<String>,
+    rating: u32,
+    hash: String,
+}
+
+impl PaprikaRecipe {
+    fn into_recipe(self) -> Recipe {
+        let ingredients = self
+            .ingredients
+            .lines()
+            .filter_map(IngredientLine::parse)
+            .map(IngredientLine::into_ingredient)
+            .collect::<Vec<Ingredient>>();
+        let mut tags: Vec<String> = vec![];
+        for tag in self.categories.iter().filter_map(|c| normalize_tag(c).ok()) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        let (prep_minutes, cook_minutes) = known_times(
+            parse_minutes(&self.prep_time),
+            parse_minutes(&self.cook_time),
+            parse_minutes(&self.total_time),
+        );
+        let image = match self.image_url.starts_with("http") {
+            true => self.image_url,
+            false => String::new(),
+        };
+        Recipe::new(
+            uuid::Uuid::parse_str(&self.uid).unwrap_or_else(|_| uuid::Uuid::new_v4()),
+            self.name.trim().to_string(),
+            image,
+            split_method(&self.directions),
+            ingredients,
+        )
+        .with_servings(
+            first_number(&self.servings)
+                .filter(|servings| *servings > 0)
+                .unwrap_or(DEFAULT_SERVINGS),
+        )
+        .with_times(prep_minutes, cook_minutes)
+        .with_difficulty(
+            self.difficulty
+                .trim()
+                .to_lowercase()
+                .parse::<Difficulty>()
+                .ok(),
+        )
+        .with_tags(tags)
+    }
+}
+
+impl From<&Recipe> for PaprikaRecipe {
+    fn from(value: &Recipe) -> Self {
+        let mut recipe = Self {
+            uid: value.uuid().to_string().to_uppercase(),
+            name: value.name().to_string(),
+            ingredients: value
+                .ingredients()
+                .iter()
+                .map(ingredient_line)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            directions: join_steps(value.steps()),
+            servings: value.servings().to_string(),
+            prep_time: value.prep_minutes().map(minutes_text).unwrap_or_default(),
+            cook_time: value.cook_minutes().map(minutes_text).unwrap_or_default(),
+            total_time: value.total_minutes().map(minutes_text).unwrap_or_default(),
+            difficulty: value
+                .difficulty()
+                .map(|difficulty| {
+                    let name = difficulty.as_str();
+                    name[..1].to_uppercase() + &name[1..]
+                })
+                .unwrap_or_default(),
+            categories: value.tags().to_vec(),
+            // Uploaded images are names on this server, meaningless elsewhere.
+            image_url: Some(value.image())
+                .filter(|image| image.starts_with("http://") || image.starts_with("https://"))
+                .unwrap_or_default()
+                .to_string(),
+            ..Default::default()
+        };
+        // Paprika tells changed recipes apart by this hash.
+        let content = serde_json::to_vec(&recipe).unwrap_or_default();
+        recipe.hash = format!("{:x}", Sha256::digest(content));
+        recipe
+    }
+}
+
+/// All `reader` gives, unless that is more than `limit` bytes: a small file
+/// may decompress into far more than anybody has memory for.
+fn read_at_most(reader: impl Read, limit: u64) -> Result<Vec<u8>, FormatError> {
+    let mut data = vec![];
+    reader
+        .take(limit + 1)
+        .read_to_end(&mut data)
+        .map_err(|e| FormatError::Malformed(e.to_string()))?;
+    if data.len() as u64 > limit {
+        return Err(FormatError::Malformed(
+            "too large once decompressed".to_string(),
+        ));
+    }
+    Ok(data)
+}
+
+/// The JSON of a gzipped recipe, at most `limit` bytes of it.
+fn gunzip(data: &[u8], limit: u64) -> Result<Vec<u8>, FormatError> {
+    read_at_most(GzDecoder::new(data), limit)
+}
+
+fn parse(json: &[u8]) -> Result<Recipe, FormatError> {
+    serde_json::from_slice::<PaprikaRecipe>(json)
+        .map(PaprikaRecipe::into_recipe)
+        .map_err(|e| FormatError::Malformed(e.to_string()))
+}
+
+/// `.paprikarecipes` archives: a zip of gzipped JSON recipes, one per
+/// entry. A single gzipped `.paprikarecipe` is read too.
+pub struct Paprika;
+
+impl RecipeFormat for Paprika {
+    fn read(&self, data: &[u8]) -> Result<Vec<Recipe>, FormatError> {
+        if data.starts_with(&GZIP_MAGIC) {
+            return Ok(vec![parse(&gunzip(data, MAX_RECIPE_BYTES)?)?]);
+        }
+        let mut archive = ZipArchive::new(Cursor::new(data))
+            .map_err(|e| FormatError::Malformed(e.to_string()))?;
+        let mut recipes = vec![];
+        let mut left = MAX_ARCHIVE_BYTES;
+        for index in 0..archive.len() {
+            let mut entry = archive
+                .by_index(index)
+                .map_err(|e| FormatError::Malformed(e.to_string()))?;
+            if entry.is_dir() {
+                continue;
+            }
+            // Entries may be deflated on top of being gzipped.
+            let limit = left.min(MAX_RECIPE_BYTES);
+            let json = gunzip(&read_at_most(&mut entry, limit)?, limit)?;
+            left -= json.len() as u64;
+            recipes.push(parse(&json)?);
+        }
+        Ok(recipes)
+    }
+
+    fn write(&self, recipes: &[Recipe]) -> Result<Vec<u8>, FormatError> {
+        let unwritable = |e: &dyn std::error::Error| FormatError::Unwritable(e.to_string());
+        let mut archive = ZipWriter::new(Cursor::new(vec![]));
+        // The entries are gzipped already.
+        let options = FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        for (index, recipe) in recipes.iter().enumerate() {
+            let json =
+                serde_json::to_vec(&PaprikaRecipe::from(recipe)).map_err(|e| unwritable(&e))?;
+            let mut gzip = GzEncoder::new(vec![], Compression::default());
+            gzip.write_all(&json).map_err(|e| unwritable(&e))?;
+            let compressed = gzip.finish().map_err(|e| unwritable(&e))?;
+            // Names only need to be unique within the archive.
+            archive
+                .start_file(
+                    format!("{} {}.paprikarecipe", index + 1, recipe.uuid()),
+                    options,
+                )
+                .map_err(|e| unwritable(&e))?;
+            archive.write_all(&compressed).map_err(|e| unwritable(&e))?;
+        }
+        Ok(archive.finish().map_err(|e| unwritable(&e))?.into_inner())
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/zip"
+    }
+
+    fn extension(&self) -> &'static str {
+        "paprikarecipes"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::services::recipes::domain::step::Step;
+
+    fn ingredients(recipe: &Recipe) -> Vec<(String, f64, String)> {
+        recipe
+            .ingredients()
+            .iter()
+            .map(|i| (i.name().to_string(), i.amount(), i.unit().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn archives_round_trip() {
+        let bread = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Bread".into(),
+            "https://example.com/bread.jpg".into(),
+            vec![
+                Step::new("Knead the dough".into()).with_duration(Some(Duration::from_secs(600))),
+                Step::new("Bake".into()),
+            ],
+            vec![
+                Ingredient::new(uuid::Uuid::new_v4(), "flour".into(), 500.0, "g".into()),
+                Ingredient::new(uuid::Uuid::new_v4(), "eggs".into(), 2.0, "piece".into()),
+                Ingredient::new(uuid::Uuid::new_v4(), "olive oil".into(), 1.5, "tbsp".into()),
+                Ingredient::new(uuid::Uuid::new_v4(), "salt".into(), 1.0, "to taste".into()),
+            ],
+        )
+        .with_servings(6)
+        .with_times(Some(20), Some(90))
+        .with_difficulty(Some(Difficulty::Medium))
+        .with_tags(vec!["baking".into()]);
+        let tea = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Tea".into(),
+            "a1b2c3.jpg".into(),
+            vec![],
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "tea".into(),
+                1.0,
+                "tsp".into(),
+            )],
+        );
+
+        assert_eq!(PaprikaRecipe::from(&tea).image_url, "");
+        let archive = Paprika.write(&[bread.clone(), tea.clone()]).unwrap();
+        let read = Paprika.read(&archive).unwrap();
+
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0].uuid(), bread.uuid());
+        assert_eq!(read[0].name(), "Bread");
+        assert_eq!(read[0].image(), bread.image());
+        assert_eq!(ingredients(&read[0]), ingredients(&bread));
+        assert_eq!(join_steps(read[0].steps()), join_steps(bread.steps()));
+        assert_eq!(read[0].servings(), 6);
+        assert_eq!(read[0].prep_minutes(), Some(20));
+        assert_eq!(read[0].cook_minutes(), Some(90));
+        assert_eq!(read[0].difficulty(), Some(Difficulty::Medium));
+        assert_eq!(read[0].tags(), ["baking"]);
+        assert_eq!(read[1].uuid(), tea.uuid());
+        assert_eq!(ingredients(&read[1]), ingredients(&tea));
+    }
+
+    #[test]
+    fn single_recipes_from_paprika_are_read() {
+        let json = r#"{
+            "uid": "not a uuid", "name": "Pancakes ", "servings": "4 servings",
+            "ingredients": "2 cups flour\n\n1 ½ cups milk", "directions": "Mix\nFry",
+            "total_time": "30 mins", "categories": ["Breakfast", "bad,tag"]
+        }"#;
+        let mut gzip = GzEncoder::new(vec![], Compression::default());
+        gzip.write_all(json.as_bytes()).unwrap();
+
+        let read = Paprika.read(&gzip.finish().unwrap()).unwrap();
+
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].name(), "Pancakes");
+        assert_eq!(read[0].servings(), 4);
+        assert_eq!(read[0].cook_minutes(), Some(30));
+        assert_eq!(read[0].tags(), ["breakfast"]);
+        assert_eq!(
+            ingredients(&read[0]),
+            [
+                ("flour".to_string(), 2.0, "cup".to_string()),
+                ("milk".to_string(), 1.5, "cup".to_string())
+            ]
+        );
+        assert!(Paprika.read(b"not an archive").is_err());
+    }
+
+    #[test]
+    fn recipes_too_large_once_decompressed_are_malformed() {
+        let mut gzip = GzEncoder::new(vec![], Compression::best());
+        gzip.write_all(br#"{"name": "Bomb", "notes": ""#).unwrap();
+        let padding = vec![b' '; 1024 * 1024];
+        for _ in 0..=MAX_RECIPE_BYTES / padding.len() as u64 {
+            gzip.write_all(&padding).unwrap();
+        }
+        gzip.write_all(br#""}"#).unwrap();
+        let bomb = gzip.finish().unwrap();
+        assert!(bomb.len() < 64 * 1024, "{}", bomb.len());
+
+        assert_eq!(
+            Paprika.read(&bomb).unwrap_err(),
+            FormatError::Malformed("too large once decompressed".to_string())
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use super::unit::{Unit, UnitKind, UnitSystem};
+
+/// Fractions cooks actually measure with, as (numerator, denominator).
+const FRACTIONS: [(u32, u32); 9] = [
+    (0, 1),
+    (1, 8),
+    (1, 4),
+    (1, 3),
+    (1, 2),
+    (2, 3),
+    (3, 4),
+    (7, 8),
+    (1, 1),
+];
+
+/// An amount paired with its unit, the unit being `None` when the stored
+/// spelling is not understood.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    amount: f64,
+    unit: Option<Unit>,
+}
+
+impl Quantity {
+    pub fn new(amount: f64, unit: Option<Unit>) -> Self {
+        Self { amount, unit }
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn unit(&self) -> Option<Unit> {
+        self.unit
+    }
+
+    pub fn scale(self, factor: f64) -> Self {
+        match self.unit {
+            Some(Unit::ToTaste) => self,
+            _ => Self {
+                amount: self.amount * factor,
+                ..self
+            },
+        }
+    }
+
+    /// Expresses the amount in `system`. Dry goods with a known `density`
+    /// (grams per millilitre) are weighed in metric and measured by the cup
+    /// in imperial; everything else keeps its kind. Amounts already in
+    /// `system`, counts and to-taste amounts are left alone. The result is
+    /// exact, `to_kitchen` rounds it.
+    pub fn convert(self, system: UnitSystem, density: Option<f64>) -> Self {
+        let Some(unit) = self.unit else {
+            return self;
+        };
+        let metric = match system {
+            UnitSystem::Original => return self,
+            UnitSystem::Metric => true,
+            UnitSystem::Imperial => false,
+        };
+        if unit.is_metric() == metric {
+            return self;
+        }
+        let target = match (unit.kind(), density, metric) {
+            (UnitKind::Volume, Some(_), true) => Unit::Gram,
+            (UnitKind::Volume, None, true) => Unit::Millilitre,
+            (UnitKind::Mass, Some(_), false) | (UnitKind::Volume, _, false) => Unit::Cup,
+            (UnitKind::Mass, None, false) => Unit::Ounce,
+            (UnitKind::Mass, _, true) => Unit::Gram,
+            (UnitKind::Count, _, _) | (UnitKind::ToTaste, _, _) => return self,
+        };
+        let base = self.amount * unit.base_factor();
+        let base = match (unit.kind(), target.kind(), density) {
+            (UnitKind::Volume, UnitKind::Mass, Some(density)) => base * density,
+            (UnitKind::Mass, UnitKind::Volume, Some(density)) => base / density,
+            _ => base,
+        };
+        Self {
+            amount: base / target.base_factor(),
+            unit: Some(target),
+        }
+    }
+
+    /// Moves the amount to the unit a cook would pick for it and rounds it
+    /// to something measurable: 1500 g becomes 1.5 kg, 0.333 cup 1/3 cup.
+    /// The measuring system of the original unit is kept.
+    pub fn to_kitchen(self) -> Self {
+        let Some(unit) = self.unit else {
+            return Self {
+                amount: round_decimal(self.amount),
+                ..self
+            };
+        };
+        let amount = self.amount;
+        let (amount, unit) = match unit {
+            Unit::Milligram | Unit::Gram | Unit::Kilogram => {
+                let grams = amount * unit.base_factor();
+                if grams >= 1000.0 {
+                    (round_decimal(grams / 1000.0), Unit::Kilogram)
+                } else if grams >= 1.0 {
+                    (round_metric(grams), Unit::Gram)
+                } else {
+                    (round_metric(grams * 1000.0), Unit::Milligram)
+                }
+            }
+            Unit::Millilitre | Unit::Centilitre | Unit::Decilitre | Unit::Litre => {
+                let millilitres = amount * unit.base_factor();
+                if millilitres >= 1000.0 {
+                    (round_decimal(millilitres / 1000.0), Unit::Litre)
+                } else {
+                    (round_metric(millilitres), Unit::Millilitre)
+                }
+            }
+            Unit::Ounce | Unit::Pound => {
+                let ounces = amount * unit.base_factor() / Unit::Ounce.base_factor();
+                if ounces >= 16.0 {
+                    (round_fraction(ounces / 16.0), Unit::Pound)
+                } else {
+                    (round_fraction(ounces), Unit::Ounce)
+                }
+            }
+            Unit::Teaspoon
+            | Unit::Tablespoon
+            | Unit::Cup
+            | Unit::FluidOunce
+            | Unit::Pint
+            | Unit::Quart
+            | Unit::Gallon => {
+                let teaspoons = amount * unit.base_factor() / Unit::Teaspoon.base_factor();
+                if teaspoons < 3.0 {
+                    (round_fraction(teaspoons), Unit::Teaspoon)
+                } else if teaspoons < 12.0 {
+                    (round_fraction(teaspoons / 3.0), Unit::Tablespoon)
+                } else if teaspoons < 768.0 {
+                    (round_fraction(teaspoons / 48.0), Unit::Cup)
+                } else {
+                    (round_fraction(teaspoons / 768.0), Unit::Gallon)
+                }
+            }
+            Unit::ToTaste => (amount, unit),
+            _ => (round_fraction(amount), unit),
+        };
+        Self {
+            amount,
+            unit: Some(unit),
+        }
+    }
+
+    /// Human readable form, fractions for customary and count units and
+    /// decimals for metric ones: "1 1/2 cup", "1.5 kg".
+    pub fn display(&self) -> String {
+        let amount = match self.unit {
+            Some(Unit::ToTaste) => return Unit::ToTaste.symbol().to_string(),
+            Some(unit) if !unit.is_metric() => format_fraction(self.amount),
+            _ => format_decimal(self.amount),
+        };
+        match self.unit {
+            Some(Unit::Piece) => amount,
+            Some(unit) => format!("{} {}", amount, unit.symbol()),
+            None => amount,
+        }
+    }
+}
+
+fn round_to(value: f64, step: f64) -> f64 {
+    (value / step).round() * step
+}
+
+/// Two decimals at most.
+fn round_decimal(value: f64) -> f64 {
+    round_to(value, 0.01)
+}
+
+/// Nobody weighs 437 g: larger amounts go to the nearest 5, small ones keep
+/// a decimal.
+fn round_metric(value: f64) -> f64 {
+    if value >= 100.0 {
+        round_to(value, 5.0)
+    } else if value >= 10.0 {
+        value.round()
+    } else {
+        round_to(value, 0.1)
+    }
+}
+
+fn nearest_fraction(value: f64) -> (f64, (u32, u32)) {
+    let whole = value.trunc();
+    let rest = value - whole;
+    let fraction = FRACTIONS
+        .iter()
+        .copied()
+        .min_by(|a, b| {
+            let a = (rest - f64::from(a.0) / f64::from(a.1)).abs();
+            let b = (rest - f64::from(b.0) / f64::from(b.1)).abs();
+            a.total_cmp(&b)
+        })
+        .unwrap_or((0, 1));
+    (whole, fraction)
+}
+
+/// Snaps to the nearest measurable fraction, never down to nothing.
+fn round_fraction(value: f64) -> f64 {
+    if value <= 0.0 {
+        return 0.0;
+    }
+    let (whole, (numerator, denominator)) = nearest_fraction(value);
+    let rounded = whole + f64::from(numerator) / f64::from(denominator);
+    if rounded == 0.0 {
+        0.125
+    } else {
+        round_to(rounded, 0.001)
+    }
+}
+
+fn format_decimal(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn format_fraction(value: f64) -> String {
+    let (whole, (numerator, denominator)) = nearest_fraction(value);
+    let (whole, numerator) = if numerator == denominator {
+        (whole + 1.0, 0)
+    } else {
+        (whole, numerator)
+    };
+    match (whole as u64, numerator) {
+        (0, 0) => "0".to_string(),
+        (whole, 0) => whole.to_string(),
+        (0, numerator) => format!("{}/{}", numerator, denominator),
+        (whole, numerator) => format!("{} {}/{}", whole, numerator, denominator),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kitchen(amount: f64, unit: Unit) -> Quantity {
+        Quantity::new(amount, Some(unit)).to_kitchen()
+    }
+
+    #[test]
+    fn metric_amounts_move_to_the_larger_unit() {
+        assert_eq!(
+            kitchen(1500.0, Unit::Gram),
+            Quantity::new(1.5, Some(Unit::Kilogram))
+        );
+        assert_eq!(
+            kitchen(0.25, Unit::Litre),
+            Quantity::new(250.0, Some(Unit::Millilitre))
+        );
+        assert_eq!(
+            kitchen(437.0, Unit::Gram),
+            Quantity::new(435.0, Some(Unit::Gram))
+        );
+        assert_eq!(kitchen(1500.0, Unit::Gram).display(), "1.5 kg");
+    }
+
+    #[test]
+    fn customary_amounts_snap_to_fractions() {
+        let third = kitchen(0.333, Unit::Cup);
+        assert_eq!(third.unit(), Some(Unit::Cup));
+        assert_eq!(third.display(), "1/3 cup");
+        assert_eq!(kitchen(6.0, Unit::Teaspoon).display(), "2 tbsp");
+        assert_eq!(kitchen(24.0, Unit::Ounce).display(), "1 1/2 lb");
+        assert_eq!(kitchen(2.5, Unit::Clove).display(), "2 1/2 clove");
+    }
+
+    #[test]
+    fn conversion_uses_density_for_dry_goods() {
+        let flour = Quantity::new(1.0, Some(Unit::Cup));
+        let grams = flour.convert(UnitSystem::Metric, Some(0.53)).to_kitchen();
+        assert_eq!(grams, Quantity::new(125.0, Some(Unit::Gram)));
+        let back = grams.convert(UnitSystem::Imperial, Some(0.53)).to_kitchen();
+        assert_eq!(back.display(), "1 cup");
+    }
+
+    #[test]
+    fn conversion_without_density_keeps_the_kind() {
+        let milk = Quantity::new(2.0, Some(Unit::Cup)).convert(UnitSystem::Metric, None);
+        assert_eq!(milk.to_kitchen().display(), "475 ml");
+        let beef = Quantity::new(500.0, Some(Unit::Gram)).convert(UnitSystem::Imperial, None);
+        assert_eq!(beef.to_kitchen().display(), "1 1/8 lb");
+        let grams = Quantity::new(500.0, Some(Unit::Gram));
+        assert_eq!(grams.convert(UnitSystem::Metric, None), grams);
+    }
+
+    #[test]
+    fn to_taste_is_not_scaled() {
+        let quantity = Quantity::new(1.0, Some(Unit::ToTaste)).scale(3.0);
+        assert_eq!(quantity.amount(), 1.0);
+        assert_eq!(quantity.display(), "to taste");
+    }
+}
@@ -0,0 +1,21 @@
+/// Star ratings a recipe received, kept as running totals by storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RatingSummary {
+    sum: u32,
+    count: u32,
+}
+
+impl RatingSummary {
+    pub fn new(sum: u32, count: u32) -> Self {
+        Self { sum, count }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Mean of the ratings, `None` until the recipe is reviewed.
+    pub fn average(&self) -> Option<f64> {
+        (self.count > 0).then(|| f64::from(self.sum) / f64::from(self.count))
+    }
+}
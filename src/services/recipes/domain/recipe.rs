@@ -1,11 +1,32 @@
-use super::ingredient::Ingredient;
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use super::{
+    access::RecipeAccess,
+    difficulty::Difficulty,
+    ingredient::Ingredient,
+    quantity::Quantity,
+    rating::RatingSummary,
+    step::{normalize_steps, Step, StepError},
+    tag::{normalize_tags, TagError},
+    unit::{UnitError, UnitSystem},
+};
+
+/// Portions assumed for recipes that never said how many they make.
+pub const DEFAULT_SERVINGS: u32 = 4;
 
 #[derive(Debug, Clone)]
 pub struct Recipe {
     uuid: uuid::Uuid,
     name: String,
     image: String,
-    method: String,
+    steps: Vec<Step>,
+    servings: u32,
+    prep_minutes: Option<u32>,
+    cook_minutes: Option<u32>,
+    difficulty: Option<Difficulty>,
+    access: RecipeAccess,
+    rating: RatingSummary,
+    tags: Vec<String>,
     ingredients: Vec<Ingredient>,
 }
 
@@ -14,18 +35,53 @@ impl Recipe {
         id: uuid::Uuid,
         name: String,
         image: String,
-        method: String,
+        steps: Vec<Step>,
         ingredients: Vec<Ingredient>,
     ) -> Self {
         Self {
             uuid: id,
             name,
             image,
-            method,
+            steps,
+            servings: DEFAULT_SERVINGS,
+            prep_minutes: None,
+            cook_minutes: None,
+            difficulty: None,
+            access: RecipeAccess::new(None, Default::default()),
+            rating: RatingSummary::default(),
+            tags: vec![],
             ingredients,
         }
     }
 
+    pub fn with_servings(self, servings: u32) -> Self {
+        Self { servings, ..self }
+    }
+
+    pub fn with_times(self, prep_minutes: Option<u32>, cook_minutes: Option<u32>) -> Self {
+        Self {
+            prep_minutes,
+            cook_minutes,
+            ..self
+        }
+    }
+
+    pub fn with_difficulty(self, difficulty: Option<Difficulty>) -> Self {
+        Self { difficulty, ..self }
+    }
+
+    pub fn with_access(self, access: RecipeAccess) -> Self {
+        Self { access, ..self }
+    }
+
+    pub fn with_rating(self, rating: RatingSummary) -> Self {
+        Self { rating, ..self }
+    }
+
+    pub fn with_tags(self, tags: Vec<String>) -> Self {
+        Self { tags, ..self }
+    }
+
     pub fn uuid(&self) -> uuid::Uuid {
         self.uuid
     }
@@ -38,11 +94,243 @@ impl Recipe {
         self.image.as_ref()
     }
 
-    pub fn method(&self) -> &str {
-        self.method.as_ref()
+    /// The method, in order.
+    pub fn steps(&self) -> &[Step] {
+        self.steps.as_ref()
+    }
+
+    pub fn servings(&self) -> u32 {
+        self.servings
+    }
+
+    pub fn prep_minutes(&self) -> Option<u32> {
+        self.prep_minutes
+    }
+
+    pub fn cook_minutes(&self) -> Option<u32> {
+        self.cook_minutes
+    }
+
+    /// Preparation and cooking time together, unknown when both are.
+    pub fn total_minutes(&self) -> Option<u32> {
+        match (self.prep_minutes, self.cook_minutes) {
+            (None, None) => None,
+            (prep, cook) => Some(prep.unwrap_or(0).saturating_add(cook.unwrap_or(0))),
+        }
+    }
+
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty
+    }
+
+    /// Owner and visibility of the recipe.
+    pub fn access(&self) -> RecipeAccess {
+        self.access
+    }
+
+    /// Average star rating and number of reviews.
+    pub fn rating(&self) -> RatingSummary {
+        self.rating
+    }
+
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_ref()
     }
 
     pub fn ingredients(&self) -> &[Ingredient] {
         self.ingredients.as_ref()
     }
+
+    /// Normalizes every ingredient unit, reporting the position of the
+    /// first ingredient whose unit is not recognised.
+    pub fn normalize_units(self) -> Result<Self, (usize, UnitError)> {
+        let ingredients = self
+            .ingredients
+            .into_iter()
+            .enumerate()
+            .map(|(index, ingredient)| ingredient.normalized().map_err(|e| (index, e)))
+            .collect::<Result<Vec<Ingredient>, _>>()?;
+        Ok(Self {
+            ingredients,
+            ..self
+        })
+    }
+
+    /// Normalizes the tags and drops duplicates, reporting the position of
+    /// the first invalid tag.
+    pub fn normalize_tags(self) -> Result<Self, (usize, TagError)> {
+        let tags = normalize_tags(&self.tags)?;
+        Ok(Self { tags, ..self })
+    }
+
+    /// Normalizes every step, reporting the position of the first invalid
+    /// one.
+    pub fn normalize_steps(self) -> Result<Self, (usize, StepError)> {
+        let steps = normalize_steps(self.steps)?;
+        Ok(Self { steps, ..self })
+    }
+
+    /// The checks every stored recipe passes, whether created or imported,
+    /// with its units, tags and steps normalized.
+    pub fn validated(self) -> Result<Self, RecipeError> {
+        if self.ingredients.is_empty() {
+            return Err(RecipeError::NoIngredients);
+        }
+        if self.servings == 0 {
+            return Err(RecipeError::InvalidServings);
+        }
+        self.normalize_units()
+            .map_err(|(index, error)| RecipeError::InvalidUnit { index, error })?
+            .normalize_tags()
+            .map_err(|(index, error)| RecipeError::InvalidTag { index, error })?
+            .normalize_steps()
+            .map_err(|(index, error)| RecipeError::InvalidStep { index, error })
+    }
+
+    fn map_quantities<F>(self, f: F) -> Self
+    where
+        F: Fn(&Ingredient) -> Quantity,
+    {
+        let ingredients = self
+            .ingredients
+            .into_iter()
+            .map(|ingredient| {
+                let quantity = f(&ingredient);
+                ingredient.with_quantity(quantity)
+            })
+            .collect();
+        Self {
+            ingredients,
+            ..self
+        }
+    }
+
+    /// Scales every ingredient to `servings` portions.
+    pub fn scale(self, servings: u32) -> Self {
+        let factor = f64::from(servings) / f64::from(self.servings.max(1));
+        Self {
+            servings,
+            ..self.map_quantities(|ingredient| ingredient.quantity().scale(factor))
+        }
+    }
+
+    /// Expresses every ingredient in `system`.
+    pub fn convert_units(self, system: UnitSystem) -> Self {
+        self.map_quantities(|ingredient| ingredient.quantity_in(system))
+    }
+
+    /// Rounds every ingredient to amounts that can be measured in a kitchen.
+    pub fn to_kitchen(self) -> Self {
+        self.map_quantities(|ingredient| ingredient.quantity().to_kitchen())
+    }
+
+    /// A copy under a new uuid, with new uuids for its ingredients too so the
+    /// copy shares nothing with the original. Steps follow the ingredients,
+    /// reviews stay with the original.
+    pub fn duplicate(&self) -> Self {
+        let uuids = self
+            .ingredients
+            .iter()
+            .map(|ingredient| (ingredient.uuid(), uuid::Uuid::new_v4()))
+            .collect::<HashMap<uuid::Uuid, uuid::Uuid>>();
+        let ingredients = self
+            .ingredients
+            .iter()
+            .map(|ingredient| {
+                Ingredient::new(
+                    uuids[&ingredient.uuid()],
+                    ingredient.name().to_string(),
+                    ingredient.amount(),
+                    ingredient.unit().to_string(),
+                )
+            })
+            .collect();
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| {
+                let ingredients = step
+                    .ingredients()
+                    .iter()
+                    .map(|uuid| uuids.get(uuid).copied().unwrap_or(*uuid))
+                    .collect();
+                step.clone().with_ingredients(ingredients)
+            })
+            .collect();
+        Self {
+            uuid: uuid::Uuid::new_v4(),
+            ingredients,
+            steps,
+            rating: RatingSummary::default(),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RecipeError {
+    NoIngredients,
+    InvalidServings,
+    InvalidUnit { index: usize, error: UnitError },
+    InvalidTag { index: usize, error: TagError },
+    InvalidStep { index: usize, error: StepError },
+}
+
+impl Display for RecipeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecipeError::NoIngredients => f.write_str("A recipe must have ingredients"),
+            RecipeError::InvalidServings => f.write_str("A recipe must serve at least one person"),
+            RecipeError::InvalidUnit { index, error } => {
+                write!(f, "{} for ingredient {}", error, index)
+            }
+            RecipeError::InvalidTag { index, error } => {
+                write!(f, "{} at position {}", error, index)
+            }
+            RecipeError::InvalidStep { index, error } => {
+                write!(f, "Invalid step {}: {}", index, error)
+            }
+        }
+    }
+}
+
+impl Error for RecipeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_time_is_unknown_only_without_any_time() {
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Tea".into(),
+            "".into(),
+            vec![],
+            vec![],
+        );
+        assert_eq!(recipe.total_minutes(), None);
+        let recipe = recipe.with_times(Some(10), None);
+        assert_eq!(recipe.total_minutes(), Some(10));
+        let recipe = recipe.with_times(Some(10), Some(25));
+        assert_eq!(recipe.total_minutes(), Some(35));
+    }
+
+    #[test]
+    fn duplicates_share_no_uuid_with_the_original() {
+        let flour = Ingredient::new(uuid::Uuid::new_v4(), "flour".into(), 1.0, "kg".into());
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Bread".into(),
+            "".into(),
+            vec![Step::new("Knead".into()).with_ingredients(vec![flour.uuid()])],
+            vec![flour.clone()],
+        );
+        let copy = recipe.duplicate();
+        assert_ne!(copy.uuid(), recipe.uuid());
+        assert_eq!(copy.name(), "Bread");
+        let ingredient = copy.ingredients()[0].uuid();
+        assert_ne!(ingredient, flour.uuid());
+        assert_eq!(copy.steps()[0].ingredients(), &[ingredient]);
+    }
 }
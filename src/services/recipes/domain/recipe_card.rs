@@ -0,0 +1,200 @@
+use std::str::FromStr;
+
+use super::{recipe::Recipe, recipe_format::minutes_text, unit::Unit};
+
+/// Sheets a card is laid out for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PaperSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PaperSize {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaperSize::A4 => "a4",
+            PaperSize::Letter => "letter",
+        }
+    }
+
+    /// Width and height in points.
+    pub fn dimensions(&self) -> (f32, f32) {
+        match self {
+            PaperSize::A4 => (595.28, 841.89),
+            PaperSize::Letter => (612.0, 792.0),
+        }
+    }
+}
+
+impl FromStr for PaperSize {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a4" => Ok(PaperSize::A4),
+            "letter" => Ok(PaperSize::Letter),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What a card is rendered as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardFormat {
+    Html,
+    Pdf,
+}
+
+impl CardFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            CardFormat::Html => "text/html; charset=utf-8",
+            CardFormat::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CardFormat::Html => "html",
+            CardFormat::Pdf => "pdf",
+        }
+    }
+}
+
+/// A recipe as printed: the text of every part of the card, whatever it is
+/// rendered as.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeCard {
+    title: String,
+    details: Vec<String>,
+    tags: Vec<String>,
+    ingredients: Vec<String>,
+    steps: Vec<String>,
+    paper: PaperSize,
+}
+
+impl RecipeCard {
+    /// Amounts are printed as they are in `recipe`, scale and convert it
+    /// beforehand.
+    pub fn new(recipe: &Recipe, paper: PaperSize) -> Self {
+        let mut details = vec![match recipe.servings() {
+            1 => "1 serving".to_string(),
+            servings => format!("{} servings", servings),
+        }];
+        if let Some(minutes) = recipe.prep_minutes() {
+            details.push(format!("Prep {}", minutes_text(minutes)));
+        }
+        if let Some(minutes) = recipe.cook_minutes() {
+            details.push(format!("Cook {}", minutes_text(minutes)));
+        }
+        if let Some(difficulty) = recipe.difficulty() {
+            let name = difficulty.as_str();
+            details.push(name[..1].to_uppercase() + &name[1..]);
+        }
+        let ingredients = recipe
+            .ingredients()
+            .iter()
+            .map(|ingredient| match ingredient.parsed_unit() {
+                Ok(Unit::ToTaste) => format!("{}, to taste", ingredient.name()),
+                Ok(_) => format!("{} {}", ingredient.quantity().display(), ingredient.name()),
+                // Units this instance does not know are printed as written.
+                Err(_) => format!(
+                    "{} {} {}",
+                    ingredient.quantity().display(),
+                    ingredient.unit(),
+                    ingredient.name()
+                ),
+            })
+            .collect();
+        Self {
+            title: recipe.name().to_string(),
+            details,
+            tags: recipe.tags().to_vec(),
+            ingredients,
+            steps: recipe
+                .steps()
+                .iter()
+                .map(|step| step.text().to_string())
+                .collect(),
+            paper,
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        self.title.as_ref()
+    }
+
+    /// Servings, times and difficulty, as "4 servings", "Prep 10 minutes".
+    pub fn details(&self) -> &[String] {
+        self.details.as_ref()
+    }
+
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_ref()
+    }
+
+    /// One line per ingredient, "200 g flour".
+    pub fn ingredients(&self) -> &[String] {
+        self.ingredients.as_ref()
+    }
+
+    pub fn steps(&self) -> &[String] {
+        self.steps.as_ref()
+    }
+
+    pub fn paper(&self) -> PaperSize {
+        self.paper
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::recipes::domain::{
+        difficulty::Difficulty, ingredient::Ingredient, step::Step,
+    };
+
+    #[test]
+    fn cards_print_what_the_recipe_says() {
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Pancakes".into(),
+            "".into(),
+            vec![Step::new("Whisk".into()), Step::new("Fry".into())],
+            vec![
+                Ingredient::new(uuid::Uuid::new_v4(), "flour".into(), 1.5, "cup".into()),
+                Ingredient::new(uuid::Uuid::new_v4(), "eggs".into(), 2.0, "piece".into()),
+                Ingredient::new(uuid::Uuid::new_v4(), "salt".into(), 1.0, "to taste".into()),
+                Ingredient::new(uuid::Uuid::new_v4(), "herbs".into(), 1.0, "handful".into()),
+            ],
+        )
+        .with_servings(4)
+        .with_times(Some(10), Some(75))
+        .with_difficulty(Some(Difficulty::Easy));
+
+        let card = RecipeCard::new(&recipe, PaperSize::Letter);
+
+        assert_eq!(card.title(), "Pancakes");
+        assert_eq!(
+            card.details(),
+            [
+                "4 servings",
+                "Prep 10 minutes",
+                "Cook 1 hour 15 minutes",
+                "Easy"
+            ]
+        );
+        assert_eq!(
+            card.ingredients(),
+            [
+                "1 1/2 cup flour",
+                "2 eggs",
+                "salt, to taste",
+                "1 handful herbs"
+            ]
+        );
+        assert_eq!(card.steps(), ["Whisk", "Fry"]);
+        assert_eq!(card.paper(), PaperSize::Letter);
+    }
+}
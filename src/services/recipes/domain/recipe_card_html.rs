@@ -0,0 +1,58 @@
+use askama::Template;
+
+use super::recipe_card::{PaperSize, RecipeCard};
+
+#[derive(Template)]
+#[template(path = "recipe_card.html")]
+struct RecipeCardPage<'a> {
+    card: &'a RecipeCard,
+    /// The `@page` size, as CSS names it.
+    page_size: &'static str,
+    image: Option<&'a str>,
+}
+
+/// `card` as a page of its own, printed on the paper of the card. `image` is
+/// the address the browser loads the image from.
+pub fn render_html(card: &RecipeCard, image: Option<&str>) -> Result<String, askama::Error> {
+    RecipeCardPage {
+        card,
+        page_size: match card.paper() {
+            PaperSize::A4 => "A4",
+            PaperSize::Letter => "letter",
+        },
+        image,
+    }
+    .render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::recipes::domain::{ingredient::Ingredient, recipe::Recipe, step::Step};
+
+    #[test]
+    fn pages_escape_the_recipe() {
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Fish & <b>chips</b>".into(),
+            "".into(),
+            vec![Step::new("Fry the fish".into())],
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "cod".into(),
+                500.0,
+                "g".into(),
+            )],
+        );
+        let card = RecipeCard::new(&recipe, PaperSize::Letter);
+
+        let page = render_html(&card, Some("/api/v1/recipes/1/image?size=800")).unwrap();
+
+        assert!(page.contains("<h1>Fish &amp; &lt;b&gt;chips&lt;/b&gt;</h1>"));
+        assert!(page.contains("size: letter;"));
+        assert!(page.contains("<li>500 g cod</li>"));
+        assert!(page.contains("<li>Fry the fish</li>"));
+        assert!(page.contains("<img src=\"/api/v1/recipes/1/image?size=800\""));
+        assert!(!render_html(&card, None).unwrap().contains("<img"));
+    }
+}
@@ -0,0 +1,413 @@
+use std::io::Write;
+
+use flate2::{write::ZlibEncoder, Compression};
+use image::imageops::FilterType;
+use pdf_writer::{Content, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+
+use super::recipe_card::RecipeCard;
+
+const MARGIN: f32 = 56.0;
+/// Where bulleted and numbered text starts, right of its marker.
+const INDENT: f32 = 18.0;
+const LEADING: f32 = 1.35;
+const MAX_IMAGE_HEIGHT: f32 = 220.0;
+/// Larger images are shrunk, a card prints them a few inches wide at most.
+const MAX_IMAGE_PIXELS: u32 = 1200;
+
+/// Widths of the printable ASCII characters in thousandths of the font size,
+/// from the metrics of the standard fonts every reader has.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn name(&self) -> Name<'static> {
+        match self {
+            Font::Regular => Name(b"F1"),
+            Font::Bold => Name(b"F2"),
+        }
+    }
+
+    /// Width of `text`, encoded by `win_ansi`, at `size`.
+    fn width(&self, text: &[u8], size: f32) -> f32 {
+        let widths = match self {
+            Font::Regular => &HELVETICA_WIDTHS,
+            Font::Bold => &HELVETICA_BOLD_WIDTHS,
+        };
+        let units: u32 = text
+            .iter()
+            .map(|byte| match byte {
+                32..=126 => u32::from(widths[usize::from(byte - 32)]),
+                _ => 556,
+            })
+            .sum();
+        units as f32 * size / 1000.0
+    }
+}
+
+/// `text` in the encoding of the standard fonts, characters it lacks as "?".
+fn win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' => c as u8,
+            '\u{a0}'..='\u{ff}' => c as u32 as u8,
+            '€' => 0x80,
+            '‚' => 0x82,
+            'ƒ' => 0x83,
+            '„' => 0x84,
+            '…' => 0x85,
+            '†' => 0x86,
+            '‡' => 0x87,
+            'ˆ' => 0x88,
+            '‰' => 0x89,
+            'Š' => 0x8a,
+            '‹' => 0x8b,
+            'Œ' => 0x8c,
+            'Ž' => 0x8e,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '˜' => 0x98,
+            '™' => 0x99,
+            'š' => 0x9a,
+            '›' => 0x9b,
+            'œ' => 0x9c,
+            'ž' => 0x9e,
+            'Ÿ' => 0x9f,
+            c if c.is_whitespace() => b' ',
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Greedy line breaking of `text` into lines at most `width` wide. Words
+/// longer than a line are cut.
+fn wrap(text: &[u8], font: Font, size: f32, width: f32) -> Vec<Vec<u8>> {
+    let mut lines = vec![];
+    let mut line: Vec<u8> = vec![];
+    for word in text.split(|byte| *byte == b' ').filter(|w| !w.is_empty()) {
+        let mut candidate = line.clone();
+        if !candidate.is_empty() {
+            candidate.push(b' ');
+        }
+        candidate.extend_from_slice(word);
+        if font.width(&candidate, size) <= width {
+            line = candidate;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        for byte in word {
+            line.push(*byte);
+            if line.len() > 1 && font.width(&line, size) > width {
+                line.pop();
+                lines.push(std::mem::replace(&mut line, vec![*byte]));
+            }
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// An image ready to be embedded: RGB samples, zlib compressed.
+#[derive(Debug, Clone)]
+pub struct CardImage {
+    width: u32,
+    height: u32,
+    samples: Vec<u8>,
+}
+
+impl CardImage {
+    /// `None` for data that is not a JPEG, PNG, WebP or GIF image.
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let mut image = image::load_from_memory(bytes).ok()?;
+        if image.width().max(image.height()) > MAX_IMAGE_PIXELS {
+            image = image.resize(MAX_IMAGE_PIXELS, MAX_IMAGE_PIXELS, FilterType::Triangle);
+        }
+        let image = image.to_rgb8();
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(image.as_raw()).ok()?;
+        Some(Self {
+            width: image.width(),
+            height: image.height(),
+            samples: encoder.finish().ok()?,
+        })
+    }
+}
+
+/// Content of the pages, written top to bottom.
+struct Layout {
+    width: f32,
+    height: f32,
+    pages: Vec<Content>,
+    content: Content,
+    /// Baseline of the last line written.
+    y: f32,
+}
+
+impl Layout {
+    fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            pages: vec![],
+            content: Content::new(),
+            y: height - MARGIN,
+        }
+    }
+
+    /// Starts a new page unless `needed` points are left on this one.
+    fn reserve(&mut self, needed: f32) {
+        if self.y - needed < MARGIN && self.y < self.height - MARGIN {
+            let page = std::mem::replace(&mut self.content, Content::new());
+            self.pages.push(page);
+            self.y = self.height - MARGIN;
+        }
+    }
+
+    fn gap(&mut self, points: f32) {
+        self.y -= points;
+    }
+
+    fn text(&mut self, font: Font, size: f32, gray: f32, x: f32, text: &[u8]) {
+        self.content
+            .begin_text()
+            .set_fill_gray(gray)
+            .set_font(font.name(), size)
+            .next_line(x, self.y)
+            .show(Str(text))
+            .end_text();
+    }
+
+    /// `text` wrapped within the margins, `marker` left of its first line.
+    fn paragraph(&mut self, font: Font, size: f32, gray: f32, marker: Option<&str>, text: &str) {
+        let x = match marker {
+            Some(_) => MARGIN + INDENT,
+            None => MARGIN,
+        };
+        let lines = wrap(&win_ansi(text), font, size, self.width - MARGIN - x);
+        for (index, line) in lines.iter().enumerate() {
+            self.reserve(size * LEADING);
+            self.gap(size * LEADING);
+            if let (0, Some(marker)) = (index, marker) {
+                self.text(font, size, gray, MARGIN, &win_ansi(marker));
+            }
+            self.text(font, size, gray, x, line);
+        }
+    }
+
+    /// A title over a rule, kept on the page of the lines that follow it.
+    fn heading(&mut self, text: &str) {
+        self.reserve(60.0);
+        self.gap(14.0);
+        self.paragraph(Font::Bold, 14.0, 0.0, None, text);
+        self.gap(6.0);
+        self.content
+            .set_stroke_gray(0.75)
+            .set_line_width(0.5)
+            .move_to(MARGIN, self.y)
+            .line_to(self.width - MARGIN, self.y)
+            .stroke();
+        self.gap(2.0);
+    }
+
+    /// The image across the width of the text at most, its ratio kept.
+    fn image(&mut self, image: &CardImage) {
+        let scale = ((self.width - 2.0 * MARGIN) / image.width as f32)
+            .min(MAX_IMAGE_HEIGHT / image.height as f32);
+        let (width, height) = (image.width as f32 * scale, image.height as f32 * scale);
+        self.reserve(height + 12.0);
+        self.gap(height + 12.0);
+        self.content
+            .save_state()
+            .transform([width, 0.0, 0.0, height, MARGIN, self.y])
+            .x_object(Name(b"Im1"))
+            .restore_state();
+    }
+
+    fn finish(mut self) -> Vec<Content> {
+        self.pages.push(self.content);
+        self.pages
+    }
+}
+
+/// `card` as a PDF document, with the standard Helvetica fonts so nothing
+/// needs embedding but the image.
+pub fn render_pdf(card: &RecipeCard, image: Option<&CardImage>) -> Vec<u8> {
+    let (width, height) = card.paper().dimensions();
+    let mut layout = Layout::new(width, height);
+    layout.paragraph(Font::Bold, 22.0, 0.0, None, card.title());
+    layout.gap(2.0);
+    layout.paragraph(Font::Regular, 10.0, 0.4, None, &card.details().join(" · "));
+    if !card.tags().is_empty() {
+        layout.paragraph(Font::Regular, 9.0, 0.55, None, &card.tags().join(", "));
+    }
+    if let Some(image) = image {
+        layout.image(image);
+    }
+    layout.heading("Ingredients");
+    for ingredient in card.ingredients() {
+        layout.paragraph(Font::Regular, 11.0, 0.0, Some("•"), ingredient);
+    }
+    if !card.steps().is_empty() {
+        layout.heading("Method");
+        for (index, step) in card.steps().iter().enumerate() {
+            layout.paragraph(
+                Font::Regular,
+                11.0,
+                0.0,
+                Some(&format!("{}.", index + 1)),
+                step,
+            );
+            layout.gap(4.0);
+        }
+    }
+    let pages = layout.finish();
+
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let regular_id = Ref::new(3);
+    let bold_id = Ref::new(4);
+    let image_id = Ref::new(5);
+    let info_id = Ref::new(6);
+    let page_ids = (0..pages.len())
+        .map(|index| Ref::new(10 + 2 * index as i32))
+        .collect::<Vec<Ref>>();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().copied())
+        .count(pages.len() as i32);
+    for (font_id, base_font) in [(regular_id, "Helvetica"), (bold_id, "Helvetica-Bold")] {
+        pdf.type1_font(font_id)
+            .base_font(Name(base_font.as_bytes()))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+    }
+    if let Some(image) = image {
+        let mut xobject = pdf.image_xobject(image_id, &image.samples);
+        xobject.filter(Filter::FlateDecode);
+        xobject.width(image.width as i32);
+        xobject.height(image.height as i32);
+        xobject.color_space().device_rgb();
+        xobject.bits_per_component(8);
+        xobject.finish();
+    }
+    pdf.document_info(info_id)
+        .title(TextStr(card.title()))
+        .producer(TextStr("yaiss"));
+    for (page_id, content) in page_ids.into_iter().zip(pages) {
+        let content_id = Ref::new(page_id.get() + 1);
+        let mut page = pdf.page(page_id);
+        page.parent(page_tree_id)
+            .media_box(Rect::new(0.0, 0.0, width, height))
+            .contents(content_id);
+        let mut resources = page.resources();
+        resources
+            .fonts()
+            .pair(Font::Regular.name(), regular_id)
+            .pair(Font::Bold.name(), bold_id);
+        if image.is_some() {
+            resources.x_objects().pair(Name(b"Im1"), image_id);
+        }
+        resources.finish();
+        page.finish();
+        pdf.stream(content_id, &content.finish());
+    }
+    pdf.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::recipes::domain::{
+        ingredient::Ingredient, recipe::Recipe, recipe_card::PaperSize, step::Step,
+    };
+
+    fn count(haystack: &[u8], needle: &[u8]) -> usize {
+        haystack
+            .windows(needle.len())
+            .filter(|window| *window == needle)
+            .count()
+    }
+
+    #[test]
+    fn long_methods_go_on_over_pages() {
+        let steps = (0..60)
+            .map(|index| Step::new(format!("Stir the pot (turn {}) and wait patiently", index)))
+            .collect();
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Crème brûlée".into(),
+            "".into(),
+            steps,
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "cream".into(),
+                0.5,
+                "l".into(),
+            )],
+        );
+        let card = RecipeCard::new(&recipe, PaperSize::A4);
+        let pixel = {
+            let mut png = std::io::Cursor::new(vec![]);
+            image::RgbImage::new(4, 2)
+                .write_to(&mut png, image::ImageFormat::Png)
+                .unwrap();
+            png.into_inner()
+        };
+        let image = CardImage::decode(&pixel).unwrap();
+
+        let pdf = render_pdf(&card, Some(&image));
+
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(count(&pdf, b"/MediaBox") > 1);
+        assert_eq!(count(&pdf, b"(0.5 l cream) Tj"), 1);
+        assert_eq!(count(&pdf, b"(60.) Tj"), 1);
+        assert_eq!(
+            count(&pdf, b"(Stir the pot (turn 59) and wait patiently) Tj"),
+            1
+        );
+        assert_eq!(count(&pdf, b"/Im1 Do"), 1);
+        assert!(CardImage::decode(b"not an image").is_none());
+    }
+
+    #[test]
+    fn text_is_encoded_for_the_standard_fonts() {
+        assert_eq!(win_ansi("Crème ½ “cup”"), b"Cr\xe8me \xbd \x93cup\x94");
+        assert_eq!(win_ansi("1\t2 🍰"), b"1 2 ?");
+    }
+
+    #[test]
+    fn lines_break_between_words() {
+        let lines = wrap(b"one two three", Font::Regular, 10.0, 40.0);
+        assert_eq!(lines, [b"one two".to_vec(), b"three".to_vec()]);
+        let lines = wrap(b"mmmmmmmmmm", Font::Regular, 10.0, 40.0);
+        assert_eq!(lines.concat(), b"mmmmmmmmmm");
+        assert!(lines.len() > 1);
+    }
+}
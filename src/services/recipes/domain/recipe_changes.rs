@@ -0,0 +1,54 @@
+use super::{access::Visibility, difficulty::Difficulty};
+
+/// Fields a recipe update sets besides its name, image and ingredients. The
+/// ones left `None` keep their stored value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RecipeChanges {
+    servings: Option<u32>,
+    prep_minutes: Option<u32>,
+    cook_minutes: Option<u32>,
+    difficulty: Option<Difficulty>,
+    visibility: Option<Visibility>,
+}
+
+impl RecipeChanges {
+    pub fn with_servings(self, servings: Option<u32>) -> Self {
+        Self { servings, ..self }
+    }
+
+    pub fn with_times(self, prep_minutes: Option<u32>, cook_minutes: Option<u32>) -> Self {
+        Self {
+            prep_minutes,
+            cook_minutes,
+            ..self
+        }
+    }
+
+    pub fn with_difficulty(self, difficulty: Option<Difficulty>) -> Self {
+        Self { difficulty, ..self }
+    }
+
+    pub fn with_visibility(self, visibility: Option<Visibility>) -> Self {
+        Self { visibility, ..self }
+    }
+
+    pub fn servings(&self) -> Option<u32> {
+        self.servings
+    }
+
+    pub fn prep_minutes(&self) -> Option<u32> {
+        self.prep_minutes
+    }
+
+    pub fn cook_minutes(&self) -> Option<u32> {
+        self.cook_minutes
+    }
+
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty
+    }
+
+    pub fn visibility(&self) -> Option<Visibility> {
+        self.visibility
+    }
+}
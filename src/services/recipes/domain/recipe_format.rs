@@ -0,0 +1,199 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use super::{
+    cooklang::Cooklang, ingredient::Ingredient, mealie::Mealie, paprika::Paprika, recipe::Recipe,
+    schema_org::iso_minutes, unit::Unit,
+};
+
+/// A file format of another recipe app, read into recipes and written back.
+pub trait RecipeFormat {
+    /// Every recipe of `data`, new to this instance unless the format keeps
+    /// uuids.
+    fn read(&self, data: &[u8]) -> Result<Vec<Recipe>, FormatError>;
+    /// `recipes` as a single file of the format.
+    fn write(&self, recipes: &[Recipe]) -> Result<Vec<u8>, FormatError>;
+    fn content_type(&self) -> &'static str;
+    /// Extension of the files written, without the dot.
+    fn extension(&self) -> &'static str;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatError {
+    /// The data is not a file of the format.
+    Malformed(String),
+    /// The format cannot hold what was asked of it.
+    Unwritable(String),
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::Malformed(reason) => write!(f, "Malformed file: {}", reason),
+            FormatError::Unwritable(reason) => write!(f, "Cannot write the file: {}", reason),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+/// The formats this instance exchanges recipes in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    Paprika,
+    Mealie,
+    Cooklang,
+}
+
+impl FormatKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FormatKind::Paprika => "paprika",
+            FormatKind::Mealie => "mealie",
+            FormatKind::Cooklang => "cooklang",
+        }
+    }
+
+    pub fn format(&self) -> Box<dyn RecipeFormat + Send + Sync> {
+        match self {
+            FormatKind::Paprika => Box::new(Paprika),
+            FormatKind::Mealie => Box::new(Mealie),
+            FormatKind::Cooklang => Box::new(Cooklang),
+        }
+    }
+}
+
+impl FromStr for FormatKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "paprika" => Ok(FormatKind::Paprika),
+            "mealie" => Ok(FormatKind::Mealie),
+            "cooklang" => Ok(FormatKind::Cooklang),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Three decimals at most, none for whole amounts: "2", "0.333".
+pub fn format_amount(amount: f64) -> String {
+    let text = format!("{:.3}", amount);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// `ingredient` as a line `IngredientLine` reads back: "200 g flour",
+/// "2 eggs", "salt to taste".
+pub fn ingredient_line(ingredient: &Ingredient) -> String {
+    match ingredient.parsed_unit() {
+        Ok(Unit::ToTaste) => format!("{} to taste", ingredient.name()),
+        Ok(Unit::Piece) => format!(
+            "{} {}",
+            format_amount(ingredient.amount()),
+            ingredient.name()
+        ),
+        _ => format!(
+            "{} {} {}",
+            format_amount(ingredient.amount()),
+            ingredient.unit(),
+            ingredient.name()
+        ),
+    }
+}
+
+/// The first whole number of `text`, as in "4 servings".
+pub fn first_number(text: &str) -> Option<u32> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .find(|digits| !digits.is_empty())
+        .and_then(|digits| digits.parse::<u32>().ok())
+}
+
+/// Minutes of a duration written for people, "1 hr 30 mins" or "45", or as
+/// ISO 8601.
+pub fn parse_minutes(text: &str) -> Option<u32> {
+    if let Some(minutes) = iso_minutes(text) {
+        return Some(minutes);
+    }
+    let text = text.to_lowercase();
+    let mut total = 0.0;
+    let mut amount = None;
+    let tokens = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .flat_map(|token| {
+            // "30min" is a number and a unit.
+            let split = token
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(token.len());
+            [&token[..split], &token[split..]]
+        })
+        .filter(|token| !token.is_empty());
+    for token in tokens {
+        if let Ok(number) = token.parse::<f64>() {
+            if let Some(previous) = amount.replace(number) {
+                total += previous;
+            }
+            continue;
+        }
+        let factor = match token {
+            "h" | "hr" | "hrs" | "hour" | "hours" => 60.0,
+            "m" | "min" | "mins" | "minute" | "minutes" => 1.0,
+            "and" => continue,
+            _ => return None,
+        };
+        total += amount.take()? * factor;
+    }
+    // A bare number counts minutes.
+    total += amount.unwrap_or(0.0);
+    (total > 0.0).then(|| total.round() as u32)
+}
+
+/// Preparation and cooking minutes of a recipe that may only give its total
+/// time. That total is counted as cooking so it is kept.
+pub fn known_times(
+    prep_minutes: Option<u32>,
+    cook_minutes: Option<u32>,
+    total_minutes: Option<u32>,
+) -> (Option<u32>, Option<u32>) {
+    match (prep_minutes, cook_minutes) {
+        (None, None) => (None, total_minutes),
+        times => times,
+    }
+}
+
+/// `minutes` written out, "1 hour 30 minutes".
+pub fn minutes_text(minutes: u32) -> String {
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    let plural = |count: u32, unit: &str| match count {
+        1 => format!("1 {}", unit),
+        _ => format!("{} {}s", count, unit),
+    };
+    match (hours, minutes) {
+        (0, minutes) => plural(minutes, "minute"),
+        (hours, 0) => plural(hours, "hour"),
+        (hours, minutes) => format!("{} {}", plural(hours, "hour"), plural(minutes, "minute")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_read_back_as_written() {
+        for minutes in [1, 45, 60, 90, 150] {
+            assert_eq!(parse_minutes(&minutes_text(minutes)), Some(minutes));
+        }
+        assert_eq!(parse_minutes("1 hr 15 mins"), Some(75));
+        assert_eq!(parse_minutes("20min"), Some(20));
+        assert_eq!(parse_minutes("PT1H"), Some(60));
+        assert_eq!(parse_minutes("40"), Some(40));
+        assert_eq!(parse_minutes("overnight"), None);
+    }
+
+    #[test]
+    fn lone_total_times_count_as_cooking() {
+        assert_eq!(known_times(None, None, Some(30)), (None, Some(30)));
+        assert_eq!(known_times(Some(10), None, Some(30)), (Some(10), None));
+        assert_eq!(known_times(None, Some(20), Some(30)), (None, Some(20)));
+    }
+}
@@ -0,0 +1,35 @@
+use super::{ingredient::Ingredient, recipe::Recipe};
+
+#[derive(Debug, Clone)]
+pub struct RecipeMatch {
+    recipe: Recipe,
+    missing: Vec<Ingredient>,
+}
+
+impl RecipeMatch {
+    /// `recipe` carries its full ingredient list, `missing` the subset not on hand.
+    pub fn new(recipe: Recipe, missing: Vec<Ingredient>) -> Self {
+        Self { recipe, missing }
+    }
+
+    pub fn recipe(&self) -> &Recipe {
+        &self.recipe
+    }
+
+    pub fn missing(&self) -> &[Ingredient] {
+        self.missing.as_ref()
+    }
+
+    pub fn matched_count(&self) -> usize {
+        self.recipe.ingredients().len() - self.missing.len()
+    }
+
+    /// Fraction of the recipe ingredients that are on hand, from 0 to 1.
+    pub fn coverage(&self) -> f64 {
+        let total = self.recipe.ingredients().len();
+        if total == 0 {
+            return 0.0;
+        }
+        self.matched_count() as f64 / total as f64
+    }
+}
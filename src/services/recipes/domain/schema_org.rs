@@ -0,0 +1,350 @@
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{Map, Value};
+
+use super::{
+    ingredient::Ingredient,
+    ingredient_line::IngredientLine,
+    recipe::{Recipe, DEFAULT_SERVINGS},
+    recipe_format::known_times,
+    step::{split_method, Step},
+    tag::normalize_tag,
+};
+
+/// Reads the first schema.org `Recipe` of an HTML page, from its JSON-LD
+/// scripts or else from its microdata. `None` when the page has none.
+pub fn extract_recipe(html: &str) -> Option<Recipe> {
+    let document = Html::parse_document(html);
+    json_ld_recipe(&document)
+        .or_else(|| microdata_recipe(&document))
+        .map(|node| into_recipe(&node))
+}
+
+fn is_recipe(node: &Map<String, Value>) -> bool {
+    match node.get("@type") {
+        Some(Value::String(kind)) => is_recipe_type(kind),
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).any(is_recipe_type),
+        _ => false,
+    }
+}
+
+fn is_recipe_type(kind: &str) -> bool {
+    kind == "Recipe" || kind.ends_with("schema.org/Recipe")
+}
+
+/// The first recipe node of a JSON-LD document, looking through arrays and
+/// `@graph`.
+fn find_recipe(value: &Value) -> Option<Map<String, Value>> {
+    match value {
+        Value::Object(node) if is_recipe(node) => Some(node.clone()),
+        Value::Object(node) => node.get("@graph").and_then(find_recipe),
+        Value::Array(nodes) => nodes.iter().find_map(find_recipe),
+        _ => None,
+    }
+}
+
+fn json_ld_recipe(document: &Html) -> Option<Map<String, Value>> {
+    let scripts = Selector::parse(r#"script[type="application/ld+json"]"#).ok()?;
+    document.select(&scripts).find_map(|script| {
+        let text = script.text().collect::<String>();
+        serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|value| find_recipe(&value))
+    })
+}
+
+fn microdata_recipe(document: &Html) -> Option<Map<String, Value>> {
+    let scopes = Selector::parse("[itemscope][itemtype]").ok()?;
+    document
+        .select(&scopes)
+        .find(|element| {
+            element
+                .value()
+                .attr("itemtype")
+                .is_some_and(|kinds| kinds.split_whitespace().any(is_recipe_type))
+        })
+        .map(microdata_item)
+}
+
+/// The properties of the item scope `scope`, each as an array of its values.
+fn microdata_item(scope: ElementRef) -> Map<String, Value> {
+    let mut node = Map::new();
+    collect_properties(scope, &mut node);
+    node
+}
+
+fn collect_properties(parent: ElementRef, node: &mut Map<String, Value>) {
+    for child in parent.children().filter_map(ElementRef::wrap) {
+        let element = child.value();
+        if let Some(properties) = element.attr("itemprop") {
+            let value = match element.attr("itemscope") {
+                Some(_) => Value::Object(microdata_item(child)),
+                None => Value::String(microdata_value(child)),
+            };
+            for property in properties.split_whitespace() {
+                let values = node
+                    .entry(property.to_string())
+                    .or_insert_with(|| Value::Array(vec![]));
+                if let Value::Array(values) = values {
+                    values.push(value.clone());
+                }
+            }
+        }
+        // Properties of a nested item belong to that item.
+        if element.attr("itemscope").is_none() {
+            collect_properties(child, node);
+        }
+    }
+}
+
+/// The value of a microdata property, which depends on the element
+/// carrying it.
+fn microdata_value(element: ElementRef) -> String {
+    let value = element.value();
+    let attribute = match value.name() {
+        "meta" => value.attr("content"),
+        "img" | "audio" | "video" | "source" | "embed" | "iframe" => value.attr("src"),
+        "a" | "link" | "area" => value.attr("href"),
+        "time" => value.attr("datetime").or(value.attr("content")),
+        "data" | "meter" => value.attr("value"),
+        _ => value.attr("content"),
+    };
+    match attribute {
+        Some(attribute) => attribute.to_string(),
+        None => element.text().collect::<String>(),
+    }
+}
+
+/// Text without markup or entities, whitespace collapsed. JSON-LD strings
+/// often carry escaped HTML.
+fn clean_text(text: &str) -> String {
+    let fragment = Html::parse_fragment(text);
+    fragment
+        .root_element()
+        .text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Every string of `value`, single values and arrays alike.
+fn texts(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(text)) => vec![text.clone()],
+        Some(Value::Number(number)) => vec![number.to_string()],
+        Some(Value::Array(values)) => values.iter().flat_map(|v| texts(Some(v))).collect(),
+        _ => vec![],
+    }
+}
+
+fn first_text(value: Option<&Value>) -> Option<String> {
+    texts(value)
+        .into_iter()
+        .map(|text| clean_text(&text))
+        .find(|text| !text.is_empty())
+}
+
+/// The image URL, given as a URL, an `ImageObject` or a list of either.
+fn image(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(url) => Some(url.trim().to_string()).filter(|url| !url.is_empty()),
+        Value::Object(image) => image
+            .get("url")
+            .or(image.get("contentUrl"))
+            .and_then(|url| first_text(Some(url))),
+        Value::Array(images) => images.iter().find_map(|image| self::image(Some(image))),
+        _ => None,
+    }
+}
+
+/// The method, given as a text, a list of texts, `HowToStep`s or
+/// `HowToSection`s of steps.
+fn steps(value: Option<&Value>) -> Vec<Step> {
+    match value {
+        Some(Value::String(text)) => {
+            // Paragraphs and line breaks separate steps of an HTML method.
+            let text = text
+                .replace("</p>", "\n")
+                .replace("<br>", "\n")
+                .replace("<br/>", "\n")
+                .replace("<br />", "\n");
+            split_method(&text)
+                .into_iter()
+                .map(|step| Step::new(clean_text(step.text())))
+                .filter(|step| !step.text().is_empty())
+                .collect()
+        }
+        Some(Value::Array(values)) => values.iter().flat_map(|v| steps(Some(v))).collect(),
+        Some(Value::Object(step)) => match step.get("itemListElement") {
+            Some(elements) => steps(Some(elements)),
+            None => first_text(step.get("text").or(step.get("name")))
+                .map(|text| vec![Step::new(text)])
+                .unwrap_or_default(),
+        },
+        _ => vec![],
+    }
+}
+
+fn minutes(value: Option<&Value>) -> Option<u32> {
+    iso_minutes(&first_text(value)?)
+}
+
+/// Minutes of an ISO 8601 duration such as "PT1H30M", days included.
+pub fn iso_minutes(duration: &str) -> Option<u32> {
+    let duration = duration.trim().to_uppercase();
+    let rest = duration.strip_prefix('P')?;
+    let mut total = 0.0;
+    let mut number = String::new();
+    let mut in_time = false;
+    for character in rest.chars() {
+        match character {
+            'T' => in_time = true,
+            '0'..='9' | '.' => number.push(character),
+            designator => {
+                let amount = number.parse::<f64>().ok()?;
+                number.clear();
+                total += match (designator, in_time) {
+                    ('D', false) => amount * 24.0 * 60.0,
+                    ('H', true) => amount * 60.0,
+                    ('M', true) => amount,
+                    ('S', true) => amount / 60.0,
+                    _ => return None,
+                };
+            }
+        }
+    }
+    (number.is_empty() && total > 0.0).then(|| total.round() as u32)
+}
+
+/// The first number of the yield, as in "4 servings".
+fn servings(value: Option<&Value>) -> Option<u32> {
+    texts(value).into_iter().find_map(|text| {
+        text.split(|c: char| !c.is_ascii_digit())
+            .find(|digits| !digits.is_empty())
+            .and_then(|digits| digits.parse::<u32>().ok())
+            .filter(|servings| *servings > 0)
+    })
+}
+
+/// Keywords, categories and cuisines that make valid tags.
+fn tags(node: &Map<String, Value>) -> Vec<String> {
+    let mut tags: Vec<String> = vec![];
+    for key in ["recipeCategory", "recipeCuisine", "keywords"] {
+        for text in texts(node.get(key)) {
+            for tag in text.split(',').filter_map(|tag| normalize_tag(tag).ok()) {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+        }
+    }
+    tags
+}
+
+fn into_recipe(node: &Map<String, Value>) -> Recipe {
+    let ingredients = texts(node.get("recipeIngredient").or(node.get("ingredients")))
+        .iter()
+        .filter_map(|line| IngredientLine::parse(&clean_text(line)))
+        .map(IngredientLine::into_ingredient)
+        .collect::<Vec<Ingredient>>();
+    let (prep_minutes, cook_minutes) = known_times(
+        minutes(node.get("prepTime")),
+        minutes(node.get("cookTime")),
+        minutes(node.get("totalTime")),
+    );
+    Recipe::new(
+        uuid::Uuid::new_v4(),
+        first_text(node.get("name")).unwrap_or_default(),
+        image(node.get("image")).unwrap_or_default(),
+        steps(node.get("recipeInstructions")),
+        ingredients,
+    )
+    .with_servings(servings(node.get("recipeYield")).unwrap_or(DEFAULT_SERVINGS))
+    .with_times(prep_minutes, cook_minutes)
+    .with_tags(tags(node))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::recipes::domain::step::join_steps;
+
+    const JSON_LD: &str = include_str!("../../../../resources/fixtures/recipe_json_ld.html");
+    const MICRODATA: &str = include_str!("../../../../resources/fixtures/recipe_microdata.html");
+
+    fn ingredients(recipe: &Recipe) -> Vec<(String, f64, String)> {
+        recipe
+            .ingredients()
+            .iter()
+            .map(|i| (i.name().to_string(), i.amount(), i.unit().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn json_ld_recipes_are_found_in_a_graph() {
+        let recipe = extract_recipe(JSON_LD).unwrap();
+        assert_eq!(recipe.name(), "Buttermilk Pancakes");
+        assert_eq!(recipe.image(), "https://example.com/images/pancakes.jpg");
+        assert_eq!(recipe.servings(), 4);
+        assert_eq!(recipe.prep_minutes(), Some(10));
+        assert_eq!(recipe.cook_minutes(), Some(20));
+        assert_eq!(
+            ingredients(&recipe),
+            vec![
+                ("flour".to_string(), 2.5, "cup".to_string()),
+                ("sugar".to_string(), 2.0, "tbsp".to_string()),
+                ("buttermilk".to_string(), 2.0, "cup".to_string()),
+                ("eggs".to_string(), 2.0, "piece".to_string()),
+                ("Salt".to_string(), 1.0, "to taste".to_string()),
+            ]
+        );
+        assert_eq!(
+            join_steps(recipe.steps()),
+            "Whisk the flour & sugar.\nStir in buttermilk and eggs.\nFry until golden."
+        );
+        assert_eq!(recipe.tags(), &["breakfast", "american", "pancakes"]);
+    }
+
+    #[test]
+    fn microdata_recipes_are_read_when_there_is_no_json_ld() {
+        let recipe = extract_recipe(MICRODATA).unwrap();
+        // The author is a nested item, its name is not the recipe's.
+        assert_eq!(recipe.name(), "Tomato Soup");
+        assert_eq!(recipe.image(), "https://example.com/soup.jpg");
+        assert_eq!(recipe.servings(), 6);
+        assert_eq!(
+            (recipe.prep_minutes(), recipe.cook_minutes()),
+            (None, Some(90))
+        );
+        assert_eq!(
+            ingredients(&recipe),
+            vec![
+                ("ripe tomatoes".to_string(), 1.0, "kg".to_string()),
+                ("olive oil".to_string(), 2.0, "tbsp".to_string()),
+                ("vegetable stock".to_string(), 500.0, "ml".to_string()),
+            ]
+        );
+        assert_eq!(
+            join_steps(recipe.steps()),
+            "Roast the tomatoes.\nSimmer with the stock and blend."
+        );
+    }
+
+    #[test]
+    fn pages_without_recipes_give_nothing() {
+        assert!(extract_recipe("<html><body><p>Hello</p></body></html>").is_none());
+        assert!(extract_recipe(
+            r#"<script type="application/ld+json">{"@type": "Article"}</script>"#
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn durations_read_hours_and_minutes() {
+        let value = |text: &str| Value::String(text.to_string());
+        assert_eq!(minutes(Some(&value("PT1H30M"))), Some(90));
+        assert_eq!(minutes(Some(&value("P1DT2H"))), Some(1560));
+        assert_eq!(minutes(Some(&value("PT"))), None);
+        assert_eq!(minutes(Some(&value("1 hour"))), None);
+    }
+}
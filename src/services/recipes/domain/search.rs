@@ -0,0 +1,32 @@
+use super::recipe::Recipe;
+
+#[derive(Debug, Clone)]
+pub struct RecipeSearchHit {
+    recipe: Recipe,
+    snippet: String,
+    score: f64,
+}
+
+impl RecipeSearchHit {
+    pub fn new(recipe: Recipe, snippet: String, score: f64) -> Self {
+        Self {
+            recipe,
+            snippet,
+            score,
+        }
+    }
+
+    pub fn recipe(&self) -> &Recipe {
+        &self.recipe
+    }
+
+    /// HTML excerpt of the matching text, escaped, the matches in `<mark>`.
+    pub fn snippet(&self) -> &str {
+        self.snippet.as_ref()
+    }
+
+    /// bm25 relevance, lower is better.
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+}
@@ -0,0 +1,181 @@
+use std::{error::Error, fmt::Display, time::Duration};
+
+pub const MAX_TIMER_LENGTH: usize = 64;
+
+/// One instruction of a recipe method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    text: String,
+    duration: Option<Duration>,
+    timer: Option<String>,
+    ingredients: Vec<uuid::Uuid>,
+}
+
+impl Step {
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            duration: None,
+            timer: None,
+            ingredients: vec![],
+        }
+    }
+
+    pub fn with_duration(self, duration: Option<Duration>) -> Self {
+        Self { duration, ..self }
+    }
+
+    /// Label shown on the timer started for this step.
+    pub fn with_timer(self, timer: Option<String>) -> Self {
+        Self { timer, ..self }
+    }
+
+    /// Ingredients of the recipe used in this step, each listed once in the
+    /// order first given.
+    pub fn with_ingredients(self, mut ingredients: Vec<uuid::Uuid>) -> Self {
+        let mut seen = std::collections::HashSet::new();
+        ingredients.retain(|ingredient| seen.insert(*ingredient));
+        Self {
+            ingredients,
+            ..self
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        self.text.as_ref()
+    }
+
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    pub fn timer(&self) -> Option<&str> {
+        self.timer.as_deref()
+    }
+
+    pub fn ingredients(&self) -> &[uuid::Uuid] {
+        self.ingredients.as_ref()
+    }
+
+    /// Trims the text and timer label, dropping blank labels and repeated
+    /// ingredients.
+    pub fn normalized(self) -> Result<Self, StepError> {
+        let text = self.text.trim().to_string();
+        if text.is_empty() {
+            return Err(StepError::EmptyText);
+        }
+        let timer = self
+            .timer
+            .map(|timer| timer.trim().to_string())
+            .filter(|timer| !timer.is_empty());
+        if timer
+            .as_ref()
+            .is_some_and(|timer| timer.chars().count() > MAX_TIMER_LENGTH)
+        {
+            return Err(StepError::TimerTooLong);
+        }
+        let mut ingredients: Vec<uuid::Uuid> = vec![];
+        for ingredient in self.ingredients {
+            if !ingredients.contains(&ingredient) {
+                ingredients.push(ingredient);
+            }
+        }
+        Ok(Self {
+            text,
+            duration: self.duration,
+            timer,
+            ingredients,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum StepError {
+    EmptyText,
+    TimerTooLong,
+}
+
+impl Display for StepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepError::EmptyText => f.write_str("Step text is empty"),
+            StepError::TimerTooLong => write!(
+                f,
+                "Timer labels are at most {} characters long",
+                MAX_TIMER_LENGTH
+            ),
+        }
+    }
+}
+
+impl Error for StepError {}
+
+/// Normalizes every step, reporting the position of the first invalid one.
+pub fn normalize_steps(steps: Vec<Step>) -> Result<Vec<Step>, (usize, StepError)> {
+    steps
+        .into_iter()
+        .enumerate()
+        .map(|(index, step)| step.normalized().map_err(|e| (index, e)))
+        .collect()
+}
+
+/// Steps of a method written as a single text, one per non blank line.
+pub fn split_method(method: &str) -> Vec<Step> {
+    method
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| Step::new(line.to_string()))
+        .collect()
+}
+
+/// The method as a single text, one step per line.
+pub fn join_steps(steps: &[Step]) -> String {
+    steps
+        .iter()
+        .map(Step::text)
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ingredients_are_listed_once() {
+        let (flour, water) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let step = Step::new("Mix".into()).with_ingredients(vec![flour, water, flour]);
+        assert_eq!(step.ingredients(), [flour, water]);
+    }
+
+    #[test]
+    fn methods_split_on_lines() {
+        let steps = split_method("Mix flour\r\n\n  Bake 20 min  \nServe");
+        assert_eq!(
+            steps.iter().map(Step::text).collect::<Vec<&str>>(),
+            vec!["Mix flour", "Bake 20 min", "Serve"]
+        );
+        assert_eq!(join_steps(&steps), "Mix flour\nBake 20 min\nServe");
+        assert!(split_method("  \n").is_empty());
+    }
+
+    #[test]
+    fn steps_need_text() {
+        assert_eq!(
+            Step::new("  ".into()).normalized(),
+            Err(StepError::EmptyText)
+        );
+        let step = Step::new(" Bake ".into())
+            .with_timer(Some(" ".into()))
+            .normalized()
+            .unwrap();
+        assert_eq!((step.text(), step.timer()), ("Bake", None));
+        assert_eq!(
+            Step::new("Bake".into())
+                .with_timer(Some("x".repeat(MAX_TIMER_LENGTH + 1)))
+                .normalized(),
+            Err(StepError::TimerTooLong)
+        );
+    }
+}
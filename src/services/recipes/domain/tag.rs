@@ -0,0 +1,115 @@
+use std::{error::Error, fmt::Display};
+
+pub const MAX_TAG_LENGTH: usize = 32;
+
+#[derive(Debug, PartialEq)]
+pub struct TagError(String);
+
+impl TagError {
+    pub fn tag(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl Display for TagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid tag `{}`", self.0)
+    }
+}
+
+impl Error for TagError {}
+
+/// Lower cases `tag` and collapses its whitespace, so "Quick  Meals" and
+/// "quick meals" are the same tag. Commas are refused, they separate tags
+/// in query strings.
+pub fn normalize_tag(tag: &str) -> Result<String, TagError> {
+    let normalized = tag
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase();
+    if normalized.is_empty()
+        || normalized.chars().count() > MAX_TAG_LENGTH
+        || normalized.contains(',')
+    {
+        return Err(TagError(tag.to_string()));
+    }
+    Ok(normalized)
+}
+
+/// Normalizes every tag and drops duplicates, keeping the first occurrence.
+/// Errors carry the position of the first invalid tag.
+pub fn normalize_tags(tags: &[String]) -> Result<Vec<String>, (usize, TagError)> {
+    let mut normalized: Vec<String> = vec![];
+    for (index, tag) in tags.iter().enumerate() {
+        let tag = normalize_tag(tag).map_err(|e| (index, e))?;
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagMatch {
+    /// Recipes carrying every tag.
+    #[default]
+    All,
+    /// Recipes carrying at least one of the tags.
+    Any,
+}
+
+/// Restricts listings to tagged recipes. Empty filters match everything.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TagFilter {
+    tags: Vec<String>,
+    mode: TagMatch,
+}
+
+impl TagFilter {
+    pub fn new(tags: Vec<String>, mode: TagMatch) -> Self {
+        Self { tags, mode }
+    }
+
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_ref()
+    }
+
+    pub fn mode(&self) -> TagMatch {
+        self.mode
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    pub fn normalized(self) -> Result<Self, TagError> {
+        let tags = normalize_tags(&self.tags).map_err(|(_, e)| e)?;
+        Ok(Self { tags, ..self })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_are_case_and_space_insensitive() {
+        assert_eq!(normalize_tag("  Quick   Meals "), Ok("quick meals".into()));
+        assert_eq!(normalize_tag("VEGETARIAN"), Ok("vegetarian".into()));
+        assert!(normalize_tag("   ").is_err());
+        assert!(normalize_tag("a,b").is_err());
+        assert!(normalize_tag(&"x".repeat(MAX_TAG_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn duplicate_tags_are_dropped() {
+        let tags = vec!["Dessert".into(), "quick".into(), "dessert ".into()];
+        assert_eq!(
+            normalize_tags(&tags),
+            Ok(vec!["dessert".to_string(), "quick".to_string()])
+        );
+        let tags = vec!["ok".into(), "".into()];
+        assert_eq!(normalize_tags(&tags).unwrap_err().0, 1);
+    }
+}
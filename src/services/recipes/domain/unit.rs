@@ -0,0 +1,293 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitKind {
+    Mass,
+    Volume,
+    Count,
+    ToTaste,
+}
+
+/// Measuring system amounts are shown in, `Original` leaving them as stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnitSystem {
+    Metric,
+    Imperial,
+    #[default]
+    Original,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Milligram,
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+    Millilitre,
+    Centilitre,
+    Decilitre,
+    Litre,
+    Teaspoon,
+    Tablespoon,
+    Cup,
+    FluidOunce,
+    Pint,
+    Quart,
+    Gallon,
+    Piece,
+    Clove,
+    Slice,
+    Can,
+    Bunch,
+    Pinch,
+    ToTaste,
+}
+
+impl Unit {
+    pub const ALL: [Unit; 23] = [
+        Unit::Milligram,
+        Unit::Gram,
+        Unit::Kilogram,
+        Unit::Ounce,
+        Unit::Pound,
+        Unit::Millilitre,
+        Unit::Centilitre,
+        Unit::Decilitre,
+        Unit::Litre,
+        Unit::Teaspoon,
+        Unit::Tablespoon,
+        Unit::Cup,
+        Unit::FluidOunce,
+        Unit::Pint,
+        Unit::Quart,
+        Unit::Gallon,
+        Unit::Piece,
+        Unit::Clove,
+        Unit::Slice,
+        Unit::Can,
+        Unit::Bunch,
+        Unit::Pinch,
+        Unit::ToTaste,
+    ];
+
+    /// Canonical spelling, the one stored in the database.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::Milligram => "mg",
+            Unit::Gram => "g",
+            Unit::Kilogram => "kg",
+            Unit::Ounce => "oz",
+            Unit::Pound => "lb",
+            Unit::Millilitre => "ml",
+            Unit::Centilitre => "cl",
+            Unit::Decilitre => "dl",
+            Unit::Litre => "l",
+            Unit::Teaspoon => "tsp",
+            Unit::Tablespoon => "tbsp",
+            Unit::Cup => "cup",
+            Unit::FluidOunce => "fl oz",
+            Unit::Pint => "pt",
+            Unit::Quart => "qt",
+            Unit::Gallon => "gal",
+            Unit::Piece => "piece",
+            Unit::Clove => "clove",
+            Unit::Slice => "slice",
+            Unit::Can => "can",
+            Unit::Bunch => "bunch",
+            Unit::Pinch => "pinch",
+            Unit::ToTaste => "to taste",
+        }
+    }
+
+    pub fn kind(&self) -> UnitKind {
+        match self {
+            Unit::Milligram | Unit::Gram | Unit::Kilogram | Unit::Ounce | Unit::Pound => {
+                UnitKind::Mass
+            }
+            Unit::Millilitre
+            | Unit::Centilitre
+            | Unit::Decilitre
+            | Unit::Litre
+            | Unit::Teaspoon
+            | Unit::Tablespoon
+            | Unit::Cup
+            | Unit::FluidOunce
+            | Unit::Pint
+            | Unit::Quart
+            | Unit::Gallon => UnitKind::Volume,
+            Unit::Piece | Unit::Clove | Unit::Slice | Unit::Can | Unit::Bunch | Unit::Pinch => {
+                UnitKind::Count
+            }
+            Unit::ToTaste => UnitKind::ToTaste,
+        }
+    }
+
+    pub fn is_metric(&self) -> bool {
+        matches!(
+            self,
+            Unit::Milligram
+                | Unit::Gram
+                | Unit::Kilogram
+                | Unit::Millilitre
+                | Unit::Centilitre
+                | Unit::Decilitre
+                | Unit::Litre
+        )
+    }
+
+    /// Size of one unit in the base unit of its kind: grams for mass,
+    /// millilitres for volume (US customary measures), itself otherwise.
+    pub fn base_factor(&self) -> f64 {
+        match self {
+            Unit::Milligram => 0.001,
+            Unit::Gram => 1.0,
+            Unit::Kilogram => 1000.0,
+            Unit::Ounce => 28.349_523_125,
+            Unit::Pound => 453.592_37,
+            Unit::Millilitre => 1.0,
+            Unit::Centilitre => 10.0,
+            Unit::Decilitre => 100.0,
+            Unit::Litre => 1000.0,
+            Unit::Teaspoon => 4.928_921_593_75,
+            Unit::Tablespoon => 14.786_764_781_25,
+            Unit::Cup => 236.588_236_5,
+            Unit::FluidOunce => 29.573_529_562_5,
+            Unit::Pint => 473.176_473,
+            Unit::Quart => 946.352_946,
+            Unit::Gallon => 3_785.411_784,
+            _ => 1.0,
+        }
+    }
+
+    /// Converts `amount` of this unit into `target`, `None` across kinds.
+    pub fn convert(&self, amount: f64, target: Unit) -> Option<f64> {
+        if self.kind() != target.kind() || matches!(self.kind(), UnitKind::Count) && self != &target
+        {
+            return None;
+        }
+        Some(amount * self.base_factor() / target.base_factor())
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitError(String);
+
+impl UnitError {
+    pub fn unit(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl Display for UnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown unit `{}`", self.0)
+    }
+}
+
+impl Error for UnitError {}
+
+impl FromStr for Unit {
+    type Err = UnitError;
+
+    /// Accepts the usual spellings, plurals and abbreviations, case
+    /// insensitively except for the `T`/`t` tablespoon/teaspoon shorthand.
+    /// An empty unit counts pieces, as in "3 eggs".
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        match trimmed {
+            "T" | "Tb" | "Tbs" => return Ok(Unit::Tablespoon),
+            "t" => return Ok(Unit::Teaspoon),
+            _ => (),
+        }
+        let normalized = trimmed
+            .to_lowercase()
+            .replace('.', " ")
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let unit = match normalized.as_str() {
+            "mg" | "milligram" | "milligrams" | "milligramme" | "milligrammes" => Unit::Milligram,
+            "g" | "gr" | "grs" | "gram" | "grams" | "gramme" | "grammes" => Unit::Gram,
+            "kg" | "kgs" | "kilo" | "kilos" | "kilogram" | "kilograms" | "kilogramme"
+            | "kilogrammes" => Unit::Kilogram,
+            "oz" | "ozs" | "ounce" | "ounces" => Unit::Ounce,
+            "lb" | "lbs" | "pound" | "pounds" => Unit::Pound,
+            "ml" | "mls" | "millilitre" | "millilitres" | "milliliter" | "milliliters" => {
+                Unit::Millilitre
+            }
+            "cl" | "centilitre" | "centilitres" | "centiliter" | "centiliters" => Unit::Centilitre,
+            "dl" | "decilitre" | "decilitres" | "deciliter" | "deciliters" => Unit::Decilitre,
+            "l" | "lt" | "ltr" | "ltrs" | "litre" | "litres" | "liter" | "liters" => Unit::Litre,
+            "tsp" | "tsps" | "teaspoon" | "teaspoons" => Unit::Teaspoon,
+            "tbsp" | "tbsps" | "tbs" | "tbl" | "tablespoon" | "tablespoons" => Unit::Tablespoon,
+            "c" | "cup" | "cups" => Unit::Cup,
+            "fl oz" | "floz" | "fl ozs" | "fluid ounce" | "fluid ounces" => Unit::FluidOunce,
+            "pt" | "pts" | "pint" | "pints" => Unit::Pint,
+            "qt" | "qts" | "quart" | "quarts" => Unit::Quart,
+            "gal" | "gals" | "gallon" | "gallons" => Unit::Gallon,
+            "" | "piece" | "pieces" | "pc" | "pcs" | "unit" | "units" | "whole" | "each" | "ea"
+            | "x" => Unit::Piece,
+            "clove" | "cloves" => Unit::Clove,
+            "slice" | "slices" => Unit::Slice,
+            "can" | "cans" | "tin" | "tins" => Unit::Can,
+            "bunch" | "bunches" => Unit::Bunch,
+            "pinch" | "pinches" => Unit::Pinch,
+            "to taste" | "taste" | "tt" => Unit::ToTaste,
+            _ => return Err(UnitError(value.to_string())),
+        };
+        Ok(unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_spellings_parse_to_the_same_unit() {
+        for (spellings, unit) in [
+            (vec!["g", "gram", "Grams", " gr "], Unit::Gram),
+            (vec!["kg", "kilos", "Kilogram"], Unit::Kilogram),
+            (vec!["ml", "millilitres", "milliliter"], Unit::Millilitre),
+            (vec!["tbsp", "Tbsp.", "tablespoons", "T"], Unit::Tablespoon),
+            (vec!["tsp", "teaspoon", "t"], Unit::Teaspoon),
+            (vec!["fl oz", "fl. oz.", "fluid ounces"], Unit::FluidOunce),
+            (vec!["", "pcs", "whole"], Unit::Piece),
+            (vec!["to taste", "To Taste"], Unit::ToTaste),
+        ] {
+            for spelling in spellings {
+                assert_eq!(spelling.parse::<Unit>(), Ok(unit), "{spelling:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn symbols_round_trip() {
+        for unit in Unit::ALL {
+            assert_eq!(unit.symbol().parse::<Unit>(), Ok(unit));
+        }
+    }
+
+    #[test]
+    fn unknown_units_are_rejected() {
+        assert_eq!(
+            "handful of".parse::<Unit>(),
+            Err(UnitError("handful of".to_string()))
+        );
+    }
+
+    #[test]
+    fn conversion_stays_within_a_kind() {
+        assert_eq!(Unit::Kilogram.convert(1.5, Unit::Gram), Some(1500.0));
+        assert_eq!(Unit::Cup.convert(1.0, Unit::Gram), None);
+        assert_eq!(Unit::Clove.convert(1.0, Unit::Piece), None);
+        assert_eq!(Unit::Clove.convert(2.0, Unit::Clove), Some(2.0));
+    }
+}
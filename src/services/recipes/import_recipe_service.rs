@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::{
+        access::{RecipeAccess, Visibility},
+        recipe::Recipe,
+        schema_org::extract_recipe,
+    },
+    insert_recipe_service::InsertRecipe,
+    ports::{
+        incoming::{
+            import_recipe_service::{ImportRecipeService, ImportRecipeServiceError, RecipeSource},
+            insert_recipe_service::{InsertRecipeService, InsertRecipeServiceError},
+        },
+        outgoing::{
+            insert_recipe_port::InsertRecipePort, query_recipe_port::QueryRecipePort,
+            recipe_page_port::RecipePagePort,
+        },
+    },
+};
+use crate::services::users::domain::caller::Caller;
+
+pub struct ImportRecipe<Storage, Pages>
+where
+    Storage: InsertRecipePort + QueryRecipePort + Sync + Send,
+    Pages: RecipePagePort + Sync + Send,
+{
+    inserter: InsertRecipe<Storage>,
+    storage: Storage,
+    pages: Pages,
+}
+
+#[async_trait]
+impl<Storage, Pages> ImportRecipeService for ImportRecipe<Storage, Pages>
+where
+    Storage: InsertRecipePort + QueryRecipePort + Sync + Send,
+    Pages: RecipePagePort + Sync + Send,
+{
+    async fn import_recipe(
+        &self,
+        caller: Caller,
+        source: RecipeSource,
+        visibility: Visibility,
+    ) -> Result<Recipe, ImportRecipeServiceError> {
+        let html = match source {
+            RecipeSource::Html(html) => html,
+            RecipeSource::Url(url) => self
+                .pages
+                .fetch_page(&url)
+                .await
+                .map_err(ImportRecipeServiceError::Page)?,
+        };
+        // A name is the one thing schema.org requires of a recipe.
+        let recipe = extract_recipe(&html)
+            .filter(|recipe| !recipe.name().is_empty())
+            .ok_or(ImportRecipeServiceError::NoRecipe)?
+            .with_access(RecipeAccess::new(Some(caller.user()), visibility));
+        let uuid = recipe.uuid();
+        // Imported recipes are checked like any other.
+        match self.inserter.insert_recipe(caller, recipe).await {
+            Ok(()) => (),
+            Err(InsertRecipeServiceError::InternalError) => {
+                return Err(ImportRecipeServiceError::InternalError)
+            }
+            Err(error) => return Err(ImportRecipeServiceError::InvalidRecipe(error)),
+        }
+        self.storage
+            .query_recipe(uuid)
+            .await
+            .map_err(|_| ImportRecipeServiceError::InternalError)
+    }
+}
+
+impl<Storage, Pages> ImportRecipe<Storage, Pages>
+where
+    Storage: InsertRecipePort + QueryRecipePort + Clone + Sync + Send,
+    Pages: RecipePagePort + Sync + Send,
+{
+    pub fn new(storage: Storage, pages: Pages) -> Self {
+        Self {
+            inserter: InsertRecipe::new(storage.clone()),
+            storage,
+            pages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::services::{
+        recipes::ports::outgoing::{
+            insert_recipe_port::InsertRecipeError, query_recipe_port::QueryRecipeError,
+            recipe_page_port::RecipePageError,
+        },
+        users::domain::caller::Role,
+    };
+
+    const JSON_LD: &str = include_str!("../../../resources/fixtures/recipe_json_ld.html");
+
+    #[derive(Clone, Default)]
+    struct StubStorage {
+        recipes: Arc<Mutex<Vec<Recipe>>>,
+    }
+
+    #[async_trait]
+    impl InsertRecipePort for StubStorage {
+        async fn insert_recipe(&self, recipe: Recipe) -> Result<(), InsertRecipeError> {
+            self.recipes.lock().unwrap().push(recipe);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl QueryRecipePort for StubStorage {
+        async fn query_recipe(&self, uuid: uuid::Uuid) -> Result<Recipe, QueryRecipeError> {
+            self.recipes
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|recipe| recipe.uuid() == uuid)
+                .cloned()
+                .ok_or(QueryRecipeError::RecordNotFound)
+        }
+    }
+
+    /// Serves the JSON-LD fixture at one URL only.
+    struct StubPages;
+
+    #[async_trait]
+    impl RecipePagePort for StubPages {
+        async fn fetch_page(&self, url: &str) -> Result<String, RecipePageError> {
+            match url {
+                "https://example.com/pancakes" => Ok(JSON_LD.to_string()),
+                _ => Err(RecipePageError::Unreachable),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn pages_are_imported_for_the_caller() {
+        let storage = StubStorage::default();
+        let service = ImportRecipe::new(storage.clone(), StubPages);
+        let caller = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+
+        let recipe = service
+            .import_recipe(
+                caller,
+                RecipeSource::Url("https://example.com/pancakes".into()),
+                Visibility::Shared,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(recipe.name(), "Buttermilk Pancakes");
+        assert_eq!(recipe.access().owner(), Some(caller.user()));
+        assert_eq!(recipe.access().visibility(), Visibility::Shared);
+        assert_eq!(storage.recipes.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn pages_without_a_usable_recipe_are_refused() {
+        let service = ImportRecipe::new(StubStorage::default(), StubPages);
+        let caller = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let import = |source| service.import_recipe(caller, source, Visibility::Private);
+
+        assert_eq!(
+            import(RecipeSource::Url("https://example.com/gone".into()))
+                .await
+                .unwrap_err(),
+            ImportRecipeServiceError::Page(RecipePageError::Unreachable)
+        );
+        assert_eq!(
+            import(RecipeSource::Html("<p>No recipe here</p>".into()))
+                .await
+                .unwrap_err(),
+            ImportRecipeServiceError::NoRecipe
+        );
+        let no_ingredients = r#"<script type="application/ld+json">
+            {"@type": "Recipe", "name": "Air", "recipeIngredient": []}
+        </script>"#;
+        assert_eq!(
+            import(RecipeSource::Html(no_ingredients.into()))
+                .await
+                .unwrap_err(),
+            ImportRecipeServiceError::InvalidRecipe(InsertRecipeServiceError::NoIngredients)
+        );
+    }
+}
@@ -1,14 +1,38 @@
 use crate::services::recipes::{
-    domain::recipe::Recipe,
+    domain::{
+        access::RecipeAccess,
+        recipe::{Recipe, RecipeError},
+    },
     ports::{
         incoming::insert_recipe_service::{InsertRecipeService, InsertRecipeServiceError},
         outgoing::insert_recipe_port::InsertRecipePort,
     },
 };
+use crate::services::users::domain::caller::Caller;
 use async_trait::async_trait;
 
 use super::ports::outgoing::insert_recipe_port::InsertRecipeError;
 
+impl From<RecipeError> for InsertRecipeServiceError {
+    fn from(value: RecipeError) -> Self {
+        match value {
+            RecipeError::NoIngredients => InsertRecipeServiceError::NoIngredients,
+            RecipeError::InvalidServings => InsertRecipeServiceError::InvalidServings,
+            RecipeError::InvalidUnit { index, error } => InsertRecipeServiceError::InvalidUnit {
+                index,
+                unit: error.unit().to_string(),
+            },
+            RecipeError::InvalidTag { index, error } => InsertRecipeServiceError::InvalidTag {
+                index,
+                tag: error.tag().to_string(),
+            },
+            RecipeError::InvalidStep { index, error } => {
+                InsertRecipeServiceError::InvalidStep { index, error }
+            }
+        }
+    }
+}
+
 pub struct InsertRecipe<Storage>
 where
     Storage: InsertRecipePort + Sync + Send,
@@ -21,12 +45,23 @@ impl<Storage> InsertRecipeService for InsertRecipe<Storage>
 where
     Storage: InsertRecipePort + Sync + Send,
 {
-    async fn insert_recipe(&self, recipe: Recipe) -> Result<(), InsertRecipeServiceError> {
-        if recipe.ingredients().is_empty() {
-            return Err(InsertRecipeServiceError::NoIngredients);
+    async fn insert_recipe(
+        &self,
+        caller: Caller,
+        recipe: Recipe,
+    ) -> Result<(), InsertRecipeServiceError> {
+        let access = recipe.access();
+        let owner = access.owner().unwrap_or(caller.user());
+        if owner != caller.user() && !caller.is_admin() {
+            return Err(InsertRecipeServiceError::Forbidden);
         }
+        let recipe = recipe.with_access(RecipeAccess::new(Some(owner), access.visibility()));
+        let recipe = recipe.validated()?;
         match self.storage.insert_recipe(recipe).await {
             Ok(()) => Ok(()),
+            Err(InsertRecipeError::UnknownStepIngredient { step }) => {
+                Err(InsertRecipeServiceError::UnknownStepIngredient { index: step })
+            }
             Err(InsertRecipeError::InternalError) => Err(InsertRecipeServiceError::InternalError),
         }
     }
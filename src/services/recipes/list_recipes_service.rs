@@ -0,0 +1,154 @@
+use super::{
+    domain::{
+        filter::RecipeFilter,
+        pagination::{Page, PageRequest},
+        recipe::Recipe,
+    },
+    ports::{
+        incoming::list_recipes_service::{ListRecipesService, ListRecipesServiceError},
+        outgoing::list_recipes_port::{ListRecipesError, ListRecipesPort},
+    },
+};
+use crate::services::users::domain::caller::Caller;
+use async_trait::async_trait;
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+impl From<ListRecipesError> for ListRecipesServiceError {
+    fn from(value: ListRecipesError) -> Self {
+        match value {
+            ListRecipesError::InternalError => ListRecipesServiceError::InternalError,
+        }
+    }
+}
+
+pub struct ListRecipes<Storage>
+where
+    Storage: ListRecipesPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> ListRecipesService for ListRecipes<Storage>
+where
+    Storage: ListRecipesPort + Send + Sync,
+{
+    async fn list_recipes(
+        &self,
+        caller: Option<Caller>,
+        request: PageRequest,
+        filter: RecipeFilter,
+    ) -> Result<Page<Recipe>, ListRecipesServiceError> {
+        if request.page_size() == 0 || request.page_size() > MAX_PAGE_SIZE {
+            return Err(ListRecipesServiceError::InvalidPageSize);
+        }
+        let filter = filter
+            .normalized()
+            .map_err(|e| ListRecipesServiceError::InvalidTag(e.tag().to_string()))?;
+        // One extra row tells us whether a next page exists.
+        let mut recipes = self
+            .storage
+            .list_recipes(caller, request, &filter, request.page_size() + 1)
+            .await?;
+        let next_page = if recipes.len() > request.page_size() as usize {
+            recipes.truncate(request.page_size() as usize);
+            Some(request.page() + 1)
+        } else {
+            None
+        };
+        Ok(Page::new(
+            recipes,
+            request.page(),
+            request.page_size(),
+            next_page,
+        ))
+    }
+}
+
+impl<Storage> ListRecipes<Storage>
+where
+    Storage: ListRecipesPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::recipes::{
+            domain::{
+                access::{RecipeAccess, Visibility},
+                ingredient::Ingredient,
+                pagination::{SortBy, SortDirection},
+            },
+            ports::outgoing::insert_recipe_port::InsertRecipePort,
+        },
+    };
+
+    async fn storage(total: usize) -> RecipeSqliteDS {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        for i in 0..total {
+            let ingredient = Ingredient::new(uuid::Uuid::new_v4(), "salt".into(), 1.0, "g".into());
+            let recipe = Recipe::new(
+                uuid::Uuid::new_v4(),
+                format!("recipe {i}"),
+                String::new(),
+                vec![],
+                vec![ingredient],
+            )
+            .with_access(RecipeAccess::new(None, Visibility::Public));
+            storage.insert_recipe(recipe).await.unwrap();
+        }
+        storage
+    }
+
+    fn request(page: u32, page_size: u32) -> PageRequest {
+        PageRequest::new(page, page_size, SortBy::Name, SortDirection::Ascending)
+    }
+
+    #[tokio::test]
+    async fn next_page_is_set_only_when_more_recipes_exist() {
+        let service = ListRecipes::new(storage(5).await);
+
+        let first = service
+            .list_recipes(None, request(0, 2), RecipeFilter::default())
+            .await
+            .unwrap();
+        let names = first.items().iter().map(Recipe::name).collect::<Vec<_>>();
+        assert_eq!(names, ["recipe 0", "recipe 1"]);
+        assert!(first
+            .items()
+            .iter()
+            .all(|recipe| recipe.ingredients().is_empty()));
+        assert_eq!(first.next_page(), Some(1));
+
+        let last = service
+            .list_recipes(None, request(2, 2), RecipeFilter::default())
+            .await
+            .unwrap();
+        let names = last.items().iter().map(Recipe::name).collect::<Vec<_>>();
+        assert_eq!(names, ["recipe 4"]);
+        assert_eq!(last.next_page(), None);
+    }
+
+    #[tokio::test]
+    async fn page_size_out_of_bounds_is_rejected() {
+        let service = ListRecipes::new(storage(0).await);
+
+        for page_size in [0, MAX_PAGE_SIZE + 1] {
+            let result = service
+                .list_recipes(None, request(0, page_size), RecipeFilter::default())
+                .await;
+            assert_eq!(
+                result.unwrap_err(),
+                ListRecipesServiceError::InvalidPageSize
+            );
+        }
+    }
+}
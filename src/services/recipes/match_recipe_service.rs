@@ -0,0 +1,150 @@
+use std::cmp::Ordering;
+
+use super::{
+    domain::recipe_match::RecipeMatch,
+    list_recipes_service::MAX_PAGE_SIZE,
+    ports::{
+        incoming::match_recipe_service::{MatchRecipeService, MatchRecipeServiceError},
+        outgoing::match_recipe_port::{MatchRecipeError, MatchRecipePort},
+    },
+};
+use crate::services::users::domain::caller::Caller;
+use async_trait::async_trait;
+
+impl From<MatchRecipeError> for MatchRecipeServiceError {
+    fn from(value: MatchRecipeError) -> Self {
+        match value {
+            MatchRecipeError::InternalError => MatchRecipeServiceError::InternalError,
+        }
+    }
+}
+
+pub struct MatchRecipe<Storage>
+where
+    Storage: MatchRecipePort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> MatchRecipeService for MatchRecipe<Storage>
+where
+    Storage: MatchRecipePort + Send + Sync,
+{
+    async fn match_recipes(
+        &self,
+        caller: Option<Caller>,
+        ingredients: Vec<String>,
+        limit: u32,
+    ) -> Result<Vec<RecipeMatch>, MatchRecipeServiceError> {
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(MatchRecipeServiceError::InvalidLimit);
+        }
+        let mut names = ingredients
+            .iter()
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<String>>();
+        names.sort();
+        names.dedup();
+        if names.is_empty() {
+            return Err(MatchRecipeServiceError::NoIngredients);
+        }
+
+        let mut matches = self.storage.match_recipes(caller, names).await?;
+        matches.sort_by(|a, b| {
+            b.coverage()
+                .partial_cmp(&a.coverage())
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.missing().len().cmp(&b.missing().len()))
+                .then_with(|| a.recipe().name().cmp(b.recipe().name()))
+        });
+        matches.truncate(limit as usize);
+        Ok(matches)
+    }
+}
+
+impl<Storage> MatchRecipe<Storage>
+where
+    Storage: MatchRecipePort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::recipes::{
+            domain::{
+                access::{RecipeAccess, Visibility},
+                ingredient::Ingredient,
+                recipe::Recipe,
+            },
+            ports::outgoing::insert_recipe_port::InsertRecipePort,
+        },
+    };
+
+    async fn service(recipes: &[(&str, &[&str])]) -> MatchRecipe<RecipeSqliteDS> {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        for (name, ingredients) in recipes {
+            let recipe = Recipe::new(
+                uuid::Uuid::new_v4(),
+                name.to_string(),
+                String::new(),
+                vec![],
+                ingredients
+                    .iter()
+                    .map(|i| Ingredient::new(uuid::Uuid::new_v4(), i.to_string(), 1.0, "g".into()))
+                    .collect(),
+            )
+            .with_access(RecipeAccess::new(None, Visibility::Public));
+            storage.insert_recipe(recipe).await.unwrap();
+        }
+        MatchRecipe::new(storage)
+    }
+
+    #[tokio::test]
+    async fn recipes_are_ranked_by_coverage() {
+        let service = service(&[
+            ("Lasagna", &["pasta", "tomato", "cheese", "beef"]),
+            ("Garlic pasta", &["Pasta", "garlic"]),
+            ("Salad", &["lettuce"]),
+        ])
+        .await;
+
+        let matches = service
+            .match_recipes(
+                None,
+                vec![" Pasta ".into(), "garlic".into(), "tomato".into()],
+                10,
+            )
+            .await
+            .unwrap();
+
+        let names = matches
+            .iter()
+            .map(|m| m.recipe().name())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["Garlic pasta", "Lasagna"]);
+        assert_eq!(matches[0].coverage(), 1.0);
+        let mut missing = matches[1]
+            .missing()
+            .iter()
+            .map(|i| i.name())
+            .collect::<Vec<&str>>();
+        missing.sort();
+        assert_eq!(missing, vec!["beef", "cheese"]);
+    }
+
+    #[tokio::test]
+    async fn blank_ingredients_are_rejected() {
+        let service = service(&[]).await;
+
+        let result = service.match_recipes(None, vec!["  ".into()], 10).await;
+        assert_eq!(result.unwrap_err(), MatchRecipeServiceError::NoIngredients);
+    }
+}
@@ -1,6 +1,11 @@
 pub mod delete_recipe_service;
 pub mod domain;
+pub mod import_recipe_service;
 pub mod insert_recipe_service;
+pub mod list_recipes_service;
+pub mod match_recipe_service;
 pub mod ports;
 pub mod query_recipe_service;
+pub mod render_recipe_service;
+pub mod search_recipe_service;
 pub mod update_recipe_service;
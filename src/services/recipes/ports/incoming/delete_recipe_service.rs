@@ -2,14 +2,22 @@ use std::{error::Error, fmt::Display};
 
 use async_trait::async_trait;
 
+use crate::services::users::domain::caller::Caller;
+
 #[async_trait]
 pub trait DeleteRecipeService {
-    async fn delete_recipe(&self, uuid: uuid::Uuid) -> Result<(), DeleteRecipeServiceError>;
+    /// Deletes a recipe owned by `caller`, or any recipe for admins.
+    async fn delete_recipe(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), DeleteRecipeServiceError>;
 }
 
 #[derive(Debug, PartialEq)]
 pub enum DeleteRecipeServiceError {
     RecipeNotFound,
+    Forbidden,
     InternalError,
 }
 
@@ -17,6 +25,9 @@ impl Display for DeleteRecipeServiceError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DeleteRecipeServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            DeleteRecipeServiceError::Forbidden => {
+                f.write_str("You are not allowed to delete this recipe")
+            }
             DeleteRecipeServiceError::InternalError => f.write_str("Internal error"),
         }
     }
@@ -0,0 +1,58 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::{
+        domain::{access::Visibility, recipe::Recipe},
+        ports::{
+            incoming::insert_recipe_service::InsertRecipeServiceError,
+            outgoing::recipe_page_port::RecipePageError,
+        },
+    },
+    users::domain::caller::Caller,
+};
+
+/// Where the markup of an imported recipe comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecipeSource {
+    Html(String),
+    Url(String),
+}
+
+#[async_trait]
+pub trait ImportRecipeService {
+    /// Creates a recipe owned by `caller` from the schema.org `Recipe` markup
+    /// of a web page, and returns it as stored.
+    async fn import_recipe(
+        &self,
+        caller: Caller,
+        source: RecipeSource,
+        visibility: Visibility,
+    ) -> Result<Recipe, ImportRecipeServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ImportRecipeServiceError {
+    Page(RecipePageError),
+    NoRecipe,
+    /// The page has a recipe this service would not store, such as one
+    /// without ingredients.
+    InvalidRecipe(InsertRecipeServiceError),
+    InternalError,
+}
+
+impl Display for ImportRecipeServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportRecipeServiceError::Page(error) => write!(f, "{}", error),
+            ImportRecipeServiceError::NoRecipe => f.write_str("The page has no schema.org recipe"),
+            ImportRecipeServiceError::InvalidRecipe(error) => {
+                write!(f, "The recipe of the page is not valid: {}", error)
+            }
+            ImportRecipeServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+
+impl Error for ImportRecipeServiceError {}
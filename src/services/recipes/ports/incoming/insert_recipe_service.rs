@@ -2,26 +2,63 @@ use std::{error::Error, fmt::Display};
 
 use async_trait::async_trait;
 
-use crate::services::recipes::domain::recipe::Recipe;
+use crate::services::{
+    recipes::domain::{recipe::Recipe, step::StepError},
+    users::domain::caller::Caller,
+};
 
 #[async_trait]
 pub trait InsertRecipeService {
-    async fn insert_recipe(&self, recipe: Recipe) -> Result<(), InsertRecipeServiceError>;
+    /// Stores `recipe` as owned by its owner, or by `caller` when it has none.
+    /// Only admins may create recipes on behalf of someone else.
+    async fn insert_recipe(
+        &self,
+        caller: Caller,
+        recipe: Recipe,
+    ) -> Result<(), InsertRecipeServiceError>;
 }
 
 #[derive(Debug, PartialEq)]
 pub enum InsertRecipeServiceError {
     InternalError,
+    Forbidden,
     NoIngredients,
+    InvalidUnit { index: usize, unit: String },
+    InvalidTag { index: usize, tag: String },
+    InvalidStep { index: usize, error: StepError },
+    UnknownStepIngredient { index: usize },
+    InvalidServings,
 }
 
 impl Display for InsertRecipeServiceError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             InsertRecipeServiceError::InternalError => f.write_str("Internal error"),
+            InsertRecipeServiceError::Forbidden => {
+                f.write_str("Only admins can create recipes for other users")
+            }
             InsertRecipeServiceError::NoIngredients => {
                 f.write_str("A recipe creation must have ingredients")
             }
+            InsertRecipeServiceError::InvalidServings => {
+                f.write_str("A recipe must serve at least one person")
+            }
+            InsertRecipeServiceError::InvalidUnit { index, unit } => {
+                write!(f, "Unknown unit `{}` for ingredient {}", unit, index)
+            }
+            InsertRecipeServiceError::InvalidTag { index, tag } => {
+                write!(f, "Invalid tag `{}` at position {}", tag, index)
+            }
+            InsertRecipeServiceError::InvalidStep { index, error } => {
+                write!(f, "Invalid step {}: {}", index, error)
+            }
+            InsertRecipeServiceError::UnknownStepIngredient { index } => {
+                write!(
+                    f,
+                    "Step {} uses an ingredient the recipe does not have",
+                    index
+                )
+            }
         }
     }
 }
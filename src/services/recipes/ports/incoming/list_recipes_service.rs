@@ -0,0 +1,42 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::domain::{
+        filter::RecipeFilter,
+        pagination::{Page, PageRequest},
+        recipe::Recipe,
+    },
+    users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait ListRecipesService {
+    /// Lists a page of summaries of the recipes `caller` may view and `filter`
+    /// matches. Summaries carry no ingredients or steps.
+    async fn list_recipes(
+        &self,
+        caller: Option<Caller>,
+        request: PageRequest,
+        filter: RecipeFilter,
+    ) -> Result<Page<Recipe>, ListRecipesServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ListRecipesServiceError {
+    InvalidPageSize,
+    InvalidTag(String),
+    InternalError,
+}
+
+impl Display for ListRecipesServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListRecipesServiceError::InvalidPageSize => f.write_str("Invalid page size"),
+            ListRecipesServiceError::InvalidTag(tag) => write!(f, "Invalid tag `{}`", tag),
+            ListRecipesServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for ListRecipesServiceError {}
@@ -0,0 +1,35 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{recipes::domain::recipe_match::RecipeMatch, users::domain::caller::Caller};
+
+#[async_trait]
+pub trait MatchRecipeService {
+    async fn match_recipes(
+        &self,
+        caller: Option<Caller>,
+        ingredients: Vec<String>,
+        limit: u32,
+    ) -> Result<Vec<RecipeMatch>, MatchRecipeServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MatchRecipeServiceError {
+    NoIngredients,
+    InvalidLimit,
+    InternalError,
+}
+
+impl Display for MatchRecipeServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchRecipeServiceError::NoIngredients => {
+                f.write_str("At least one ingredient must be given")
+            }
+            MatchRecipeServiceError::InvalidLimit => f.write_str("Invalid limit"),
+            MatchRecipeServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for MatchRecipeServiceError {}
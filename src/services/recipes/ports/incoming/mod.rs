@@ -1,4 +1,9 @@
 pub mod delete_recipe_service;
+pub mod import_recipe_service;
 pub mod insert_recipe_service;
+pub mod list_recipes_service;
+pub mod match_recipe_service;
 pub mod query_recipe_service;
+pub mod render_recipe_service;
+pub mod search_recipe_service;
 pub mod update_recipe_service;
@@ -2,16 +2,28 @@ use std::{error::Error, fmt::Display};
 
 use async_trait::async_trait;
 
-use crate::services::recipes::domain::recipe::Recipe;
+use crate::services::{
+    recipes::domain::{recipe::Recipe, unit::UnitSystem},
+    users::domain::caller::Caller,
+};
 
 #[async_trait]
 pub trait QueryRecipeService {
-    async fn query_recipe(&self, uuid: uuid::Uuid) -> Result<Recipe, QueryRecipeServiceError>;
+    /// Fetches a recipe `caller` may view, scaled to `servings` portions when
+    /// given and with amounts expressed in `units`.
+    async fn query_recipe(
+        &self,
+        caller: Option<Caller>,
+        uuid: uuid::Uuid,
+        servings: Option<u32>,
+        units: UnitSystem,
+    ) -> Result<Recipe, QueryRecipeServiceError>;
 }
 
 #[derive(Debug, PartialEq)]
 pub enum QueryRecipeServiceError {
     RecipeNotFound,
+    InvalidServings,
     InternalError,
 }
 
@@ -19,6 +31,9 @@ impl Display for QueryRecipeServiceError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             QueryRecipeServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            QueryRecipeServiceError::InvalidServings => {
+                f.write_str("Servings must be at least one")
+            }
             QueryRecipeServiceError::InternalError => f.write_str("Internal error"),
         }
     }
@@ -0,0 +1,46 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::domain::{
+        recipe_card::{CardFormat, PaperSize},
+        unit::UnitSystem,
+    },
+    users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait RenderRecipeService {
+    /// A printable card of a recipe `caller` may view, scaled to `servings`
+    /// portions when given and with amounts expressed in `units`.
+    async fn render_recipe(
+        &self,
+        caller: Option<Caller>,
+        uuid: uuid::Uuid,
+        format: CardFormat,
+        servings: Option<u32>,
+        units: UnitSystem,
+        paper: PaperSize,
+    ) -> Result<Vec<u8>, RenderRecipeServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RenderRecipeServiceError {
+    RecipeNotFound,
+    InvalidServings,
+    InternalError,
+}
+
+impl Display for RenderRecipeServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderRecipeServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            RenderRecipeServiceError::InvalidServings => {
+                f.write_str("Servings must be at least one")
+            }
+            RenderRecipeServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for RenderRecipeServiceError {}
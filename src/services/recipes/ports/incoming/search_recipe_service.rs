@@ -0,0 +1,40 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::domain::{pagination::Page, search::RecipeSearchHit, tag::TagFilter},
+    users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait SearchRecipeService {
+    async fn search_recipes(
+        &self,
+        caller: Option<Caller>,
+        query: String,
+        tags: TagFilter,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Page<RecipeSearchHit>, SearchRecipeServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SearchRecipeServiceError {
+    EmptyQuery,
+    InvalidPageSize,
+    InvalidTag(String),
+    InternalError,
+}
+
+impl Display for SearchRecipeServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchRecipeServiceError::EmptyQuery => f.write_str("Search query is empty"),
+            SearchRecipeServiceError::InvalidPageSize => f.write_str("Invalid page size"),
+            SearchRecipeServiceError::InvalidTag(tag) => write!(f, "Invalid tag `{}`", tag),
+            SearchRecipeServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for SearchRecipeServiceError {}
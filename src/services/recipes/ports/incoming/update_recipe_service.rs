@@ -2,21 +2,42 @@ use std::{error::Error, fmt::Display};
 
 use async_trait::async_trait;
 
-use crate::services::recipes::domain::recipe::Recipe;
+use crate::services::{
+    recipes::domain::{
+        recipe::Recipe,
+        recipe_changes::RecipeChanges,
+        step::{Step, StepError},
+    },
+    users::domain::caller::Caller,
+};
 
 #[async_trait]
 pub trait UpdateRecipeService {
+    /// Updates a recipe owned by `caller`, or any recipe for admins. The
+    /// owner never changes, the `changes`, tags and steps only when given.
     async fn update_recipe(
         &self,
+        caller: Caller,
         recipe: Recipe,
+        changes: RecipeChanges,
         delete_ingredients: Vec<uuid::Uuid>,
-    ) -> Result<(), UpdateRecipeServiceError>;
+        tags: Option<Vec<String>>,
+        steps: Option<Vec<Step>>,
+    ) -> Result<Recipe, UpdateRecipeServiceError>;
 }
 
 #[derive(Debug, PartialEq)]
 pub enum UpdateRecipeServiceError {
     InternalError,
     RecipeNotFound,
+    Forbidden,
+    NoIngredients,
+    ForeignIngredient { index: usize },
+    InvalidUnit { index: usize, unit: String },
+    InvalidTag { index: usize, tag: String },
+    InvalidStep { index: usize, error: StepError },
+    UnknownStepIngredient { index: usize },
+    InvalidServings,
 }
 
 impl Display for UpdateRecipeServiceError {
@@ -24,6 +45,34 @@ impl Display for UpdateRecipeServiceError {
         match self {
             UpdateRecipeServiceError::InternalError => f.write_str("Internal error"),
             UpdateRecipeServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            UpdateRecipeServiceError::Forbidden => {
+                f.write_str("You are not allowed to change this recipe")
+            }
+            UpdateRecipeServiceError::NoIngredients => {
+                f.write_str("A recipe update must leave at least one ingredient")
+            }
+            UpdateRecipeServiceError::ForeignIngredient { index } => {
+                write!(f, "Ingredient {} belongs to another recipe", index)
+            }
+            UpdateRecipeServiceError::InvalidServings => {
+                f.write_str("A recipe must serve at least one person")
+            }
+            UpdateRecipeServiceError::InvalidUnit { index, unit } => {
+                write!(f, "Unknown unit `{}` for ingredient {}", unit, index)
+            }
+            UpdateRecipeServiceError::InvalidTag { index, tag } => {
+                write!(f, "Invalid tag `{}` at position {}", tag, index)
+            }
+            UpdateRecipeServiceError::InvalidStep { index, error } => {
+                write!(f, "Invalid step {}: {}", index, error)
+            }
+            UpdateRecipeServiceError::UnknownStepIngredient { index } => {
+                write!(
+                    f,
+                    "Step {} uses an ingredient the recipe does not have",
+                    index
+                )
+            }
         }
     }
 }
@@ -6,17 +6,25 @@ use crate::services::recipes::domain::recipe::Recipe;
 // #[automock(type Index = i64;)]
 #[async_trait]
 pub trait InsertRecipePort {
+    /// Steps may only use ingredients of the recipe.
     async fn insert_recipe(&self, recipe: Recipe) -> Result<(), InsertRecipeError>;
 }
 
 #[derive(Debug)]
 pub enum InsertRecipeError {
+    /// A step uses an ingredient the recipe does not have.
+    UnknownStepIngredient {
+        step: usize,
+    },
     InternalError,
 }
 
 impl Display for InsertRecipeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::UnknownStepIngredient { step } => {
+                write!(f, "Step {} uses an unknown ingredient", step)
+            }
             Self::InternalError => write!(f, "Internal error"),
         }
     }
@@ -0,0 +1,33 @@
+use crate::services::recipes::domain::{
+    filter::RecipeFilter, pagination::PageRequest, recipe::Recipe,
+};
+use crate::services::users::domain::caller::Caller;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait ListRecipesPort {
+    /// Returns at most `limit` of the recipes `caller` may view and `filter`
+    /// matches, ordered and offset as described by `request`.
+    async fn list_recipes(
+        &self,
+        caller: Option<Caller>,
+        request: PageRequest,
+        filter: &RecipeFilter,
+        limit: u32,
+    ) -> Result<Vec<Recipe>, ListRecipesError>;
+}
+
+#[derive(Debug)]
+pub enum ListRecipesError {
+    InternalError,
+}
+
+impl Display for ListRecipesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for ListRecipesError {}
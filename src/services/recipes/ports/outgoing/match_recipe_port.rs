@@ -0,0 +1,29 @@
+use crate::services::recipes::domain::recipe_match::RecipeMatch;
+use crate::services::users::domain::caller::Caller;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait MatchRecipePort {
+    /// Returns every recipe `caller` may view using at least one of the
+    /// normalised ingredient `names`, unordered.
+    async fn match_recipes(
+        &self,
+        caller: Option<Caller>,
+        names: Vec<String>,
+    ) -> Result<Vec<RecipeMatch>, MatchRecipeError>;
+}
+
+#[derive(Debug)]
+pub enum MatchRecipeError {
+    InternalError,
+}
+
+impl Display for MatchRecipeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for MatchRecipeError {}
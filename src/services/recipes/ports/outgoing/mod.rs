@@ -1,4 +1,9 @@
 pub mod delete_recipe_port;
 pub mod insert_recipe_port;
+pub mod list_recipes_port;
+pub mod match_recipe_port;
 pub mod query_recipe_port;
+pub mod recipe_access_port;
+pub mod recipe_page_port;
+pub mod search_recipe_port;
 pub mod update_recipe_port;
@@ -0,0 +1,26 @@
+use crate::services::recipes::domain::access::RecipeAccess;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+/// Looks up who owns a recipe without loading the whole of it.
+#[async_trait]
+pub trait RecipeAccessPort {
+    async fn recipe_access(&self, uuid: uuid::Uuid) -> Result<RecipeAccess, RecipeAccessError>;
+}
+
+#[derive(Debug)]
+pub enum RecipeAccessError {
+    RecordNotFound,
+    InternalError,
+}
+
+impl Display for RecipeAccessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecordNotFound => write!(f, "Record not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for RecipeAccessError {}
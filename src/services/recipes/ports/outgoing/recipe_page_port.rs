@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait RecipePagePort {
+    /// The HTML of the web page at `url`.
+    async fn fetch_page(&self, url: &str) -> Result<String, RecipePageError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RecipePageError {
+    /// Not an absolute http or https URL.
+    InvalidUrl,
+    /// The URL, or one it redirects to, points to the loopback, a private or
+    /// a link-local network.
+    PrivateAddress,
+    /// The page could not be loaded, or was not a successful response.
+    Unreachable,
+    TooLarge,
+}
+
+impl Display for RecipePageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUrl => write!(f, "Only http and https URLs can be imported"),
+            Self::PrivateAddress => write!(f, "Pages on private networks cannot be imported"),
+            Self::Unreachable => write!(f, "The page could not be loaded"),
+            Self::TooLarge => write!(f, "The page is too large"),
+        }
+    }
+}
+
+impl Error for RecipePageError {}
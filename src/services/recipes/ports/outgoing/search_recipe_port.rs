@@ -0,0 +1,32 @@
+use crate::services::recipes::domain::{search::RecipeSearchHit, tag::TagFilter};
+use crate::services::users::domain::caller::Caller;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait SearchRecipePort {
+    /// Returns at most `limit` hits for the free text `terms` among the
+    /// recipes `caller` may view and `tags` matches, best match first.
+    async fn search_recipes(
+        &self,
+        caller: Option<Caller>,
+        terms: Vec<String>,
+        tags: &TagFilter,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<RecipeSearchHit>, SearchRecipeError>;
+}
+
+#[derive(Debug)]
+pub enum SearchRecipeError {
+    InternalError,
+}
+
+impl Display for SearchRecipeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for SearchRecipeError {}
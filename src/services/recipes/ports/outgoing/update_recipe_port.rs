@@ -1,18 +1,34 @@
-use crate::services::recipes::domain::recipe::Recipe;
+use crate::services::recipes::domain::{recipe::Recipe, recipe_changes::RecipeChanges, step::Step};
 use async_trait::async_trait;
 use std::{error::Error, fmt::Display};
 #[async_trait]
 pub trait UpdateRecipePort {
+    /// Sets the name, image and ingredients of `recipe` and the `changes`
+    /// given. Replaces the tags and steps of the recipe unless they are `None`.
+    /// Steps may only use ingredients the recipe has after the update, the
+    /// ingredients of the recipe only uuids no other recipe uses.
     async fn update_recipe(
         &self,
         recipe: Recipe,
+        changes: RecipeChanges,
         deleted_ingredients: Vec<uuid::Uuid>,
-    ) -> Result<(), UpdateRecipeError>;
+        tags: Option<Vec<String>>,
+        steps: Option<Vec<Step>>,
+    ) -> Result<Recipe, UpdateRecipeError>;
 }
 
 #[derive(Debug)]
 pub enum UpdateRecipeError {
     RecordNotFound,
+    NoIngredients,
+    /// An updated ingredient belongs to another recipe.
+    ForeignIngredient {
+        index: usize,
+    },
+    /// A step uses an ingredient the recipe does not have.
+    UnknownStepIngredient {
+        step: usize,
+    },
     InternalError,
 }
 
@@ -20,6 +36,13 @@ impl Display for UpdateRecipeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::RecordNotFound => write!(f, "Record not found"),
+            Self::NoIngredients => write!(f, "Record has no ingredients"),
+            Self::ForeignIngredient { index } => {
+                write!(f, "Ingredient {} belongs to another recipe", index)
+            }
+            Self::UnknownStepIngredient { step } => {
+                write!(f, "Step {} uses an unknown ingredient", step)
+            }
             Self::InternalError => write!(f, "Internal error"),
         }
     }
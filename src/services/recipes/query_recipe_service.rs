@@ -1,10 +1,11 @@
 use super::{
-    domain::recipe::Recipe,
+    domain::{recipe::Recipe, unit::UnitSystem},
     ports::{
         incoming::query_recipe_service::{QueryRecipeService, QueryRecipeServiceError},
         outgoing::query_recipe_port::{QueryRecipeError, QueryRecipePort},
     },
 };
+use crate::services::users::domain::caller::Caller;
 use async_trait::async_trait;
 impl From<QueryRecipeError> for QueryRecipeServiceError {
     fn from(value: QueryRecipeError) -> Self {
@@ -27,11 +28,28 @@ impl<Storage> QueryRecipeService for QueryRecipe<Storage>
 where
     Storage: QueryRecipePort + Send + Sync,
 {
-    async fn query_recipe(&self, uuid: uuid::Uuid) -> Result<Recipe, QueryRecipeServiceError> {
-        self.storage
-            .query_recipe(uuid)
-            .await
-            .map_err(|err| err.into())
+    async fn query_recipe(
+        &self,
+        caller: Option<Caller>,
+        uuid: uuid::Uuid,
+        servings: Option<u32>,
+        units: UnitSystem,
+    ) -> Result<Recipe, QueryRecipeServiceError> {
+        if servings == Some(0) {
+            return Err(QueryRecipeServiceError::InvalidServings);
+        }
+        let recipe = self.storage.query_recipe(uuid).await?;
+        if !recipe.access().can_view(caller.as_ref()) {
+            return Err(QueryRecipeServiceError::RecipeNotFound);
+        }
+        if servings.is_none() && units == UnitSystem::Original {
+            return Ok(recipe);
+        }
+        let recipe = match servings {
+            Some(servings) => recipe.scale(servings),
+            None => recipe,
+        };
+        Ok(recipe.convert_units(units).to_kitchen())
     }
 }
 
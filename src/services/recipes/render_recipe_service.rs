@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use tracing::info;
+
+use super::{
+    domain::{
+        access::Visibility,
+        recipe::Recipe,
+        recipe_card::{CardFormat, PaperSize, RecipeCard},
+        recipe_card_html::render_html,
+        recipe_card_pdf::{render_pdf, CardImage},
+        unit::UnitSystem,
+    },
+    ports::{
+        incoming::{
+            query_recipe_service::{QueryRecipeService, QueryRecipeServiceError},
+            render_recipe_service::{RenderRecipeService, RenderRecipeServiceError},
+        },
+        outgoing::query_recipe_port::QueryRecipePort,
+    },
+    query_recipe_service::QueryRecipe,
+};
+use crate::services::{
+    images::{domain::image::ImageName, ports::outgoing::image_storage_port::ImageStoragePort},
+    users::domain::caller::Caller,
+};
+
+/// Width of the image variant a printed page asks for.
+const CARD_IMAGE_SIZE: u32 = 800;
+
+impl From<QueryRecipeServiceError> for RenderRecipeServiceError {
+    fn from(value: QueryRecipeServiceError) -> Self {
+        match value {
+            QueryRecipeServiceError::RecipeNotFound => RenderRecipeServiceError::RecipeNotFound,
+            QueryRecipeServiceError::InvalidServings => RenderRecipeServiceError::InvalidServings,
+            QueryRecipeServiceError::InternalError => RenderRecipeServiceError::InternalError,
+        }
+    }
+}
+
+pub struct RenderRecipe<Storage, Images>
+where
+    Storage: QueryRecipePort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    query: QueryRecipe<Storage>,
+    images: Images,
+}
+
+impl<Storage, Images> RenderRecipe<Storage, Images>
+where
+    Storage: QueryRecipePort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    pub fn new(storage: Storage, images: Images) -> Self {
+        Self {
+            query: QueryRecipe::new(storage),
+            images,
+        }
+    }
+
+    /// Where the browser loads the image of `recipe` from. The image endpoint
+    /// only serves anonymous browsers the images of public recipes, the others
+    /// are embedded as they are uploaded.
+    async fn card_image_src(&self, recipe: &Recipe) -> Option<String> {
+        match ImageName::parse(recipe.image()) {
+            Some(_) if recipe.access().visibility() == Visibility::Public => Some(format!(
+                "/api/v1/recipes/{}/image?size={}",
+                recipe.uuid(),
+                CARD_IMAGE_SIZE
+            )),
+            Some(name) => {
+                let image = self.images.load_image(&name).await.ok()?;
+                Some(format!(
+                    "data:{};base64,{}",
+                    image.format().mime(),
+                    STANDARD.encode(image.bytes())
+                ))
+            }
+            None => Some(recipe.image().to_string()).filter(|image| image.starts_with("http")),
+        }
+    }
+
+    /// The uploaded image of `recipe`, ready to embed. Cards of recipes whose
+    /// image is missing, undecodable or elsewhere on the web go without.
+    async fn card_image(&self, recipe: &Recipe) -> Option<CardImage> {
+        let name = ImageName::parse(recipe.image())?;
+        let image = self.images.load_image(&name).await.ok()?;
+        // Decoding is CPU bound.
+        tokio::task::spawn_blocking(move || CardImage::decode(image.bytes()))
+            .await
+            .map_err(|e| info!("{}", e))
+            .ok()
+            .flatten()
+    }
+}
+
+#[async_trait]
+impl<Storage, Images> RenderRecipeService for RenderRecipe<Storage, Images>
+where
+    Storage: QueryRecipePort + Sync + Send,
+    Images: ImageStoragePort + Sync + Send,
+{
+    async fn render_recipe(
+        &self,
+        caller: Option<Caller>,
+        uuid: uuid::Uuid,
+        format: CardFormat,
+        servings: Option<u32>,
+        units: UnitSystem,
+        paper: PaperSize,
+    ) -> Result<Vec<u8>, RenderRecipeServiceError> {
+        let recipe = self
+            .query
+            .query_recipe(caller, uuid, servings, units)
+            .await?;
+        let card = RecipeCard::new(&recipe, paper);
+        match format {
+            CardFormat::Html => {
+                let image = self.card_image_src(&recipe).await;
+                render_html(&card, image.as_deref())
+                    .map(String::into_bytes)
+                    .map_err(|e| {
+                        info!("{}", e);
+                        RenderRecipeServiceError::InternalError
+                    })
+            }
+            CardFormat::Pdf => {
+                let image = self.card_image(&recipe).await;
+                Ok(render_pdf(&card, image.as_ref()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::{
+            images::{
+                domain::image::{Image, ImageFormat},
+                ports::outgoing::image_storage_port::ImageStorageError,
+            },
+            recipes::{
+                domain::{
+                    access::{RecipeAccess, Visibility},
+                    ingredient::Ingredient,
+                },
+                ports::outgoing::insert_recipe_port::InsertRecipePort,
+            },
+            users::domain::caller::Role,
+        },
+    };
+
+    struct StubImages(Image);
+
+    #[async_trait]
+    impl ImageStoragePort for StubImages {
+        async fn store_image(&self, _image: &Image) -> Result<(), ImageStorageError> {
+            Ok(())
+        }
+
+        async fn load_image(&self, name: &ImageName) -> Result<Image, ImageStorageError> {
+            match name == self.0.name() {
+                true => Ok(self.0.clone()),
+                false => Err(ImageStorageError::NotFound),
+            }
+        }
+
+        async fn remove_image(&self, _name: &ImageName) -> Result<(), ImageStorageError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn cards_are_scaled_and_show_the_uploaded_image() {
+        let png = {
+            let mut png = std::io::Cursor::new(vec![]);
+            image::RgbImage::new(3, 3)
+                .write_to(&mut png, image::ImageFormat::Png)
+                .unwrap();
+            png.into_inner()
+        };
+        let image = Image::new(ImageName::for_content(&png, ImageFormat::Png), png);
+        let owner = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Bread".into(),
+            image.name().as_str().to_string(),
+            vec![],
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "flour".into(),
+                250.0,
+                "g".into(),
+            )],
+        )
+        .with_servings(2)
+        .with_access(RecipeAccess::new(Some(owner.user()), Visibility::Private));
+        let uuid = recipe.uuid();
+        let recipes = RecipeSqliteDS::new(memory_pool().await);
+        recipes.insert_recipe(recipe).await.unwrap();
+        let service = RenderRecipe::new(recipes, StubImages(image));
+        let render = |caller, format| {
+            service.render_recipe(
+                caller,
+                uuid,
+                format,
+                Some(4),
+                UnitSystem::Metric,
+                PaperSize::A4,
+            )
+        };
+
+        let html = String::from_utf8(render(Some(owner), CardFormat::Html).await.unwrap()).unwrap();
+        assert!(html.contains("<li>500 g flour</li>"));
+        assert!(html.contains("<img src=\"data:image/png;base64,"));
+        let pdf = render(Some(owner), CardFormat::Pdf).await.unwrap();
+        assert!(pdf.windows(7).any(|window| window == b"/Im1 Do"));
+        assert_eq!(
+            render(None, CardFormat::Pdf).await.unwrap_err(),
+            RenderRecipeServiceError::RecipeNotFound
+        );
+    }
+}
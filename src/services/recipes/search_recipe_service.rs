@@ -0,0 +1,144 @@
+use super::{
+    domain::{pagination::Page, search::RecipeSearchHit, tag::TagFilter},
+    list_recipes_service::MAX_PAGE_SIZE,
+    ports::{
+        incoming::search_recipe_service::{SearchRecipeService, SearchRecipeServiceError},
+        outgoing::search_recipe_port::{SearchRecipeError, SearchRecipePort},
+    },
+};
+use crate::services::users::domain::caller::Caller;
+use async_trait::async_trait;
+
+impl From<SearchRecipeError> for SearchRecipeServiceError {
+    fn from(value: SearchRecipeError) -> Self {
+        match value {
+            SearchRecipeError::InternalError => SearchRecipeServiceError::InternalError,
+        }
+    }
+}
+
+pub struct SearchRecipe<Storage>
+where
+    Storage: SearchRecipePort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> SearchRecipeService for SearchRecipe<Storage>
+where
+    Storage: SearchRecipePort + Send + Sync,
+{
+    async fn search_recipes(
+        &self,
+        caller: Option<Caller>,
+        query: String,
+        tags: TagFilter,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Page<RecipeSearchHit>, SearchRecipeServiceError> {
+        if page_size == 0 || page_size > MAX_PAGE_SIZE {
+            return Err(SearchRecipeServiceError::InvalidPageSize);
+        }
+        let terms = query
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<String>>();
+        if terms.is_empty() {
+            return Err(SearchRecipeServiceError::EmptyQuery);
+        }
+        let tags = tags
+            .normalized()
+            .map_err(|e| SearchRecipeServiceError::InvalidTag(e.tag().to_string()))?;
+
+        let offset = u64::from(page) * u64::from(page_size);
+        let mut hits = self
+            .storage
+            .search_recipes(caller, terms, &tags, offset, page_size + 1)
+            .await?;
+        let next_page = if hits.len() > page_size as usize {
+            hits.truncate(page_size as usize);
+            Some(page + 1)
+        } else {
+            None
+        };
+        Ok(Page::new(hits, page, page_size, next_page))
+    }
+}
+
+impl<Storage> SearchRecipe<Storage>
+where
+    Storage: SearchRecipePort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::recipes::{
+            domain::{
+                access::{RecipeAccess, Visibility},
+                ingredient::Ingredient,
+                recipe::Recipe,
+            },
+            ports::outgoing::insert_recipe_port::InsertRecipePort,
+        },
+    };
+
+    async fn service(names: &[&str]) -> SearchRecipe<RecipeSqliteDS> {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        for name in names {
+            let ingredient = Ingredient::new(uuid::Uuid::new_v4(), "salt".into(), 1.0, "g".into());
+            let recipe = Recipe::new(
+                uuid::Uuid::new_v4(),
+                name.to_string(),
+                String::new(),
+                vec![],
+                vec![ingredient],
+            )
+            .with_access(RecipeAccess::new(None, Visibility::Public));
+            storage.insert_recipe(recipe).await.unwrap();
+        }
+        SearchRecipe::new(storage)
+    }
+
+    #[tokio::test]
+    async fn queries_without_terms_are_rejected() {
+        let service = service(&["Pea soup"]).await;
+
+        for query in ["", "  ", "\"*()"] {
+            let result = service
+                .search_recipes(None, query.into(), TagFilter::default(), 0, 10)
+                .await;
+            assert_eq!(result.unwrap_err(), SearchRecipeServiceError::EmptyQuery);
+        }
+    }
+
+    #[tokio::test]
+    async fn hits_are_paginated() {
+        let service = service(&["Pea soup", "Leek soup", "Onion soup", "Bread"]).await;
+        let search =
+            |page| service.search_recipes(None, "soup".into(), TagFilter::default(), page, 2);
+
+        let first = search(0).await.unwrap();
+        assert_eq!(first.items().len(), 2);
+        assert_eq!(first.next_page(), Some(1));
+        let last = search(1).await.unwrap();
+        assert_eq!(last.items().len(), 1);
+        assert_eq!(last.next_page(), None);
+        let mut names = first
+            .items()
+            .iter()
+            .chain(last.items())
+            .map(|hit| hit.recipe().name())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["Leek soup", "Onion soup", "Pea soup"]);
+    }
+}
@@ -1,15 +1,49 @@
 use super::{
-    domain::recipe::Recipe,
+    domain::{
+        recipe::Recipe,
+        recipe_changes::RecipeChanges,
+        step::{normalize_steps, Step},
+        tag::normalize_tags,
+    },
     ports::{
         incoming::update_recipe_service::{UpdateRecipeService, UpdateRecipeServiceError},
-        outgoing::update_recipe_port::UpdateRecipePort,
+        outgoing::{
+            recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+            update_recipe_port::{UpdateRecipeError, UpdateRecipePort},
+        },
     },
 };
+use crate::services::users::domain::caller::Caller;
 use async_trait::async_trait;
 
+impl From<UpdateRecipeError> for UpdateRecipeServiceError {
+    fn from(value: UpdateRecipeError) -> Self {
+        match value {
+            UpdateRecipeError::RecordNotFound => UpdateRecipeServiceError::RecipeNotFound,
+            UpdateRecipeError::NoIngredients => UpdateRecipeServiceError::NoIngredients,
+            UpdateRecipeError::ForeignIngredient { index } => {
+                UpdateRecipeServiceError::ForeignIngredient { index }
+            }
+            UpdateRecipeError::UnknownStepIngredient { step } => {
+                UpdateRecipeServiceError::UnknownStepIngredient { index: step }
+            }
+            UpdateRecipeError::InternalError => UpdateRecipeServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for UpdateRecipeServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => UpdateRecipeServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => UpdateRecipeServiceError::InternalError,
+        }
+    }
+}
+
 pub struct UpdateRecipe<Storage>
 where
-    Storage: UpdateRecipePort + Sync + Send,
+    Storage: UpdateRecipePort + RecipeAccessPort + Sync + Send,
 {
     storage: Storage,
 }
@@ -17,16 +51,54 @@ where
 #[async_trait]
 impl<Storage> UpdateRecipeService for UpdateRecipe<Storage>
 where
-    Storage: UpdateRecipePort + Sync + Send,
+    Storage: UpdateRecipePort + RecipeAccessPort + Sync + Send,
 {
-    async fn update_recipe(&self, _recipe: Recipe) -> Result<(), UpdateRecipeServiceError> {
-        Ok(())
+    async fn update_recipe(
+        &self,
+        caller: Caller,
+        recipe: Recipe,
+        changes: RecipeChanges,
+        delete_ingredients: Vec<uuid::Uuid>,
+        tags: Option<Vec<String>>,
+        steps: Option<Vec<Step>>,
+    ) -> Result<Recipe, UpdateRecipeServiceError> {
+        let access = self.storage.recipe_access(recipe.uuid()).await?;
+        if !access.can_view(Some(&caller)) {
+            return Err(UpdateRecipeServiceError::RecipeNotFound);
+        }
+        if !access.can_modify(&caller) {
+            return Err(UpdateRecipeServiceError::Forbidden);
+        }
+        if changes.servings() == Some(0) {
+            return Err(UpdateRecipeServiceError::InvalidServings);
+        }
+        let recipe = recipe.normalize_units().map_err(|(index, e)| {
+            UpdateRecipeServiceError::InvalidUnit {
+                index,
+                unit: e.unit().to_string(),
+            }
+        })?;
+        let tags = tags
+            .map(|tags| normalize_tags(&tags))
+            .transpose()
+            .map_err(|(index, e)| UpdateRecipeServiceError::InvalidTag {
+                index,
+                tag: e.tag().to_string(),
+            })?;
+        let steps = steps
+            .map(normalize_steps)
+            .transpose()
+            .map_err(|(index, error)| UpdateRecipeServiceError::InvalidStep { index, error })?;
+        self.storage
+            .update_recipe(recipe, changes, delete_ingredients, tags, steps)
+            .await
+            .map_err(|err| err.into())
     }
 }
 
 impl<Storage> UpdateRecipe<Storage>
 where
-    Storage: UpdateRecipePort + Sync + Send,
+    Storage: UpdateRecipePort + RecipeAccessPort + Sync + Send,
 {
     pub fn new(storage: Storage) -> Self {
         Self { storage }
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+
+use super::ports::{
+    incoming::delete_review_service::{DeleteReviewService, DeleteReviewServiceError},
+    outgoing::{
+        delete_review_port::{DeleteReviewError, DeleteReviewPort},
+        query_review_port::{QueryReviewError, QueryReviewPort},
+    },
+};
+use crate::services::users::domain::caller::Caller;
+
+impl From<QueryReviewError> for DeleteReviewServiceError {
+    fn from(value: QueryReviewError) -> Self {
+        match value {
+            QueryReviewError::RecordNotFound => DeleteReviewServiceError::ReviewNotFound,
+            QueryReviewError::InternalError => DeleteReviewServiceError::InternalError,
+        }
+    }
+}
+
+impl From<DeleteReviewError> for DeleteReviewServiceError {
+    fn from(value: DeleteReviewError) -> Self {
+        match value {
+            DeleteReviewError::RecordNotFound => DeleteReviewServiceError::ReviewNotFound,
+            DeleteReviewError::InternalError => DeleteReviewServiceError::InternalError,
+        }
+    }
+}
+
+pub struct DeleteReview<Storage>
+where
+    Storage: DeleteReviewPort + QueryReviewPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> DeleteReviewService for DeleteReview<Storage>
+where
+    Storage: DeleteReviewPort + QueryReviewPort + Send + Sync,
+{
+    async fn delete_review(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), DeleteReviewServiceError> {
+        let review = self.storage.query_review(uuid).await?;
+        if review.author() != caller.user() && !caller.is_admin() {
+            return Err(DeleteReviewServiceError::Forbidden);
+        }
+        Ok(self.storage.delete_review(uuid).await?)
+    }
+}
+
+impl<Storage> DeleteReview<Storage>
+where
+    Storage: DeleteReviewPort + QueryReviewPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
@@ -0,0 +1,157 @@
+use std::{error::Error, fmt::Display};
+
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+pub const MAX_REVIEW_LENGTH: usize = 4000;
+
+/// A user's star rating of a recipe, with an optional text. Users review a
+/// recipe at most once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Review {
+    uuid: uuid::Uuid,
+    recipe: uuid::Uuid,
+    author: uuid::Uuid,
+    rating: u8,
+    text: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl Review {
+    pub fn new(
+        uuid: uuid::Uuid,
+        recipe: uuid::Uuid,
+        author: uuid::Uuid,
+        rating: u8,
+        text: Option<String>,
+    ) -> Self {
+        Self {
+            uuid,
+            recipe,
+            author,
+            rating,
+            text,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    /// Set by storage, empty for reviews that were never stored.
+    pub fn with_timestamps(self, created_at: String, updated_at: String) -> Self {
+        Self {
+            created_at,
+            updated_at,
+            ..self
+        }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn recipe(&self) -> uuid::Uuid {
+        self.recipe
+    }
+
+    pub fn author(&self) -> uuid::Uuid {
+        self.author
+    }
+
+    pub fn rating(&self) -> u8 {
+        self.rating
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn created_at(&self) -> &str {
+        self.created_at.as_ref()
+    }
+
+    pub fn updated_at(&self) -> &str {
+        self.updated_at.as_ref()
+    }
+
+    /// Checks the rating and trims the text, dropping a blank one.
+    pub fn normalized(self) -> Result<Self, ReviewError> {
+        if !(MIN_RATING..=MAX_RATING).contains(&self.rating) {
+            return Err(ReviewError::InvalidRating);
+        }
+        let text = self
+            .text
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty());
+        if text
+            .as_ref()
+            .is_some_and(|text| text.chars().count() > MAX_REVIEW_LENGTH)
+        {
+            return Err(ReviewError::TextTooLong);
+        }
+        Ok(Self { text, ..self })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReviewError {
+    InvalidRating,
+    TextTooLong,
+}
+
+impl Display for ReviewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewError::InvalidRating => {
+                write!(f, "Ratings go from {} to {} stars", MIN_RATING, MAX_RATING)
+            }
+            ReviewError::TextTooLong => write!(
+                f,
+                "Reviews are at most {} characters long",
+                MAX_REVIEW_LENGTH
+            ),
+        }
+    }
+}
+
+impl Error for ReviewError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review(rating: u8, text: Option<&str>) -> Review {
+        Review::new(
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+            rating,
+            text.map(str::to_string),
+        )
+    }
+
+    #[test]
+    fn ratings_go_from_one_to_five_stars() {
+        assert_eq!(
+            review(0, None).normalized(),
+            Err(ReviewError::InvalidRating)
+        );
+        assert_eq!(
+            review(6, None).normalized(),
+            Err(ReviewError::InvalidRating)
+        );
+        assert!(review(1, None).normalized().is_ok());
+        assert!(review(5, None).normalized().is_ok());
+    }
+
+    #[test]
+    fn blank_texts_are_dropped() {
+        let normalized = review(3, Some("  ")).normalized().unwrap();
+        assert_eq!(normalized.text(), None);
+        let normalized = review(3, Some(" Lovely ")).normalized().unwrap();
+        assert_eq!(normalized.text(), Some("Lovely"));
+        assert_eq!(
+            review(3, Some(&"x".repeat(MAX_REVIEW_LENGTH + 1))).normalized(),
+            Err(ReviewError::TextTooLong)
+        );
+    }
+}
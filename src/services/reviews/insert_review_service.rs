@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::review::Review,
+    ports::{
+        incoming::insert_review_service::{InsertReviewService, InsertReviewServiceError},
+        outgoing::insert_review_port::{InsertReviewError, InsertReviewPort},
+    },
+};
+use crate::services::{
+    recipes::ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    users::domain::caller::Caller,
+};
+
+impl From<InsertReviewError> for InsertReviewServiceError {
+    fn from(value: InsertReviewError) -> Self {
+        match value {
+            InsertReviewError::ReviewExists => InsertReviewServiceError::AlreadyReviewed,
+            InsertReviewError::RecipeNotFound => InsertReviewServiceError::RecipeNotFound,
+            InsertReviewError::InternalError => InsertReviewServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for InsertReviewServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => InsertReviewServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => InsertReviewServiceError::InternalError,
+        }
+    }
+}
+
+pub struct InsertReview<Storage, Recipes>
+where
+    Storage: InsertReviewPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+}
+
+#[async_trait]
+impl<Storage, Recipes> InsertReviewService for InsertReview<Storage, Recipes>
+where
+    Storage: InsertReviewPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    async fn insert_review(
+        &self,
+        caller: Caller,
+        review: Review,
+    ) -> Result<Review, InsertReviewServiceError> {
+        let review = review
+            .normalized()
+            .map_err(InsertReviewServiceError::InvalidReview)?;
+        let access = self.recipes.recipe_access(review.recipe()).await?;
+        if !access.can_view(Some(&caller)) {
+            return Err(InsertReviewServiceError::RecipeNotFound);
+        }
+        let review = Review::new(
+            review.uuid(),
+            review.recipe(),
+            caller.user(),
+            review.rating(),
+            review.text().map(str::to_string),
+        );
+        Ok(self.storage.insert_review(review).await?)
+    }
+}
+
+impl<Storage, Recipes> InsertReview<Storage, Recipes>
+where
+    Storage: InsertReviewPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes) -> Self {
+        Self { storage, recipes }
+    }
+}
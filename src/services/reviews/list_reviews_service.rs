@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::review::Review,
+    ports::{
+        incoming::list_reviews_service::{ListReviewsService, ListReviewsServiceError},
+        outgoing::list_reviews_port::{ListReviewsError, ListReviewsPort},
+    },
+};
+use crate::services::{
+    recipes::{
+        domain::pagination::Page,
+        list_recipes_service::MAX_PAGE_SIZE,
+        ports::outgoing::recipe_access_port::{RecipeAccessError, RecipeAccessPort},
+    },
+    users::domain::caller::Caller,
+};
+
+impl From<ListReviewsError> for ListReviewsServiceError {
+    fn from(value: ListReviewsError) -> Self {
+        match value {
+            ListReviewsError::InternalError => ListReviewsServiceError::InternalError,
+        }
+    }
+}
+
+impl From<RecipeAccessError> for ListReviewsServiceError {
+    fn from(value: RecipeAccessError) -> Self {
+        match value {
+            RecipeAccessError::RecordNotFound => ListReviewsServiceError::RecipeNotFound,
+            RecipeAccessError::InternalError => ListReviewsServiceError::InternalError,
+        }
+    }
+}
+
+pub struct ListReviews<Storage, Recipes>
+where
+    Storage: ListReviewsPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+}
+
+#[async_trait]
+impl<Storage, Recipes> ListReviewsService for ListReviews<Storage, Recipes>
+where
+    Storage: ListReviewsPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    async fn list_reviews(
+        &self,
+        caller: Option<Caller>,
+        recipe: uuid::Uuid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Page<Review>, ListReviewsServiceError> {
+        if page_size == 0 || page_size > MAX_PAGE_SIZE {
+            return Err(ListReviewsServiceError::InvalidPageSize);
+        }
+        let access = self.recipes.recipe_access(recipe).await?;
+        if !access.can_view(caller.as_ref()) {
+            return Err(ListReviewsServiceError::RecipeNotFound);
+        }
+        // One extra row tells us whether a next page exists.
+        let offset = u64::from(page) * u64::from(page_size);
+        let mut reviews = self
+            .storage
+            .list_reviews(recipe, offset, page_size + 1)
+            .await?;
+        let next_page = if reviews.len() > page_size as usize {
+            reviews.truncate(page_size as usize);
+            Some(page + 1)
+        } else {
+            None
+        };
+        Ok(Page::new(reviews, page, page_size, next_page))
+    }
+}
+
+impl<Storage, Recipes> ListReviews<Storage, Recipes>
+where
+    Storage: ListReviewsPort + Send + Sync,
+    Recipes: RecipeAccessPort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes) -> Self {
+        Self { storage, recipes }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod delete_review_service;
+pub mod domain;
+pub mod insert_review_service;
+pub mod list_reviews_service;
+pub mod ports;
+pub mod update_review_service;
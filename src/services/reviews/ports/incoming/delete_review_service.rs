@@ -0,0 +1,35 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::users::domain::caller::Caller;
+
+#[async_trait]
+pub trait DeleteReviewService {
+    /// Deletes a review written by `caller`, or any review for admins.
+    async fn delete_review(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), DeleteReviewServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DeleteReviewServiceError {
+    ReviewNotFound,
+    Forbidden,
+    InternalError,
+}
+
+impl Display for DeleteReviewServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeleteReviewServiceError::ReviewNotFound => f.write_str("Review not found"),
+            DeleteReviewServiceError::Forbidden => {
+                f.write_str("You are not allowed to delete this review")
+            }
+            DeleteReviewServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for DeleteReviewServiceError {}
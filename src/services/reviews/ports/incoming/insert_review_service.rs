@@ -0,0 +1,40 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    reviews::domain::review::{Review, ReviewError},
+    users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait InsertReviewService {
+    /// Stores `review`, written by `caller` on a recipe they may view.
+    async fn insert_review(
+        &self,
+        caller: Caller,
+        review: Review,
+    ) -> Result<Review, InsertReviewServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertReviewServiceError {
+    InvalidReview(ReviewError),
+    RecipeNotFound,
+    AlreadyReviewed,
+    InternalError,
+}
+
+impl Display for InsertReviewServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InsertReviewServiceError::InvalidReview(error) => write!(f, "{}", error),
+            InsertReviewServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            InsertReviewServiceError::AlreadyReviewed => {
+                f.write_str("You already reviewed this recipe")
+            }
+            InsertReviewServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for InsertReviewServiceError {}
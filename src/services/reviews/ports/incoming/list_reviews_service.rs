@@ -0,0 +1,39 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::domain::pagination::Page, reviews::domain::review::Review,
+    users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait ListReviewsService {
+    /// Lists a page of the reviews of a recipe `caller` may view, newest
+    /// first.
+    async fn list_reviews(
+        &self,
+        caller: Option<Caller>,
+        recipe: uuid::Uuid,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Page<Review>, ListReviewsServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ListReviewsServiceError {
+    InvalidPageSize,
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for ListReviewsServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListReviewsServiceError::InvalidPageSize => f.write_str("Invalid page size"),
+            ListReviewsServiceError::RecipeNotFound => f.write_str("Recipe not found"),
+            ListReviewsServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for ListReviewsServiceError {}
@@ -0,0 +1,4 @@
+pub mod delete_review_service;
+pub mod insert_review_service;
+pub mod list_reviews_service;
+pub mod update_review_service;
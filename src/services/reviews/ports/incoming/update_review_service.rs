@@ -0,0 +1,40 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    reviews::domain::review::{Review, ReviewError},
+    users::domain::caller::Caller,
+};
+
+#[async_trait]
+pub trait UpdateReviewService {
+    /// Changes the rating and text of a review written by `caller`.
+    async fn update_review(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        rating: u8,
+        text: Option<String>,
+    ) -> Result<Review, UpdateReviewServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateReviewServiceError {
+    InvalidReview(ReviewError),
+    ReviewNotFound,
+    Forbidden,
+    InternalError,
+}
+
+impl Display for UpdateReviewServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpdateReviewServiceError::InvalidReview(error) => write!(f, "{}", error),
+            UpdateReviewServiceError::ReviewNotFound => f.write_str("Review not found"),
+            UpdateReviewServiceError::Forbidden => f.write_str("Only the author may edit a review"),
+            UpdateReviewServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for UpdateReviewServiceError {}
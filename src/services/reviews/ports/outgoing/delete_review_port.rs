@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait DeleteReviewPort {
+    async fn delete_review(&self, uuid: uuid::Uuid) -> Result<(), DeleteReviewError>;
+}
+
+#[derive(Debug)]
+pub enum DeleteReviewError {
+    RecordNotFound,
+    InternalError,
+}
+
+impl Display for DeleteReviewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecordNotFound => write!(f, "Record not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for DeleteReviewError {}
@@ -0,0 +1,29 @@
+use crate::services::reviews::domain::review::Review;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait InsertReviewPort {
+    /// Stores a new review, returning it as stored.
+    async fn insert_review(&self, review: Review) -> Result<Review, InsertReviewError>;
+}
+
+#[derive(Debug)]
+pub enum InsertReviewError {
+    /// The author already reviewed the recipe.
+    ReviewExists,
+    RecipeNotFound,
+    InternalError,
+}
+
+impl Display for InsertReviewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReviewExists => write!(f, "Review already exists"),
+            Self::RecipeNotFound => write!(f, "Recipe not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for InsertReviewError {}
@@ -0,0 +1,29 @@
+use crate::services::reviews::domain::review::Review;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait ListReviewsPort {
+    /// Reviews of `recipe`, newest first.
+    async fn list_reviews(
+        &self,
+        recipe: uuid::Uuid,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<Review>, ListReviewsError>;
+}
+
+#[derive(Debug)]
+pub enum ListReviewsError {
+    InternalError,
+}
+
+impl Display for ListReviewsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for ListReviewsError {}
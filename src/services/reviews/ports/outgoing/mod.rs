@@ -0,0 +1,5 @@
+pub mod delete_review_port;
+pub mod insert_review_port;
+pub mod list_reviews_port;
+pub mod query_review_port;
+pub mod update_review_port;
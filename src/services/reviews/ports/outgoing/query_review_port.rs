@@ -0,0 +1,25 @@
+use crate::services::reviews::domain::review::Review;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait QueryReviewPort {
+    async fn query_review(&self, uuid: uuid::Uuid) -> Result<Review, QueryReviewError>;
+}
+
+#[derive(Debug)]
+pub enum QueryReviewError {
+    RecordNotFound,
+    InternalError,
+}
+
+impl Display for QueryReviewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecordNotFound => write!(f, "Record not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for QueryReviewError {}
@@ -0,0 +1,26 @@
+use crate::services::reviews::domain::review::Review;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait UpdateReviewPort {
+    /// Replaces the rating and text of a review, returning it as stored.
+    async fn update_review(&self, review: Review) -> Result<Review, UpdateReviewError>;
+}
+
+#[derive(Debug)]
+pub enum UpdateReviewError {
+    RecordNotFound,
+    InternalError,
+}
+
+impl Display for UpdateReviewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecordNotFound => write!(f, "Record not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for UpdateReviewError {}
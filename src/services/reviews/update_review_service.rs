@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::review::Review,
+    ports::{
+        incoming::update_review_service::{UpdateReviewService, UpdateReviewServiceError},
+        outgoing::{
+            query_review_port::{QueryReviewError, QueryReviewPort},
+            update_review_port::{UpdateReviewError, UpdateReviewPort},
+        },
+    },
+};
+use crate::services::users::domain::caller::Caller;
+
+impl From<QueryReviewError> for UpdateReviewServiceError {
+    fn from(value: QueryReviewError) -> Self {
+        match value {
+            QueryReviewError::RecordNotFound => UpdateReviewServiceError::ReviewNotFound,
+            QueryReviewError::InternalError => UpdateReviewServiceError::InternalError,
+        }
+    }
+}
+
+impl From<UpdateReviewError> for UpdateReviewServiceError {
+    fn from(value: UpdateReviewError) -> Self {
+        match value {
+            UpdateReviewError::RecordNotFound => UpdateReviewServiceError::ReviewNotFound,
+            UpdateReviewError::InternalError => UpdateReviewServiceError::InternalError,
+        }
+    }
+}
+
+pub struct UpdateReview<Storage>
+where
+    Storage: UpdateReviewPort + QueryReviewPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> UpdateReviewService for UpdateReview<Storage>
+where
+    Storage: UpdateReviewPort + QueryReviewPort + Send + Sync,
+{
+    async fn update_review(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        rating: u8,
+        text: Option<String>,
+    ) -> Result<Review, UpdateReviewServiceError> {
+        let review = self.storage.query_review(uuid).await?;
+        // Admins may remove reviews but never speak for their authors.
+        if review.author() != caller.user() {
+            return Err(UpdateReviewServiceError::Forbidden);
+        }
+        let review = Review::new(uuid, review.recipe(), review.author(), rating, text)
+            .normalized()
+            .map_err(UpdateReviewServiceError::InvalidReview)?;
+        Ok(self.storage.update_review(review).await?)
+    }
+}
+
+impl<Storage> UpdateReview<Storage>
+where
+    Storage: UpdateReviewPort + QueryReviewPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, reviews::reviews_sqlite_ds::ReviewSqliteDS, stored_recipe},
+        services::{
+            recipes::domain::access::{RecipeAccess, Visibility},
+            reviews::{
+                domain::review::ReviewError, ports::outgoing::insert_review_port::InsertReviewPort,
+            },
+            users::domain::caller::Role,
+        },
+    };
+
+    async fn service(author: uuid::Uuid) -> (UpdateReview<ReviewSqliteDS>, uuid::Uuid) {
+        let pool = memory_pool().await;
+        let recipe = stored_recipe(&pool, RecipeAccess::new(None, Visibility::Private)).await;
+        let storage = ReviewSqliteDS::new(pool);
+        let review = Review::new(uuid::Uuid::new_v4(), recipe.uuid(), author, 3, None);
+        let uuid = review.uuid();
+        storage.insert_review(review).await.unwrap();
+        (UpdateReview::new(storage), uuid)
+    }
+
+    #[tokio::test]
+    async fn only_the_author_edits_a_review() {
+        let author = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        let (service, uuid) = service(author.user()).await;
+
+        let result = service.update_review(admin, uuid, 1, None).await;
+        assert_eq!(result.unwrap_err(), UpdateReviewServiceError::Forbidden);
+
+        let review = service
+            .update_review(author, uuid, 5, Some(" Great ".into()))
+            .await
+            .unwrap();
+        assert_eq!((review.rating(), review.text()), (5, Some("Great")));
+    }
+
+    #[tokio::test]
+    async fn edits_are_validated() {
+        let author = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let (service, uuid) = service(author.user()).await;
+
+        let result = service.update_review(author, uuid, 0, None).await;
+        assert_eq!(
+            result.unwrap_err(),
+            UpdateReviewServiceError::InvalidReview(ReviewError::InvalidRating)
+        );
+        let result = service
+            .update_review(author, uuid::Uuid::new_v4(), 4, None)
+            .await;
+        assert_eq!(
+            result.unwrap_err(),
+            UpdateReviewServiceError::ReviewNotFound
+        );
+    }
+}
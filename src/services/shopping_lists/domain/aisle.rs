@@ -0,0 +1,209 @@
+use std::str::FromStr;
+
+/// Section of the shop an item is found in, in the order a shop is usually
+/// walked through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Aisle {
+    Produce,
+    Bakery,
+    Meat,
+    Fish,
+    Dairy,
+    Pantry,
+    Spices,
+    Frozen,
+    Drinks,
+    Other,
+}
+
+/// Words naming common ingredients and their aisle, most specific names
+/// first so "coconut milk" wins over "milk" and "black pepper" over
+/// "pepper".
+const AISLES: [(&str, Aisle); 96] = [
+    ("coconut milk", Aisle::Pantry),
+    ("peanut butter", Aisle::Pantry),
+    ("garlic powder", Aisle::Spices),
+    ("onion powder", Aisle::Spices),
+    ("chili powder", Aisle::Spices),
+    ("black pepper", Aisle::Spices),
+    ("baking powder", Aisle::Pantry),
+    ("baking soda", Aisle::Pantry),
+    ("frozen", Aisle::Frozen),
+    ("ice cream", Aisle::Frozen),
+    ("puff pastry", Aisle::Frozen),
+    ("peas", Aisle::Frozen),
+    ("apple", Aisle::Produce),
+    ("avocado", Aisle::Produce),
+    ("banana", Aisle::Produce),
+    ("basil", Aisle::Produce),
+    ("broccoli", Aisle::Produce),
+    ("cabbage", Aisle::Produce),
+    ("carrot", Aisle::Produce),
+    ("celery", Aisle::Produce),
+    ("cilantro", Aisle::Produce),
+    ("courgette", Aisle::Produce),
+    ("cucumber", Aisle::Produce),
+    ("garlic", Aisle::Produce),
+    ("ginger", Aisle::Produce),
+    ("leek", Aisle::Produce),
+    ("lemon", Aisle::Produce),
+    ("lettuce", Aisle::Produce),
+    ("lime", Aisle::Produce),
+    ("mushroom", Aisle::Produce),
+    ("onion", Aisle::Produce),
+    ("parsley", Aisle::Produce),
+    ("pepper", Aisle::Produce),
+    ("potato", Aisle::Produce),
+    ("shallot", Aisle::Produce),
+    ("spinach", Aisle::Produce),
+    ("tomato", Aisle::Produce),
+    ("zucchini", Aisle::Produce),
+    ("bread", Aisle::Bakery),
+    ("baguette", Aisle::Bakery),
+    ("bun", Aisle::Bakery),
+    ("tortilla", Aisle::Bakery),
+    ("bacon", Aisle::Meat),
+    ("beef", Aisle::Meat),
+    ("chicken", Aisle::Meat),
+    ("ham", Aisle::Meat),
+    ("lamb", Aisle::Meat),
+    ("pork", Aisle::Meat),
+    ("sausage", Aisle::Meat),
+    ("turkey", Aisle::Meat),
+    ("cod", Aisle::Fish),
+    ("fish", Aisle::Fish),
+    ("prawn", Aisle::Fish),
+    ("salmon", Aisle::Fish),
+    ("shrimp", Aisle::Fish),
+    ("tuna", Aisle::Fish),
+    ("butter", Aisle::Dairy),
+    ("buttermilk", Aisle::Dairy),
+    ("cheese", Aisle::Dairy),
+    ("cream", Aisle::Dairy),
+    ("egg", Aisle::Dairy),
+    ("milk", Aisle::Dairy),
+    ("mozzarella", Aisle::Dairy),
+    ("parmesan", Aisle::Dairy),
+    ("yogurt", Aisle::Dairy),
+    ("beans", Aisle::Pantry),
+    ("breadcrumbs", Aisle::Pantry),
+    ("chocolate", Aisle::Pantry),
+    ("cocoa", Aisle::Pantry),
+    ("cornstarch", Aisle::Pantry),
+    ("flour", Aisle::Pantry),
+    ("honey", Aisle::Pantry),
+    ("lentils", Aisle::Pantry),
+    ("noodles", Aisle::Pantry),
+    ("oats", Aisle::Pantry),
+    ("oil", Aisle::Pantry),
+    ("pasta", Aisle::Pantry),
+    ("rice", Aisle::Pantry),
+    ("spaghetti", Aisle::Pantry),
+    ("stock", Aisle::Pantry),
+    ("sugar", Aisle::Pantry),
+    ("syrup", Aisle::Pantry),
+    ("vinegar", Aisle::Pantry),
+    ("yeast", Aisle::Pantry),
+    ("cinnamon", Aisle::Spices),
+    ("cumin", Aisle::Spices),
+    ("nutmeg", Aisle::Spices),
+    ("oregano", Aisle::Spices),
+    ("paprika", Aisle::Spices),
+    ("salt", Aisle::Spices),
+    ("thyme", Aisle::Spices),
+    ("vanilla", Aisle::Spices),
+    ("beer", Aisle::Drinks),
+    ("juice", Aisle::Drinks),
+    ("water", Aisle::Drinks),
+    ("wine", Aisle::Drinks),
+];
+
+impl Aisle {
+    pub const ALL: [Aisle; 10] = [
+        Aisle::Produce,
+        Aisle::Bakery,
+        Aisle::Meat,
+        Aisle::Fish,
+        Aisle::Dairy,
+        Aisle::Pantry,
+        Aisle::Spices,
+        Aisle::Frozen,
+        Aisle::Drinks,
+        Aisle::Other,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Aisle::Produce => "produce",
+            Aisle::Bakery => "bakery",
+            Aisle::Meat => "meat",
+            Aisle::Fish => "fish",
+            Aisle::Dairy => "dairy",
+            Aisle::Pantry => "pantry",
+            Aisle::Spices => "spices",
+            Aisle::Frozen => "frozen",
+            Aisle::Drinks => "drinks",
+            Aisle::Other => "other",
+        }
+    }
+
+    /// Aisle of the ingredient called `name`, `Other` when it is not a
+    /// common one. Matches whole words, singular or plural, so "tomatoes"
+    /// are produce but "hamburger buns" are not ham.
+    pub fn of(name: &str) -> Self {
+        let name = name.to_lowercase();
+        let words = name
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<&str>>();
+        let matches = |word: &str, key: &str| {
+            word == key
+                || word
+                    .strip_prefix(key)
+                    .is_some_and(|suffix| suffix == "s" || suffix == "es")
+        };
+        AISLES
+            .iter()
+            .find_map(|(key, aisle)| {
+                let key = key.split(' ').collect::<Vec<&str>>();
+                words
+                    .windows(key.len())
+                    .any(|window| window.iter().zip(&key).all(|(w, k)| matches(w, k)))
+                    .then_some(*aisle)
+            })
+            .unwrap_or(Aisle::Other)
+    }
+}
+
+impl FromStr for Aisle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Aisle::ALL
+            .into_iter()
+            .find(|aisle| aisle.as_str() == s)
+            .ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ingredients_are_found_by_whole_words() {
+        assert_eq!(Aisle::of("Cherry tomatoes"), Aisle::Produce);
+        assert_eq!(Aisle::of("2 eggs, beaten"), Aisle::Dairy);
+        assert_eq!(Aisle::of("light coconut milk"), Aisle::Pantry);
+        assert_eq!(Aisle::of("whole milk"), Aisle::Dairy);
+        assert_eq!(Aisle::of("hamburger buns"), Aisle::Bakery);
+        assert_eq!(Aisle::of("dragon fruit"), Aisle::Other);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for aisle in Aisle::ALL {
+            assert_eq!(aisle.as_str().parse(), Ok(aisle));
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod aisle;
+pub mod shopping_item;
+pub mod shopping_list;
@@ -0,0 +1,217 @@
+use super::aisle::Aisle;
+use crate::services::recipes::domain::{
+    ingredient::{name_key, Ingredient},
+    quantity::Quantity,
+    unit::{Unit, UnitKind},
+};
+
+/// One thing to buy, the merged amounts of an ingredient across recipes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShoppingItem {
+    uuid: uuid::Uuid,
+    name: String,
+    amount: f64,
+    unit: String,
+    aisle: Aisle,
+    checked: bool,
+}
+
+impl ShoppingItem {
+    pub fn new(uuid: uuid::Uuid, name: String, amount: f64, unit: String, aisle: Aisle) -> Self {
+        Self {
+            uuid,
+            name,
+            amount,
+            unit,
+            aisle,
+            checked: false,
+        }
+    }
+
+    pub fn with_checked(self, checked: bool) -> Self {
+        Self { checked, ..self }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn unit(&self) -> &str {
+        self.unit.as_ref()
+    }
+
+    pub fn quantity(&self) -> Quantity {
+        Quantity::new(self.amount, self.unit.parse().ok())
+    }
+
+    pub fn aisle(&self) -> Aisle {
+        self.aisle
+    }
+
+    /// Whether the item is already in the basket.
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+}
+
+/// What amounts of an ingredient can be added up: masses and volumes in
+/// any unit of their kind, counts only in the same unit, and units that are
+/// not understood only when spelled the same.
+#[derive(Debug, PartialEq)]
+enum MergeKey {
+    Kind(UnitKind),
+    Unit(Unit),
+    Spelling(String),
+}
+
+struct Line {
+    name: String,
+    key: String,
+    merge: MergeKey,
+    /// The first unit met, the merged amount is expressed in its system.
+    unit: Option<Unit>,
+    spelling: String,
+    /// In grams or millilitres for masses and volumes.
+    amount: f64,
+}
+
+/// Merges identical ingredients, adding up amounts of compatible units, so
+/// 200 g and 0.5 kg of flour make 700 g. Masses and volumes of the same
+/// ingredient stay apart. Items are sorted by aisle then name.
+pub fn merge_ingredients<'a, I>(ingredients: I) -> Vec<ShoppingItem>
+where
+    I: IntoIterator<Item = &'a Ingredient>,
+{
+    let mut lines: Vec<Line> = vec![];
+    for ingredient in ingredients {
+        let name = ingredient.name().trim();
+        if name.is_empty() {
+            continue;
+        }
+        let key = name_key(name);
+        let unit = ingredient.parsed_unit().ok();
+        let (merge, amount) = match unit {
+            Some(unit) if matches!(unit.kind(), UnitKind::Mass | UnitKind::Volume) => (
+                MergeKey::Kind(unit.kind()),
+                ingredient.amount() * unit.base_factor(),
+            ),
+            Some(unit) => (MergeKey::Unit(unit), ingredient.amount()),
+            None => (
+                MergeKey::Spelling(name_key(ingredient.unit())),
+                ingredient.amount(),
+            ),
+        };
+        match lines
+            .iter_mut()
+            .find(|line| line.key == key && line.merge == merge)
+        {
+            Some(_) if merge == MergeKey::Unit(Unit::ToTaste) => (),
+            Some(line) => line.amount += amount,
+            None => lines.push(Line {
+                name: name.to_string(),
+                key,
+                merge,
+                unit,
+                spelling: ingredient.unit().trim().to_string(),
+                amount,
+            }),
+        }
+    }
+    let mut items = lines
+        .into_iter()
+        .map(|line| {
+            let (amount, unit) = match (line.merge, line.unit) {
+                (MergeKey::Kind(_), Some(unit)) => {
+                    let quantity =
+                        Quantity::new(line.amount / unit.base_factor(), Some(unit)).to_kitchen();
+                    (quantity.amount(), quantity.unit().unwrap_or(unit).symbol())
+                }
+                (MergeKey::Unit(unit), _) => {
+                    let quantity = Quantity::new(line.amount, Some(unit)).to_kitchen();
+                    (quantity.amount(), unit.symbol())
+                }
+                _ => (line.amount, line.spelling.as_str()),
+            };
+            let unit = unit.to_string();
+            let aisle = Aisle::of(&line.name);
+            ShoppingItem::new(uuid::Uuid::new_v4(), line.name, amount, unit, aisle)
+        })
+        .collect::<Vec<ShoppingItem>>();
+    items.sort_by(|a, b| {
+        a.aisle
+            .cmp(&b.aisle)
+            .then_with(|| name_key(&a.name).cmp(&name_key(&b.name)))
+    });
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(name: &str, amount: f64, unit: &str) -> Ingredient {
+        Ingredient::new(
+            uuid::Uuid::new_v4(),
+            name.to_string(),
+            amount,
+            unit.to_string(),
+        )
+    }
+
+    fn summary(items: &[ShoppingItem]) -> Vec<(&str, String, Aisle)> {
+        items
+            .iter()
+            .map(|item| (item.name(), item.quantity().display(), item.aisle()))
+            .collect()
+    }
+
+    #[test]
+    fn compatible_units_are_added_up() {
+        let items = merge_ingredients(&[
+            ingredient("Flour", 200.0, "g"),
+            ingredient("flour", 0.5, "kg"),
+            ingredient("Milk", 1.0, "cup"),
+            ingredient("milk", 8.0, "tbsp"),
+            ingredient("garlic", 2.0, "clove"),
+            ingredient("Garlic", 1.0, "clove"),
+        ]);
+        assert_eq!(
+            summary(&items),
+            vec![
+                ("garlic", "3 clove".to_string(), Aisle::Produce),
+                ("Milk", "1 1/2 cup".to_string(), Aisle::Dairy),
+                ("Flour", "700 g".to_string(), Aisle::Pantry),
+            ]
+        );
+    }
+
+    #[test]
+    fn incompatible_units_stay_apart() {
+        let items = merge_ingredients(&[
+            ingredient("butter", 100.0, "g"),
+            ingredient("butter", 2.0, "tbsp"),
+            ingredient("salt", 1.0, "to taste"),
+            ingredient("salt", 1.0, "to taste"),
+            ingredient("basil", 1.0, "handful"),
+            ingredient("basil", 2.0, "handful"),
+        ]);
+        assert_eq!(
+            summary(&items),
+            vec![
+                ("basil", "3".to_string(), Aisle::Produce),
+                ("butter", "100 g".to_string(), Aisle::Dairy),
+                ("butter", "2 tbsp".to_string(), Aisle::Dairy),
+                ("salt", "to taste".to_string(), Aisle::Spices),
+            ]
+        );
+        assert_eq!(items[0].unit(), "handful");
+    }
+}
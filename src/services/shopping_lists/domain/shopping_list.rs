@@ -0,0 +1,79 @@
+use std::{error::Error, fmt::Display};
+
+use super::shopping_item::ShoppingItem;
+
+pub const MAX_SHOPPING_LIST_NAME_LENGTH: usize = 64;
+
+/// Items to buy for a set of recipes, kept by one user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShoppingList {
+    uuid: uuid::Uuid,
+    owner: uuid::Uuid,
+    name: String,
+    items: Vec<ShoppingItem>,
+}
+
+impl ShoppingList {
+    pub fn new(uuid: uuid::Uuid, owner: uuid::Uuid, name: String) -> Self {
+        Self {
+            uuid,
+            owner,
+            name,
+            items: vec![],
+        }
+    }
+
+    /// Items in the order they are shown.
+    pub fn with_items(self, items: Vec<ShoppingItem>) -> Self {
+        Self { items, ..self }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn owner(&self) -> uuid::Uuid {
+        self.owner
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn items(&self) -> &[ShoppingItem] {
+        self.items.as_ref()
+    }
+
+    /// Trims the name.
+    pub fn normalized(self) -> Result<Self, ShoppingListNameError> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err(ShoppingListNameError::Empty);
+        }
+        if name.chars().count() > MAX_SHOPPING_LIST_NAME_LENGTH {
+            return Err(ShoppingListNameError::TooLong);
+        }
+        Ok(Self { name, ..self })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ShoppingListNameError {
+    Empty,
+    TooLong,
+}
+
+impl Display for ShoppingListNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShoppingListNameError::Empty => f.write_str("Shopping list name is empty"),
+            ShoppingListNameError::TooLong => write!(
+                f,
+                "Shopping list names are at most {} characters long",
+                MAX_SHOPPING_LIST_NAME_LENGTH
+            ),
+        }
+    }
+}
+
+impl Error for ShoppingListNameError {}
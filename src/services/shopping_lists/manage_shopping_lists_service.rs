@@ -0,0 +1,361 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use async_trait::async_trait;
+
+use super::{
+    domain::{
+        shopping_item::{merge_ingredients, ShoppingItem},
+        shopping_list::ShoppingList,
+    },
+    ports::{
+        incoming::manage_shopping_lists_service::{
+            ManageShoppingListsService, ManageShoppingListsServiceError, ShoppingListSource,
+        },
+        outgoing::shopping_list_port::{ShoppingListError, ShoppingListPort},
+    },
+};
+use crate::services::{
+    meal_plans::{
+        manage_meal_plan_service::MAX_RANGE_DAYS,
+        ports::outgoing::meal_plan_port::{MealPlanError, MealPlanPort},
+    },
+    recipes::{
+        domain::{ingredient::Ingredient, recipe::Recipe},
+        ports::outgoing::query_recipe_port::{QueryRecipeError, QueryRecipePort},
+    },
+    users::domain::caller::Caller,
+};
+
+/// Most recipes a list is made from when they are given by uuid.
+pub const MAX_SHOPPING_LIST_RECIPES: usize = 50;
+
+impl From<ShoppingListError> for ManageShoppingListsServiceError {
+    fn from(value: ShoppingListError) -> Self {
+        match value {
+            ShoppingListError::ListNotFound => ManageShoppingListsServiceError::ListNotFound,
+            ShoppingListError::ItemNotFound => ManageShoppingListsServiceError::ItemNotFound,
+            ShoppingListError::InternalError => ManageShoppingListsServiceError::InternalError,
+        }
+    }
+}
+
+impl From<MealPlanError> for ManageShoppingListsServiceError {
+    fn from(_: MealPlanError) -> Self {
+        ManageShoppingListsServiceError::InternalError
+    }
+}
+
+pub struct ManageShoppingLists<Storage, Recipes, Plans>
+where
+    Storage: ShoppingListPort + Send + Sync,
+    Recipes: QueryRecipePort + Send + Sync,
+    Plans: MealPlanPort + Send + Sync,
+{
+    storage: Storage,
+    recipes: Recipes,
+    plans: Plans,
+}
+
+impl<Storage, Recipes, Plans> ManageShoppingLists<Storage, Recipes, Plans>
+where
+    Storage: ShoppingListPort + Send + Sync,
+    Recipes: QueryRecipePort + Send + Sync,
+    Plans: MealPlanPort + Send + Sync,
+{
+    pub fn new(storage: Storage, recipes: Recipes, plans: Plans) -> Self {
+        Self {
+            storage,
+            recipes,
+            plans,
+        }
+    }
+
+    /// The list, unless it belongs to somebody else.
+    async fn owned_list(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<ShoppingList, ManageShoppingListsServiceError> {
+        let list = self.storage.query_list(uuid).await?;
+        if list.owner() != caller.user() {
+            return Err(ManageShoppingListsServiceError::ListNotFound);
+        }
+        Ok(list)
+    }
+
+    /// Ingredients of the given recipes scaled to their servings, failing
+    /// on the first recipe `caller` may not view when `strict`, leaving it
+    /// out otherwise.
+    async fn ingredients(
+        &self,
+        caller: Caller,
+        portions: &[(uuid::Uuid, Option<u32>)],
+        strict: bool,
+    ) -> Result<Vec<Ingredient>, ManageShoppingListsServiceError> {
+        let mut recipes: HashMap<uuid::Uuid, Option<Recipe>> = HashMap::new();
+        let mut ingredients = vec![];
+        for &(uuid, servings) in portions {
+            if let Entry::Vacant(entry) = recipes.entry(uuid) {
+                let recipe = match self.recipes.query_recipe(uuid).await {
+                    Ok(recipe) if recipe.access().can_view(Some(&caller)) => Some(recipe),
+                    Ok(_) | Err(QueryRecipeError::RecordNotFound) if strict => {
+                        return Err(ManageShoppingListsServiceError::RecipeNotFound(uuid))
+                    }
+                    Ok(_) | Err(QueryRecipeError::RecordNotFound) => None,
+                    Err(QueryRecipeError::InternalError) => {
+                        return Err(ManageShoppingListsServiceError::InternalError)
+                    }
+                };
+                entry.insert(recipe);
+            }
+            if let Some(Some(recipe)) = recipes.get(&uuid) {
+                let recipe = match servings {
+                    Some(servings) => recipe.clone().scale(servings),
+                    None => recipe.clone(),
+                };
+                ingredients.extend_from_slice(recipe.ingredients());
+            }
+        }
+        Ok(ingredients)
+    }
+}
+
+#[async_trait]
+impl<Storage, Recipes, Plans> ManageShoppingListsService
+    for ManageShoppingLists<Storage, Recipes, Plans>
+where
+    Storage: ShoppingListPort + Send + Sync,
+    Recipes: QueryRecipePort + Send + Sync,
+    Plans: MealPlanPort + Send + Sync,
+{
+    async fn list_lists(
+        &self,
+        caller: Caller,
+    ) -> Result<Vec<ShoppingList>, ManageShoppingListsServiceError> {
+        Ok(self.storage.list_lists(caller.user()).await?)
+    }
+
+    async fn query_list(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<ShoppingList, ManageShoppingListsServiceError> {
+        self.owned_list(caller, uuid).await
+    }
+
+    async fn create_list(
+        &self,
+        caller: Caller,
+        name: Option<String>,
+        source: ShoppingListSource,
+    ) -> Result<ShoppingList, ManageShoppingListsServiceError> {
+        let (default_name, portions, strict) = match source {
+            ShoppingListSource::Recipes(portions) => {
+                if portions.len() > MAX_SHOPPING_LIST_RECIPES {
+                    return Err(ManageShoppingListsServiceError::TooManyRecipes);
+                }
+                ("Shopping list".to_string(), portions, true)
+            }
+            ShoppingListSource::MealPlan(from, to) => {
+                if !(0..MAX_RANGE_DAYS).contains(&from.days_until(&to)) {
+                    return Err(ManageShoppingListsServiceError::InvalidRange);
+                }
+                let portions = self
+                    .plans
+                    .list_entries(caller.user(), from, to)
+                    .await?
+                    .iter()
+                    .map(|entry| (entry.recipe(), entry.servings()))
+                    .collect();
+                (format!("Meal plan {} to {}", from, to), portions, false)
+            }
+        };
+        if portions.is_empty() {
+            return Err(ManageShoppingListsServiceError::NoRecipes);
+        }
+        if portions.iter().any(|(_, servings)| *servings == Some(0)) {
+            return Err(ManageShoppingListsServiceError::InvalidServings);
+        }
+        let list = ShoppingList::new(
+            uuid::Uuid::new_v4(),
+            caller.user(),
+            name.unwrap_or(default_name),
+        )
+        .normalized()
+        .map_err(ManageShoppingListsServiceError::InvalidName)?;
+        let ingredients = self.ingredients(caller, &portions, strict).await?;
+        let list = list.with_items(merge_ingredients(&ingredients));
+        Ok(self.storage.insert_list(&list).await?)
+    }
+
+    async fn check_item(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        item: uuid::Uuid,
+        checked: bool,
+    ) -> Result<ShoppingItem, ManageShoppingListsServiceError> {
+        self.owned_list(caller, uuid).await?;
+        Ok(self.storage.set_checked(uuid, item, checked).await?)
+    }
+
+    async fn delete_list(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManageShoppingListsServiceError> {
+        self.owned_list(caller, uuid).await?;
+        Ok(self.storage.delete_list(uuid).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{
+            meal_plans::meal_plans_sqlite_ds::MealPlanSqliteDS, memory_pool,
+            recipes::recipes_sqlite_ds::RecipeSqliteDS,
+            shopping_lists::shopping_lists_sqlite_ds::ShoppingListSqliteDS,
+        },
+        services::{
+            meal_plans::domain::{meal_slot::MealSlot, plan_date::PlanDate, plan_entry::PlanEntry},
+            recipes::{
+                domain::access::{RecipeAccess, Visibility},
+                ports::outgoing::insert_recipe_port::InsertRecipePort,
+            },
+            users::domain::caller::Role,
+        },
+    };
+
+    type Service = ManageShoppingLists<ShoppingListSqliteDS, RecipeSqliteDS, MealPlanSqliteDS>;
+
+    /// Recipes for two making 100 g of flour each, the private one owned
+    /// by somebody else.
+    struct Recipes {
+        public: uuid::Uuid,
+        private: uuid::Uuid,
+    }
+
+    fn date(date: &str) -> PlanDate {
+        date.parse().unwrap()
+    }
+
+    async fn service() -> (Service, Recipes, MealPlanSqliteDS) {
+        let pool = memory_pool().await;
+        let recipes = RecipeSqliteDS::new(pool.clone());
+        let mut stored = vec![];
+        for visibility in [Visibility::Public, Visibility::Private] {
+            let flour = Ingredient::new(uuid::Uuid::new_v4(), "Flour".into(), 100.0, "g".into());
+            let recipe = Recipe::new(
+                uuid::Uuid::new_v4(),
+                "Bread".into(),
+                "".into(),
+                vec![],
+                vec![flour],
+            )
+            .with_servings(2)
+            .with_access(RecipeAccess::new(Some(uuid::Uuid::new_v4()), visibility));
+            recipes.insert_recipe(recipe.clone()).await.unwrap();
+            stored.push(recipe.uuid());
+        }
+        let plans = MealPlanSqliteDS::new(pool.clone());
+        let service =
+            ManageShoppingLists::new(ShoppingListSqliteDS::new(pool), recipes, plans.clone());
+        let recipes = Recipes {
+            public: stored[0],
+            private: stored[1],
+        };
+        (service, recipes, plans)
+    }
+
+    fn amounts(list: &ShoppingList) -> Vec<(&str, String)> {
+        list.items()
+            .iter()
+            .map(|item| (item.name(), item.quantity().display()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn recipes_are_scaled_and_merged() {
+        let caller = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let (service, recipes, _) = service().await;
+
+        let list = service
+            .create_list(
+                caller,
+                None,
+                ShoppingListSource::Recipes(vec![
+                    (recipes.public, Some(4)),
+                    (recipes.public, None),
+                ]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(list.name(), "Shopping list");
+        assert_eq!(amounts(&list), vec![("Flour", "300 g".to_string())]);
+
+        for (portions, error) in [
+            (
+                vec![(recipes.private, None)],
+                ManageShoppingListsServiceError::RecipeNotFound(recipes.private),
+            ),
+            (
+                vec![(recipes.public, Some(0))],
+                ManageShoppingListsServiceError::InvalidServings,
+            ),
+            (vec![], ManageShoppingListsServiceError::NoRecipes),
+        ] {
+            assert_eq!(
+                service
+                    .create_list(caller, None, ShoppingListSource::Recipes(portions))
+                    .await
+                    .unwrap_err(),
+                error
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn meal_plans_leave_hidden_recipes_out() {
+        let caller = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let (service, recipes, plans) = service().await;
+        for (recipe, servings) in [
+            (recipes.public, Some(6)),
+            (recipes.private, None),
+            (recipes.public, None),
+        ] {
+            let entry = PlanEntry::new(
+                uuid::Uuid::new_v4(),
+                caller.user(),
+                date("2024-01-01"),
+                MealSlot::Dinner,
+                recipe,
+            )
+            .with_servings(servings);
+            plans.insert_entry(&entry).await.unwrap();
+        }
+
+        let list = service
+            .create_list(
+                caller,
+                None,
+                ShoppingListSource::MealPlan(date("2024-01-01"), date("2024-01-07")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(list.name(), "Meal plan 2024-01-01 to 2024-01-07");
+        assert_eq!(amounts(&list), vec![("Flour", "400 g".to_string())]);
+        assert_eq!(
+            service
+                .create_list(
+                    caller,
+                    None,
+                    ShoppingListSource::MealPlan(date("2024-01-07"), date("2024-01-01")),
+                )
+                .await
+                .unwrap_err(),
+            ManageShoppingListsServiceError::InvalidRange
+        );
+    }
+}
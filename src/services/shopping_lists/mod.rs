@@ -0,0 +1,3 @@
+pub mod domain;
+pub mod manage_shopping_lists_service;
+pub mod ports;
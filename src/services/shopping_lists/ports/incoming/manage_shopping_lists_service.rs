@@ -0,0 +1,105 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{
+    meal_plans::{domain::plan_date::PlanDate, manage_meal_plan_service::MAX_RANGE_DAYS},
+    shopping_lists::{
+        domain::{
+            shopping_item::ShoppingItem,
+            shopping_list::{ShoppingList, ShoppingListNameError},
+        },
+        manage_shopping_lists_service::MAX_SHOPPING_LIST_RECIPES,
+    },
+    users::domain::caller::Caller,
+};
+
+/// What a shopping list is made from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShoppingListSource {
+    /// Recipes and the servings to shop for, the recipe's own when `None`.
+    Recipes(Vec<(uuid::Uuid, Option<u32>)>),
+    /// Entries of the caller's meal plan from the first date to the second
+    /// included.
+    MealPlan(PlanDate, PlanDate),
+}
+
+/// Shopping lists are private, other users' lists are reported as not
+/// found.
+#[async_trait]
+pub trait ManageShoppingListsService {
+    async fn list_lists(
+        &self,
+        caller: Caller,
+    ) -> Result<Vec<ShoppingList>, ManageShoppingListsServiceError>;
+    async fn query_list(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<ShoppingList, ManageShoppingListsServiceError>;
+    /// Merges the ingredients of the recipes of `source` into a new list.
+    /// `caller` must be able to view each recipe given by uuid; planned
+    /// recipes that became private since are left out. The list is named
+    /// after its source when `name` is `None`.
+    async fn create_list(
+        &self,
+        caller: Caller,
+        name: Option<String>,
+        source: ShoppingListSource,
+    ) -> Result<ShoppingList, ManageShoppingListsServiceError>;
+    /// Ticks an item off, or back on.
+    async fn check_item(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+        item: uuid::Uuid,
+        checked: bool,
+    ) -> Result<ShoppingItem, ManageShoppingListsServiceError>;
+    async fn delete_list(
+        &self,
+        caller: Caller,
+        uuid: uuid::Uuid,
+    ) -> Result<(), ManageShoppingListsServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ManageShoppingListsServiceError {
+    InvalidName(ShoppingListNameError),
+    InvalidRange,
+    InvalidServings,
+    NoRecipes,
+    TooManyRecipes,
+    ListNotFound,
+    ItemNotFound,
+    RecipeNotFound(uuid::Uuid),
+    InternalError,
+}
+
+impl Display for ManageShoppingListsServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageShoppingListsServiceError::InvalidName(error) => write!(f, "{}", error),
+            ManageShoppingListsServiceError::InvalidRange => write!(
+                f,
+                "Ranges end on or after they start and span at most {} days",
+                MAX_RANGE_DAYS
+            ),
+            ManageShoppingListsServiceError::InvalidServings => {
+                f.write_str("Servings must be at least one")
+            }
+            ManageShoppingListsServiceError::NoRecipes => {
+                f.write_str("There are no recipes to shop for")
+            }
+            ManageShoppingListsServiceError::TooManyRecipes => write!(
+                f,
+                "Shopping lists are made from at most {} recipes",
+                MAX_SHOPPING_LIST_RECIPES
+            ),
+            ManageShoppingListsServiceError::ListNotFound => f.write_str("Shopping list not found"),
+            ManageShoppingListsServiceError::ItemNotFound => f.write_str("Item not found"),
+            ManageShoppingListsServiceError::RecipeNotFound(_) => f.write_str("Recipe not found"),
+            ManageShoppingListsServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for ManageShoppingListsServiceError {}
@@ -0,0 +1 @@
+pub mod manage_shopping_lists_service;
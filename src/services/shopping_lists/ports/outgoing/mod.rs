@@ -0,0 +1 @@
+pub mod shopping_list_port;
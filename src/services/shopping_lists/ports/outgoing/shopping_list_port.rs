@@ -0,0 +1,40 @@
+use crate::services::shopping_lists::domain::{
+    shopping_item::ShoppingItem, shopping_list::ShoppingList,
+};
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+#[async_trait]
+pub trait ShoppingListPort {
+    /// Lists of `owner`, newest first.
+    async fn list_lists(&self, owner: uuid::Uuid) -> Result<Vec<ShoppingList>, ShoppingListError>;
+    async fn query_list(&self, uuid: uuid::Uuid) -> Result<ShoppingList, ShoppingListError>;
+    /// Stores the list and its items, in order.
+    async fn insert_list(&self, list: &ShoppingList) -> Result<ShoppingList, ShoppingListError>;
+    async fn set_checked(
+        &self,
+        list: uuid::Uuid,
+        item: uuid::Uuid,
+        checked: bool,
+    ) -> Result<ShoppingItem, ShoppingListError>;
+    async fn delete_list(&self, uuid: uuid::Uuid) -> Result<(), ShoppingListError>;
+}
+
+#[derive(Debug)]
+pub enum ShoppingListError {
+    ListNotFound,
+    ItemNotFound,
+    InternalError,
+}
+
+impl Display for ShoppingListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ListNotFound => write!(f, "Shopping list not found"),
+            Self::ItemNotFound => write!(f, "Item not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for ShoppingListError {}
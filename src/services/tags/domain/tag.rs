@@ -0,0 +1,20 @@
+/// A tag and how many recipes carry it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    name: String,
+    recipes: u32,
+}
+
+impl Tag {
+    pub fn new(name: String, recipes: u32) -> Self {
+        Self { name, recipes }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn recipes(&self) -> u32 {
+        self.recipes
+    }
+}
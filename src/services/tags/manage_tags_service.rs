@@ -0,0 +1,161 @@
+use async_trait::async_trait;
+
+use crate::services::{
+    recipes::domain::tag::{self, normalize_tag},
+    users::domain::caller::Caller,
+};
+
+use super::{
+    domain::tag::Tag,
+    ports::{
+        incoming::manage_tags_service::{ManageTagsService, ManageTagsServiceError},
+        outgoing::tag_port::{TagError, TagPort},
+    },
+};
+
+impl From<TagError> for ManageTagsServiceError {
+    fn from(value: TagError) -> Self {
+        match value {
+            TagError::TagNotFound => ManageTagsServiceError::TagNotFound,
+            TagError::TagExists => ManageTagsServiceError::TagExists,
+            TagError::InternalError => ManageTagsServiceError::InternalError,
+        }
+    }
+}
+
+impl From<tag::TagError> for ManageTagsServiceError {
+    fn from(value: tag::TagError) -> Self {
+        ManageTagsServiceError::InvalidTag(value.tag().to_string())
+    }
+}
+
+pub struct ManageTags<Storage>
+where
+    Storage: TagPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> ManageTagsService for ManageTags<Storage>
+where
+    Storage: TagPort + Send + Sync,
+{
+    async fn list_tags(&self, caller: Option<Caller>) -> Result<Vec<Tag>, ManageTagsServiceError> {
+        self.storage
+            .list_tags(caller)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    async fn create_tag(
+        &self,
+        _caller: Caller,
+        name: String,
+    ) -> Result<Tag, ManageTagsServiceError> {
+        let name = normalize_tag(&name)?;
+        self.storage
+            .insert_tag(&name)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    async fn rename_tag(
+        &self,
+        caller: Caller,
+        name: String,
+        new_name: String,
+    ) -> Result<Tag, ManageTagsServiceError> {
+        if !caller.is_admin() {
+            return Err(ManageTagsServiceError::Forbidden);
+        }
+        let name = normalize_tag(&name)?;
+        let new_name = normalize_tag(&new_name)?;
+        self.storage
+            .rename_tag(&name, &new_name)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    async fn delete_tag(&self, caller: Caller, name: String) -> Result<(), ManageTagsServiceError> {
+        if !caller.is_admin() {
+            return Err(ManageTagsServiceError::Forbidden);
+        }
+        let name = normalize_tag(&name)?;
+        self.storage
+            .delete_tag(&name)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+impl<Storage> ManageTags<Storage>
+where
+    Storage: TagPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, tags::tags_sqlite_ds::TagSqliteDS},
+        services::users::domain::caller::Role,
+    };
+
+    async fn service() -> ManageTags<TagSqliteDS> {
+        let storage = TagSqliteDS::new(memory_pool().await);
+        storage.insert_tag("quick").await.unwrap();
+        ManageTags::new(storage)
+    }
+
+    #[tokio::test]
+    async fn only_admins_rename_and_delete_tags() {
+        let service = service().await;
+        let member = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let admin = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+
+        assert_eq!(
+            service
+                .rename_tag(member, "quick".into(), "fast".into())
+                .await,
+            Err(ManageTagsServiceError::Forbidden)
+        );
+        assert_eq!(
+            service.delete_tag(member, "quick".into()).await,
+            Err(ManageTagsServiceError::Forbidden)
+        );
+        assert_eq!(
+            service
+                .rename_tag(admin, "Quick".into(), " Fast  Food ".into())
+                .await,
+            Ok(Tag::new("fast food".into(), 0))
+        );
+        assert_eq!(
+            service.list_tags(None).await,
+            Ok(vec![Tag::new("fast food".into(), 0)])
+        );
+    }
+
+    #[tokio::test]
+    async fn created_tags_are_normalized() {
+        let service = service().await;
+        let member = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+
+        assert_eq!(
+            service.create_tag(member, "Vegetarian ".into()).await,
+            Ok(Tag::new("vegetarian".into(), 0))
+        );
+        assert_eq!(
+            service.create_tag(member, " Quick".into()).await,
+            Err(ManageTagsServiceError::TagExists)
+        );
+        assert_eq!(
+            service.create_tag(member, " ".into()).await,
+            Err(ManageTagsServiceError::InvalidTag(" ".into()))
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod domain;
+pub mod manage_tags_service;
+pub mod ports;
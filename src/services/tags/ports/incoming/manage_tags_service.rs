@@ -0,0 +1,46 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::{tags::domain::tag::Tag, users::domain::caller::Caller};
+
+#[async_trait]
+pub trait ManageTagsService {
+    /// Every tag with the number of recipes `caller` may view carrying it.
+    async fn list_tags(&self, caller: Option<Caller>) -> Result<Vec<Tag>, ManageTagsServiceError>;
+    async fn create_tag(&self, caller: Caller, name: String)
+        -> Result<Tag, ManageTagsServiceError>;
+    /// Renames the tag on every recipe carrying it. Admins only.
+    async fn rename_tag(
+        &self,
+        caller: Caller,
+        name: String,
+        new_name: String,
+    ) -> Result<Tag, ManageTagsServiceError>;
+    /// Removes the tag from every recipe carrying it. Admins only.
+    async fn delete_tag(&self, caller: Caller, name: String) -> Result<(), ManageTagsServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ManageTagsServiceError {
+    InvalidTag(String),
+    TagNotFound,
+    TagExists,
+    Forbidden,
+    InternalError,
+}
+
+impl Display for ManageTagsServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageTagsServiceError::InvalidTag(tag) => write!(f, "Invalid tag `{}`", tag),
+            ManageTagsServiceError::TagNotFound => f.write_str("Tag not found"),
+            ManageTagsServiceError::TagExists => f.write_str("Tag already exists"),
+            ManageTagsServiceError::Forbidden => {
+                f.write_str("Only admins may rename or delete tags")
+            }
+            ManageTagsServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for ManageTagsServiceError {}
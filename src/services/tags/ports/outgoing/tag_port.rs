@@ -0,0 +1,32 @@
+use crate::services::{tags::domain::tag::Tag, users::domain::caller::Caller};
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+
+/// Tag names given to the port are already normalized.
+#[async_trait]
+pub trait TagPort {
+    /// Every tag, counting only the recipes `caller` may view.
+    async fn list_tags(&self, caller: Option<Caller>) -> Result<Vec<Tag>, TagError>;
+    async fn insert_tag(&self, name: &str) -> Result<Tag, TagError>;
+    async fn rename_tag(&self, name: &str, new_name: &str) -> Result<Tag, TagError>;
+    async fn delete_tag(&self, name: &str) -> Result<(), TagError>;
+}
+
+#[derive(Debug)]
+pub enum TagError {
+    TagNotFound,
+    TagExists,
+    InternalError,
+}
+
+impl Display for TagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TagNotFound => write!(f, "Tag not found"),
+            Self::TagExists => write!(f, "Tag already exists"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for TagError {}
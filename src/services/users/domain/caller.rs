@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Role {
+    #[default]
+    Member,
+    /// May change and see every recipe, whoever owns it.
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Member => "member",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "member" => Ok(Role::Member),
+            "admin" => Ok(Role::Admin),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Whoever a request is made on behalf of. Services take it to decide what
+/// the request may do, independently of how the caller was identified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Caller {
+    user: uuid::Uuid,
+    role: Role,
+}
+
+impl Caller {
+    pub fn new(user: uuid::Uuid, role: Role) -> Self {
+        Self { user, role }
+    }
+
+    pub fn user(&self) -> uuid::Uuid {
+        self.user
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.role == Role::Admin
+    }
+}
@@ -0,0 +1,4 @@
+pub mod caller;
+pub mod password;
+pub mod session;
+pub mod user;
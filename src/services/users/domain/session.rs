@@ -0,0 +1,190 @@
+use std::{error::Error, fmt::Display, sync::Arc};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::caller::{Caller, Role};
+
+const TOKEN_VERSION: &str = "v2";
+
+/// What a verified session token says about its bearer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Claims {
+    user: uuid::Uuid,
+    role: Role,
+    expires_at: u64,
+}
+
+impl Claims {
+    pub fn user(&self) -> uuid::Uuid {
+        self.user
+    }
+
+    pub fn caller(&self) -> Caller {
+        Caller::new(self.user, self.role)
+    }
+
+    /// Expiry as seconds since the Unix epoch.
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionToken {
+    token: String,
+    claims: Claims,
+}
+
+impl SessionToken {
+    pub fn as_str(&self) -> &str {
+        self.token.as_ref()
+    }
+
+    pub fn claims(&self) -> Claims {
+        self.claims
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TokenError {
+    Malformed,
+    BadSignature,
+    Expired,
+}
+
+impl Display for TokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenError::Malformed => f.write_str("Malformed token"),
+            TokenError::BadSignature => f.write_str("Invalid token signature"),
+            TokenError::Expired => f.write_str("Token expired"),
+        }
+    }
+}
+
+impl Error for TokenError {}
+
+/// Issues and checks stateless session tokens: a base64url payload of
+/// version, user, role and expiry, followed by its HMAC-SHA256 signature.
+#[derive(Clone)]
+pub struct TokenSigner {
+    secret: Arc<Vec<u8>>,
+    ttl: u64,
+}
+
+impl TokenSigner {
+    /// `ttl` is the lifetime of issued tokens in seconds.
+    pub fn new(secret: &[u8], ttl: u64) -> Self {
+        Self {
+            secret: Arc::new(secret.to_vec()),
+            ttl,
+        }
+    }
+
+    fn mac(&self) -> Hmac<Sha256> {
+        Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any size")
+    }
+
+    pub fn issue(&self, caller: Caller, now: u64) -> SessionToken {
+        let claims = Claims {
+            user: caller.user(),
+            role: caller.role(),
+            expires_at: now + self.ttl,
+        };
+        let payload = format!(
+            "{}.{}.{}.{}",
+            TOKEN_VERSION,
+            claims.user,
+            claims.role.as_str(),
+            claims.expires_at
+        );
+        let mut mac = self.mac();
+        mac.update(payload.as_bytes());
+        let signature = mac.finalize().into_bytes();
+        SessionToken {
+            token: format!(
+                "{}.{}",
+                URL_SAFE_NO_PAD.encode(payload),
+                URL_SAFE_NO_PAD.encode(signature)
+            ),
+            claims,
+        }
+    }
+
+    pub fn verify(&self, token: &str, now: u64) -> Result<Claims, TokenError> {
+        let (payload, signature) = token.split_once('.').ok_or(TokenError::Malformed)?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_e| TokenError::Malformed)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_e| TokenError::Malformed)?;
+        let mut mac = self.mac();
+        mac.update(&payload);
+        mac.verify_slice(&signature)
+            .map_err(|_e| TokenError::BadSignature)?;
+
+        let payload = String::from_utf8(payload).map_err(|_e| TokenError::Malformed)?;
+        let mut parts = payload.split('.');
+        let (Some(TOKEN_VERSION), Some(user), Some(role), Some(expires_at), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return Err(TokenError::Malformed);
+        };
+        let claims = Claims {
+            user: uuid::Uuid::parse_str(user).map_err(|_e| TokenError::Malformed)?,
+            role: role.parse().map_err(|_e| TokenError::Malformed)?,
+            expires_at: expires_at.parse().map_err(|_e| TokenError::Malformed)?,
+        };
+        if claims.expires_at <= now {
+            return Err(TokenError::Expired);
+        }
+        Ok(claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issued_tokens_verify_until_they_expire() {
+        let signer = TokenSigner::new(b"secret", 60);
+        let caller = Caller::new(uuid::Uuid::new_v4(), Role::Admin);
+        let token = signer.issue(caller, 1_000);
+        assert_eq!(
+            signer.verify(token.as_str(), 1_059).unwrap().caller(),
+            caller
+        );
+        assert_eq!(
+            signer.verify(token.as_str(), 1_060),
+            Err(TokenError::Expired)
+        );
+    }
+
+    #[test]
+    fn tampered_tokens_are_rejected() {
+        let signer = TokenSigner::new(b"secret", 60);
+        let token = signer.issue(Caller::new(uuid::Uuid::new_v4(), Role::Member), 1_000);
+        let other = TokenSigner::new(b"other secret", 60);
+        assert_eq!(
+            other.verify(token.as_str(), 1_000),
+            Err(TokenError::BadSignature)
+        );
+
+        let (_, signature) = token.as_str().split_once('.').unwrap();
+        let forged = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(format!("v2.{}.admin.9999", uuid::Uuid::new_v4())),
+            signature
+        );
+        assert_eq!(signer.verify(&forged, 1_000), Err(TokenError::BadSignature));
+        assert_eq!(signer.verify("garbage", 1_000), Err(TokenError::Malformed));
+    }
+}
@@ -0,0 +1,60 @@
+use super::caller::{Caller, Role};
+
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const MAX_USERNAME_LENGTH: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct User {
+    uuid: uuid::Uuid,
+    username: String,
+    password_hash: String,
+    role: Role,
+}
+
+impl User {
+    pub fn new(uuid: uuid::Uuid, username: String, password_hash: String) -> Self {
+        Self {
+            uuid,
+            username,
+            password_hash,
+            role: Role::default(),
+        }
+    }
+
+    pub fn with_role(self, role: Role) -> Self {
+        Self { role, ..self }
+    }
+
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.uuid
+    }
+
+    pub fn username(&self) -> &str {
+        self.username.as_ref()
+    }
+
+    /// PHC string of the argon2 hash, never the password itself.
+    pub fn password_hash(&self) -> &str {
+        self.password_hash.as_ref()
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn caller(&self) -> Caller {
+        Caller::new(self.uuid, self.role)
+    }
+}
+
+/// Usernames are 3 to 32 ASCII letters, digits, `_`, `-` or `.`.
+pub fn is_valid_username(username: &str) -> bool {
+    (3..=MAX_USERNAME_LENGTH).contains(&username.len())
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+pub fn is_valid_password(password: &str) -> bool {
+    password.chars().count() >= MIN_PASSWORD_LENGTH
+}
@@ -0,0 +1,117 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+
+use super::{
+    domain::{
+        password::verify_password,
+        session::{SessionToken, TokenSigner},
+    },
+    ports::{
+        incoming::login_service::{LoginService, LoginServiceError},
+        outgoing::query_user_port::{QueryUserError, QueryUserPort},
+    },
+};
+
+/// Verified against when the username is unknown, so both failures take as
+/// long and don't reveal which usernames exist.
+const DUMMY_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$c29tZXNhbHRzb21lc2FsdA$Pb5MFhyGP2SY6UqHBLPcTe7Jq3A8Mz3IU7yBukXE8Jc";
+
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+pub struct Login<Storage>
+where
+    Storage: QueryUserPort + Send + Sync,
+{
+    storage: Storage,
+    signer: TokenSigner,
+}
+
+#[async_trait]
+impl<Storage> LoginService for Login<Storage>
+where
+    Storage: QueryUserPort + Send + Sync,
+{
+    async fn login(
+        &self,
+        username: String,
+        password: String,
+    ) -> Result<SessionToken, LoginServiceError> {
+        let user = match self.storage.query_user_by_username(username.trim()).await {
+            Ok(user) => Some(user),
+            Err(QueryUserError::RecordNotFound) => None,
+            Err(QueryUserError::InternalError) => return Err(LoginServiceError::InternalError),
+        };
+        let hash = user
+            .as_ref()
+            .map(|user| user.password_hash().to_string())
+            .unwrap_or_else(|| DUMMY_HASH.to_string());
+        let verified = tokio::task::spawn_blocking(move || verify_password(&password, &hash))
+            .await
+            .map_err(|_e| LoginServiceError::InternalError)?;
+        match user {
+            Some(user) if verified => Ok(self.signer.issue(user.caller(), unix_now())),
+            _ => Err(LoginServiceError::InvalidCredentials),
+        }
+    }
+}
+
+impl<Storage> Login<Storage>
+where
+    Storage: QueryUserPort + Send + Sync,
+{
+    pub fn new(storage: Storage, signer: TokenSigner) -> Self {
+        Self { storage, signer }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data_storage::{memory_pool, users::users_sqlite_ds::UserSqliteDS},
+        services::users::{
+            domain::{password::hash_password, user::User},
+            ports::outgoing::insert_user_port::InsertUserPort,
+        },
+    };
+
+    async fn service() -> (Login<UserSqliteDS>, User) {
+        let storage = UserSqliteDS::new(memory_pool().await);
+        let user = User::new(
+            uuid::Uuid::new_v4(),
+            "alice".to_string(),
+            hash_password("correct horse").unwrap(),
+        );
+        storage.insert_user(&user).await.unwrap();
+        (Login::new(storage, TokenSigner::new(b"secret", 60)), user)
+    }
+
+    #[tokio::test]
+    async fn valid_credentials_get_a_token_for_the_user() {
+        let (service, user) = service().await;
+        let token = service
+            .login("Alice".to_string(), "correct horse".to_string())
+            .await
+            .unwrap();
+        assert_eq!(token.claims().user(), user.uuid());
+        assert!(service.signer.verify(token.as_str(), unix_now()).is_ok());
+    }
+
+    #[tokio::test]
+    async fn wrong_password_and_unknown_user_look_the_same() {
+        let (service, _user) = service().await;
+        for (username, password) in [("alice", "battery staple"), ("bob", "correct horse")] {
+            let result = service
+                .login(username.to_string(), password.to_string())
+                .await;
+            assert_eq!(result.unwrap_err(), LoginServiceError::InvalidCredentials);
+        }
+    }
+}
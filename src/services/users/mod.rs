@@ -0,0 +1,5 @@
+pub mod domain;
+pub mod login_service;
+pub mod ports;
+pub mod query_user_service;
+pub mod register_user_service;
@@ -0,0 +1,30 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::users::domain::session::SessionToken;
+
+#[async_trait]
+pub trait LoginService {
+    async fn login(
+        &self,
+        username: String,
+        password: String,
+    ) -> Result<SessionToken, LoginServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LoginServiceError {
+    InvalidCredentials,
+    InternalError,
+}
+
+impl Display for LoginServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoginServiceError::InvalidCredentials => f.write_str("Invalid username or password"),
+            LoginServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for LoginServiceError {}
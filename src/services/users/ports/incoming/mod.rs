@@ -0,0 +1,3 @@
+pub mod login_service;
+pub mod query_user_service;
+pub mod register_user_service;
@@ -0,0 +1,26 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::users::domain::user::User;
+
+#[async_trait]
+pub trait QueryUserService {
+    async fn query_user(&self, uuid: uuid::Uuid) -> Result<User, QueryUserServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QueryUserServiceError {
+    UserNotFound,
+    InternalError,
+}
+
+impl Display for QueryUserServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryUserServiceError::UserNotFound => f.write_str("User not found"),
+            QueryUserServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for QueryUserServiceError {}
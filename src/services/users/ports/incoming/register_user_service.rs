@@ -0,0 +1,38 @@
+use std::{error::Error, fmt::Display};
+
+use async_trait::async_trait;
+
+use crate::services::users::domain::user::User;
+
+#[async_trait]
+pub trait RegisterUserService {
+    async fn register_user(
+        &self,
+        username: String,
+        password: String,
+    ) -> Result<User, RegisterUserServiceError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RegisterUserServiceError {
+    InvalidUsername,
+    WeakPassword,
+    UsernameTaken,
+    InternalError,
+}
+
+impl Display for RegisterUserServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisterUserServiceError::InvalidUsername => f.write_str(
+                "A username must be 3 to 32 letters, digits, underscores, dashes or dots",
+            ),
+            RegisterUserServiceError::WeakPassword => {
+                f.write_str("A password must be at least 8 characters long")
+            }
+            RegisterUserServiceError::UsernameTaken => f.write_str("Username already taken"),
+            RegisterUserServiceError::InternalError => f.write_str("Internal error"),
+        }
+    }
+}
+impl Error for RegisterUserServiceError {}
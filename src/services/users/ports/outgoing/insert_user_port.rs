@@ -0,0 +1,24 @@
+use crate::services::users::domain::user::User;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait InsertUserPort {
+    async fn insert_user(&self, user: &User) -> Result<(), InsertUserError>;
+}
+
+#[derive(Debug)]
+pub enum InsertUserError {
+    UsernameTaken,
+    InternalError,
+}
+
+impl Display for InsertUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UsernameTaken => write!(f, "Username taken"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for InsertUserError {}
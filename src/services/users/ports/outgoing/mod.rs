@@ -0,0 +1,2 @@
+pub mod insert_user_port;
+pub mod query_user_port;
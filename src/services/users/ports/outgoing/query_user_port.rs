@@ -0,0 +1,26 @@
+use crate::services::users::domain::user::User;
+use async_trait::async_trait;
+use std::{error::Error, fmt::Display};
+#[async_trait]
+pub trait QueryUserPort {
+    async fn query_user(&self, uuid: uuid::Uuid) -> Result<User, QueryUserError>;
+    /// Usernames are compared case-insensitively.
+    async fn query_user_by_username(&self, username: &str) -> Result<User, QueryUserError>;
+}
+
+#[derive(Debug)]
+pub enum QueryUserError {
+    RecordNotFound,
+    InternalError,
+}
+
+impl Display for QueryUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecordNotFound => write!(f, "Record not found"),
+            Self::InternalError => write!(f, "Internal error"),
+        }
+    }
+}
+
+impl Error for QueryUserError {}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::user::User,
+    ports::{
+        incoming::query_user_service::{QueryUserService, QueryUserServiceError},
+        outgoing::query_user_port::{QueryUserError, QueryUserPort},
+    },
+};
+
+impl From<QueryUserError> for QueryUserServiceError {
+    fn from(value: QueryUserError) -> Self {
+        match value {
+            QueryUserError::RecordNotFound => QueryUserServiceError::UserNotFound,
+            QueryUserError::InternalError => QueryUserServiceError::InternalError,
+        }
+    }
+}
+
+pub struct QueryUser<Storage>
+where
+    Storage: QueryUserPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> QueryUserService for QueryUser<Storage>
+where
+    Storage: QueryUserPort + Send + Sync,
+{
+    async fn query_user(&self, uuid: uuid::Uuid) -> Result<User, QueryUserServiceError> {
+        self.storage
+            .query_user(uuid)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+impl<Storage> QueryUser<Storage>
+where
+    Storage: QueryUserPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+
+use super::{
+    domain::{
+        password::hash_password,
+        user::{is_valid_password, is_valid_username, User},
+    },
+    ports::{
+        incoming::register_user_service::{RegisterUserService, RegisterUserServiceError},
+        outgoing::insert_user_port::{InsertUserError, InsertUserPort},
+    },
+};
+
+impl From<InsertUserError> for RegisterUserServiceError {
+    fn from(value: InsertUserError) -> Self {
+        match value {
+            InsertUserError::UsernameTaken => RegisterUserServiceError::UsernameTaken,
+            InsertUserError::InternalError => RegisterUserServiceError::InternalError,
+        }
+    }
+}
+
+pub struct RegisterUser<Storage>
+where
+    Storage: InsertUserPort + Send + Sync,
+{
+    storage: Storage,
+}
+
+#[async_trait]
+impl<Storage> RegisterUserService for RegisterUser<Storage>
+where
+    Storage: InsertUserPort + Send + Sync,
+{
+    async fn register_user(
+        &self,
+        username: String,
+        password: String,
+    ) -> Result<User, RegisterUserServiceError> {
+        let username = username.trim().to_string();
+        if !is_valid_username(&username) {
+            return Err(RegisterUserServiceError::InvalidUsername);
+        }
+        if !is_valid_password(&password) {
+            return Err(RegisterUserServiceError::WeakPassword);
+        }
+        // Argon2 is deliberately slow, keep it off the async workers.
+        let password_hash = tokio::task::spawn_blocking(move || hash_password(&password))
+            .await
+            .map_err(|_e| RegisterUserServiceError::InternalError)?
+            .map_err(|_e| RegisterUserServiceError::InternalError)?;
+        let user = User::new(uuid::Uuid::new_v4(), username, password_hash);
+        self.storage.insert_user(&user).await?;
+        Ok(user)
+    }
+}
+
+impl<Storage> RegisterUser<Storage>
+where
+    Storage: InsertUserPort + Send + Sync,
+{
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
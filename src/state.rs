@@ -1,12 +1,23 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use argon2::password_hash::rand_core::{OsRng, RngCore};
 use sqlx::SqlitePool;
 
-use crate::configuration::Configuration;
+use crate::{
+    configuration::{Configuration, IdentitySource},
+    data_storage::nutrition::nutrients_sqlite_ds,
+    services::users::domain::session::TokenSigner,
+};
 
 #[derive(Clone)]
 pub struct State {
     pool: SqlitePool,
+    image_base_path: PathBuf,
+    image_max_size: usize,
+    image_sizes: Vec<u32>,
+    image_quality: u8,
+    token_signer: TokenSigner,
+    identity_source: IdentitySource,
 }
 
 impl State {
@@ -30,15 +41,63 @@ impl State {
                     .run(&inner_pool)
                     .await
                     .expect("Failed to run migrations");
+                if let Some(path) = configuration.nutrient_seed_path() {
+                    // Nutrition facts are optional, the server runs without them.
+                    match nutrients_sqlite_ds::seed_nutrients(&inner_pool, Path::new(path)).await {
+                        Ok(count) => tracing::info!("Seeded {} nutrients from {}", count, path),
+                        Err(e) => tracing::warn!("Failed to seed nutrients from {}: {}", path, e),
+                    }
+                }
                 pool = Some(inner_pool);
             });
         });
 
         let pool = pool.unwrap();
-        Self { pool }
+        let token_signer = match configuration.auth_secret() {
+            Some(secret) => TokenSigner::new(secret.as_bytes(), configuration.token_ttl()),
+            None => {
+                tracing::warn!("No [AUTH] secret configured, sessions end with the process");
+                let mut secret = [0u8; 32];
+                OsRng.fill_bytes(&mut secret);
+                TokenSigner::new(&secret, configuration.token_ttl())
+            }
+        };
+        Self {
+            pool,
+            image_base_path: PathBuf::from(configuration.image_base_path()),
+            image_max_size: configuration.image_max_size(),
+            image_sizes: configuration.image_sizes(),
+            image_quality: configuration.image_quality(),
+            token_signer,
+            identity_source: configuration.identity_source(),
+        }
     }
 
     pub fn pool(&self) -> SqlitePool {
         self.pool.clone()
     }
+
+    pub fn image_base_path(&self) -> PathBuf {
+        self.image_base_path.clone()
+    }
+
+    pub fn image_max_size(&self) -> usize {
+        self.image_max_size
+    }
+
+    pub fn image_sizes(&self) -> Vec<u32> {
+        self.image_sizes.clone()
+    }
+
+    pub fn image_quality(&self) -> u8 {
+        self.image_quality
+    }
+
+    pub fn token_signer(&self) -> TokenSigner {
+        self.token_signer.clone()
+    }
+
+    pub fn identity_source(&self) -> IdentitySource {
+        self.identity_source
+    }
 }
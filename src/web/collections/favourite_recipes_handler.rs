@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::collections::ports::incoming::favourite_recipes_service::{
+        FavouriteRecipesService, FavouriteRecipesServiceError,
+    },
+    web::users::authenticated_user::AuthenticatedUser,
+};
+
+fn error_response(error: FavouriteRecipesServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        FavouriteRecipesServiceError::RecipeNotFound => StatusCode::NOT_FOUND,
+        FavouriteRecipesServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynFavouriteRecipesService = Arc<dyn FavouriteRecipesService + Sync + Send>;
+pub async fn list_favourites_handler(
+    axum::extract::State(service): axum::extract::State<DynFavouriteRecipesService>,
+    user: AuthenticatedUser,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.list_favourites(user.caller()).await {
+        Ok(recipes) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "recipes": recipes
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn toggle_favourite_handler(
+    axum::extract::State(service): axum::extract::State<DynFavouriteRecipesService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.toggle_favourite(user.caller(), identifier.0).await {
+        Ok(favourite) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "recipe": identifier.0,
+                    "favourite": favourite,
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
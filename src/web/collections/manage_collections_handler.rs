@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::collections::{
+        domain::collection::Collection,
+        ports::incoming::manage_collections_service::{
+            ManageCollectionsService, ManageCollectionsServiceError,
+        },
+    },
+    web::users::authenticated_user::AuthenticatedUser,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateCollectionJson {
+    name: String,
+    #[serde(default)]
+    recipes: Vec<Uuid>,
+}
+
+/// Fields left out are kept as they are.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateCollectionJson {
+    name: Option<String>,
+    recipes: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CollectionRecipeJson {
+    recipe: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CollectionJson {
+    uuid: Uuid,
+    name: String,
+    recipes: Vec<Uuid>,
+}
+
+impl From<Collection> for CollectionJson {
+    fn from(value: Collection) -> Self {
+        Self {
+            uuid: value.uuid(),
+            name: value.name().to_string(),
+            recipes: value.recipes().to_vec(),
+        }
+    }
+}
+
+fn error_response(error: ManageCollectionsServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let (status, recipe) = match error {
+        ManageCollectionsServiceError::InvalidName(_) => (StatusCode::BAD_REQUEST, None),
+        ManageCollectionsServiceError::CollectionNotFound => (StatusCode::NOT_FOUND, None),
+        ManageCollectionsServiceError::CollectionExists => (StatusCode::CONFLICT, None),
+        ManageCollectionsServiceError::RecipeNotFound(recipe) => {
+            (StatusCode::NOT_FOUND, Some(recipe))
+        }
+        ManageCollectionsServiceError::RecipeExists => (StatusCode::CONFLICT, None),
+        ManageCollectionsServiceError::InternalError => (StatusCode::INTERNAL_SERVER_ERROR, None),
+    };
+    let body = match recipe {
+        Some(recipe) => json!({
+            "error": format!("{}", error),
+            "recipe": recipe,
+        }),
+        None => json!({
+            "error": format!("{}", error)
+        }),
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(Json(body).to_string()))
+        .map_err(|e| e.into())
+}
+
+fn collection_response(
+    status: StatusCode,
+    collection: Collection,
+) -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!(CollectionJson::from(collection))).to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynManageCollectionsService = Arc<dyn ManageCollectionsService + Sync + Send>;
+pub async fn list_collections_handler(
+    axum::extract::State(service): axum::extract::State<DynManageCollectionsService>,
+    user: AuthenticatedUser,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.list_collections(user.caller()).await {
+        Ok(collections) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "collections": collections
+                        .into_iter()
+                        .map(CollectionJson::from)
+                        .collect::<Vec<CollectionJson>>()
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn create_collection_handler(
+    axum::extract::State(service): axum::extract::State<DynManageCollectionsService>,
+    user: AuthenticatedUser,
+    json: Json<CreateCollectionJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service
+        .create_collection(user.caller(), json.0.name, json.0.recipes)
+        .await
+    {
+        Ok(collection) => collection_response(StatusCode::CREATED, collection),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn query_collection_handler(
+    axum::extract::State(service): axum::extract::State<DynManageCollectionsService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.query_collection(user.caller(), identifier.0).await {
+        Ok(collection) => collection_response(StatusCode::OK, collection),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn update_collection_handler(
+    axum::extract::State(service): axum::extract::State<DynManageCollectionsService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    json: Json<UpdateCollectionJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service
+        .update_collection(user.caller(), identifier.0, json.0.name, json.0.recipes)
+        .await
+    {
+        Ok(collection) => collection_response(StatusCode::OK, collection),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn delete_collection_handler(
+    axum::extract::State(service): axum::extract::State<DynManageCollectionsService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.delete_collection(user.caller(), identifier.0).await {
+        Ok(()) => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(body::boxed(BoxBody::default()))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn add_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynManageCollectionsService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    json: Json<CollectionRecipeJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service
+        .add_recipe(user.caller(), identifier.0, json.0.recipe)
+        .await
+    {
+        Ok(collection) => collection_response(StatusCode::OK, collection),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn remove_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynManageCollectionsService>,
+    user: AuthenticatedUser,
+    path: axum::extract::Path<(Uuid, Uuid)>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let (identifier, recipe) = path.0;
+    match service
+        .remove_recipe(user.caller(), identifier, recipe)
+        .await
+    {
+        Ok(collection) => collection_response(StatusCode::OK, collection),
+        Err(error) => error_response(error),
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{delete, get, post},
+    Router,
+};
+
+use crate::{
+    data_storage::{
+        collections::collections_sqlite_ds::CollectionSqliteDS,
+        recipes::recipes_sqlite_ds::RecipeSqliteDS,
+    },
+    services::collections::{
+        favourite_recipes_service::FavouriteRecipes, manage_collections_service::ManageCollections,
+    },
+    state::State,
+};
+
+use self::{
+    favourite_recipes_handler::DynFavouriteRecipesService,
+    manage_collections_handler::DynManageCollectionsService,
+};
+
+pub mod favourite_recipes_handler;
+pub mod manage_collections_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = CollectionSqliteDS::new(state.pool());
+    let recipes = RecipeSqliteDS::new(state.pool());
+
+    let manage_collections_service =
+        Arc::new(ManageCollections::new(storage.clone(), recipes.clone()))
+            as DynManageCollectionsService;
+    let favourite_recipes_service =
+        Arc::new(FavouriteRecipes::new(storage, recipes)) as DynFavouriteRecipesService;
+
+    let collections_routes = Router::new()
+        .route(
+            "/",
+            get(manage_collections_handler::list_collections_handler)
+                .post(manage_collections_handler::create_collection_handler),
+        )
+        .route(
+            "/:identifier",
+            get(manage_collections_handler::query_collection_handler)
+                .put(manage_collections_handler::update_collection_handler)
+                .delete(manage_collections_handler::delete_collection_handler),
+        )
+        .route(
+            "/:identifier/recipes",
+            post(manage_collections_handler::add_recipe_handler),
+        )
+        .route(
+            "/:identifier/recipes/:recipe",
+            delete(manage_collections_handler::remove_recipe_handler),
+        )
+        .with_state(manage_collections_service)
+        .route(
+            "/favourites",
+            get(favourite_recipes_handler::list_favourites_handler),
+        )
+        .with_state(favourite_recipes_service.clone());
+    let favourite_routes = Router::new()
+        .route(
+            "/:identifier/favourite",
+            post(favourite_recipes_handler::toggle_favourite_handler),
+        )
+        .with_state(favourite_recipes_service);
+
+    let collections_router = Router::new()
+        .nest("/collections", collections_routes)
+        .nest("/recipes", favourite_routes);
+    Router::new().nest("/api/v1", collections_router)
+}
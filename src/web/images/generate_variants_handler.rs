@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::images::{
+        domain::variant::ImageVariant,
+        ports::incoming::generate_variants_service::{
+            GenerateVariantsService, GenerateVariantsServiceError,
+        },
+    },
+    web::users::authenticated_user::AuthenticatedUser,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageVariantJson {
+    size: u32,
+    format: &'static str,
+    name: String,
+    width: u32,
+    height: u32,
+}
+
+impl From<&ImageVariant> for ImageVariantJson {
+    fn from(value: &ImageVariant) -> Self {
+        Self {
+            size: value.size(),
+            format: value.format().extension(),
+            name: value.name().as_str().to_string(),
+            width: value.width(),
+            height: value.height(),
+        }
+    }
+}
+
+pub(crate) type DynGenerateVariantsService = Arc<dyn GenerateVariantsService + Sync + Send>;
+pub async fn generate_variants_handler(
+    axum::extract::State(service): axum::extract::State<DynGenerateVariantsService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<uuid::Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let builder = match service.generate_variants(user.caller(), identifier.0).await {
+        Ok(variants) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "variants": variants
+                        .iter()
+                        .map(ImageVariantJson::from)
+                        .collect::<Vec<ImageVariantJson>>()
+                }))
+                .to_string(),
+            )),
+        Err(error @ GenerateVariantsServiceError::RecipeNotFound)
+        | Err(error @ GenerateVariantsServiceError::ImageNotFound) => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", error)
+                }))
+                .to_string(),
+            )),
+        Err(error @ GenerateVariantsServiceError::Forbidden) => Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", error)
+                }))
+                .to_string(),
+            )),
+        Err(error @ GenerateVariantsServiceError::UndecodableImage) => Response::builder()
+            .status(StatusCode::UNPROCESSABLE_ENTITY)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", error)
+                }))
+                .to_string(),
+            )),
+        Err(GenerateVariantsServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", GenerateVariantsServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
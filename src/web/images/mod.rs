@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Router,
+};
+
+use crate::{
+    data_storage::{images::images_fs_ds::ImageFsDS, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+    services::images::{
+        domain::variant::VariantSettings, generate_variants_service::GenerateVariants,
+        query_image_service::QueryImage, upload_image_service::UploadImage,
+    },
+    state::State,
+};
+
+use self::{
+    generate_variants_handler::DynGenerateVariantsService,
+    query_image_handler::DynQueryImageService, upload_image_handler::DynUploadImageService,
+};
+
+pub mod generate_variants_handler;
+pub mod query_image_handler;
+pub mod upload_image_handler;
+
+// Room for the multipart boundaries and headers around the image itself.
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = RecipeSqliteDS::new(state.pool());
+    let images = ImageFsDS::new(state.image_base_path());
+    let settings = VariantSettings::new(state.image_sizes(), state.image_quality());
+
+    let upload_image_service = Arc::new(UploadImage::new(
+        storage.clone(),
+        images.clone(),
+        state.image_max_size(),
+        settings.clone(),
+    )) as DynUploadImageService;
+    let query_image_service =
+        Arc::new(QueryImage::new(storage.clone(), images.clone())) as DynQueryImageService;
+    let generate_variants_service = Arc::new(GenerateVariants::new(
+        storage.clone(),
+        images.clone(),
+        settings,
+    )) as DynGenerateVariantsService;
+
+    let images_routes = Router::new()
+        .route(
+            "/:identifier/image",
+            post(upload_image_handler::upload_image_handler).layer(DefaultBodyLimit::max(
+                state.image_max_size() + MULTIPART_OVERHEAD,
+            )),
+        )
+        .with_state(upload_image_service)
+        .route(
+            "/:identifier/image",
+            get(query_image_handler::query_image_handler),
+        )
+        .with_state(query_image_service)
+        .route(
+            "/:identifier/image/variants",
+            post(generate_variants_handler::generate_variants_handler),
+        )
+        .with_state(generate_variants_service);
+
+    let recipes_router = Router::new().nest("/recipes", images_routes);
+    Router::new().nest("/api/v1", recipes_router)
+}
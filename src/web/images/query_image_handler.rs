@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{HeaderMap, Response, StatusCode},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::images::{
+        domain::image::ImageFormat,
+        ports::incoming::query_image_service::{QueryImageService, QueryImageServiceError},
+    },
+    web::users::authenticated_user::OptionalUser,
+};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VariantFormatJson {
+    Webp,
+    Jpeg,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryImageParams {
+    size: Option<u32>,
+    format: Option<VariantFormatJson>,
+}
+
+/// Explicit `format` wins, otherwise WebP is served to clients accepting it.
+fn preferred_formats(params: &QueryImageParams, headers: &HeaderMap) -> Vec<ImageFormat> {
+    match params.format {
+        Some(VariantFormatJson::Webp) => vec![ImageFormat::Webp],
+        Some(VariantFormatJson::Jpeg) => vec![ImageFormat::Jpeg],
+        None => {
+            let accepts_webp = headers
+                .get(axum::http::header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.contains("image/webp"))
+                .unwrap_or(false);
+            if accepts_webp {
+                vec![ImageFormat::Webp, ImageFormat::Jpeg]
+            } else {
+                vec![ImageFormat::Jpeg]
+            }
+        }
+    }
+}
+
+// The URL is stable while the image behind it can change, so clients revalidate
+// after an hour using the content hash as ETag. Images served to an identified
+// caller may belong to a recipe others cannot view, so shared caches keep out.
+const CACHE_CONTROL: &str = "public, max-age=3600";
+const PRIVATE_CACHE_CONTROL: &str = "private, max-age=3600";
+
+pub(crate) type DynQueryImageService = Arc<dyn QueryImageService + Sync + Send>;
+pub async fn query_image_handler(
+    axum::extract::State(service): axum::extract::State<DynQueryImageService>,
+    user: OptionalUser,
+    identifier: axum::extract::Path<uuid::Uuid>,
+    params: axum::extract::Query<QueryImageParams>,
+    headers: HeaderMap,
+) -> Result<Response<BoxBody>, YaissError> {
+    let formats = preferred_formats(&params.0, &headers);
+    let builder = match service
+        .query_image(user.caller(), identifier.0, params.0.size, formats)
+        .await
+    {
+        Ok(image) => {
+            let etag = format!("\"{}\"", image.name().digest());
+            let not_modified = headers
+                .get(axum::http::header::IF_NONE_MATCH)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.split(',').any(|tag| tag.trim() == etag))
+                .unwrap_or(false);
+            let cache_control = match user.caller() {
+                Some(_) => PRIVATE_CACHE_CONTROL,
+                None => CACHE_CONTROL,
+            };
+            let builder = Response::builder()
+                .header(axum::http::header::ETAG, etag)
+                .header(axum::http::header::CACHE_CONTROL, cache_control)
+                .header(axum::http::header::VARY, "accept");
+            if not_modified {
+                builder
+                    .status(StatusCode::NOT_MODIFIED)
+                    .body(BoxBody::default())
+            } else {
+                builder
+                    .status(StatusCode::OK)
+                    .header(axum::http::header::CONTENT_TYPE, image.format().mime())
+                    .body(body::boxed(body::Full::from(image.into_bytes())))
+            }
+        }
+        Err(error @ QueryImageServiceError::RecipeNotFound)
+        | Err(error @ QueryImageServiceError::ImageNotFound) => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", error)
+                }))
+                .to_string(),
+            )),
+        Err(QueryImageServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", QueryImageServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, routing::get, Extension, Router};
+    use tower::ServiceExt;
+
+    use crate::{
+        data_storage::{
+            images::images_fs_ds::ImageFsDS, memory_pool,
+            recipes::recipes_sqlite_ds::RecipeSqliteDS, stored_recipe,
+        },
+        services::{
+            images::query_image_service::QueryImage,
+            recipes::domain::access::{RecipeAccess, Visibility},
+        },
+        web::users::identity::{DynIdentityExtractor, TrustedHeaderIdentity},
+    };
+
+    #[tokio::test]
+    async fn images_of_private_recipes_are_only_served_to_their_viewers() {
+        let pool = memory_pool().await;
+        let owner = uuid::Uuid::new_v4();
+        let recipe =
+            stored_recipe(&pool, RecipeAccess::new(Some(owner), Visibility::Private)).await;
+        let storage = RecipeSqliteDS::new(pool);
+        let images = ImageFsDS::new(std::env::temp_dir().join("no-images"));
+        let service = Arc::new(QueryImage::new(storage, images)) as DynQueryImageService;
+        let app = Router::new()
+            .route("/:identifier/image", get(query_image_handler))
+            .with_state(service)
+            .layer(Extension(
+                Arc::new(TrustedHeaderIdentity) as DynIdentityExtractor
+            ));
+
+        // Only the owner passes the check, and learns that there is no image.
+        for (user, error) in [
+            (None, "Recipe not found"),
+            (Some(uuid::Uuid::new_v4()), "Recipe not found"),
+            (Some(owner), "Image not found"),
+        ] {
+            let mut request = Request::get(format!("/{}/image", recipe.uuid()));
+            if let Some(user) = user {
+                request = request.header("x-user-id", user.to_string());
+            }
+            let response = app
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["error"], error);
+        }
+    }
+}
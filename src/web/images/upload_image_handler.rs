@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    extract::Multipart,
+    http::{Response, StatusCode},
+    Json,
+};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::images::ports::incoming::upload_image_service::{
+        UploadImageService, UploadImageServiceError,
+    },
+    web::users::authenticated_user::AuthenticatedUser,
+};
+
+pub(crate) const IMAGE_FIELD: &str = "image";
+
+pub(crate) type DynUploadImageService = Arc<dyn UploadImageService + Sync + Send>;
+pub async fn upload_image_handler(
+    axum::extract::State(service): axum::extract::State<DynUploadImageService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<uuid::Uuid>,
+    mut multipart: Multipart,
+) -> Result<Response<BoxBody>, YaissError> {
+    let mut upload: Option<(String, Vec<u8>)> = None;
+    loop {
+        match multipart.next_field().await {
+            Ok(Some(field)) if field.name() == Some(IMAGE_FIELD) => {
+                let content_type = field.content_type().unwrap_or_default().to_string();
+                match field.bytes().await {
+                    Ok(bytes) => upload = Some((content_type, bytes.to_vec())),
+                    Err(e) => return error_response(e.status(), e.body_text()),
+                }
+                break;
+            }
+            Ok(Some(_)) => continue,
+            Ok(None) => break,
+            Err(e) => return error_response(e.status(), e.body_text()),
+        }
+    }
+    let Some((content_type, bytes)) = upload else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Missing multipart field `{}`", IMAGE_FIELD),
+        );
+    };
+
+    match service
+        .upload_image(user.caller(), identifier.0, content_type, bytes)
+        .await
+    {
+        Ok(name) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "image": name.as_str(),
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error @ UploadImageServiceError::RecipeNotFound) => {
+            error_response(StatusCode::NOT_FOUND, format!("{}", error))
+        }
+        Err(error @ UploadImageServiceError::Forbidden) => {
+            error_response(StatusCode::FORBIDDEN, format!("{}", error))
+        }
+        Err(error @ UploadImageServiceError::EmptyImage)
+        | Err(error @ UploadImageServiceError::UndecodableImage) => {
+            error_response(StatusCode::BAD_REQUEST, format!("{}", error))
+        }
+        Err(error @ UploadImageServiceError::ImageTooLarge) => {
+            error_response(StatusCode::PAYLOAD_TOO_LARGE, format!("{}", error))
+        }
+        Err(error @ UploadImageServiceError::UnsupportedContentType) => {
+            error_response(StatusCode::UNSUPPORTED_MEDIA_TYPE, format!("{}", error))
+        }
+        Err(error @ UploadImageServiceError::InternalError) => {
+            error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("{}", error))
+        }
+    }
+}
+
+fn error_response(status: StatusCode, error: String) -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": error,
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody, Bytes},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::{
+        library::ports::incoming::exchange_recipes_service::{
+            ExchangeRecipesService, ExchangeRecipesServiceError,
+        },
+        recipes::domain::recipe_format::FormatKind,
+    },
+    web::{
+        recipes::query_recipe_handler::VisibilityJson,
+        users::authenticated_user::{AuthenticatedUser, OptionalUser},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormatKindJson {
+    Paprika,
+    Mealie,
+    Cooklang,
+}
+
+impl From<FormatKindJson> for FormatKind {
+    fn from(value: FormatKindJson) -> Self {
+        match value {
+            FormatKindJson::Paprika => FormatKind::Paprika,
+            FormatKindJson::Mealie => FormatKind::Mealie,
+            FormatKindJson::Cooklang => FormatKind::Cooklang,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportRecipesParams {
+    #[serde(default)]
+    visibility: VisibilityJson,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportRecipeParams {
+    format: FormatKindJson,
+}
+
+fn error_response(error: ExchangeRecipesServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        ExchangeRecipesServiceError::InvalidFile(_) => StatusCode::BAD_REQUEST,
+        ExchangeRecipesServiceError::NoRecipes => StatusCode::UNPROCESSABLE_ENTITY,
+        ExchangeRecipesServiceError::TooManyRecipes => StatusCode::PAYLOAD_TOO_LARGE,
+        ExchangeRecipesServiceError::InvalidRecipe { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        ExchangeRecipesServiceError::RecipeNotFound => StatusCode::NOT_FOUND,
+        ExchangeRecipesServiceError::Unwritable(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        ExchangeRecipesServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let body = match &error {
+        ExchangeRecipesServiceError::InvalidRecipe { index, .. } => json!({
+            "error": format!("{}", error),
+            "field": format!("recipes[{}]", index),
+        }),
+        _ => json!({ "error": format!("{}", error) }),
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(Json(body).to_string()))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynExchangeRecipesService = Arc<dyn ExchangeRecipesService + Sync + Send>;
+
+/// The file is the whole body, whatever its content type.
+pub async fn import_recipes_handler(
+    axum::extract::State(service): axum::extract::State<DynExchangeRecipesService>,
+    user: AuthenticatedUser,
+    format: axum::extract::Path<FormatKindJson>,
+    params: axum::extract::Query<ImportRecipesParams>,
+    data: Bytes,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service
+        .import_recipes(
+            user.caller(),
+            format.0.into(),
+            data.to_vec(),
+            params.visibility.into(),
+        )
+        .await
+    {
+        Ok(recipes) => Response::builder()
+            .status(StatusCode::CREATED)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(Json(json!({ "recipes": recipes })).to_string()))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn export_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynExchangeRecipesService>,
+    user: OptionalUser,
+    uuid: axum::extract::Path<uuid::Uuid>,
+    params: axum::extract::Query<ExportRecipeParams>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let format = FormatKind::from(params.format);
+    match service.export_recipe(user.caller(), format, uuid.0).await {
+        Ok(file) => {
+            let format = format.format();
+            Response::builder()
+                .status(StatusCode::OK)
+                .header(axum::http::header::CONTENT_TYPE, format.content_type())
+                .header(
+                    axum::http::header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}.{}\"", uuid.0, format.extension()),
+                )
+                .body(body::boxed(body::Full::from(file)))
+                .map_err(|e| e.into())
+        }
+        Err(error) => error_response(error),
+    }
+}
@@ -0,0 +1,525 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    body::{self, BoxBody, Bytes, StreamBody},
+    http::{HeaderMap, Response, StatusCode},
+    Json,
+};
+use futures::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::{
+        library::{
+            domain::library_import::{ConflictMode, ImportSummary, LIBRARY_VERSION},
+            ports::incoming::manage_library_service::{
+                ManageLibraryService, ManageLibraryServiceError,
+            },
+        },
+        recipes::{
+            domain::{access::RecipeAccess, ingredient::Ingredient, recipe::Recipe, step::Step},
+            list_recipes_service::MAX_PAGE_SIZE,
+        },
+        users::domain::caller::Caller,
+    },
+    web::{
+        recipes::query_recipe_handler::{DifficultyJson, VisibilityJson},
+        users::authenticated_user::AuthenticatedUser,
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryIngredientJson {
+    uuid: uuid::Uuid,
+    name: String,
+    amount: f64,
+    unit: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryStepJson {
+    text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    duration_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    ingredients: Vec<uuid::Uuid>,
+}
+
+/// A recipe as stored, without what the instance derives from other data
+/// such as its rating.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryRecipeJson {
+    uuid: uuid::Uuid,
+    name: String,
+    #[serde(default)]
+    image: String,
+    servings: u32,
+    #[serde(default)]
+    prep_minutes: Option<u32>,
+    #[serde(default)]
+    cook_minutes: Option<u32>,
+    #[serde(default)]
+    difficulty: Option<DifficultyJson>,
+    #[serde(default)]
+    owner: Option<uuid::Uuid>,
+    #[serde(default)]
+    visibility: VisibilityJson,
+    #[serde(default)]
+    tags: Vec<String>,
+    ingredients: Vec<LibraryIngredientJson>,
+    #[serde(default)]
+    steps: Vec<LibraryStepJson>,
+}
+
+impl From<Recipe> for LibraryRecipeJson {
+    fn from(value: Recipe) -> Self {
+        Self {
+            uuid: value.uuid(),
+            name: value.name().to_string(),
+            image: value.image().to_string(),
+            servings: value.servings(),
+            prep_minutes: value.prep_minutes(),
+            cook_minutes: value.cook_minutes(),
+            difficulty: value.difficulty().map(DifficultyJson::from),
+            owner: value.access().owner(),
+            visibility: value.access().visibility().into(),
+            tags: value.tags().to_vec(),
+            ingredients: value
+                .ingredients()
+                .iter()
+                .map(|ingredient| LibraryIngredientJson {
+                    uuid: ingredient.uuid(),
+                    name: ingredient.name().to_string(),
+                    amount: ingredient.amount(),
+                    unit: ingredient.unit().to_string(),
+                })
+                .collect(),
+            steps: value
+                .steps()
+                .iter()
+                .map(|step| LibraryStepJson {
+                    text: step.text().to_string(),
+                    duration_seconds: step.duration().map(|duration| duration.as_secs()),
+                    timer: step.timer().map(str::to_string),
+                    ingredients: step.ingredients().to_vec(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<LibraryRecipeJson> for Recipe {
+    fn from(value: LibraryRecipeJson) -> Self {
+        let ingredients = value
+            .ingredients
+            .into_iter()
+            .map(|ingredient| {
+                Ingredient::new(
+                    ingredient.uuid,
+                    ingredient.name,
+                    ingredient.amount,
+                    ingredient.unit,
+                )
+            })
+            .collect();
+        let steps = value
+            .steps
+            .into_iter()
+            .map(|step| {
+                Step::new(step.text)
+                    .with_duration(step.duration_seconds.map(Duration::from_secs))
+                    .with_timer(step.timer)
+                    .with_ingredients(step.ingredients)
+            })
+            .collect();
+        Recipe::new(value.uuid, value.name, value.image, steps, ingredients)
+            .with_servings(value.servings)
+            .with_times(value.prep_minutes, value.cook_minutes)
+            .with_difficulty(value.difficulty.map(Into::into))
+            .with_access(RecipeAccess::new(value.owner, value.visibility.into()))
+            .with_tags(value.tags)
+    }
+}
+
+/// The document written by export and read by import.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LibraryJson {
+    version: u32,
+    #[serde(default)]
+    recipes: Vec<LibraryRecipeJson>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LibraryFormatJson {
+    #[default]
+    Json,
+    Yaml,
+}
+
+impl LibraryFormatJson {
+    fn content_type(&self) -> &'static str {
+        match self {
+            LibraryFormatJson::Json => "application/json",
+            LibraryFormatJson::Yaml => "application/yaml",
+        }
+    }
+
+    /// The document up to its first recipe.
+    fn header(&self, empty: bool) -> String {
+        match (self, empty) {
+            (LibraryFormatJson::Json, _) => {
+                format!("{{\"version\":{},\"recipes\":[", LIBRARY_VERSION)
+            }
+            (LibraryFormatJson::Yaml, false) => format!("version: {}\nrecipes:\n", LIBRARY_VERSION),
+            (LibraryFormatJson::Yaml, true) => {
+                format!("version: {}\nrecipes: []\n", LIBRARY_VERSION)
+            }
+        }
+    }
+
+    /// `recipes` as items of the recipe list, following `written` recipes.
+    fn recipes(
+        &self,
+        recipes: Vec<Recipe>,
+        written: usize,
+    ) -> Result<String, ManageLibraryServiceError> {
+        let recipes = recipes
+            .into_iter()
+            .map(LibraryRecipeJson::from)
+            .collect::<Vec<LibraryRecipeJson>>();
+        match self {
+            LibraryFormatJson::Json => {
+                let mut chunk = String::new();
+                for (index, recipe) in recipes.iter().enumerate() {
+                    if written + index > 0 {
+                        chunk.push(',');
+                    }
+                    chunk.push_str(
+                        &serde_json::to_string(recipe)
+                            .map_err(|_e| ManageLibraryServiceError::InternalError)?,
+                    );
+                }
+                Ok(chunk)
+            }
+            // A block sequence goes on as long as its items follow each other.
+            LibraryFormatJson::Yaml => serde_yaml::to_string(&recipes)
+                .map_err(|_e| ManageLibraryServiceError::InternalError),
+        }
+    }
+
+    fn footer(&self) -> &'static str {
+        match self {
+            LibraryFormatJson::Json => "]}\n",
+            LibraryFormatJson::Yaml => "",
+        }
+    }
+
+    fn parse(&self, document: &[u8]) -> Result<LibraryJson, String> {
+        match self {
+            LibraryFormatJson::Json => serde_json::from_slice(document).map_err(|e| e.to_string()),
+            LibraryFormatJson::Yaml => serde_yaml::from_slice(document).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictModeJson {
+    #[default]
+    Skip,
+    Overwrite,
+    Duplicate,
+}
+
+impl From<ConflictModeJson> for ConflictMode {
+    fn from(value: ConflictModeJson) -> Self {
+        match value {
+            ConflictModeJson::Skip => ConflictMode::Skip,
+            ConflictModeJson::Overwrite => ConflictMode::Overwrite,
+            ConflictModeJson::Duplicate => ConflictMode::Duplicate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportParams {
+    #[serde(default)]
+    format: LibraryFormatJson,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportParams {
+    #[serde(default)]
+    conflict: ConflictModeJson,
+    /// Read from the content type when not given.
+    format: Option<LibraryFormatJson>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicatedJson {
+    original: uuid::Uuid,
+    copy: uuid::Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportSummaryJson {
+    created: Vec<uuid::Uuid>,
+    overwritten: Vec<uuid::Uuid>,
+    skipped: Vec<uuid::Uuid>,
+    duplicated: Vec<DuplicatedJson>,
+}
+
+impl From<ImportSummary> for ImportSummaryJson {
+    fn from(value: ImportSummary) -> Self {
+        Self {
+            created: value.created().to_vec(),
+            overwritten: value.overwritten().to_vec(),
+            skipped: value.skipped().to_vec(),
+            duplicated: value
+                .duplicated()
+                .iter()
+                .map(|(original, copy)| DuplicatedJson {
+                    original: *original,
+                    copy: *copy,
+                })
+                .collect(),
+        }
+    }
+}
+
+fn error_response(error: ManageLibraryServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        ManageLibraryServiceError::Forbidden => StatusCode::FORBIDDEN,
+        ManageLibraryServiceError::InvalidPageSize => StatusCode::BAD_REQUEST,
+        ManageLibraryServiceError::UnsupportedVersion(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        ManageLibraryServiceError::TooManyRecipes => StatusCode::PAYLOAD_TOO_LARGE,
+        ManageLibraryServiceError::InvalidRecipe { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        ManageLibraryServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let body = match &error {
+        ManageLibraryServiceError::InvalidRecipe { index, .. } => json!({
+            "error": format!("{}", error),
+            "field": format!("recipes[{}]", index),
+        }),
+        _ => json!({ "error": format!("{}", error) }),
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(Json(body).to_string()))
+        .map_err(|e| e.into())
+}
+
+/// Where the export goes on from.
+enum ExportCursor {
+    Page(Vec<Recipe>),
+    After(uuid::Uuid),
+    Done,
+}
+
+pub(crate) type DynManageLibraryService = Arc<dyn ManageLibraryService + Sync + Send>;
+
+/// Streams the library page by page. The first page is read before
+/// answering, so a refused export gets a proper status.
+pub async fn export_handler(
+    axum::extract::State(service): axum::extract::State<DynManageLibraryService>,
+    user: AuthenticatedUser,
+    params: axum::extract::Query<ExportParams>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let caller = user.caller();
+    let format = params.format;
+    let first = match service.export_recipes(caller, None, MAX_PAGE_SIZE).await {
+        Ok(first) => first,
+        Err(error) => return error_response(error),
+    };
+    let header = format.header(first.is_empty());
+    let pages = stream::unfold((ExportCursor::Page(first), 0), move |(cursor, written)| {
+        let service = service.clone();
+        async move { next_chunk(service, caller, format, cursor, written).await }
+    });
+    let document = stream::once(async move { Ok(header) })
+        .chain(pages)
+        .chain(stream::once(async move { Ok(format.footer().to_string()) }));
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(axum::http::header::CONTENT_TYPE, format.content_type())
+        .body(body::boxed(StreamBody::new(document)))
+        .map_err(|e| e.into())
+}
+
+async fn next_chunk(
+    service: DynManageLibraryService,
+    caller: Caller,
+    format: LibraryFormatJson,
+    cursor: ExportCursor,
+    written: usize,
+) -> Option<(
+    Result<String, ManageLibraryServiceError>,
+    (ExportCursor, usize),
+)> {
+    let page = match cursor {
+        ExportCursor::Page(page) => page,
+        ExportCursor::After(after) => {
+            match service
+                .export_recipes(caller, Some(after), MAX_PAGE_SIZE)
+                .await
+            {
+                Ok(page) => page,
+                Err(error) => return Some((Err(error), (ExportCursor::Done, written))),
+            }
+        }
+        ExportCursor::Done => return None,
+    };
+    let next = match page.last() {
+        Some(last) if page.len() == MAX_PAGE_SIZE as usize => ExportCursor::After(last.uuid()),
+        Some(_) => ExportCursor::Done,
+        None => return None,
+    };
+    let count = page.len();
+    Some((format.recipes(page, written), (next, written + count)))
+}
+
+/// Reads JSON, or YAML when asked for by `format` or the content type.
+pub async fn import_handler(
+    axum::extract::State(service): axum::extract::State<DynManageLibraryService>,
+    user: AuthenticatedUser,
+    params: axum::extract::Query<ImportParams>,
+    headers: HeaderMap,
+    document: Bytes,
+) -> Result<Response<BoxBody>, YaissError> {
+    let format = params.format.unwrap_or_else(|| {
+        let yaml = headers
+            .get(axum::http::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .is_some_and(|content_type| content_type.contains("yaml"));
+        match yaml {
+            true => LibraryFormatJson::Yaml,
+            false => LibraryFormatJson::Json,
+        }
+    });
+    let library = match format.parse(&document) {
+        Ok(library) => library,
+        Err(error) => {
+            return Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({ "error": format!("Invalid library document: {}", error) }))
+                        .to_string(),
+                ))
+                .map_err(|e| e.into())
+        }
+    };
+    let recipes = library.recipes.into_iter().map(Recipe::from).collect();
+    match service
+        .import_recipes(
+            user.caller(),
+            library.version,
+            recipes,
+            params.conflict.into(),
+        )
+        .await
+    {
+        Ok(summary) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!(ImportSummaryJson::from(summary))).to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::recipes::domain::access::Visibility;
+
+    fn recipes() -> Vec<Recipe> {
+        let flour = Ingredient::new(uuid::Uuid::new_v4(), "flour".into(), 500.0, "g".into());
+        let water = Ingredient::new(uuid::Uuid::new_v4(), "water".into(), 0.3, "l".into());
+        let bread = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Bread".into(),
+            "".into(),
+            vec![Step::new("Knead".into())
+                .with_duration(Some(Duration::from_secs(600)))
+                .with_ingredients(vec![flour.uuid(), water.uuid()])],
+            vec![flour, water],
+        )
+        .with_servings(4)
+        .with_access(RecipeAccess::new(
+            Some(uuid::Uuid::new_v4()),
+            Visibility::Public,
+        ))
+        .with_tags(vec!["baking".into()]);
+        let tea = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Tea".into(),
+            "".into(),
+            vec![],
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "tea".into(),
+                1.0,
+                "tsp".into(),
+            )],
+        );
+        vec![bread, tea]
+    }
+
+    /// The whole document as the export writes it, one page per recipe.
+    fn document(format: LibraryFormatJson, recipes: Vec<Recipe>) -> String {
+        let mut document = format.header(recipes.is_empty());
+        for (written, recipe) in recipes.into_iter().enumerate() {
+            document.push_str(&format.recipes(vec![recipe], written).unwrap());
+        }
+        document.push_str(format.footer());
+        document
+    }
+
+    #[test]
+    fn exports_read_back_the_same() {
+        for format in [LibraryFormatJson::Json, LibraryFormatJson::Yaml] {
+            let expected = recipes();
+            let document = document(format, expected.clone());
+            let library = format.parse(document.as_bytes()).unwrap();
+            assert_eq!(library.version, LIBRARY_VERSION);
+            let read = library
+                .recipes
+                .into_iter()
+                .map(Recipe::from)
+                .collect::<Vec<Recipe>>();
+            assert_eq!(read.len(), expected.len());
+            assert_eq!(read[0].uuid(), expected[0].uuid());
+            assert_eq!(read[0].access().owner(), expected[0].access().owner());
+            assert_eq!(read[0].name(), "Bread");
+            assert_eq!(read[0].tags(), ["baking"]);
+            assert_eq!(read[0].access().visibility(), Visibility::Public);
+            assert_eq!(read[0].steps()[0].ingredients().len(), 2);
+            assert_eq!(
+                read[0].steps()[0].ingredients()[1],
+                read[0].ingredients()[1].uuid()
+            );
+            assert_eq!(read[1].ingredients()[0].unit(), "tsp");
+        }
+    }
+
+    #[test]
+    fn empty_exports_are_valid_documents() {
+        for format in [LibraryFormatJson::Json, LibraryFormatJson::Yaml] {
+            let document = document(format, vec![]);
+            assert!(format
+                .parse(document.as_bytes())
+                .unwrap()
+                .recipes
+                .is_empty());
+        }
+    }
+}
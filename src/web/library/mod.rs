@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Router,
+};
+
+use crate::{
+    data_storage::recipes::recipes_sqlite_ds::RecipeSqliteDS,
+    services::library::{
+        exchange_recipes_service::ExchangeRecipes, manage_library_service::ManageLibrary,
+    },
+    state::State,
+};
+
+use self::{
+    exchange_recipes_handler::DynExchangeRecipesService,
+    manage_library_handler::DynManageLibraryService,
+};
+
+pub mod exchange_recipes_handler;
+pub mod manage_library_handler;
+
+/// Library documents and exported archives hold every recipe of an instance.
+const MAX_DOCUMENT_SIZE: usize = 64 * 1024 * 1024;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = RecipeSqliteDS::new(state.pool());
+
+    let manage_library_service =
+        Arc::new(ManageLibrary::new(storage.clone())) as DynManageLibraryService;
+    let exchange_recipes_service =
+        Arc::new(ExchangeRecipes::new(storage)) as DynExchangeRecipesService;
+
+    let library_router = Router::new()
+        .route("/export", get(manage_library_handler::export_handler))
+        .route(
+            "/import",
+            post(manage_library_handler::import_handler)
+                .layer(DefaultBodyLimit::max(MAX_DOCUMENT_SIZE)),
+        )
+        .with_state(manage_library_service)
+        .route(
+            "/recipes/import/:format",
+            post(exchange_recipes_handler::import_recipes_handler)
+                .layer(DefaultBodyLimit::max(MAX_DOCUMENT_SIZE)),
+        )
+        .route(
+            "/recipes/:identifier/export",
+            get(exchange_recipes_handler::export_recipe_handler),
+        )
+        .with_state(exchange_recipes_service);
+    Router::new().nest("/api/v1", library_router)
+}
@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::meal_plans::{
+        domain::{meal_slot::MealSlot, plan_date::PlanDate, plan_entry::PlanEntry},
+        ports::incoming::manage_meal_plan_service::{
+            ManageMealPlanService, ManageMealPlanServiceError,
+        },
+    },
+    web::users::authenticated_user::AuthenticatedUser,
+};
+
+/// A date written `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct DateJson(PlanDate);
+
+impl TryFrom<String> for DateJson {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value
+            .parse()
+            .map(DateJson)
+            .map_err(|_| format!("invalid date `{}`, expected YYYY-MM-DD", value))
+    }
+}
+
+impl From<PlanDate> for DateJson {
+    fn from(value: PlanDate) -> Self {
+        DateJson(value)
+    }
+}
+
+impl From<DateJson> for PlanDate {
+    fn from(value: DateJson) -> Self {
+        value.0
+    }
+}
+
+impl From<DateJson> for String {
+    fn from(value: DateJson) -> Self {
+        value.0.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MealSlotJson {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+impl From<MealSlotJson> for MealSlot {
+    fn from(value: MealSlotJson) -> Self {
+        match value {
+            MealSlotJson::Breakfast => MealSlot::Breakfast,
+            MealSlotJson::Lunch => MealSlot::Lunch,
+            MealSlotJson::Dinner => MealSlot::Dinner,
+            MealSlotJson::Snack => MealSlot::Snack,
+        }
+    }
+}
+
+impl From<MealSlot> for MealSlotJson {
+    fn from(value: MealSlot) -> Self {
+        match value {
+            MealSlot::Breakfast => MealSlotJson::Breakfast,
+            MealSlot::Lunch => MealSlotJson::Lunch,
+            MealSlot::Dinner => MealSlotJson::Dinner,
+            MealSlot::Snack => MealSlotJson::Snack,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DateRangeParams {
+    from: DateJson,
+    to: DateJson,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateEntryJson {
+    date: DateJson,
+    slot: MealSlotJson,
+    recipe: Uuid,
+    servings: Option<u32>,
+}
+
+/// Tells a `null` field, `Some(None)`, from one left out, `None`.
+fn present<'de, D>(deserializer: D) -> Result<Option<Option<u32>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<u32>::deserialize(deserializer).map(Some)
+}
+
+/// Leaving `servings` out keeps the entry's servings, `null` goes back to the
+/// recipe's own.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveEntryJson {
+    date: DateJson,
+    slot: MealSlotJson,
+    #[serde(default, deserialize_with = "present")]
+    servings: Option<Option<u32>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EntryJson {
+    uuid: Uuid,
+    date: DateJson,
+    slot: MealSlotJson,
+    recipe: Uuid,
+    servings: Option<u32>,
+}
+
+impl From<PlanEntry> for EntryJson {
+    fn from(value: PlanEntry) -> Self {
+        Self {
+            uuid: value.uuid(),
+            date: DateJson(value.date()),
+            slot: value.slot().into(),
+            recipe: value.recipe(),
+            servings: value.servings(),
+        }
+    }
+}
+
+fn error_response(error: ManageMealPlanServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        ManageMealPlanServiceError::InvalidRange => StatusCode::BAD_REQUEST,
+        ManageMealPlanServiceError::InvalidServings => StatusCode::BAD_REQUEST,
+        ManageMealPlanServiceError::EntryNotFound => StatusCode::NOT_FOUND,
+        ManageMealPlanServiceError::RecipeNotFound => StatusCode::NOT_FOUND,
+        ManageMealPlanServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+fn entry_response(status: StatusCode, entry: PlanEntry) -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(Json(json!(EntryJson::from(entry))).to_string()))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynManageMealPlanService = Arc<dyn ManageMealPlanService + Sync + Send>;
+pub async fn list_entries_handler(
+    axum::extract::State(service): axum::extract::State<DynManageMealPlanService>,
+    user: AuthenticatedUser,
+    params: axum::extract::Query<DateRangeParams>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service
+        .list_entries(user.caller(), params.from.0, params.to.0)
+        .await
+    {
+        Ok(entries) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "from": params.from,
+                    "to": params.to,
+                    "entries": entries.into_iter().map(EntryJson::from).collect::<Vec<EntryJson>>(),
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn create_entry_handler(
+    axum::extract::State(service): axum::extract::State<DynManageMealPlanService>,
+    user: AuthenticatedUser,
+    json: Json<CreateEntryJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let json = json.0;
+    match service
+        .create_entry(
+            user.caller(),
+            json.date.0,
+            json.slot.into(),
+            json.recipe,
+            json.servings,
+        )
+        .await
+    {
+        Ok(entry) => entry_response(StatusCode::CREATED, entry),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn move_entry_handler(
+    axum::extract::State(service): axum::extract::State<DynManageMealPlanService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    json: Json<MoveEntryJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let json = json.0;
+    match service
+        .move_entry(
+            user.caller(),
+            identifier.0,
+            json.date.0,
+            json.slot.into(),
+            json.servings,
+        )
+        .await
+    {
+        Ok(entry) => entry_response(StatusCode::OK, entry),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn delete_entry_handler(
+    axum::extract::State(service): axum::extract::State<DynManageMealPlanService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.delete_entry(user.caller(), identifier.0).await {
+        Ok(()) => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(body::boxed(BoxBody::default()))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_tell_cleared_servings_from_left_out_ones() {
+        let servings = |body: serde_json::Value| {
+            serde_json::from_value::<MoveEntryJson>(body)
+                .unwrap()
+                .servings
+        };
+        assert_eq!(
+            servings(json!({"date": "2024-01-01", "slot": "lunch"})),
+            None
+        );
+        assert_eq!(
+            servings(json!({"date": "2024-01-01", "slot": "lunch", "servings": null})),
+            Some(None)
+        );
+        assert_eq!(
+            servings(json!({"date": "2024-01-01", "slot": "lunch", "servings": 4})),
+            Some(Some(4))
+        );
+    }
+}
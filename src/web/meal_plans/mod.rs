@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{get, put},
+    Router,
+};
+
+use crate::{
+    data_storage::{
+        meal_plans::meal_plans_sqlite_ds::MealPlanSqliteDS,
+        recipes::recipes_sqlite_ds::RecipeSqliteDS,
+    },
+    services::meal_plans::manage_meal_plan_service::ManageMealPlan,
+    state::State,
+};
+
+use self::manage_meal_plan_handler::DynManageMealPlanService;
+
+pub mod manage_meal_plan_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = MealPlanSqliteDS::new(state.pool());
+    let recipes = RecipeSqliteDS::new(state.pool());
+
+    let manage_meal_plan_service =
+        Arc::new(ManageMealPlan::new(storage, recipes)) as DynManageMealPlanService;
+
+    let meal_plan_routes = Router::new()
+        .route(
+            "/",
+            get(manage_meal_plan_handler::list_entries_handler)
+                .post(manage_meal_plan_handler::create_entry_handler),
+        )
+        .route(
+            "/:identifier",
+            put(manage_meal_plan_handler::move_entry_handler)
+                .delete(manage_meal_plan_handler::delete_entry_handler),
+        )
+        .with_state(manage_meal_plan_service);
+
+    let meal_plan_router = Router::new().nest("/meal-plan", meal_plan_routes);
+    Router::new().nest("/api/v1", meal_plan_router)
+}
@@ -3,7 +3,17 @@ use hyper::{Body, StatusCode};
 use serde_json::json;
 
 use crate::error::YaissError;
+pub mod collections;
+pub mod images;
+pub mod library;
+pub mod meal_plans;
+pub mod nutrition;
+pub mod pantry;
 pub mod recipes;
+pub mod reviews;
+pub mod shopping_lists;
+pub mod tags;
+pub mod users;
 
 pub async fn handler_404() -> Result<Response<Body>, YaissError> {
     let body = Json(json!({
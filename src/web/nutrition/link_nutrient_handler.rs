@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::nutrition::ports::incoming::link_nutrient_service::{
+        LinkNutrientService, LinkNutrientServiceError,
+    },
+    web::users::authenticated_user::AuthenticatedUser,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinkNutrientJson {
+    nutrient_id: i64,
+}
+
+fn error_response(error: LinkNutrientServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        LinkNutrientServiceError::IngredientNotFound
+        | LinkNutrientServiceError::NutrientNotFound => StatusCode::NOT_FOUND,
+        LinkNutrientServiceError::Forbidden => StatusCode::FORBIDDEN,
+        LinkNutrientServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynLinkNutrientService = Arc<dyn LinkNutrientService + Sync + Send>;
+pub async fn link_nutrient_handler(
+    axum::extract::State(service): axum::extract::State<DynLinkNutrientService>,
+    user: AuthenticatedUser,
+    path: axum::extract::Path<(uuid::Uuid, uuid::Uuid)>,
+    json: Json<LinkNutrientJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let (recipe, ingredient) = path.0;
+    match service
+        .link_nutrient(user.caller(), recipe, ingredient, json.0.nutrient_id)
+        .await
+    {
+        Ok(nutrient) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "nutrient_id": nutrient.id(),
+                    "name": nutrient.name(),
+                    "description": nutrient.description(),
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn unlink_nutrient_handler(
+    axum::extract::State(service): axum::extract::State<DynLinkNutrientService>,
+    user: AuthenticatedUser,
+    path: axum::extract::Path<(uuid::Uuid, uuid::Uuid)>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let (recipe, ingredient) = path.0;
+    match service
+        .unlink_nutrient(user.caller(), recipe, ingredient)
+        .await
+    {
+        Ok(()) => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(body::boxed(BoxBody::default()))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
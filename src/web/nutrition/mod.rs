@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{get, put},
+    Router,
+};
+
+use crate::{
+    data_storage::{
+        nutrition::nutrients_sqlite_ds::NutrientSqliteDS,
+        recipes::recipes_sqlite_ds::RecipeSqliteDS,
+    },
+    services::nutrition::{
+        link_nutrient_service::LinkNutrient, query_nutrition_service::QueryNutrition,
+    },
+    state::State,
+};
+
+use self::{
+    link_nutrient_handler::DynLinkNutrientService,
+    query_nutrition_handler::DynQueryNutritionService,
+};
+
+pub mod link_nutrient_handler;
+pub mod query_nutrition_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let recipes = RecipeSqliteDS::new(state.pool());
+    let nutrients = NutrientSqliteDS::new(state.pool());
+
+    let query_nutrition_service = Arc::new(QueryNutrition::new(recipes.clone(), nutrients.clone()))
+        as DynQueryNutritionService;
+    let link_nutrient_service =
+        Arc::new(LinkNutrient::new(nutrients, recipes)) as DynLinkNutrientService;
+
+    let nutrition_routes = Router::new()
+        .route(
+            "/:identifier/nutrition",
+            get(query_nutrition_handler::query_nutrition_handler),
+        )
+        .with_state(query_nutrition_service)
+        .route(
+            "/:identifier/ingredients/:ingredient/nutrient",
+            put(link_nutrient_handler::link_nutrient_handler)
+                .delete(link_nutrient_handler::unlink_nutrient_handler),
+        )
+        .with_state(link_nutrient_service);
+
+    let recipes_router = Router::new().nest("/recipes", nutrition_routes);
+    Router::new().nest("/api/v1", recipes_router)
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, Body},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::nutrition::{
+        domain::{
+            nutrient::Nutrients,
+            nutrition::{
+                IngredientNutrition, RecipeNutrition, UnmatchedIngredient, UnmatchedReason,
+            },
+        },
+        ports::incoming::query_nutrition_service::{
+            QueryNutritionService, QueryNutritionServiceError,
+        },
+    },
+    web::users::authenticated_user::OptionalUser,
+};
+
+fn round(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NutrientsJson {
+    energy_kcal: f64,
+    protein_g: f64,
+    fat_g: f64,
+    carbohydrate_g: f64,
+    fiber_g: f64,
+    sugars_g: f64,
+}
+
+impl From<Nutrients> for NutrientsJson {
+    fn from(value: Nutrients) -> Self {
+        Self {
+            energy_kcal: round(value.energy_kcal()),
+            protein_g: round(value.protein_g()),
+            fat_g: round(value.fat_g()),
+            carbohydrate_g: round(value.carbohydrate_g()),
+            fiber_g: round(value.fiber_g()),
+            sugars_g: round(value.sugars_g()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IngredientNutritionJson {
+    uuid: uuid::Uuid,
+    name: String,
+    nutrient_id: i64,
+    grams: f64,
+    nutrients: NutrientsJson,
+}
+
+impl From<&IngredientNutrition> for IngredientNutritionJson {
+    fn from(value: &IngredientNutrition) -> Self {
+        Self {
+            uuid: value.uuid(),
+            name: value.name().to_string(),
+            nutrient_id: value.nutrient_id(),
+            grams: round(value.grams()),
+            nutrients: value.nutrients().into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnmatchedIngredientJson {
+    uuid: uuid::Uuid,
+    name: String,
+    reason: &'static str,
+}
+
+impl From<&UnmatchedIngredient> for UnmatchedIngredientJson {
+    fn from(value: &UnmatchedIngredient) -> Self {
+        Self {
+            uuid: value.uuid(),
+            name: value.name().to_string(),
+            reason: match value.reason() {
+                UnmatchedReason::NoNutrientMatch => "no_nutrient_match",
+                UnmatchedReason::UnknownUnit => "unknown_unit",
+                UnmatchedReason::NoWeight => "no_weight",
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecipeNutritionJson {
+    recipe: uuid::Uuid,
+    servings: u32,
+    total: NutrientsJson,
+    per_serving: NutrientsJson,
+    ingredients: Vec<IngredientNutritionJson>,
+    unmatched: Vec<UnmatchedIngredientJson>,
+}
+
+impl From<RecipeNutrition> for RecipeNutritionJson {
+    fn from(value: RecipeNutrition) -> Self {
+        Self {
+            recipe: value.recipe(),
+            servings: value.servings(),
+            total: value.total().into(),
+            per_serving: value.per_serving().into(),
+            ingredients: value
+                .ingredients()
+                .iter()
+                .map(IngredientNutritionJson::from)
+                .collect(),
+            unmatched: value
+                .unmatched()
+                .iter()
+                .map(UnmatchedIngredientJson::from)
+                .collect(),
+        }
+    }
+}
+
+pub(crate) type DynQueryNutritionService = Arc<dyn QueryNutritionService + Sync + Send>;
+pub async fn query_nutrition_handler(
+    axum::extract::State(service): axum::extract::State<DynQueryNutritionService>,
+    user: OptionalUser,
+    identifier: axum::extract::Path<uuid::Uuid>,
+) -> Result<Response<Body>, YaissError> {
+    let builder = match service.query_nutrition(user.caller(), identifier.0).await {
+        Ok(nutrition) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!(RecipeNutritionJson::from(nutrition))).to_string(),
+            )),
+        Err(QueryNutritionServiceError::RecipeNotFound) => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", QueryNutritionServiceError::RecipeNotFound)
+                }))
+                .to_string(),
+            )),
+        Err(QueryNutritionServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", QueryNutritionServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
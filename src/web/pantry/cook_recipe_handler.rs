@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::{
+        pantry::{
+            domain::{cooking_entry::CookingEntry, stock::Shortage},
+            ports::incoming::cook_recipe_service::{CookRecipeService, CookRecipeServiceError},
+        },
+        recipes::{domain::pagination::Page, list_recipes_service::DEFAULT_PAGE_SIZE},
+    },
+    web::{
+        pantry::manage_pantry_handler::PantryItemJson, users::authenticated_user::AuthenticatedUser,
+    },
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServingsParams {
+    servings: Option<u32>,
+}
+
+/// Leaving `servings` out cooks the recipe's own servings.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CookJson {
+    servings: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HistoryParams {
+    #[serde(default)]
+    page: u32,
+    page_size: Option<u32>,
+}
+
+/// Amounts are in the unit of the recipe.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShortageJson {
+    name: String,
+    unit: String,
+    needed: f64,
+    available: f64,
+    missing: String,
+}
+
+impl From<&Shortage> for ShortageJson {
+    fn from(value: &Shortage) -> Self {
+        Self {
+            name: value.name().to_string(),
+            unit: value.unit().to_string(),
+            needed: value.needed(),
+            available: value.available(),
+            missing: value.missing().to_kitchen().display(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CookingEntryJson {
+    uuid: Uuid,
+    recipe: Uuid,
+    servings: u32,
+    cooked_at: String,
+}
+
+impl From<CookingEntry> for CookingEntryJson {
+    fn from(value: CookingEntry) -> Self {
+        Self {
+            uuid: value.uuid(),
+            recipe: value.recipe(),
+            servings: value.servings(),
+            cooked_at: value.cooked_at().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CookingHistoryPageJson {
+    history: Vec<CookingEntryJson>,
+    page: u32,
+    page_size: u32,
+    next_page: Option<u32>,
+}
+
+impl From<Page<CookingEntry>> for CookingHistoryPageJson {
+    fn from(value: Page<CookingEntry>) -> Self {
+        let (page, page_size, next_page) = (value.page(), value.page_size(), value.next_page());
+        Self {
+            history: value
+                .into_items()
+                .into_iter()
+                .map(CookingEntryJson::from)
+                .collect(),
+            page,
+            page_size,
+            next_page,
+        }
+    }
+}
+
+fn error_response(error: CookRecipeServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        CookRecipeServiceError::InvalidServings => StatusCode::BAD_REQUEST,
+        CookRecipeServiceError::InvalidPageSize => StatusCode::BAD_REQUEST,
+        CookRecipeServiceError::RecipeNotFound => StatusCode::NOT_FOUND,
+        CookRecipeServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynCookRecipeService = Arc<dyn CookRecipeService + Sync + Send>;
+pub async fn missing_ingredients_handler(
+    axum::extract::State(service): axum::extract::State<DynCookRecipeService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    params: axum::extract::Query<ServingsParams>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service
+        .missing_ingredients(user.caller(), identifier.0, params.servings)
+        .await
+    {
+        Ok(shortages) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "recipe": identifier.0,
+                    "missing": shortages.iter().map(ShortageJson::from).collect::<Vec<ShortageJson>>(),
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+/// The body is optional, an empty one cooks the recipe's own servings.
+pub async fn cook_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynCookRecipeService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    json: Option<Json<CookJson>>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let json = json.map(|json| json.0).unwrap_or_default();
+    match service
+        .cook_recipe(user.caller(), identifier.0, json.servings)
+        .await
+    {
+        Ok((entry, deduction)) => Response::builder()
+            .status(StatusCode::CREATED)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "entry": CookingEntryJson::from(entry),
+                    "updated": deduction.updated().iter().map(PantryItemJson::from).collect::<Vec<PantryItemJson>>(),
+                    "removed": deduction.removed(),
+                    "missing": deduction.shortages().iter().map(ShortageJson::from).collect::<Vec<ShortageJson>>(),
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn list_history_handler(
+    axum::extract::State(service): axum::extract::State<DynCookRecipeService>,
+    user: AuthenticatedUser,
+    params: axum::extract::Query<HistoryParams>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let page_size = params.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    match service
+        .list_history(user.caller(), params.page, page_size)
+        .await
+    {
+        Ok(page) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!(CookingHistoryPageJson::from(page))).to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
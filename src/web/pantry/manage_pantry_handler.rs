@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::pantry::{
+        domain::pantry_item::PantryItem,
+        ports::incoming::manage_pantry_service::{ManagePantryService, ManagePantryServiceError},
+    },
+    web::{
+        meal_plans::manage_meal_plan_handler::DateJson,
+        users::authenticated_user::AuthenticatedUser,
+    },
+};
+
+/// Leaving `expires_on` out stores an item that does not expire.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PantryItemRequestJson {
+    name: String,
+    amount: f64,
+    unit: String,
+    expires_on: Option<DateJson>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PantryItemJson {
+    uuid: Uuid,
+    name: String,
+    amount: f64,
+    unit: String,
+    display: String,
+    expires_on: Option<DateJson>,
+}
+
+impl From<&PantryItem> for PantryItemJson {
+    fn from(value: &PantryItem) -> Self {
+        Self {
+            uuid: value.uuid(),
+            name: value.name().to_string(),
+            amount: value.amount(),
+            unit: value.unit().to_string(),
+            display: value.quantity().display(),
+            expires_on: value.expires_on().map(DateJson::from),
+        }
+    }
+}
+
+fn error_response(error: ManagePantryServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        ManagePantryServiceError::InvalidItem(_) => StatusCode::BAD_REQUEST,
+        ManagePantryServiceError::ItemNotFound => StatusCode::NOT_FOUND,
+        ManagePantryServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+fn item_response(status: StatusCode, item: PantryItem) -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!(PantryItemJson::from(&item))).to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynManagePantryService = Arc<dyn ManagePantryService + Sync + Send>;
+pub async fn list_items_handler(
+    axum::extract::State(service): axum::extract::State<DynManagePantryService>,
+    user: AuthenticatedUser,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.list_items(user.caller()).await {
+        Ok(items) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "items": items.iter().map(PantryItemJson::from).collect::<Vec<PantryItemJson>>(),
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn add_item_handler(
+    axum::extract::State(service): axum::extract::State<DynManagePantryService>,
+    user: AuthenticatedUser,
+    json: Json<PantryItemRequestJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let json = json.0;
+    match service
+        .add_item(
+            user.caller(),
+            json.name,
+            json.amount,
+            json.unit,
+            json.expires_on.map(Into::into),
+        )
+        .await
+    {
+        Ok(item) => item_response(StatusCode::CREATED, item),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn update_item_handler(
+    axum::extract::State(service): axum::extract::State<DynManagePantryService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    json: Json<PantryItemRequestJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let json = json.0;
+    match service
+        .update_item(
+            user.caller(),
+            identifier.0,
+            json.name,
+            json.amount,
+            json.unit,
+            json.expires_on.map(Into::into),
+        )
+        .await
+    {
+        Ok(item) => item_response(StatusCode::OK, item),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn delete_item_handler(
+    axum::extract::State(service): axum::extract::State<DynManagePantryService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.delete_item(user.caller(), identifier.0).await {
+        Ok(()) => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(body::boxed(BoxBody::default()))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::{
+    data_storage::{
+        pantry::pantry_sqlite_ds::PantrySqliteDS, recipes::recipes_sqlite_ds::RecipeSqliteDS,
+    },
+    services::pantry::{cook_recipe_service::CookRecipe, manage_pantry_service::ManagePantry},
+    state::State,
+};
+
+use self::{
+    cook_recipe_handler::DynCookRecipeService, manage_pantry_handler::DynManagePantryService,
+};
+
+pub mod cook_recipe_handler;
+pub mod manage_pantry_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = PantrySqliteDS::new(state.pool());
+    let recipes = RecipeSqliteDS::new(state.pool());
+
+    let manage_pantry_service =
+        Arc::new(ManagePantry::new(storage.clone())) as DynManagePantryService;
+    let cook_recipe_service = Arc::new(CookRecipe::new(storage, recipes)) as DynCookRecipeService;
+
+    let pantry_routes = Router::new()
+        .route(
+            "/",
+            get(manage_pantry_handler::list_items_handler)
+                .post(manage_pantry_handler::add_item_handler),
+        )
+        .route(
+            "/:identifier",
+            put(manage_pantry_handler::update_item_handler)
+                .delete(manage_pantry_handler::delete_item_handler),
+        )
+        .with_state(manage_pantry_service)
+        .route("/history", get(cook_recipe_handler::list_history_handler))
+        .with_state(cook_recipe_service.clone());
+    let recipe_routes = Router::new()
+        .route(
+            "/:identifier/missing",
+            get(cook_recipe_handler::missing_ingredients_handler),
+        )
+        .route(
+            "/:identifier/cook",
+            post(cook_recipe_handler::cook_recipe_handler),
+        )
+        .with_state(cook_recipe_service);
+
+    let pantry_router = Router::new()
+        .nest("/pantry", pantry_routes)
+        .nest("/recipes", recipe_routes);
+    Router::new().nest("/api/v1", pantry_router)
+}
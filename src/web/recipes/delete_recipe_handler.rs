@@ -13,15 +13,17 @@ use crate::{
     services::recipes::ports::incoming::delete_recipe_service::{
         DeleteRecipeService, DeleteRecipeServiceError,
     },
+    web::users::authenticated_user::AuthenticatedUser,
 };
 
 pub(crate) type DynDeleteRecipesService = Arc<dyn DeleteRecipeService + Send + Sync>;
 
 pub async fn delete_recipe_handler(
     axum::extract::State(service): axum::extract::State<DynDeleteRecipesService>,
+    user: AuthenticatedUser,
     identifier: axum::extract::Path<Uuid>,
 ) -> Result<Response<BoxBody>, YaissError> {
-    let builder = match service.delete_recipe(identifier.0).await {
+    let builder = match service.delete_recipe(user.caller(), identifier.0).await {
         Ok(()) => Response::builder()
             .status(StatusCode::OK)
             .header(axum::http::header::CONTENT_TYPE, "application/json")
@@ -35,6 +37,15 @@ pub async fn delete_recipe_handler(
                 }))
                 .to_string(),
             )),
+        Err(DeleteRecipeServiceError::Forbidden) => Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", DeleteRecipeServiceError::Forbidden)
+                }))
+                .to_string(),
+            )),
         Err(DeleteRecipeServiceError::RecipeNotFound) => Response::builder()
             .status(StatusCode::NOT_FOUND)
             .header(axum::http::header::CONTENT_TYPE, "application/json")
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::recipes::ports::{
+        incoming::import_recipe_service::{
+            ImportRecipeService, ImportRecipeServiceError, RecipeSource,
+        },
+        outgoing::recipe_page_port::RecipePageError,
+    },
+    web::{
+        recipes::query_recipe_handler::{RecipeJson, VisibilityJson},
+        users::authenticated_user::AuthenticatedUser,
+    },
+};
+
+/// The page is sent as is, or fetched by the server.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RecipeSourceJson {
+    Html { html: String },
+    Url { url: String },
+}
+
+impl From<RecipeSourceJson> for RecipeSource {
+    fn from(value: RecipeSourceJson) -> Self {
+        match value {
+            RecipeSourceJson::Html { html } => RecipeSource::Html(html),
+            RecipeSourceJson::Url { url } => RecipeSource::Url(url),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportRecipeJson {
+    #[serde(flatten)]
+    source: RecipeSourceJson,
+    #[serde(default)]
+    visibility: VisibilityJson,
+}
+
+pub(crate) type DynImportRecipeService = Arc<dyn ImportRecipeService + Sync + Send>;
+pub async fn import_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynImportRecipeService>,
+    user: AuthenticatedUser,
+    json: Json<ImportRecipeJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let json = json.0;
+    let (status, body) = match service
+        .import_recipe(user.caller(), json.source.into(), json.visibility.into())
+        .await
+    {
+        Ok(recipe) => (StatusCode::CREATED, json!(RecipeJson::from(recipe))),
+        Err(error) => {
+            let status = match error {
+                ImportRecipeServiceError::Page(RecipePageError::InvalidUrl) => {
+                    StatusCode::BAD_REQUEST
+                }
+                ImportRecipeServiceError::Page(RecipePageError::PrivateAddress) => {
+                    StatusCode::BAD_REQUEST
+                }
+                ImportRecipeServiceError::Page(RecipePageError::Unreachable) => {
+                    StatusCode::BAD_GATEWAY
+                }
+                ImportRecipeServiceError::Page(RecipePageError::TooLarge) => {
+                    StatusCode::BAD_GATEWAY
+                }
+                ImportRecipeServiceError::NoRecipe => StatusCode::UNPROCESSABLE_ENTITY,
+                ImportRecipeServiceError::InvalidRecipe(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                ImportRecipeServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, json!({ "error": format!("{}", error) }))
+        }
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(Json(body).to_string()))
+        .map_err(|e| e.into())
+}
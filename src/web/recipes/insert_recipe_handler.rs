@@ -1,70 +1,251 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use axum::{
     body::{self, BoxBody},
     http::{Response, StatusCode},
     Json,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{
     error::YaissError,
     services::recipes::{
-        domain::{ingredient::Ingredient, recipe::Recipe},
+        domain::{
+            access::RecipeAccess,
+            difficulty::Difficulty,
+            ingredient::Ingredient,
+            ingredient_line::{IngredientLine, ParseWarning},
+            recipe::{Recipe, DEFAULT_SERVINGS},
+            step::{split_method, Step},
+        },
         ports::incoming::insert_recipe_service::{InsertRecipeService, InsertRecipeServiceError},
     },
+    web::{
+        recipes::query_recipe_handler::{DifficultyJson, VisibilityJson},
+        users::authenticated_user::AuthenticatedUser,
+    },
 };
 
+/// Ingredients are given field by field, or as a single line of text.
 #[derive(Debug, Clone, Deserialize)]
-pub struct IngredientJson {
-    name: String,
-    amount: f64,
-    unit: String,
+#[serde(untagged)]
+pub enum IngredientJson {
+    Structured {
+        name: String,
+        amount: f64,
+        unit: String,
+    },
+    /// A line such as "1½ tbsp olive oil, warmed".
+    Raw { raw: String },
+}
+
+impl IngredientJson {
+    /// The ingredient, with how a raw line could also have been read.
+    /// `None` when a raw line names no ingredient.
+    fn into_ingredient(self) -> Option<(Ingredient, Vec<ParseWarning>)> {
+        match self {
+            IngredientJson::Structured { name, amount, unit } => Some((
+                Ingredient::new(uuid::Uuid::new_v4(), name, amount, unit),
+                vec![],
+            )),
+            IngredientJson::Raw { raw } => IngredientLine::parse(&raw).map(|line| {
+                let warnings = line.warnings().to_vec();
+                (line.into_ingredient(), warnings)
+            }),
+        }
+    }
+}
+
+/// A raw ingredient line read one way that could have meant another.
+#[derive(Debug, Clone, Serialize)]
+pub struct IngredientWarningJson {
+    field: String,
+    warning: &'static str,
+    message: String,
+}
+
+/// Steps name ingredients by uuid, or by position for the ingredients sent
+/// in the same request.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged)]
+pub enum IngredientRefJson {
+    Uuid(uuid::Uuid),
+    Position(usize),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StepJson {
+    text: String,
+    duration_seconds: Option<u64>,
+    timer: Option<String>,
+    #[serde(default)]
+    ingredients: Vec<IngredientRefJson>,
 }
 
-impl Into<Ingredient> for IngredientJson {
-    fn into(self) -> Ingredient {
-        Ingredient::new(uuid::Uuid::new_v4(), self.name, self.amount, self.unit)
+impl StepJson {
+    /// Positions refer to `ingredients`. Positions past its end become the
+    /// nil uuid, which the recipe never has, so the step is refused.
+    pub(crate) fn into_step(self, ingredients: &[Ingredient]) -> Step {
+        let references = self
+            .ingredients
+            .into_iter()
+            .map(|reference| match reference {
+                IngredientRefJson::Uuid(uuid) => uuid,
+                IngredientRefJson::Position(position) => ingredients
+                    .get(position)
+                    .map(Ingredient::uuid)
+                    .unwrap_or_else(uuid::Uuid::nil),
+            })
+            .collect();
+        Step::new(self.text)
+            .with_duration(self.duration_seconds.map(Duration::from_secs))
+            .with_timer(self.timer)
+            .with_ingredients(references)
     }
 }
 
+/// The method as structured `steps`, or as the legacy `method` text which
+/// gets a step per line. `None` when neither is given.
+pub(crate) fn method_steps(
+    method: Option<String>,
+    steps: Option<Vec<StepJson>>,
+    ingredients: &[Ingredient],
+) -> Option<Vec<Step>> {
+    match (steps, method) {
+        (Some(steps), _) => Some(
+            steps
+                .into_iter()
+                .map(|step| step.into_step(ingredients))
+                .collect(),
+        ),
+        (None, Some(method)) => Some(split_method(&method)),
+        (None, None) => None,
+    }
+}
+
+pub(crate) fn ambiguous_method_response() -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(StatusCode::BAD_REQUEST)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": "Give either `method` or `steps`, not both",
+                "field": "steps",
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+/// Has no image, those are uploaded once the recipe exists.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RecipeJson {
     name: String,
-    image: String,
-    method: String,
+    /// Legacy form of `steps`, one step per line.
+    method: Option<String>,
+    steps: Option<Vec<StepJson>>,
+    #[serde(default = "default_servings")]
+    servings: u32,
+    prep_minutes: Option<u32>,
+    cook_minutes: Option<u32>,
+    difficulty: Option<DifficultyJson>,
+    /// Defaults to the caller, only admins may name someone else.
+    #[serde(default)]
+    owner: Option<uuid::Uuid>,
+    #[serde(default)]
+    visibility: VisibilityJson,
+    #[serde(default)]
+    tags: Vec<String>,
     ingredients: Vec<IngredientJson>,
 }
 
-impl Into<Recipe> for RecipeJson {
-    fn into(self) -> Recipe {
-        Recipe::new(
+pub(crate) fn default_servings() -> u32 {
+    DEFAULT_SERVINGS
+}
+
+impl RecipeJson {
+    /// The recipe, with warnings on the raw ingredient lines that were
+    /// ambiguous. Errors carry the position of the first raw line that names
+    /// no ingredient.
+    fn into_recipe(self) -> Result<(Recipe, Vec<IngredientWarningJson>), usize> {
+        let mut ingredients: Vec<Ingredient> = vec![];
+        let mut warnings: Vec<IngredientWarningJson> = vec![];
+        for (index, ingredient) in self.ingredients.into_iter().enumerate() {
+            let (ingredient, line_warnings) = ingredient.into_ingredient().ok_or(index)?;
+            ingredients.push(ingredient);
+            warnings.extend(
+                line_warnings
+                    .into_iter()
+                    .map(|warning| IngredientWarningJson {
+                        field: format!("ingredients[{}].raw", index),
+                        warning: warning.code(),
+                        message: format!("{}", warning),
+                    }),
+            );
+        }
+        let steps = method_steps(self.method, self.steps, &ingredients).unwrap_or_default();
+        let recipe = Recipe::new(
             uuid::Uuid::new_v4(),
             self.name,
-            self.image,
-            self.method,
-            self.ingredients
-                .into_iter()
-                .map(IngredientJson::into)
-                .collect::<Vec<Ingredient>>(),
+            String::new(),
+            steps,
+            ingredients,
         )
+        .with_servings(self.servings)
+        .with_times(self.prep_minutes, self.cook_minutes)
+        .with_difficulty(self.difficulty.map(Difficulty::from))
+        .with_access(RecipeAccess::new(self.owner, self.visibility.into()))
+        .with_tags(self.tags);
+        Ok((recipe, warnings))
     }
 }
 
 pub(crate) type DynInsertRecipeService = Arc<dyn InsertRecipeService + Sync + Send>;
 pub async fn insert_recipe_handler(
     axum::extract::State(service): axum::extract::State<DynInsertRecipeService>,
+    user: AuthenticatedUser,
     recipe: Json<RecipeJson>,
 ) -> Result<Response<BoxBody>, YaissError> {
-    let result = service.insert_recipe(recipe.0.into()).await;
+    if recipe.method.is_some() && recipe.steps.is_some() {
+        return ambiguous_method_response();
+    }
+    let (recipe, warnings) = match recipe.0.into_recipe() {
+        Ok(parsed) => parsed,
+        Err(index) => {
+            return Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": "Ingredient line names no ingredient",
+                        "field": format!("ingredients[{}].raw", index),
+                    }))
+                    .to_string(),
+                ))
+                .map_err(|e| e.into())
+        }
+    };
+    let result = service.insert_recipe(user.caller(), recipe).await;
     match result {
-        Ok(()) => {
+        Ok(()) if warnings.is_empty() => {
             let builder = Response::builder()
                 .status(StatusCode::CREATED)
                 .body(body::boxed(BoxBody::default()));
             builder.map_err(|e| e.into())
         }
+        Ok(()) => {
+            let builder = Response::builder()
+                .status(StatusCode::CREATED)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "warnings": warnings,
+                    }))
+                    .to_string(),
+                ));
+            builder.map_err(|e| e.into())
+        }
         Err(InsertRecipeServiceError::NoIngredients) => {
             let builder = Response::builder()
                 .status(StatusCode::BAD_REQUEST)
@@ -77,6 +258,84 @@ pub async fn insert_recipe_handler(
                 ));
             builder.map_err(|e| e.into())
         }
+        Err(InsertRecipeServiceError::InvalidUnit { index, unit }) => {
+            let builder = Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": format!("Unknown unit `{}`", unit),
+                        "field": format!("ingredients[{}].unit", index),
+                    }))
+                    .to_string(),
+                ));
+            builder.map_err(|e| e.into())
+        }
+        Err(InsertRecipeServiceError::InvalidTag { index, tag }) => {
+            let builder = Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": format!("Invalid tag `{}`", tag),
+                        "field": format!("tags[{}]", index),
+                    }))
+                    .to_string(),
+                ));
+            builder.map_err(|e| e.into())
+        }
+        Err(InsertRecipeServiceError::InvalidStep { index, error }) => {
+            let builder = Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": format!("{}", error),
+                        "field": format!("steps[{}]", index),
+                    }))
+                    .to_string(),
+                ));
+            builder.map_err(|e| e.into())
+        }
+        Err(error @ InsertRecipeServiceError::UnknownStepIngredient { index }) => {
+            let builder = Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": format!("{}", error),
+                        "field": format!("steps[{}].ingredients", index),
+                    }))
+                    .to_string(),
+                ));
+            builder.map_err(|e| e.into())
+        }
+        Err(InsertRecipeServiceError::InvalidServings) => {
+            let builder = Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": format!("{}", InsertRecipeServiceError::InvalidServings),
+                        "field": "servings",
+                    }))
+                    .to_string(),
+                ));
+            builder.map_err(|e| e.into())
+        }
+        Err(InsertRecipeServiceError::Forbidden) => {
+            let builder = Response::builder()
+                .status(StatusCode::FORBIDDEN)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": format!("{}", InsertRecipeServiceError::Forbidden),
+                        "field": "owner",
+                    }))
+                    .to_string(),
+                ));
+            builder.map_err(|e| e.into())
+        }
         Err(InsertRecipeServiceError::InternalError) => {
             let builder = Response::builder()
                 .status(StatusCode::INTERNAL_SERVER_ERROR)
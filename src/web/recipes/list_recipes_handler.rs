@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, Body},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::recipes::{
+        domain::{
+            difficulty::Difficulty,
+            filter::RecipeFilter,
+            pagination::{Page, PageRequest, SortBy, SortDirection},
+            recipe::Recipe,
+            tag::{TagFilter, TagMatch},
+        },
+        list_recipes_service::DEFAULT_PAGE_SIZE,
+        ports::incoming::list_recipes_service::{ListRecipesService, ListRecipesServiceError},
+    },
+    web::{
+        recipes::query_recipe_handler::{DifficultyJson, RecipeJson},
+        users::authenticated_user::OptionalUser,
+    },
+};
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortByJson {
+    Name,
+    #[default]
+    CreatedAt,
+}
+
+impl From<SortByJson> for SortBy {
+    fn from(value: SortByJson) -> Self {
+        match value {
+            SortByJson::Name => SortBy::Name,
+            SortByJson::CreatedAt => SortBy::CreatedAt,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirectionJson {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl From<SortDirectionJson> for SortDirection {
+    fn from(value: SortDirectionJson) -> Self {
+        match value {
+            SortDirectionJson::Asc => SortDirection::Ascending,
+            SortDirectionJson::Desc => SortDirection::Descending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagMatchJson {
+    #[default]
+    All,
+    Any,
+}
+
+impl From<TagMatchJson> for TagMatch {
+    fn from(value: TagMatchJson) -> Self {
+        match value {
+            TagMatchJson::All => TagMatch::All,
+            TagMatchJson::Any => TagMatch::Any,
+        }
+    }
+}
+
+/// Filter from the comma separated `tags` query parameter.
+pub(crate) fn tag_filter(tags: Option<&str>, mode: TagMatchJson) -> TagFilter {
+    let tags = tags
+        .unwrap_or_default()
+        .split(',')
+        .filter(|tag| !tag.trim().is_empty())
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+    TagFilter::new(tags, mode.into())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRecipesParams {
+    #[serde(default)]
+    page: u32,
+    page_size: Option<u32>,
+    #[serde(default)]
+    sort: SortByJson,
+    #[serde(default)]
+    order: SortDirectionJson,
+    tags: Option<String>,
+    #[serde(default)]
+    tag_match: TagMatchJson,
+    /// Keeps the recipes whose preparation and cooking time is known and
+    /// at most this long.
+    max_total_minutes: Option<u32>,
+    difficulty: Option<DifficultyJson>,
+    /// Summaries carry no quantities to convert, so asking for units is an
+    /// error rather than silently ignored.
+    units: Option<String>,
+}
+
+impl From<ListRecipesParams> for PageRequest {
+    fn from(value: ListRecipesParams) -> Self {
+        PageRequest::new(
+            value.page,
+            value.page_size.unwrap_or(DEFAULT_PAGE_SIZE),
+            value.sort.into(),
+            value.order.into(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecipePageJson {
+    recipes: Vec<RecipeJson>,
+    page: u32,
+    page_size: u32,
+    next_page: Option<u32>,
+}
+
+impl From<Page<Recipe>> for RecipePageJson {
+    fn from(value: Page<Recipe>) -> Self {
+        let (page, page_size, next_page) = (value.page(), value.page_size(), value.next_page());
+        Self {
+            recipes: value
+                .into_items()
+                .into_iter()
+                .map(RecipeJson::from)
+                .collect::<Vec<RecipeJson>>(),
+            page,
+            page_size,
+            next_page,
+        }
+    }
+}
+
+pub(crate) type DynListRecipesService = Arc<dyn ListRecipesService + Sync + Send>;
+pub async fn list_recipes_handler(
+    axum::extract::State(service): axum::extract::State<DynListRecipesService>,
+    user: OptionalUser,
+    params: axum::extract::Query<ListRecipesParams>,
+) -> Result<Response<Body>, YaissError> {
+    if params.0.units.is_some() {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": "Recipe summaries have no quantities to convert",
+                    "field": "units",
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into());
+    }
+    let filter = RecipeFilter::new(tag_filter(params.0.tags.as_deref(), params.0.tag_match))
+        .with_max_total_minutes(params.0.max_total_minutes)
+        .with_difficulty(params.0.difficulty.map(Difficulty::from));
+    let builder = match service
+        .list_recipes(user.caller(), params.0.into(), filter)
+        .await
+    {
+        Ok(page) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!(RecipePageJson::from(page))).to_string(),
+            )),
+        Err(ListRecipesServiceError::InvalidPageSize) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", ListRecipesServiceError::InvalidPageSize)
+                }))
+                .to_string(),
+            )),
+        Err(error @ ListRecipesServiceError::InvalidTag(_)) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", error),
+                    "field": "tags",
+                }))
+                .to_string(),
+            )),
+        Err(ListRecipesServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", ListRecipesServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, routing::get, Extension, Router};
+    use tower::ServiceExt;
+
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::recipes::list_recipes_service::ListRecipes,
+        web::users::identity::{DynIdentityExtractor, TrustedHeaderIdentity},
+    };
+
+    #[tokio::test]
+    async fn listings_refuse_unit_conversion() {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        let service = Arc::new(ListRecipes::new(storage)) as DynListRecipesService;
+        let app = Router::new()
+            .route("/", get(list_recipes_handler))
+            .with_state(service)
+            .layer(Extension(
+                Arc::new(TrustedHeaderIdentity) as DynIdentityExtractor
+            ));
+
+        for (query, expected) in [
+            ("/", StatusCode::OK),
+            ("/?units=imperial", StatusCode::BAD_REQUEST),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::get(query).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), expected);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, Body},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::recipes::{
+        domain::recipe_match::RecipeMatch,
+        list_recipes_service::DEFAULT_PAGE_SIZE,
+        ports::incoming::match_recipe_service::{MatchRecipeService, MatchRecipeServiceError},
+    },
+    web::{
+        recipes::query_recipe_handler::{IngredientJson, RecipeJson},
+        users::authenticated_user::OptionalUser,
+    },
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MatchRecipeJson {
+    ingredients: Vec<String>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecipeMatchJson {
+    recipe: RecipeJson,
+    coverage: f64,
+    matched: usize,
+    missing: Vec<IngredientJson>,
+}
+
+impl From<RecipeMatch> for RecipeMatchJson {
+    fn from(value: RecipeMatch) -> Self {
+        Self {
+            coverage: value.coverage(),
+            matched: value.matched_count(),
+            missing: value
+                .missing()
+                .iter()
+                .map(IngredientJson::from)
+                .collect::<Vec<IngredientJson>>(),
+            recipe: RecipeJson::from(value.recipe().clone()),
+        }
+    }
+}
+
+pub(crate) type DynMatchRecipeService = Arc<dyn MatchRecipeService + Sync + Send>;
+pub async fn match_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynMatchRecipeService>,
+    user: OptionalUser,
+    json: Json<MatchRecipeJson>,
+) -> Result<Response<Body>, YaissError> {
+    let json = json.0;
+    let result = service
+        .match_recipes(
+            user.caller(),
+            json.ingredients,
+            json.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        )
+        .await;
+    let builder = match result {
+        Ok(matches) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "matches": matches
+                        .into_iter()
+                        .map(RecipeMatchJson::from)
+                        .collect::<Vec<RecipeMatchJson>>()
+                }))
+                .to_string(),
+            )),
+        Err(error @ MatchRecipeServiceError::NoIngredients)
+        | Err(error @ MatchRecipeServiceError::InvalidLimit) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", error)
+                }))
+                .to_string(),
+            )),
+        Err(MatchRecipeServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", MatchRecipeServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
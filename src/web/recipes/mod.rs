@@ -7,34 +7,58 @@ use axum::{
 };
 
 use crate::{
-    data_storage::recipes::recipes_sqlite_ds::RecipeSqliteDS,
+    data_storage::{
+        images::images_fs_ds::ImageFsDS,
+        recipes::{recipe_page_http_ds::RecipePageHttpDS, recipes_sqlite_ds::RecipeSqliteDS},
+    },
     services::recipes::{
-        delete_recipe_service::DeleteRecipe, insert_recipe_service::InsertRecipe,
-        query_recipe_service::QueryRecipe, update_recipe_service::UpdateRecipe,
+        delete_recipe_service::DeleteRecipe, import_recipe_service::ImportRecipe,
+        insert_recipe_service::InsertRecipe, list_recipes_service::ListRecipes,
+        match_recipe_service::MatchRecipe, query_recipe_service::QueryRecipe,
+        render_recipe_service::RenderRecipe, search_recipe_service::SearchRecipe,
+        update_recipe_service::UpdateRecipe,
     },
     state::State,
 };
 
 use self::{
-    delete_recipe_handler::DynDeleteRecipesService, insert_recipe_handler::DynInsertRecipeService,
-    query_recipe_handler::DynQueryRecipeService, update_recipe_handler::DynUpdateRecipeService,
+    delete_recipe_handler::DynDeleteRecipesService, import_recipe_handler::DynImportRecipeService,
+    insert_recipe_handler::DynInsertRecipeService, list_recipes_handler::DynListRecipesService,
+    match_recipe_handler::DynMatchRecipeService, query_recipe_handler::DynQueryRecipeService,
+    render_recipe_handler::DynRenderRecipeService, search_recipe_handler::DynSearchRecipeService,
+    update_recipe_handler::DynUpdateRecipeService,
 };
 
 pub mod delete_recipe_handler;
+pub mod import_recipe_handler;
 pub mod insert_recipe_handler;
+pub mod list_recipes_handler;
+pub mod match_recipe_handler;
 pub mod query_recipe_handler;
+pub mod render_recipe_handler;
+pub mod search_recipe_handler;
 pub mod update_recipe_handler;
 
 pub fn router(state: State) -> Router<(), Body> {
     let storage = RecipeSqliteDS::new(state.pool());
+    let images = ImageFsDS::new(state.image_base_path());
 
     let delete_recipe_service =
-        Arc::new(DeleteRecipe::new(storage.clone())) as DynDeleteRecipesService;
+        Arc::new(DeleteRecipe::new(storage.clone(), images.clone())) as DynDeleteRecipesService;
     let query_recipe_service = Arc::new(QueryRecipe::new(storage.clone())) as DynQueryRecipeService;
     let insert_recipe_service =
         Arc::new(InsertRecipe::new(storage.clone())) as DynInsertRecipeService;
     let update_recipe_service =
         Arc::new(UpdateRecipe::new(storage.clone())) as DynUpdateRecipeService;
+    let list_recipes_service = Arc::new(ListRecipes::new(storage.clone())) as DynListRecipesService;
+    let match_recipe_service = Arc::new(MatchRecipe::new(storage.clone())) as DynMatchRecipeService;
+    let search_recipe_service =
+        Arc::new(SearchRecipe::new(storage.clone())) as DynSearchRecipeService;
+    let import_recipe_service =
+        Arc::new(ImportRecipe::new(storage.clone(), RecipePageHttpDS::new()))
+            as DynImportRecipeService;
+    let render_recipe_service =
+        Arc::new(RenderRecipe::new(storage.clone(), images)) as DynRenderRecipeService;
 
     let recipes_routes = Router::new()
         .route(
@@ -53,7 +77,23 @@ pub fn router(state: State) -> Router<(), Body> {
         )
         .with_state(query_recipe_service.clone())
         .route("/", post(insert_recipe_handler::insert_recipe_handler))
-        .with_state(insert_recipe_service.clone());
+        .with_state(insert_recipe_service.clone())
+        .route("/", get(list_recipes_handler::list_recipes_handler))
+        .with_state(list_recipes_service)
+        .route("/search", get(search_recipe_handler::search_recipe_handler))
+        .with_state(search_recipe_service)
+        .route("/match", post(match_recipe_handler::match_recipe_handler))
+        .with_state(match_recipe_service)
+        .route(
+            "/import",
+            post(import_recipe_handler::import_recipe_handler),
+        )
+        .with_state(import_recipe_service)
+        .route(
+            "/:identifier/render",
+            get(render_recipe_handler::render_recipe_handler),
+        )
+        .with_state(render_recipe_service);
 
     let recipes_router = Router::new().nest("/recipes", recipes_routes);
     Router::new().nest("/api/v1", recipes_router)
@@ -5,15 +5,23 @@ use axum::{
     http::{Response, StatusCode},
     Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{
     error::YaissError,
     services::recipes::{
-        domain::{ingredient::Ingredient, recipe::Recipe},
+        domain::{
+            access::Visibility,
+            difficulty::Difficulty,
+            ingredient::Ingredient,
+            recipe::Recipe,
+            step::{join_steps, Step},
+            unit::UnitSystem,
+        },
         ports::incoming::query_recipe_service::{QueryRecipeService, QueryRecipeServiceError},
     },
+    web::users::authenticated_user::OptionalUser,
 };
 
 #[derive(Debug, Clone, Serialize)]
@@ -22,6 +30,8 @@ pub struct IngredientJson {
     name: String,
     amount: f64,
     unit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display: Option<String>,
 }
 
 impl From<&Ingredient> for IngredientJson {
@@ -31,6 +41,29 @@ impl From<&Ingredient> for IngredientJson {
             name: value.name().to_string(),
             amount: value.amount(),
             unit: value.unit().to_string(),
+            display: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepJson {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timer: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ingredients: Vec<uuid::Uuid>,
+}
+
+impl From<&Step> for StepJson {
+    fn from(value: &Step) -> Self {
+        Self {
+            text: value.text().to_string(),
+            duration_seconds: value.duration().map(|duration| duration.as_secs()),
+            timer: value.timer().map(str::to_string),
+            ingredients: value.ingredients().to_vec(),
         }
     }
 }
@@ -40,7 +73,23 @@ pub struct RecipeJson {
     uuid: uuid::Uuid,
     name: String,
     image: String,
+    /// The steps as a single text, for clients predating `steps`.
     method: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    steps: Vec<StepJson>,
+    servings: u32,
+    prep_minutes: Option<u32>,
+    cook_minutes: Option<u32>,
+    total_minutes: Option<u32>,
+    difficulty: Option<DifficultyJson>,
+    owner: Option<uuid::Uuid>,
+    visibility: VisibilityJson,
+    /// Mean star rating rounded to two decimals, `null` until reviewed.
+    average_rating: Option<f64>,
+    review_count: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     ingredients: Vec<IngredientJson>,
 }
 
@@ -50,27 +99,160 @@ impl From<Recipe> for RecipeJson {
             uuid: value.uuid(),
             name: value.name().to_string(),
             image: value.image().to_string(),
-            method: value.method().to_string(),
+            method: join_steps(value.steps()),
+            steps: value.steps().iter().map(StepJson::from).collect(),
+            servings: value.servings(),
+            prep_minutes: value.prep_minutes(),
+            cook_minutes: value.cook_minutes(),
+            total_minutes: value.total_minutes(),
+            difficulty: value.difficulty().map(DifficultyJson::from),
+            owner: value.access().owner(),
+            visibility: value.access().visibility().into(),
+            average_rating: value
+                .rating()
+                .average()
+                .map(|average| (average * 100.0).round() / 100.0),
+            review_count: value.rating().count(),
+            tags: value.tags().to_vec(),
             ingredients: value
                 .ingredients()
                 .iter()
-                .map(|e| IngredientJson::from(e))
+                .map(IngredientJson::from)
                 .collect::<Vec<IngredientJson>>(),
         }
     }
 }
 
+impl RecipeJson {
+    /// Same as `From<Recipe>`, with a readable quantity on every ingredient.
+    pub(crate) fn with_display(recipe: Recipe) -> Self {
+        let displays = recipe
+            .ingredients()
+            .iter()
+            .map(|ingredient| ingredient.quantity().display())
+            .collect::<Vec<String>>();
+        let mut json = Self::from(recipe);
+        for (ingredient, display) in json.ingredients.iter_mut().zip(displays) {
+            ingredient.display = Some(display);
+        }
+        json
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitSystemJson {
+    Metric,
+    Imperial,
+    #[default]
+    Original,
+}
+
+impl From<UnitSystemJson> for UnitSystem {
+    fn from(value: UnitSystemJson) -> Self {
+        match value {
+            UnitSystemJson::Metric => UnitSystem::Metric,
+            UnitSystemJson::Imperial => UnitSystem::Imperial,
+            UnitSystemJson::Original => UnitSystem::Original,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VisibilityJson {
+    #[default]
+    Private,
+    Shared,
+    Public,
+}
+
+impl From<VisibilityJson> for Visibility {
+    fn from(value: VisibilityJson) -> Self {
+        match value {
+            VisibilityJson::Private => Visibility::Private,
+            VisibilityJson::Shared => Visibility::Shared,
+            VisibilityJson::Public => Visibility::Public,
+        }
+    }
+}
+
+impl From<Visibility> for VisibilityJson {
+    fn from(value: Visibility) -> Self {
+        match value {
+            Visibility::Private => VisibilityJson::Private,
+            Visibility::Shared => VisibilityJson::Shared,
+            Visibility::Public => VisibilityJson::Public,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DifficultyJson {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl From<DifficultyJson> for Difficulty {
+    fn from(value: DifficultyJson) -> Self {
+        match value {
+            DifficultyJson::Easy => Difficulty::Easy,
+            DifficultyJson::Medium => Difficulty::Medium,
+            DifficultyJson::Hard => Difficulty::Hard,
+        }
+    }
+}
+
+impl From<Difficulty> for DifficultyJson {
+    fn from(value: Difficulty) -> Self {
+        match value {
+            Difficulty::Easy => DifficultyJson::Easy,
+            Difficulty::Medium => DifficultyJson::Medium,
+            Difficulty::Hard => DifficultyJson::Hard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryRecipeParams {
+    servings: Option<u32>,
+    #[serde(default)]
+    units: UnitSystemJson,
+}
+
 pub(crate) type DynQueryRecipeService = Arc<dyn QueryRecipeService + Sync + Send>;
 pub async fn query_recipe_handler(
     axum::extract::State(service): axum::extract::State<DynQueryRecipeService>,
+    user: OptionalUser,
     index: axum::extract::Path<uuid::Uuid>,
+    params: axum::extract::Query<QueryRecipeParams>,
 ) -> Result<Response<Body>, YaissError> {
-    let builder = match service.clone().query_recipe(index.0).await {
-        Ok(recipe) => Response::builder()
-            .status(StatusCode::OK)
+    let QueryRecipeParams { servings, units } = params.0;
+    let builder = match service
+        .clone()
+        .query_recipe(user.caller(), index.0, servings, units.into())
+        .await
+    {
+        Ok(recipe) => {
+            let json = match (servings, units) {
+                (None, UnitSystemJson::Original) => RecipeJson::from(recipe),
+                _ => RecipeJson::with_display(recipe),
+            };
+            Response::builder()
+                .status(StatusCode::OK)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::Body::from(Json(json!(json)).to_string()))
+        }
+        Err(QueryRecipeServiceError::InvalidServings) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
             .header(axum::http::header::CONTENT_TYPE, "application/json")
             .body(body::Body::from(
-                Json(json!(RecipeJson::from(recipe))).to_string(),
+                Json(json!({
+                    "error": format!("{}", QueryRecipeServiceError::InvalidServings)
+                }))
+                .to_string(),
             )),
         Err(QueryRecipeServiceError::RecipeNotFound) => Response::builder()
             .status(StatusCode::NOT_FOUND)
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::recipes::{
+        domain::recipe_card::{CardFormat, PaperSize},
+        ports::incoming::render_recipe_service::{RenderRecipeService, RenderRecipeServiceError},
+    },
+    web::{recipes::query_recipe_handler::UnitSystemJson, users::authenticated_user::OptionalUser},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardFormatJson {
+    #[default]
+    Pdf,
+    Html,
+}
+
+impl From<CardFormatJson> for CardFormat {
+    fn from(value: CardFormatJson) -> Self {
+        match value {
+            CardFormatJson::Pdf => CardFormat::Pdf,
+            CardFormatJson::Html => CardFormat::Html,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaperSizeJson {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl From<PaperSizeJson> for PaperSize {
+    fn from(value: PaperSizeJson) -> Self {
+        match value {
+            PaperSizeJson::A4 => PaperSize::A4,
+            PaperSizeJson::Letter => PaperSize::Letter,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RenderRecipeParams {
+    #[serde(default)]
+    format: CardFormatJson,
+    servings: Option<u32>,
+    #[serde(default)]
+    units: UnitSystemJson,
+    #[serde(default)]
+    paper: PaperSizeJson,
+}
+
+pub(crate) type DynRenderRecipeService = Arc<dyn RenderRecipeService + Sync + Send>;
+pub async fn render_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynRenderRecipeService>,
+    user: OptionalUser,
+    identifier: axum::extract::Path<uuid::Uuid>,
+    params: axum::extract::Query<RenderRecipeParams>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let RenderRecipeParams {
+        format,
+        servings,
+        units,
+        paper,
+    } = params.0;
+    let format = CardFormat::from(format);
+    match service
+        .render_recipe(
+            user.caller(),
+            identifier.0,
+            format,
+            servings,
+            units.into(),
+            paper.into(),
+        )
+        .await
+    {
+        Ok(card) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, format.content_type())
+            .header(
+                axum::http::header::CONTENT_DISPOSITION,
+                format!(
+                    "inline; filename=\"{}.{}\"",
+                    identifier.0,
+                    format.extension()
+                ),
+            )
+            .body(body::boxed(body::Full::from(card)))
+            .map_err(|e| e.into()),
+        Err(error) => {
+            let status = match error {
+                RenderRecipeServiceError::RecipeNotFound => StatusCode::NOT_FOUND,
+                RenderRecipeServiceError::InvalidServings => StatusCode::BAD_REQUEST,
+                RenderRecipeServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            Response::builder()
+                .status(status)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({ "error": format!("{}", error) })).to_string(),
+                ))
+                .map_err(|e| e.into())
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, Body},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::recipes::{
+        domain::{pagination::Page, search::RecipeSearchHit},
+        list_recipes_service::DEFAULT_PAGE_SIZE,
+        ports::incoming::search_recipe_service::{SearchRecipeService, SearchRecipeServiceError},
+    },
+    web::{
+        recipes::{
+            list_recipes_handler::{tag_filter, TagMatchJson},
+            query_recipe_handler::RecipeJson,
+        },
+        users::authenticated_user::OptionalUser,
+    },
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchRecipeParams {
+    q: String,
+    #[serde(default)]
+    page: u32,
+    page_size: Option<u32>,
+    tags: Option<String>,
+    #[serde(default)]
+    tag_match: TagMatchJson,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecipeSearchHitJson {
+    recipe: RecipeJson,
+    snippet: String,
+    score: f64,
+}
+
+impl From<RecipeSearchHit> for RecipeSearchHitJson {
+    fn from(value: RecipeSearchHit) -> Self {
+        Self {
+            recipe: RecipeJson::from(value.recipe().clone()),
+            snippet: value.snippet().to_string(),
+            score: value.score(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecipeSearchPageJson {
+    results: Vec<RecipeSearchHitJson>,
+    page: u32,
+    page_size: u32,
+    next_page: Option<u32>,
+}
+
+impl From<Page<RecipeSearchHit>> for RecipeSearchPageJson {
+    fn from(value: Page<RecipeSearchHit>) -> Self {
+        let (page, page_size, next_page) = (value.page(), value.page_size(), value.next_page());
+        Self {
+            results: value
+                .into_items()
+                .into_iter()
+                .map(RecipeSearchHitJson::from)
+                .collect::<Vec<RecipeSearchHitJson>>(),
+            page,
+            page_size,
+            next_page,
+        }
+    }
+}
+
+pub(crate) type DynSearchRecipeService = Arc<dyn SearchRecipeService + Sync + Send>;
+pub async fn search_recipe_handler(
+    axum::extract::State(service): axum::extract::State<DynSearchRecipeService>,
+    user: OptionalUser,
+    params: axum::extract::Query<SearchRecipeParams>,
+) -> Result<Response<Body>, YaissError> {
+    let params = params.0;
+    let result = service
+        .search_recipes(
+            user.caller(),
+            params.q,
+            tag_filter(params.tags.as_deref(), params.tag_match),
+            params.page,
+            params.page_size.unwrap_or(DEFAULT_PAGE_SIZE),
+        )
+        .await;
+    let builder = match result {
+        Ok(page) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!(RecipeSearchPageJson::from(page))).to_string(),
+            )),
+        Err(error @ SearchRecipeServiceError::EmptyQuery)
+        | Err(error @ SearchRecipeServiceError::InvalidPageSize)
+        | Err(error @ SearchRecipeServiceError::InvalidTag(_)) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", error)
+                }))
+                .to_string(),
+            )),
+        Err(SearchRecipeServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::Body::from(
+                Json(json!({
+                    "error": format!("{}", SearchRecipeServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
@@ -11,66 +11,366 @@ use serde_json::json;
 use crate::{
     error::YaissError,
     services::recipes::{
-        domain::{ingredient::Ingredient, recipe::Recipe},
-        ports::incoming::update_recipe_service::UpdateRecipeService,
+        domain::{
+            access::Visibility, difficulty::Difficulty, ingredient::Ingredient, recipe::Recipe,
+            recipe_changes::RecipeChanges, step::Step,
+        },
+        ports::incoming::update_recipe_service::{UpdateRecipeService, UpdateRecipeServiceError},
+    },
+    web::{
+        recipes::{
+            insert_recipe_handler::{ambiguous_method_response, method_steps, StepJson},
+            query_recipe_handler::{self, DifficultyJson, VisibilityJson},
+        },
+        users::authenticated_user::AuthenticatedUser,
     },
 };
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct IngredientJson {
-    uuid: uuid::Uuid,
+    uuid: Option<uuid::Uuid>,
     name: String,
     amount: f64,
     unit: String,
 }
 
-impl Into<Ingredient> for IngredientJson {
-    fn into(self) -> Ingredient {
-        Ingredient::new(self.uuid, self.name, self.amount, self.unit)
+impl From<IngredientJson> for Ingredient {
+    fn from(value: IngredientJson) -> Self {
+        Ingredient::new(
+            value.uuid.unwrap_or_else(uuid::Uuid::new_v4),
+            value.name,
+            value.amount,
+            value.unit,
+        )
     }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct RecipeJson {
-    uuid: uuid::Uuid,
     name: String,
-    image: String,
-    method: String,
+    /// Legacy form of `steps`. The steps are left unchanged when both are
+    /// absent, positions in them refer to `update_ingredients`.
+    method: Option<String>,
+    steps: Option<Vec<StepJson>>,
+    /// Left unchanged when absent, like the times and the difficulty.
+    servings: Option<u32>,
+    prep_minutes: Option<u32>,
+    cook_minutes: Option<u32>,
+    difficulty: Option<DifficultyJson>,
+    /// Left unchanged when absent.
+    visibility: Option<VisibilityJson>,
+    /// Replaces every tag of the recipe, left unchanged when absent.
+    tags: Option<Vec<String>>,
+    #[serde(default)]
     update_ingredients: Vec<IngredientJson>,
+    #[serde(default)]
     delete_ingredients: Vec<uuid::Uuid>,
 }
 
-impl Into<Recipe> for RecipeJson {
-    fn into(self) -> Recipe {
-        Recipe::new(
-            self.uuid,
-            self.name,
-            self.image,
-            self.method,
-            self.update_ingredients
-                .into_iter()
-                .map(|e| e.into())
-                .collect(),
-        )
+impl RecipeJson {
+    fn into_recipe(
+        self,
+        uuid: uuid::Uuid,
+    ) -> (Recipe, RecipeChanges, Vec<uuid::Uuid>, Option<Vec<Step>>) {
+        let ingredients = self
+            .update_ingredients
+            .into_iter()
+            .map(Ingredient::from)
+            .collect::<Vec<Ingredient>>();
+        let steps = method_steps(self.method, self.steps, &ingredients);
+        let changes = RecipeChanges::default()
+            .with_servings(self.servings)
+            .with_times(self.prep_minutes, self.cook_minutes)
+            .with_difficulty(self.difficulty.map(Difficulty::from))
+            .with_visibility(self.visibility.map(Visibility::from));
+        let recipe = Recipe::new(uuid, self.name, String::new(), vec![], ingredients);
+        (recipe, changes, self.delete_ingredients, steps)
     }
 }
 
 pub(crate) type DynUpdateRecipeService = Arc<dyn UpdateRecipeService + Sync + Send>;
 pub async fn update_recipe_handler(
     axum::extract::State(service): axum::extract::State<DynUpdateRecipeService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<uuid::Uuid>,
     json: Json<RecipeJson>,
 ) -> Result<Response<BoxBody>, YaissError> {
-    let (delete_ingredients, recipe): (Vec<uuid::Uuid>, Recipe) =
-        (json.0.delete_ingredients.clone(), json.0.into());
-    let result = service.update_recipe(recipe, delete_ingredients);
-    let builder = Response::builder()
-        .status(StatusCode::INTERNAL_SERVER_ERROR)
-        .header(axum::http::header::CONTENT_TYPE, "application/json")
-        .body(body::boxed(
-            Json(json!({
-                "error": "not implemented",
-            }))
-            .to_string(),
-        ));
+    if json.method.is_some() && json.steps.is_some() {
+        return ambiguous_method_response();
+    }
+    let tags = json.tags.clone();
+    let (recipe, changes, delete_ingredients, steps) = json.0.into_recipe(identifier.0);
+    let builder = match service
+        .update_recipe(
+            user.caller(),
+            recipe,
+            changes,
+            delete_ingredients,
+            tags,
+            steps,
+        )
+        .await
+    {
+        Ok(recipe) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!(query_recipe_handler::RecipeJson::from(recipe))).to_string(),
+            )),
+        Err(UpdateRecipeServiceError::RecipeNotFound) => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", UpdateRecipeServiceError::RecipeNotFound)
+                }))
+                .to_string(),
+            )),
+        Err(UpdateRecipeServiceError::Forbidden) => Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", UpdateRecipeServiceError::Forbidden)
+                }))
+                .to_string(),
+            )),
+        Err(UpdateRecipeServiceError::NoIngredients) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", UpdateRecipeServiceError::NoIngredients)
+                }))
+                .to_string(),
+            )),
+        Err(error @ UpdateRecipeServiceError::ForeignIngredient { index }) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", error),
+                    "field": format!("update_ingredients[{}].uuid", index),
+                }))
+                .to_string(),
+            )),
+        Err(UpdateRecipeServiceError::InvalidUnit { index, unit }) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("Unknown unit `{}`", unit),
+                    "field": format!("update_ingredients[{}].unit", index),
+                }))
+                .to_string(),
+            )),
+        Err(UpdateRecipeServiceError::InvalidTag { index, tag }) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("Invalid tag `{}`", tag),
+                    "field": format!("tags[{}]", index),
+                }))
+                .to_string(),
+            )),
+        Err(UpdateRecipeServiceError::InvalidStep { index, error }) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", error),
+                    "field": format!("steps[{}]", index),
+                }))
+                .to_string(),
+            )),
+        Err(error @ UpdateRecipeServiceError::UnknownStepIngredient { index }) => {
+            Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .header(axum::http::header::CONTENT_TYPE, "application/json")
+                .body(body::boxed(
+                    Json(json!({
+                        "error": format!("{}", error),
+                        "field": format!("steps[{}].ingredients", index),
+                    }))
+                    .to_string(),
+                ))
+        }
+        Err(UpdateRecipeServiceError::InvalidServings) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", UpdateRecipeServiceError::InvalidServings),
+                    "field": "servings",
+                }))
+                .to_string(),
+            )),
+        Err(UpdateRecipeServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", UpdateRecipeServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
     builder.map_err(|e| e.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, routing::put, Extension, Router};
+    use tower::ServiceExt;
+
+    use crate::{
+        data_storage::{memory_pool, recipes::recipes_sqlite_ds::RecipeSqliteDS},
+        services::recipes::{
+            domain::access::RecipeAccess,
+            ports::outgoing::{
+                insert_recipe_port::InsertRecipePort, query_recipe_port::QueryRecipePort,
+            },
+            update_recipe_service::UpdateRecipe,
+        },
+        web::users::identity::{DynIdentityExtractor, TrustedHeaderIdentity},
+    };
+
+    /// Stores `recipe` for `owner` and sends it the update `body` as `owner`.
+    async fn update(
+        recipe: Recipe,
+        owner: uuid::Uuid,
+        body: serde_json::Value,
+    ) -> (StatusCode, Recipe) {
+        let access = RecipeAccess::new(Some(owner), Visibility::Private);
+        update_as(recipe.with_access(access), owner, body).await
+    }
+
+    /// Stores `recipe` and sends it the update `body` as `caller`.
+    async fn update_as(
+        recipe: Recipe,
+        caller: uuid::Uuid,
+        body: serde_json::Value,
+    ) -> (StatusCode, Recipe) {
+        let storage = RecipeSqliteDS::new(memory_pool().await);
+        storage.insert_recipe(recipe.clone()).await.unwrap();
+        let service = Arc::new(UpdateRecipe::new(storage.clone())) as DynUpdateRecipeService;
+        let app = Router::new()
+            .route("/:identifier", put(update_recipe_handler))
+            .with_state(service)
+            .layer(Extension(
+                Arc::new(TrustedHeaderIdentity) as DynIdentityExtractor
+            ));
+
+        let request = Request::put(format!("/{}", recipe.uuid()))
+            .header("x-user-id", caller.to_string())
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let status = app.oneshot(request).await.unwrap().status();
+        (status, storage.query_recipe(recipe.uuid()).await.unwrap())
+    }
+
+    fn soup() -> Recipe {
+        Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Soup".into(),
+            "".into(),
+            vec![],
+            vec![Ingredient::new(
+                uuid::Uuid::new_v4(),
+                "leek".into(),
+                2.0,
+                "piece".into(),
+            )],
+        )
+    }
+
+    #[tokio::test]
+    async fn updates_without_servings_keep_them() {
+        let owner = uuid::Uuid::new_v4();
+
+        let (status, stored) =
+            update(soup().with_servings(6), owner, json!({"name": "Leek soup"})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stored.name(), "Leek soup");
+        assert_eq!(stored.servings(), 6);
+
+        let (status, stored) = update(
+            soup().with_servings(6),
+            owner,
+            json!({"name": "Soup", "servings": 2}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stored.servings(), 2);
+    }
+
+    #[tokio::test]
+    async fn updates_without_times_or_difficulty_keep_them() {
+        let owner = uuid::Uuid::new_v4();
+        let recipe = soup()
+            .with_times(Some(10), Some(30))
+            .with_difficulty(Some(Difficulty::Easy));
+
+        let (status, stored) = update(recipe.clone(), owner, json!({"name": "Leek soup"})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stored.prep_minutes(), Some(10));
+        assert_eq!(stored.cook_minutes(), Some(30));
+        assert_eq!(stored.difficulty(), Some(Difficulty::Easy));
+
+        let (status, stored) = update(
+            recipe,
+            owner,
+            json!({"name": "Soup", "cook_minutes": 45, "difficulty": "hard"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stored.prep_minutes(), Some(10));
+        assert_eq!(stored.cook_minutes(), Some(45));
+        assert_eq!(stored.difficulty(), Some(Difficulty::Hard));
+    }
+
+    #[tokio::test]
+    async fn invalid_updates_by_others_are_refused_before_being_checked() {
+        let owner = uuid::Uuid::new_v4();
+        let body = json!({"name": "", "servings": 0});
+
+        let (status, stored) = update(soup(), owner, body.clone()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(stored.name(), "Soup");
+
+        for (visibility, expected) in [
+            (Visibility::Private, StatusCode::NOT_FOUND),
+            (Visibility::Shared, StatusCode::FORBIDDEN),
+        ] {
+            let recipe = soup().with_access(RecipeAccess::new(Some(owner), visibility));
+            let (status, stored) = update_as(recipe, uuid::Uuid::new_v4(), body.clone()).await;
+            assert_eq!(status, expected);
+            assert_eq!(stored.name(), "Soup");
+        }
+    }
+
+    #[tokio::test]
+    async fn updates_leave_the_image_alone() {
+        let owner = uuid::Uuid::new_v4();
+        let recipe = Recipe::new(
+            uuid::Uuid::new_v4(),
+            "Soup".into(),
+            "a1b2c3.jpg".into(),
+            vec![],
+            soup().ingredients().to_vec(),
+        );
+
+        let (status, stored) = update(
+            recipe,
+            owner,
+            json!({"name": "Leek soup", "image": "", "servings": 2}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stored.name(), "Leek soup");
+        assert_eq!(stored.image(), "a1b2c3.jpg");
+    }
+}
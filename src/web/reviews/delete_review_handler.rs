@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::reviews::ports::incoming::delete_review_service::{
+        DeleteReviewService, DeleteReviewServiceError,
+    },
+    web::users::authenticated_user::AuthenticatedUser,
+};
+
+fn error_response(error: DeleteReviewServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        DeleteReviewServiceError::ReviewNotFound => StatusCode::NOT_FOUND,
+        DeleteReviewServiceError::Forbidden => StatusCode::FORBIDDEN,
+        DeleteReviewServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynDeleteReviewService = Arc<dyn DeleteReviewService + Sync + Send>;
+pub async fn delete_review_handler(
+    axum::extract::State(service): axum::extract::State<DynDeleteReviewService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.delete_review(user.caller(), identifier.0).await {
+        Ok(()) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(BoxBody::default())
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
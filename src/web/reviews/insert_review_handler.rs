@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::reviews::{
+        domain::review::Review,
+        ports::incoming::insert_review_service::{InsertReviewService, InsertReviewServiceError},
+    },
+    web::{
+        reviews::list_reviews_handler::review_response,
+        users::authenticated_user::AuthenticatedUser,
+    },
+};
+
+/// Body of the requests creating or editing a review.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReviewRequestJson {
+    pub(crate) rating: u8,
+    pub(crate) text: Option<String>,
+}
+
+fn error_response(error: InsertReviewServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        InsertReviewServiceError::InvalidReview(_) => StatusCode::BAD_REQUEST,
+        InsertReviewServiceError::RecipeNotFound => StatusCode::NOT_FOUND,
+        InsertReviewServiceError::AlreadyReviewed => StatusCode::CONFLICT,
+        InsertReviewServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynInsertReviewService = Arc<dyn InsertReviewService + Sync + Send>;
+pub async fn insert_review_handler(
+    axum::extract::State(service): axum::extract::State<DynInsertReviewService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    review: Json<ReviewRequestJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let review = Review::new(
+        uuid::Uuid::new_v4(),
+        identifier.0,
+        user.uuid(),
+        review.0.rating,
+        review.0.text,
+    );
+    match service.insert_review(user.caller(), review).await {
+        Ok(review) => review_response(StatusCode::CREATED, review),
+        Err(error) => error_response(error),
+    }
+}
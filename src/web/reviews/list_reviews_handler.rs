@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::{
+        recipes::{domain::pagination::Page, list_recipes_service::DEFAULT_PAGE_SIZE},
+        reviews::{
+            domain::review::Review,
+            ports::incoming::list_reviews_service::{ListReviewsService, ListReviewsServiceError},
+        },
+    },
+    web::users::authenticated_user::OptionalUser,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewJson {
+    uuid: uuid::Uuid,
+    recipe: uuid::Uuid,
+    author: uuid::Uuid,
+    rating: u8,
+    text: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<Review> for ReviewJson {
+    fn from(value: Review) -> Self {
+        Self {
+            uuid: value.uuid(),
+            recipe: value.recipe(),
+            author: value.author(),
+            rating: value.rating(),
+            text: value.text().map(str::to_string),
+            created_at: value.created_at().to_string(),
+            updated_at: value.updated_at().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewPageJson {
+    reviews: Vec<ReviewJson>,
+    page: u32,
+    page_size: u32,
+    next_page: Option<u32>,
+}
+
+impl From<Page<Review>> for ReviewPageJson {
+    fn from(value: Page<Review>) -> Self {
+        let (page, page_size, next_page) = (value.page(), value.page_size(), value.next_page());
+        Self {
+            reviews: value
+                .into_items()
+                .into_iter()
+                .map(ReviewJson::from)
+                .collect(),
+            page,
+            page_size,
+            next_page,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListReviewsParams {
+    #[serde(default)]
+    page: u32,
+    page_size: Option<u32>,
+}
+
+pub(crate) fn review_response(
+    status: StatusCode,
+    review: Review,
+) -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!(ReviewJson::from(review))).to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+fn error_response(error: ListReviewsServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        ListReviewsServiceError::InvalidPageSize => StatusCode::BAD_REQUEST,
+        ListReviewsServiceError::RecipeNotFound => StatusCode::NOT_FOUND,
+        ListReviewsServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynListReviewsService = Arc<dyn ListReviewsService + Sync + Send>;
+pub async fn list_reviews_handler(
+    axum::extract::State(service): axum::extract::State<DynListReviewsService>,
+    user: OptionalUser,
+    identifier: axum::extract::Path<Uuid>,
+    params: axum::extract::Query<ListReviewsParams>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let page_size = params.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    match service
+        .list_reviews(user.caller(), identifier.0, params.page, page_size)
+        .await
+    {
+        Ok(page) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!(ReviewPageJson::from(page))).to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{delete, get, post, put},
+    Router,
+};
+
+use crate::{
+    data_storage::{
+        recipes::recipes_sqlite_ds::RecipeSqliteDS, reviews::reviews_sqlite_ds::ReviewSqliteDS,
+    },
+    services::reviews::{
+        delete_review_service::DeleteReview, insert_review_service::InsertReview,
+        list_reviews_service::ListReviews, update_review_service::UpdateReview,
+    },
+    state::State,
+};
+
+use self::{
+    delete_review_handler::DynDeleteReviewService, insert_review_handler::DynInsertReviewService,
+    list_reviews_handler::DynListReviewsService, update_review_handler::DynUpdateReviewService,
+};
+
+pub mod delete_review_handler;
+pub mod insert_review_handler;
+pub mod list_reviews_handler;
+pub mod update_review_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = ReviewSqliteDS::new(state.pool());
+    let recipes = RecipeSqliteDS::new(state.pool());
+
+    let insert_review_service =
+        Arc::new(InsertReview::new(storage.clone(), recipes.clone())) as DynInsertReviewService;
+    let list_reviews_service =
+        Arc::new(ListReviews::new(storage.clone(), recipes)) as DynListReviewsService;
+    let update_review_service =
+        Arc::new(UpdateReview::new(storage.clone())) as DynUpdateReviewService;
+    let delete_review_service = Arc::new(DeleteReview::new(storage)) as DynDeleteReviewService;
+
+    let recipe_reviews_routes = Router::new()
+        .route(
+            "/:identifier/reviews",
+            get(list_reviews_handler::list_reviews_handler),
+        )
+        .with_state(list_reviews_service)
+        .route(
+            "/:identifier/reviews",
+            post(insert_review_handler::insert_review_handler),
+        )
+        .with_state(insert_review_service);
+    let reviews_routes = Router::new()
+        .route(
+            "/:identifier",
+            put(update_review_handler::update_review_handler),
+        )
+        .with_state(update_review_service)
+        .route(
+            "/:identifier",
+            delete(delete_review_handler::delete_review_handler),
+        )
+        .with_state(delete_review_service);
+
+    let reviews_router = Router::new()
+        .nest("/recipes", recipe_reviews_routes)
+        .nest("/reviews", reviews_routes);
+    Router::new().nest("/api/v1", reviews_router)
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::reviews::ports::incoming::update_review_service::{
+        UpdateReviewService, UpdateReviewServiceError,
+    },
+    web::{
+        reviews::{
+            insert_review_handler::ReviewRequestJson, list_reviews_handler::review_response,
+        },
+        users::authenticated_user::AuthenticatedUser,
+    },
+};
+
+fn error_response(error: UpdateReviewServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        UpdateReviewServiceError::InvalidReview(_) => StatusCode::BAD_REQUEST,
+        UpdateReviewServiceError::ReviewNotFound => StatusCode::NOT_FOUND,
+        UpdateReviewServiceError::Forbidden => StatusCode::FORBIDDEN,
+        UpdateReviewServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynUpdateReviewService = Arc<dyn UpdateReviewService + Sync + Send>;
+pub async fn update_review_handler(
+    axum::extract::State(service): axum::extract::State<DynUpdateReviewService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+    review: Json<ReviewRequestJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service
+        .update_review(user.caller(), identifier.0, review.0.rating, review.0.text)
+        .await
+    {
+        Ok(review) => review_response(StatusCode::OK, review),
+        Err(error) => error_response(error),
+    }
+}
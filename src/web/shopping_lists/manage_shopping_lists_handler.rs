@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    error::YaissError,
+    services::shopping_lists::{
+        domain::{aisle::Aisle, shopping_item::ShoppingItem, shopping_list::ShoppingList},
+        ports::incoming::manage_shopping_lists_service::{
+            ManageShoppingListsService, ManageShoppingListsServiceError, ShoppingListSource,
+        },
+    },
+    web::{
+        meal_plans::manage_meal_plan_handler::DateJson,
+        users::authenticated_user::AuthenticatedUser,
+    },
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortionJson {
+    recipe: Uuid,
+    servings: Option<u32>,
+}
+
+/// Lists are made either from recipes or from a range of the meal plan.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum SourceJson {
+    Recipes { recipes: Vec<PortionJson> },
+    MealPlan { from: DateJson, to: DateJson },
+}
+
+impl From<SourceJson> for ShoppingListSource {
+    fn from(value: SourceJson) -> Self {
+        match value {
+            SourceJson::Recipes { recipes } => ShoppingListSource::Recipes(
+                recipes
+                    .into_iter()
+                    .map(|portion| (portion.recipe, portion.servings))
+                    .collect(),
+            ),
+            SourceJson::MealPlan { from, to } => {
+                ShoppingListSource::MealPlan(from.into(), to.into())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateShoppingListJson {
+    name: Option<String>,
+    #[serde(flatten)]
+    source: SourceJson,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckItemJson {
+    checked: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShoppingItemJson {
+    uuid: Uuid,
+    name: String,
+    amount: f64,
+    unit: String,
+    display: String,
+    checked: bool,
+}
+
+impl From<&ShoppingItem> for ShoppingItemJson {
+    fn from(value: &ShoppingItem) -> Self {
+        Self {
+            uuid: value.uuid(),
+            name: value.name().to_string(),
+            amount: value.amount(),
+            unit: value.unit().to_string(),
+            display: value.quantity().display(),
+            checked: value.checked(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AisleJson {
+    aisle: &'static str,
+    items: Vec<ShoppingItemJson>,
+}
+
+/// Items are grouped by aisle, in the order a shop is walked through.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShoppingListJson {
+    uuid: Uuid,
+    name: String,
+    aisles: Vec<AisleJson>,
+}
+
+impl From<ShoppingList> for ShoppingListJson {
+    fn from(value: ShoppingList) -> Self {
+        let aisles = Aisle::ALL
+            .into_iter()
+            .filter_map(|aisle| {
+                let items = value
+                    .items()
+                    .iter()
+                    .filter(|item| item.aisle() == aisle)
+                    .map(ShoppingItemJson::from)
+                    .collect::<Vec<ShoppingItemJson>>();
+                (!items.is_empty()).then_some(AisleJson {
+                    aisle: aisle.as_str(),
+                    items,
+                })
+            })
+            .collect();
+        Self {
+            uuid: value.uuid(),
+            name: value.name().to_string(),
+            aisles,
+        }
+    }
+}
+
+fn error_response(error: ManageShoppingListsServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let (status, recipe) = match error {
+        ManageShoppingListsServiceError::InvalidName(_) => (StatusCode::BAD_REQUEST, None),
+        ManageShoppingListsServiceError::InvalidRange => (StatusCode::BAD_REQUEST, None),
+        ManageShoppingListsServiceError::InvalidServings => (StatusCode::BAD_REQUEST, None),
+        ManageShoppingListsServiceError::NoRecipes => (StatusCode::BAD_REQUEST, None),
+        ManageShoppingListsServiceError::TooManyRecipes => (StatusCode::BAD_REQUEST, None),
+        ManageShoppingListsServiceError::ListNotFound => (StatusCode::NOT_FOUND, None),
+        ManageShoppingListsServiceError::ItemNotFound => (StatusCode::NOT_FOUND, None),
+        ManageShoppingListsServiceError::RecipeNotFound(recipe) => {
+            (StatusCode::NOT_FOUND, Some(recipe))
+        }
+        ManageShoppingListsServiceError::InternalError => (StatusCode::INTERNAL_SERVER_ERROR, None),
+    };
+    let body = match recipe {
+        Some(recipe) => json!({
+            "error": format!("{}", error),
+            "recipe": recipe,
+        }),
+        None => json!({
+            "error": format!("{}", error)
+        }),
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(Json(body).to_string()))
+        .map_err(|e| e.into())
+}
+
+fn list_response(status: StatusCode, list: ShoppingList) -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!(ShoppingListJson::from(list))).to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynManageShoppingListsService = Arc<dyn ManageShoppingListsService + Sync + Send>;
+pub async fn list_lists_handler(
+    axum::extract::State(service): axum::extract::State<DynManageShoppingListsService>,
+    user: AuthenticatedUser,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.list_lists(user.caller()).await {
+        Ok(lists) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "shopping_lists": lists
+                        .into_iter()
+                        .map(ShoppingListJson::from)
+                        .collect::<Vec<ShoppingListJson>>(),
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn query_list_handler(
+    axum::extract::State(service): axum::extract::State<DynManageShoppingListsService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.query_list(user.caller(), identifier.0).await {
+        Ok(list) => list_response(StatusCode::OK, list),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn create_list_handler(
+    axum::extract::State(service): axum::extract::State<DynManageShoppingListsService>,
+    user: AuthenticatedUser,
+    json: Json<CreateShoppingListJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let json = json.0;
+    match service
+        .create_list(user.caller(), json.name, json.source.into())
+        .await
+    {
+        Ok(list) => list_response(StatusCode::CREATED, list),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn check_item_handler(
+    axum::extract::State(service): axum::extract::State<DynManageShoppingListsService>,
+    user: AuthenticatedUser,
+    identifiers: axum::extract::Path<(Uuid, Uuid)>,
+    json: Json<CheckItemJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let (list, item) = identifiers.0;
+    match service
+        .check_item(user.caller(), list, item, json.checked)
+        .await
+    {
+        Ok(item) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!(ShoppingItemJson::from(&item))).to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn delete_list_handler(
+    axum::extract::State(service): axum::extract::State<DynManageShoppingListsService>,
+    user: AuthenticatedUser,
+    identifier: axum::extract::Path<Uuid>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.delete_list(user.caller(), identifier.0).await {
+        Ok(()) => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(body::boxed(BoxBody::default()))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{get, put},
+    Router,
+};
+
+use crate::{
+    data_storage::{
+        meal_plans::meal_plans_sqlite_ds::MealPlanSqliteDS,
+        recipes::recipes_sqlite_ds::RecipeSqliteDS,
+        shopping_lists::shopping_lists_sqlite_ds::ShoppingListSqliteDS,
+    },
+    services::shopping_lists::manage_shopping_lists_service::ManageShoppingLists,
+    state::State,
+};
+
+use self::manage_shopping_lists_handler::DynManageShoppingListsService;
+
+pub mod manage_shopping_lists_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = ShoppingListSqliteDS::new(state.pool());
+    let recipes = RecipeSqliteDS::new(state.pool());
+    let plans = MealPlanSqliteDS::new(state.pool());
+
+    let manage_shopping_lists_service = Arc::new(ManageShoppingLists::new(storage, recipes, plans))
+        as DynManageShoppingListsService;
+
+    let shopping_list_routes = Router::new()
+        .route(
+            "/",
+            get(manage_shopping_lists_handler::list_lists_handler)
+                .post(manage_shopping_lists_handler::create_list_handler),
+        )
+        .route(
+            "/:identifier",
+            get(manage_shopping_lists_handler::query_list_handler)
+                .delete(manage_shopping_lists_handler::delete_list_handler),
+        )
+        .route(
+            "/:identifier/items/:item",
+            put(manage_shopping_lists_handler::check_item_handler),
+        )
+        .with_state(manage_shopping_lists_service);
+
+    let shopping_list_router = Router::new().nest("/shopping-lists", shopping_list_routes);
+    Router::new().nest("/api/v1", shopping_list_router)
+}
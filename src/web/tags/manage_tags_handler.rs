@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::tags::{
+        domain::tag::Tag,
+        ports::incoming::manage_tags_service::{ManageTagsService, ManageTagsServiceError},
+    },
+    web::users::authenticated_user::{AuthenticatedUser, OptionalUser},
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TagNameJson {
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagJson {
+    name: String,
+    recipes: u32,
+}
+
+impl From<Tag> for TagJson {
+    fn from(value: Tag) -> Self {
+        Self {
+            name: value.name().to_string(),
+            recipes: value.recipes(),
+        }
+    }
+}
+
+fn error_response(error: ManageTagsServiceError) -> Result<Response<BoxBody>, YaissError> {
+    let status = match error {
+        ManageTagsServiceError::InvalidTag(_) => StatusCode::BAD_REQUEST,
+        ManageTagsServiceError::TagNotFound => StatusCode::NOT_FOUND,
+        ManageTagsServiceError::TagExists => StatusCode::CONFLICT,
+        ManageTagsServiceError::Forbidden => StatusCode::FORBIDDEN,
+        ManageTagsServiceError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(
+            Json(json!({
+                "error": format!("{}", error)
+            }))
+            .to_string(),
+        ))
+        .map_err(|e| e.into())
+}
+
+fn tag_response(status: StatusCode, tag: Tag) -> Result<Response<BoxBody>, YaissError> {
+    Response::builder()
+        .status(status)
+        .header(axum::http::header::CONTENT_TYPE, "application/json")
+        .body(body::boxed(Json(json!(TagJson::from(tag))).to_string()))
+        .map_err(|e| e.into())
+}
+
+pub(crate) type DynManageTagsService = Arc<dyn ManageTagsService + Sync + Send>;
+pub async fn list_tags_handler(
+    axum::extract::State(service): axum::extract::State<DynManageTagsService>,
+    user: OptionalUser,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.list_tags(user.caller()).await {
+        Ok(tags) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "tags": tags.into_iter().map(TagJson::from).collect::<Vec<TagJson>>()
+                }))
+                .to_string(),
+            ))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn create_tag_handler(
+    axum::extract::State(service): axum::extract::State<DynManageTagsService>,
+    user: AuthenticatedUser,
+    json: Json<TagNameJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.create_tag(user.caller(), json.0.name).await {
+        Ok(tag) => tag_response(StatusCode::CREATED, tag),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn rename_tag_handler(
+    axum::extract::State(service): axum::extract::State<DynManageTagsService>,
+    user: AuthenticatedUser,
+    name: axum::extract::Path<String>,
+    json: Json<TagNameJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.rename_tag(user.caller(), name.0, json.0.name).await {
+        Ok(tag) => tag_response(StatusCode::OK, tag),
+        Err(error) => error_response(error),
+    }
+}
+
+pub async fn delete_tag_handler(
+    axum::extract::State(service): axum::extract::State<DynManageTagsService>,
+    user: AuthenticatedUser,
+    name: axum::extract::Path<String>,
+) -> Result<Response<BoxBody>, YaissError> {
+    match service.delete_tag(user.caller(), name.0).await {
+        Ok(()) => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(body::boxed(BoxBody::default()))
+            .map_err(|e| e.into()),
+        Err(error) => error_response(error),
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{get, put},
+    Router,
+};
+
+use crate::{
+    data_storage::tags::tags_sqlite_ds::TagSqliteDS,
+    services::tags::manage_tags_service::ManageTags, state::State,
+};
+
+use self::manage_tags_handler::DynManageTagsService;
+
+pub mod manage_tags_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = TagSqliteDS::new(state.pool());
+
+    let manage_tags_service = Arc::new(ManageTags::new(storage)) as DynManageTagsService;
+
+    let tags_routes = Router::new()
+        .route(
+            "/",
+            get(manage_tags_handler::list_tags_handler)
+                .post(manage_tags_handler::create_tag_handler),
+        )
+        .route(
+            "/:name",
+            put(manage_tags_handler::rename_tag_handler)
+                .delete(manage_tags_handler::delete_tag_handler),
+        )
+        .with_state(manage_tags_service);
+
+    let tags_router = Router::new().nest("/tags", tags_routes);
+    Router::new().nest("/api/v1", tags_router)
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use axum::{
+    body::{self, BoxBody},
+    extract::FromRequestParts,
+    http::{header, request::Parts, Response, StatusCode},
+    Json,
+};
+use serde_json::json;
+
+use crate::services::users::domain::caller::Caller;
+
+use super::identity::DynIdentityExtractor;
+
+/// The caller of a request identified by the configured identity extractor.
+/// Handlers taking it answer 401 to anonymous requests.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticatedUser(Caller);
+
+impl AuthenticatedUser {
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.0.user()
+    }
+
+    pub fn caller(&self) -> Caller {
+        self.0
+    }
+}
+
+/// Like [`AuthenticatedUser`], for handlers that also serve anonymous
+/// requests. Credentials that are given but invalid are still refused.
+#[derive(Debug, Clone, Copy)]
+pub struct OptionalUser(Option<Caller>);
+
+impl OptionalUser {
+    pub fn caller(&self) -> Option<Caller> {
+        self.0
+    }
+}
+
+fn unauthorized(message: String) -> Response<BoxBody> {
+    Response::builder()
+        .status(StatusCode::UNAUTHORIZED)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::WWW_AUTHENTICATE, "Bearer")
+        .body(body::boxed(
+            Json(json!({
+                "error": message
+            }))
+            .to_string(),
+        ))
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuthenticatedUser
+where
+    S: Send + Sync,
+{
+    type Rejection = Response<BoxBody>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        OptionalUser::from_request_parts(parts, state)
+            .await?
+            .caller()
+            .map(AuthenticatedUser)
+            .ok_or_else(|| unauthorized("Missing credentials".to_string()))
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for OptionalUser
+where
+    S: Send + Sync,
+{
+    type Rejection = Response<BoxBody>;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // The extractor is put in the request extensions by the server router.
+        let Some(identity) = parts.extensions.get::<DynIdentityExtractor>() else {
+            return Err(Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(BoxBody::default())
+                .unwrap_or_default());
+        };
+        identity
+            .identify(parts)
+            .map(OptionalUser)
+            .map_err(|e| unauthorized(e.to_string()))
+    }
+}
@@ -0,0 +1,143 @@
+use std::{error::Error, fmt::Display, sync::Arc};
+
+use axum::http::{header, request::Parts, HeaderName};
+
+use crate::services::users::{
+    domain::{
+        caller::{Caller, Role},
+        session::TokenSigner,
+    },
+    login_service::unix_now,
+};
+
+pub const USER_ID_HEADER: HeaderName = HeaderName::from_static("x-user-id");
+pub const USER_ROLE_HEADER: HeaderName = HeaderName::from_static("x-user-role");
+
+#[derive(Debug, PartialEq)]
+pub struct IdentityError(String);
+
+impl Display for IdentityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for IdentityError {}
+
+/// Establishes who a request is made by. The server puts the configured
+/// implementation in the request extensions.
+pub trait IdentityExtractor {
+    /// `Ok(None)` for anonymous requests, an error when credentials are given
+    /// but cannot be trusted.
+    fn identify(&self, parts: &Parts) -> Result<Option<Caller>, IdentityError>;
+}
+
+pub(crate) type DynIdentityExtractor = Arc<dyn IdentityExtractor + Sync + Send>;
+
+/// Identifies callers by the session token they got from logging in.
+pub struct BearerTokenIdentity {
+    signer: TokenSigner,
+}
+
+impl BearerTokenIdentity {
+    pub fn new(signer: TokenSigner) -> Self {
+        Self { signer }
+    }
+}
+
+impl IdentityExtractor for BearerTokenIdentity {
+    fn identify(&self, parts: &Parts) -> Result<Option<Caller>, IdentityError> {
+        let Some(value) = parts.headers.get(header::AUTHORIZATION) else {
+            return Ok(None);
+        };
+        let token = value
+            .to_str()
+            .ok()
+            .and_then(|value| value.split_once(' '))
+            .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+            .map(|(_, token)| token.trim())
+            .ok_or_else(|| IdentityError("Malformed bearer token".to_string()))?;
+        self.signer
+            .verify(token, unix_now())
+            .map(|claims| Some(claims.caller()))
+            .map_err(|e| IdentityError(e.to_string()))
+    }
+}
+
+/// Trusts the `X-User-Id` and `X-User-Role` headers set by a gateway that
+/// has already authenticated the caller. Only safe when every request goes
+/// through that gateway and it drops these headers from client requests.
+pub struct TrustedHeaderIdentity;
+
+impl IdentityExtractor for TrustedHeaderIdentity {
+    fn identify(&self, parts: &Parts) -> Result<Option<Caller>, IdentityError> {
+        let Some(user) = parts.headers.get(USER_ID_HEADER) else {
+            return Ok(None);
+        };
+        let user = user
+            .to_str()
+            .ok()
+            .and_then(|user| uuid::Uuid::parse_str(user.trim()).ok())
+            .ok_or_else(|| IdentityError(format!("Malformed {} header", USER_ID_HEADER)))?;
+        let role = match parts.headers.get(USER_ROLE_HEADER) {
+            Some(role) => role
+                .to_str()
+                .ok()
+                .and_then(|role| role.trim().parse::<Role>().ok())
+                .ok_or_else(|| IdentityError(format!("Malformed {} header", USER_ROLE_HEADER)))?,
+            None => Role::default(),
+        };
+        Ok(Some(Caller::new(user, role)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+
+    fn parts(headers: &[(&str, &str)]) -> Parts {
+        let mut request = Request::builder();
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        request.body(()).unwrap().into_parts().0
+    }
+
+    #[test]
+    fn bearer_tokens_identify_their_caller() {
+        let signer = TokenSigner::new(b"secret", 60);
+        let caller = Caller::new(uuid::Uuid::new_v4(), Role::Member);
+        let token = signer.issue(caller, unix_now());
+        let identity = BearerTokenIdentity::new(signer);
+
+        let header = format!("Bearer {}", token.as_str());
+        assert_eq!(
+            identity.identify(&parts(&[("authorization", &header)])),
+            Ok(Some(caller))
+        );
+        assert_eq!(identity.identify(&parts(&[])), Ok(None));
+        assert!(identity
+            .identify(&parts(&[("authorization", "Basic abc")]))
+            .is_err());
+    }
+
+    #[test]
+    fn trusted_headers_identify_their_caller() {
+        let user = uuid::Uuid::new_v4();
+        let id = user.to_string();
+
+        assert_eq!(
+            TrustedHeaderIdentity.identify(&parts(&[("x-user-id", &id)])),
+            Ok(Some(Caller::new(user, Role::Member)))
+        );
+        assert_eq!(
+            TrustedHeaderIdentity.identify(&parts(&[("x-user-id", &id), ("x-user-role", "admin")])),
+            Ok(Some(Caller::new(user, Role::Admin)))
+        );
+        assert_eq!(TrustedHeaderIdentity.identify(&parts(&[])), Ok(None));
+        assert!(TrustedHeaderIdentity
+            .identify(&parts(&[("x-user-id", "nobody")]))
+            .is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::users::ports::incoming::login_service::{LoginService, LoginServiceError},
+    web::users::register_user_handler::CredentialsJson,
+};
+
+pub(crate) type DynLoginService = Arc<dyn LoginService + Sync + Send>;
+pub async fn login_handler(
+    axum::extract::State(service): axum::extract::State<DynLoginService>,
+    json: Json<CredentialsJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let CredentialsJson { username, password } = json.0;
+    let builder = match service.login(username, password).await {
+        Ok(token) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .header(axum::http::header::CACHE_CONTROL, "no-store")
+            .body(body::boxed(
+                Json(json!({
+                    "token": token.as_str(),
+                    "token_type": "Bearer",
+                    "user": token.claims().user(),
+                    "expires_at": token.claims().expires_at(),
+                }))
+                .to_string(),
+            )),
+        Err(LoginServiceError::InvalidCredentials) => Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", LoginServiceError::InvalidCredentials)
+                }))
+                .to_string(),
+            )),
+        Err(LoginServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", LoginServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    routing::{get, post},
+    Router,
+};
+
+use crate::{
+    data_storage::users::users_sqlite_ds::UserSqliteDS,
+    services::users::{
+        login_service::Login, query_user_service::QueryUser, register_user_service::RegisterUser,
+    },
+    state::State,
+};
+
+use self::{
+    login_handler::DynLoginService, query_user_handler::DynQueryUserService,
+    register_user_handler::DynRegisterUserService,
+};
+
+pub mod authenticated_user;
+pub mod identity;
+pub mod login_handler;
+pub mod query_user_handler;
+pub mod register_user_handler;
+
+pub fn router(state: State) -> Router<(), Body> {
+    let storage = UserSqliteDS::new(state.pool());
+
+    let register_user_service =
+        Arc::new(RegisterUser::new(storage.clone())) as DynRegisterUserService;
+    let login_service =
+        Arc::new(Login::new(storage.clone(), state.token_signer())) as DynLoginService;
+    let query_user_service = Arc::new(QueryUser::new(storage)) as DynQueryUserService;
+
+    let users_routes = Router::new()
+        .route("/", post(register_user_handler::register_user_handler))
+        .with_state(register_user_service)
+        .route("/login", post(login_handler::login_handler))
+        .with_state(login_service)
+        .route("/me", get(query_user_handler::query_user_handler))
+        .with_state(query_user_service);
+
+    let users_router = Router::new().nest("/users", users_routes);
+    Router::new().nest("/api/v1", users_router)
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::users::ports::incoming::query_user_service::{
+        QueryUserService, QueryUserServiceError,
+    },
+    web::users::{authenticated_user::AuthenticatedUser, register_user_handler::UserJson},
+};
+
+pub(crate) type DynQueryUserService = Arc<dyn QueryUserService + Sync + Send>;
+pub async fn query_user_handler(
+    axum::extract::State(service): axum::extract::State<DynQueryUserService>,
+    user: AuthenticatedUser,
+) -> Result<Response<BoxBody>, YaissError> {
+    let builder = match service.query_user(user.uuid()).await {
+        Ok(user) => Response::builder()
+            .status(StatusCode::OK)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(Json(json!(UserJson::from(user))).to_string())),
+        Err(QueryUserServiceError::UserNotFound) => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", QueryUserServiceError::UserNotFound)
+                }))
+                .to_string(),
+            )),
+        Err(QueryUserServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", QueryUserServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}
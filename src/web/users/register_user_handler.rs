@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use axum::{
+    body::{self, BoxBody},
+    http::{Response, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    error::YaissError,
+    services::users::{
+        domain::user::User,
+        ports::incoming::register_user_service::{RegisterUserService, RegisterUserServiceError},
+    },
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CredentialsJson {
+    pub(crate) username: String,
+    pub(crate) password: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UserJson {
+    uuid: uuid::Uuid,
+    username: String,
+    role: &'static str,
+}
+
+impl From<User> for UserJson {
+    fn from(value: User) -> Self {
+        Self {
+            uuid: value.uuid(),
+            username: value.username().to_string(),
+            role: value.role().as_str(),
+        }
+    }
+}
+
+pub(crate) type DynRegisterUserService = Arc<dyn RegisterUserService + Sync + Send>;
+pub async fn register_user_handler(
+    axum::extract::State(service): axum::extract::State<DynRegisterUserService>,
+    json: Json<CredentialsJson>,
+) -> Result<Response<BoxBody>, YaissError> {
+    let CredentialsJson { username, password } = json.0;
+    let builder = match service.register_user(username, password).await {
+        Ok(user) => Response::builder()
+            .status(StatusCode::CREATED)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(Json(json!(UserJson::from(user))).to_string())),
+        Err(
+            error @ (RegisterUserServiceError::InvalidUsername
+            | RegisterUserServiceError::WeakPassword),
+        ) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", error)
+                }))
+                .to_string(),
+            )),
+        Err(RegisterUserServiceError::UsernameTaken) => Response::builder()
+            .status(StatusCode::CONFLICT)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", RegisterUserServiceError::UsernameTaken)
+                }))
+                .to_string(),
+            )),
+        Err(RegisterUserServiceError::InternalError) => Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .header(axum::http::header::CONTENT_TYPE, "application/json")
+            .body(body::boxed(
+                Json(json!({
+                    "error": format!("{}", RegisterUserServiceError::InternalError)
+                }))
+                .to_string(),
+            )),
+    };
+    builder.map_err(|e| e.into())
+}